@@ -1,38 +1,288 @@
+use crate::config::{self, Profile};
 use crate::error::Error;
-use std::process::Command;
-
-/// Load the Linear API key from environment or macOS Keychain.
-pub fn load_api_key() -> Result<String, Error> {
-    // 1. Check environment variable
-    if let Ok(key) = std::env::var("LINEAR_API_KEY") {
-        if !key.is_empty() {
-            return Ok(key);
-        }
-    }
+use crate::oauth::{OAuthApp, OAuthSession};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+const DEFAULT_ENV_VAR: &str = "LINEAR_API_KEY";
+const DEFAULT_SERVICE: &str = "linear-api-key";
+/// Environment variable naming a key file (e.g. a systemd credential).
+const KEY_FILE_ENV_VAR: &str = "LINEAR_API_KEY_FILE";
+
+/// How the server authenticates to Linear.
+pub enum Credential {
+    ApiKey(String),
+    OAuth(OAuthSession),
+}
+
+/// Load a Linear credential from the first source that has one.
+///
+/// Sources are tried in order: environment variable, stored OAuth tokens
+/// (when the profile has an OAuth app), `key_command`, key file, macOS
+/// Keychain, then the freedesktop Secret Service. The source used is logged;
+/// if none works, the error lists why each one failed.
+pub fn load_credential(profile: &Profile) -> Result<Credential, Error> {
+    let env_var = profile.api_key_env.as_deref().unwrap_or(DEFAULT_ENV_VAR);
+    let service = profile
+        .keychain_service
+        .as_deref()
+        .unwrap_or(DEFAULT_SERVICE);
+
+    let key_file = key_file_path(profile);
 
-    // 2. Try macOS Keychain
+    // Sources are only tried until one succeeds, so e.g. `pass` does not
+    // prompt for a GPG passphrase when the environment already has a key.
+    type Source<'a> = (String, Box<dyn FnOnce() -> Result<Credential, String> + 'a>);
+    let api_key = |result: Result<String, String>| result.map(Credential::ApiKey);
+    let mut sources: Vec<Source> = vec![(
+        format!("env {}", env_var),
+        Box::new(move || api_key(from_env(env_var))),
+    )];
+    match OAuthApp::from_profile(profile) {
+        Ok(Some(app)) => sources.push((
+            app.store_description(),
+            Box::new(move || {
+                let tokens = app.load_tokens()?;
+                Ok(Credential::OAuth(OAuthSession::new(app, tokens)))
+            }),
+        )),
+        Ok(None) => {}
+        Err(reason) => sources.push(("OAuth token file".into(), Box::new(move || Err(reason)))),
+    }
+    if let Some(ref command) = profile.key_command {
+        sources.push((
+            format!("key_command `{}`", command),
+            Box::new(move || api_key(from_command(command))),
+        ));
+    }
+    match key_file {
+        Some(ref path) => sources.push((
+            format!("key file {}", path.display()),
+            Box::new(move || api_key(read_secret_file(path))),
+        )),
+        None => sources.push((
+            "key file".into(),
+            Box::new(|| Err("no config directory".into())),
+        )),
+    }
     if cfg!(target_os = "macos") {
-        if let Ok(key) = keychain_get("linear-api-key") {
-            return Ok(key);
+        sources.push((
+            format!("macOS Keychain '{}'", service),
+            Box::new(move || api_key(keychain_get(service))),
+        ));
+    } else if cfg!(unix) {
+        sources.push((
+            format!("Secret Service '{}'", service),
+            Box::new(move || api_key(secret_service_get(service))),
+        ));
+    }
+
+    let mut failures = Vec::new();
+    for (source, fetch) in sources {
+        match fetch() {
+            Ok(credential) => {
+                for failure in &failures {
+                    tracing::debug!("Skipped key source {}", failure);
+                }
+                tracing::info!("Using Linear credentials from {}", source);
+                return Ok(credential);
+            }
+            Err(reason) => failures.push(format!("{}: {}", source, reason)),
         }
     }
+    Err(Error::Auth(failures.join("\n  ")))
+}
 
-    Err(Error::Auth)
+fn from_env(var: &str) -> Result<String, String> {
+    match std::env::var(var) {
+        Ok(key) => non_empty(key),
+        Err(_) => Err("not set".into()),
+    }
 }
 
-fn keychain_get(service: &str) -> Result<String, Error> {
-    let output = Command::new("security")
-        .args(["find-generic-password", "-s", service, "-w"])
+/// Run `command` through the shell and take the first line of its output,
+/// which is where `pass` and most password managers print the secret.
+fn from_command(command: &str) -> Result<String, String> {
+    let output = Command::new("sh")
+        .args(["-c", command])
         .output()
-        .map_err(|_| Error::Auth)?;
+        .map_err(|e| format!("could not run: {}", e))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("exited with {}: {}", output.status, stderr.trim()));
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    non_empty(stdout.lines().next().unwrap_or_default().to_string())
+}
+
+/// `key_file` from the profile, then `$LINEAR_API_KEY_FILE`, then
+/// `<config dir>/api-key`.
+fn key_file_path(profile: &Profile) -> Option<PathBuf> {
+    profile
+        .key_file
+        .clone()
+        .or_else(|| std::env::var_os(KEY_FILE_ENV_VAR).map(PathBuf::from))
+        .or_else(|| Some(config::config_dir()?.join("api-key")))
+}
+
+/// Read a secret from a file that only its owner can read.
+pub fn read_secret_file(path: &Path) -> Result<String, String> {
+    let metadata = match std::fs::metadata(path) {
+        Ok(metadata) => metadata,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Err("not found".into()),
+        Err(e) => return Err(e.to_string()),
+    };
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = metadata.permissions().mode() & 0o777;
+        if mode & 0o077 != 0 {
+            return Err(format!(
+                "permissions {:o} are too open; run `chmod 600 {}`",
+                mode,
+                path.display()
+            ));
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = metadata;
+    let key = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+    non_empty(key.trim().to_string())
+}
+
+/// Write a secret to `path` with mode 600, creating parent directories.
+pub fn write_secret_file(path: &Path, secret: &str) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    }
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(path).map_err(|e| e.to_string())?;
+    // `mode` only applies to new files; tighten one that already existed.
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(std::fs::Permissions::from_mode(0o600))
+            .map_err(|e| e.to_string())?;
+    }
+    file.write_all(secret.as_bytes()).map_err(|e| e.to_string())
+}
+
+/// Look up `service` in the platform credential store.
+pub fn keychain_or_secret_service_get(service: &str) -> Result<String, String> {
+    if cfg!(target_os = "macos") {
+        keychain_get(service)
+    } else {
+        secret_service_get(service)
+    }
+}
 
+/// Store `secret` under `service` in the platform credential store,
+/// replacing any existing entry.
+pub fn keychain_or_secret_service_set(service: &str, secret: &str) -> Result<(), String> {
+    let (program, args): (&str, Vec<&str>) = if cfg!(target_os = "macos") {
+        (
+            "security",
+            // `-w` with no value last prompts for the secret, so it never
+            // appears in the process list.
+            vec![
+                "add-generic-password",
+                "-U",
+                "-s",
+                service,
+                "-a",
+                "linear-mcp",
+                "-w",
+            ],
+        )
+    } else {
+        (
+            "secret-tool",
+            vec!["store", "--label", service, "service", service],
+        )
+    };
+    // `security` asks twice, each answer ending at a newline; secret-tool
+    // reads to the end of input.
+    let input = if cfg!(target_os = "macos") {
+        format!("{secret}\n{secret}\n")
+    } else {
+        secret.to_string()
+    };
+    let mut child = Command::new(program)
+        .args(&args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("could not run `{}`: {}", program, e))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        let _ = stdin.write_all(input.as_bytes());
+    }
+    let output = child.wait_with_output().map_err(|e| e.to_string())?;
     if output.status.success() {
-        let key = String::from_utf8_lossy(&output.stdout).trim().to_string();
-        if key.is_empty() {
-            return Err(Error::Auth);
+        Ok(())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+fn keychain_get(service: &str) -> Result<String, String> {
+    run_helper("security", &["find-generic-password", "-s", service, "-w"])
+}
+
+/// Look up the key with `secret-tool`, matching items stored with
+/// `secret-tool store --label=... service <service>`.
+fn secret_service_get(service: &str) -> Result<String, String> {
+    run_helper("secret-tool", &["lookup", "service", service])
+}
+
+fn run_helper(program: &str, args: &[&str]) -> Result<String, String> {
+    let output = Command::new(program).args(args).output().map_err(|e| {
+        if e.kind() == std::io::ErrorKind::NotFound {
+            format!("`{}` is not installed", program)
+        } else {
+            format!("could not run `{}`: {}", program, e)
         }
-        Ok(key)
+    })?;
+
+    if output.status.success() {
+        non_empty(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    } else {
+        Err("no matching entry".into())
+    }
+}
+
+fn non_empty(key: String) -> Result<String, String> {
+    if key.is_empty() {
+        Err("empty".into())
     } else {
-        Err(Error::Auth)
+        Ok(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    #[test]
+    fn rewriting_a_secret_file_makes_it_private() {
+        use std::os::unix::fs::PermissionsExt;
+        let path = std::env::temp_dir().join(format!("linear-mcp-secret-{}", std::process::id()));
+        std::fs::write(&path, "old").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).unwrap();
+        assert!(read_secret_file(&path).unwrap_err().contains("too open"));
+
+        write_secret_file(&path, "new").unwrap();
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode() & 0o777;
+        assert_eq!(mode, 0o600);
+        assert_eq!(read_secret_file(&path).unwrap(), "new");
+        std::fs::remove_file(&path).unwrap();
     }
 }
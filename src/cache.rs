@@ -1,56 +1,727 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::future::Future;
-use std::sync::Arc;
-use tokio::sync::RwLock;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
-/// Maximum number of entries before the oldest entries are evicted.
-const MAX_CACHE_ENTRIES: usize = 10_000;
+use serde::{Deserialize, Serialize};
+use tokio::sync::watch;
 
-/// Simple in-memory cache for entity lookups with a max-entry cap.
-/// Session-scoped (lives as long as the MCP server process).
-/// When the cache exceeds MAX_CACHE_ENTRIES, it is cleared to prevent
-/// unbounded memory growth in long-lived sessions.
+use crate::pipeline::Request;
+
+/// Default maximum number of cached lookups.
+pub const MAX_CACHE_ENTRIES: usize = 10_000;
+
+/// How often a persistent cache is written to disk while entries change.
+const SAVE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// How long the name lists offered for argument completion are kept.
+const NAMES_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// What a cached lookup resolves. Each kind has its own time to live.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EntityKind {
+    /// Issue identifier (`ENG-123`) → issue UUID.
+    Issue,
+    /// Issue UUID → team key.
+    IssueTeam,
+    /// Team key → team UUID.
+    Team,
+    /// Team UUID → team key.
+    TeamKey,
+    /// User email → user UUID.
+    User,
+    /// Team key and workflow state name → state UUID.
+    State,
+    /// Team key (or `*`) and label name → label UUID.
+    Label,
+    /// Project name → project UUID.
+    Project,
+    /// Project UUID and milestone name → milestone UUID.
+    Milestone,
+    /// Initiative name → initiative UUID.
+    Initiative,
+    /// Team key and cycle number or name → cycle UUID.
+    Cycle,
+    /// Custom view name → view UUID.
+    View,
+    /// Document title → document UUID.
+    Document,
+}
+
+impl EntityKind {
+    pub const ALL: [EntityKind; 13] = [
+        EntityKind::Issue,
+        EntityKind::IssueTeam,
+        EntityKind::Team,
+        EntityKind::TeamKey,
+        EntityKind::User,
+        EntityKind::State,
+        EntityKind::Label,
+        EntityKind::Project,
+        EntityKind::Milestone,
+        EntityKind::Initiative,
+        EntityKind::Cycle,
+        EntityKind::View,
+        EntityKind::Document,
+    ];
+
+    /// Prefix of this kind's keys in the cache.
+    pub fn name(self) -> &'static str {
+        match self {
+            EntityKind::Issue => "issue",
+            EntityKind::IssueTeam => "issue_team",
+            EntityKind::Team => "team",
+            EntityKind::TeamKey => "team_key",
+            EntityKind::User => "user",
+            EntityKind::State => "state",
+            EntityKind::Label => "label",
+            EntityKind::Project => "project",
+            EntityKind::Milestone => "milestone",
+            EntityKind::Initiative => "initiative",
+            EntityKind::Cycle => "cycle",
+            EntityKind::View => "view",
+            EntityKind::Document => "document",
+        }
+    }
+
+    /// Keys that rarely change are kept longer. Issues can be moved to
+    /// another team by anyone, so their team is only trusted briefly.
+    pub fn default_ttl(self) -> Duration {
+        match self {
+            EntityKind::Issue | EntityKind::Team | EntityKind::TeamKey | EntityKind::User => {
+                Duration::from_secs(24 * 3600)
+            }
+            EntityKind::IssueTeam => Duration::from_secs(5 * 60),
+            EntityKind::State
+            | EntityKind::Label
+            | EntityKind::Project
+            | EntityKind::Milestone
+            | EntityKind::Initiative
+            | EntityKind::Cycle
+            | EntityKind::View
+            | EntityKind::Document => Duration::from_secs(3600),
+        }
+    }
+
+    /// Kinds whose entries a successful mutation may have made stale, and
+    /// kinds to drop entirely because their keys embed the changed entity.
+    fn invalidated_by(operation: &str) -> (&'static [EntityKind], &'static [EntityKind]) {
+        match operation {
+            "UpdateIssue" | "BatchUpdateIssues" | "ArchiveIssue" | "DeleteIssue" => {
+                (&[EntityKind::Issue, EntityKind::IssueTeam], &[])
+            }
+            "UpdateTeam" | "DeleteTeam" => (
+                &[EntityKind::Team, EntityKind::TeamKey],
+                &[
+                    EntityKind::Issue,
+                    EntityKind::IssueTeam,
+                    EntityKind::State,
+                    EntityKind::Label,
+                ],
+            ),
+            "UpdateUser" => (&[EntityKind::User], &[]),
+            "UpdateWorkflowState" | "ArchiveWorkflowState" => (&[EntityKind::State], &[]),
+            "UpdateLabel" | "DeleteLabel" => (&[EntityKind::Label], &[]),
+            "UpdateProject" | "ArchiveProject" | "DeleteProject" => {
+                (&[EntityKind::Project], &[EntityKind::Milestone])
+            }
+            "UpdateProjectMilestone" | "DeleteProjectMilestone" => (&[EntityKind::Milestone], &[]),
+            "UpdateInitiative" | "ArchiveInitiative" | "DeleteInitiative" => {
+                (&[EntityKind::Initiative], &[])
+            }
+            "UpdateCycle" | "ArchiveCycle" => (&[EntityKind::Cycle], &[]),
+            "UpdateView" | "DeleteView" => (&[EntityKind::View], &[]),
+            "UpdateDocument" | "DeleteDocument" => (&[EntityKind::Document], &[]),
+            _ => (&[], &[]),
+        }
+    }
+}
+
+/// Whether a successful mutation may have added, renamed or removed
+/// something offered for argument completion.
+fn changes_names(operation: &str) -> bool {
+    matches!(
+        operation,
+        "CreateTeam"
+            | "UpdateTeam"
+            | "DeleteTeam"
+            | "UnarchiveTeam"
+            | "UpdateUser"
+            | "CreateWorkflowState"
+            | "UpdateWorkflowState"
+            | "ArchiveWorkflowState"
+            | "CreateLabel"
+            | "UpdateLabel"
+            | "DeleteLabel"
+            | "CreateProject"
+            | "UpdateProject"
+            | "ArchiveProject"
+            | "UnarchiveProject"
+            | "DeleteProject"
+            | "CreateCycle"
+            | "UpdateCycle"
+            | "ArchiveCycle"
+            | "CreateTemplate"
+            | "UpdateTemplate"
+            | "DeleteTemplate"
+    )
+}
+
+/// Limits, lifetimes, and storage of an `EntityCache`.
+#[derive(Debug, Clone)]
+pub struct CacheSettings {
+    pub max_entries: usize,
+    pub ttls: HashMap<EntityKind, Duration>,
+    /// File the cache is loaded from and saved to; in memory only when unset.
+    pub path: Option<PathBuf>,
+}
+
+impl Default for CacheSettings {
+    fn default() -> Self {
+        Self {
+            max_entries: MAX_CACHE_ENTRIES,
+            ttls: HashMap::new(),
+            path: None,
+        }
+    }
+}
+
+impl CacheSettings {
+    fn ttl(&self, kind: EntityKind) -> Duration {
+        self.ttls
+            .get(&kind)
+            .copied()
+            .unwrap_or_else(|| kind.default_ttl())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Entry {
+    value: String,
+    /// UTC epoch seconds.
+    expires_at: u64,
+    /// Recency stamp for LRU eviction.
+    #[serde(skip)]
+    last_used: u64,
+}
+
+struct Inner {
+    entries: HashMap<String, Entry>,
+    /// Keys of `entries` by their `last_used` stamp, least recent first.
+    recency: BTreeMap<u64, String>,
+    settings: CacheSettings,
+    /// Incremented on every access; the source of `Entry::last_used`.
+    clock: u64,
+    dirty: bool,
+    last_saved: Instant,
+    /// Lookups waiting to be sent together, per kind.
+    batches: HashMap<EntityKind, PendingBatch>,
+    /// Name lists for argument completion, by scope; never persisted.
+    names: HashMap<String, (Instant, Arc<Vec<String>>)>,
+}
+
+/// Outcome of a batch shared with its waiters: the keys found, or `None`
+/// if the fetch failed (each waiter then retries its own keys).
+type BatchResult = Option<Arc<Option<HashMap<String, String>>>>;
+
+/// Keys collected for one batched fetch that has not been sent yet.
+struct PendingBatch {
+    keys: Vec<String>,
+    done: watch::Sender<BatchResult>,
+}
+
+/// Removes a leader's batch from `Inner::batches` unless taken, so that a
+/// leader cancelled before sending it closes the channel its joiners wait on.
+struct PendingGuard<'a> {
+    cache: &'a EntityCache,
+    kind: EntityKind,
+    taken: bool,
+}
+
+impl PendingGuard<'_> {
+    fn take(mut self) -> Option<PendingBatch> {
+        self.taken = true;
+        self.cache.lock().batches.remove(&self.kind)
+    }
+}
+
+impl Drop for PendingGuard<'_> {
+    fn drop(&mut self) {
+        if !self.taken {
+            self.cache.lock().batches.remove(&self.kind);
+        }
+    }
+}
+
+/// Cache for resolver lookups (names, keys, and identifiers to UUIDs) with
+/// per-kind TTLs and least-recently-used eviction. Optionally persisted to
+/// disk between sessions. Clones share the same entries.
 #[derive(Clone)]
-pub struct EntityCache<T: Clone> {
-    inner: Arc<RwLock<HashMap<String, T>>>,
+pub struct EntityCache {
+    inner: Arc<Mutex<Inner>>,
 }
 
-impl<T: Clone> Default for EntityCache<T> {
+impl Default for EntityCache {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl<T: Clone> EntityCache<T> {
+impl EntityCache {
     pub fn new() -> Self {
+        Self::with_settings(CacheSettings::default())
+    }
+
+    /// A cache with `settings`, pre-filled from `settings.path` if it exists.
+    pub fn with_settings(settings: CacheSettings) -> Self {
+        let entries = settings.path.as_deref().map(load).unwrap_or_default();
+        let mut inner = Inner {
+            entries,
+            recency: BTreeMap::new(),
+            settings,
+            clock: 0,
+            dirty: false,
+            last_saved: Instant::now(),
+            batches: HashMap::new(),
+            names: HashMap::new(),
+        };
+        inner.purge_expired();
+        for (key, entry) in inner.entries.iter_mut() {
+            inner.clock += 1;
+            entry.last_used = inner.clock;
+            inner.recency.insert(inner.clock, key.clone());
+        }
         Self {
-            inner: Arc::new(RwLock::new(HashMap::new())),
+            inner: Arc::new(Mutex::new(inner)),
+        }
+    }
+
+    /// A new, empty, in-memory cache with the same limits as this one.
+    pub fn empty_like(&self) -> Self {
+        let settings = CacheSettings {
+            path: None,
+            ..self.lock().settings.clone()
+        };
+        Self::with_settings(settings)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Inner> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub fn get(&self, kind: EntityKind, key: &str) -> Option<String> {
+        let mut inner = self.lock();
+        let key = cache_key(kind, key);
+        let now = unix_secs();
+        inner.clock += 1;
+        let clock = inner.clock;
+        let entry = inner.entries.get_mut(&key)?;
+        let previous = entry.last_used;
+        if entry.expires_at > now {
+            entry.last_used = clock;
+            let value = entry.value.clone();
+            inner.recency.remove(&previous);
+            inner.recency.insert(clock, key);
+            Some(value)
+        } else {
+            inner.entries.remove(&key);
+            inner.recency.remove(&previous);
+            None
         }
     }
 
-    /// Get a cached value or fetch and cache it.
-    pub async fn get_or_fetch<F, Fut, E>(&self, key: &str, fetch: F) -> Result<T, E>
+    pub fn insert(&self, kind: EntityKind, key: &str, value: String) {
+        let mut inner = self.lock();
+        let expires_at = unix_secs() + inner.settings.ttl(kind).as_secs();
+        let key = cache_key(kind, key);
+        match inner.entries.get(&key) {
+            Some(entry) => {
+                let previous = entry.last_used;
+                inner.recency.remove(&previous);
+            }
+            // Expired entries go too: nothing reads them, so they are the
+            // least recently used.
+            None => {
+                while inner.entries.len() >= inner.settings.max_entries.max(1) {
+                    inner.evict_least_recent();
+                }
+            }
+        }
+        inner.clock += 1;
+        let last_used = inner.clock;
+        inner.recency.insert(last_used, key.clone());
+        inner.entries.insert(
+            key,
+            Entry {
+                value,
+                expires_at,
+                last_used,
+            },
+        );
+        inner.dirty = true;
+        if inner.last_saved.elapsed() >= SAVE_INTERVAL {
+            inner.save();
+        }
+    }
+
+    /// Resolve `keys` DataLoader-style: cached keys are answered directly,
+    /// and the misses of every concurrent `load_many` call for `kind` are
+    /// collected and sent as a single `fetch`. Found values are cached;
+    /// keys `fetch` does not return come back as `None`.
+    ///
+    /// Concurrent callers must be polled together (e.g. via `join!`) to
+    /// share a batch.
+    pub async fn load_many<F, Fut, E>(
+        &self,
+        kind: EntityKind,
+        keys: &[String],
+        fetch: F,
+    ) -> Result<Vec<Option<String>>, E>
+    where
+        F: Fn(Vec<String>) -> Fut,
+        Fut: Future<Output = Result<HashMap<String, String>, E>>,
+    {
+        let mut values: Vec<Option<String>> = keys.iter().map(|k| self.get(kind, k)).collect();
+        let misses: Vec<String> = keys
+            .iter()
+            .zip(&values)
+            .filter(|(_, v)| v.is_none())
+            .map(|(k, _)| k.clone())
+            .collect();
+        if misses.is_empty() {
+            return Ok(values);
+        }
+
+        let (leader, mut done) = {
+            let mut inner = self.lock();
+            match inner.batches.get_mut(&kind) {
+                Some(batch) => {
+                    batch.keys.extend(misses.iter().cloned());
+                    (false, batch.done.subscribe())
+                }
+                None => {
+                    let (tx, rx) = watch::channel(None);
+                    let batch = PendingBatch {
+                        keys: misses.clone(),
+                        done: tx,
+                    };
+                    inner.batches.insert(kind, batch);
+                    (true, rx)
+                }
+            }
+        };
+
+        let found = if leader {
+            // Let the other lookups that are ready in this poll join in. If
+            // this future is dropped meanwhile, the guard clears the batch so
+            // its joiners fetch on their own rather than wait forever.
+            let pending = PendingGuard {
+                cache: self,
+                kind,
+                taken: false,
+            };
+            tokio::task::yield_now().await;
+            let batch = pending.take();
+            let mut keys = batch.as_ref().map(|b| b.keys.clone()).unwrap_or(misses);
+            keys.sort();
+            keys.dedup();
+            let result = fetch(keys).await;
+            let shared = result.as_ref().ok().cloned();
+            if let Some(batch) = batch {
+                batch.done.send_replace(Some(Arc::new(shared)));
+            }
+            result?
+        } else {
+            let shared = match done.wait_for(Option::is_some).await {
+                Ok(result) => result.clone().and_then(|r| (*r).clone()),
+                Err(_) => None,
+            };
+            match shared {
+                Some(found) => found,
+                None => fetch(misses).await?,
+            }
+        };
+
+        for (key, value) in keys.iter().zip(values.iter_mut()) {
+            if value.is_none()
+                && let Some(found) = found.get(key)
+            {
+                self.insert(kind, key, found.clone());
+                *value = Some(found.clone());
+            }
+        }
+        Ok(values)
+    }
+
+    /// Get a cached value or fetch and cache it. Failed fetches are not cached.
+    pub async fn get_or_fetch<F, Fut, E>(
+        &self,
+        kind: EntityKind,
+        key: &str,
+        fetch: F,
+    ) -> Result<String, E>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<String, E>>,
+    {
+        if let Some(value) = self.get(kind, key) {
+            return Ok(value);
+        }
+        let value = fetch().await?;
+        self.insert(kind, key, value.clone());
+        Ok(value)
+    }
+
+    /// The names listed under `scope` (e.g. `state:ENG`, a team's workflow
+    /// states) for argument completion, fetching them when missing or
+    /// older than ten minutes. Failed fetches and empty lists are not cached.
+    pub async fn names_or_fetch<F, Fut, E>(
+        &self,
+        scope: &str,
+        fetch: F,
+    ) -> Result<Arc<Vec<String>>, E>
     where
         F: FnOnce() -> Fut,
-        Fut: Future<Output = Result<T, E>>,
+        Fut: Future<Output = Result<Vec<String>, E>>,
     {
-        // Check cache first (read lock)
+        if let Some((fetched, names)) = self.lock().names.get(scope)
+            && fetched.elapsed() < NAMES_TTL
         {
-            let cache = self.inner.read().await;
-            if let Some(val) = cache.get(key) {
-                return Ok(val.clone());
+            return Ok(names.clone());
+        }
+        let names = Arc::new(fetch().await?);
+        if !names.is_empty() {
+            self.lock()
+                .names
+                .insert(scope.to_string(), (Instant::now(), names.clone()));
+        }
+        Ok(names)
+    }
+
+    /// Drop what a successful mutation may have made stale: entries of the
+    /// affected kinds that resolve to (or are keyed by) an id in its
+    /// `id`/`ids` variables, or every entry of those kinds when it has none.
+    pub fn invalidate_after(&self, request: &Request) {
+        if changes_names(request.operation_name()) {
+            self.lock().names.clear();
+        }
+        let (by_id, whole) = EntityKind::invalidated_by(request.operation_name());
+        if by_id.is_empty() && whole.is_empty() {
+            return;
+        }
+        let ids = mutation_ids(&request.variables);
+        let prefixes = |kinds: &[EntityKind]| -> Vec<String> {
+            kinds.iter().map(|k| format!("{}:", k.name())).collect()
+        };
+        let by_id = prefixes(by_id);
+        let whole = prefixes(whole);
+
+        let mut inner = self.lock();
+        let before = inner.entries.len();
+        inner.entries.retain(|key, entry| {
+            if whole.iter().any(|p| key.starts_with(p.as_str())) {
+                return false;
             }
+            if !by_id.iter().any(|p| key.starts_with(p.as_str())) {
+                return true;
+            }
+            !ids.is_empty()
+                && !ids
+                    .iter()
+                    .any(|id| entry.value == *id || key.ends_with(id.as_str()))
+        });
+        let removed = before - inner.entries.len();
+        if removed > 0 {
+            inner.reindex();
+            tracing::debug!(
+                "{} invalidated {} cached lookups",
+                request.operation_name(),
+                removed
+            );
+            inner.dirty = true;
         }
+    }
 
-        // Cache miss — fetch and store
-        let val = fetch().await?;
-        {
-            let mut cache = self.inner.write().await;
-            if cache.len() >= MAX_CACHE_ENTRIES {
-                cache.clear();
+    /// Write a persistent cache to disk if it changed since the last save.
+    pub fn save(&self) {
+        self.lock().save();
+    }
+}
+
+impl Inner {
+    fn purge_expired(&mut self) {
+        let now = unix_secs();
+        let before = self.entries.len();
+        self.entries.retain(|_, entry| entry.expires_at > now);
+        if self.entries.len() < before {
+            self.reindex();
+        }
+    }
+
+    /// Drop the recency of entries removed in bulk.
+    fn reindex(&mut self) {
+        let entries = &self.entries;
+        self.recency.retain(|_, key| entries.contains_key(key));
+    }
+
+    fn evict_least_recent(&mut self) {
+        match self.recency.pop_first() {
+            Some((_, key)) => {
+                self.entries.remove(&key);
             }
-            cache.insert(key.to_string(), val.clone());
+            // Out of step; cannot happen, but never loop forever.
+            None => self.entries.clear(),
+        }
+    }
+
+    fn save(&mut self) {
+        self.last_saved = Instant::now();
+        let Some(path) = self.settings.path.clone() else {
+            return;
+        };
+        if !self.dirty {
+            return;
+        }
+        self.purge_expired();
+        let json = serde_json::to_string(&self.entries).unwrap_or_default();
+        match crate::auth::write_secret_file(&path, &json) {
+            Ok(()) => self.dirty = false,
+            Err(e) => tracing::warn!("Cannot save cache to {}: {}", path.display(), e),
+        }
+    }
+}
+
+fn load(path: &Path) -> HashMap<String, Entry> {
+    let text = match std::fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return HashMap::new(),
+        Err(e) => {
+            tracing::warn!("Cannot read cache {}: {}", path.display(), e);
+            return HashMap::new();
         }
-        Ok(val)
+    };
+    serde_json::from_str(&text).unwrap_or_else(|e| {
+        tracing::warn!("Ignoring corrupt cache {}: {}", path.display(), e);
+        HashMap::new()
+    })
+}
+
+fn cache_key(kind: EntityKind, key: &str) -> String {
+    format!("{}:{}", kind.name(), key)
+}
+
+/// The `id` and `ids` variables of a mutation.
+fn mutation_ids(variables: &serde_json::Value) -> Vec<String> {
+    let mut ids = Vec::new();
+    for name in ["id", "ids"] {
+        match &variables[name] {
+            serde_json::Value::String(id) => ids.push(id.clone()),
+            serde_json::Value::Array(items) => {
+                ids.extend(items.iter().filter_map(|v| v.as_str()).map(str::to_string))
+            }
+            _ => {}
+        }
+    }
+    ids
+}
+
+fn unix_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn small(max_entries: usize) -> EntityCache {
+        EntityCache::with_settings(CacheSettings {
+            max_entries,
+            ..CacheSettings::default()
+        })
+    }
+
+    async fn lookup(keys: Vec<String>) -> Result<HashMap<String, String>, ()> {
+        Ok(keys
+            .into_iter()
+            .map(|k| (k.clone(), format!("id-{k}")))
+            .collect())
+    }
+
+    #[test]
+    fn evicts_the_least_recently_used() {
+        let cache = small(2);
+        cache.insert(EntityKind::Team, "a", "1".into());
+        cache.insert(EntityKind::Team, "b", "2".into());
+        assert_eq!(cache.get(EntityKind::Team, "a").as_deref(), Some("1"));
+        cache.insert(EntityKind::Team, "c", "3".into());
+        assert_eq!(cache.get(EntityKind::Team, "b"), None);
+        assert_eq!(cache.get(EntityKind::Team, "a").as_deref(), Some("1"));
+        assert_eq!(cache.get(EntityKind::Team, "c").as_deref(), Some("3"));
+
+        // Replacing a value is a use, not a new entry.
+        cache.insert(EntityKind::Team, "a", "4".into());
+        cache.insert(EntityKind::Team, "d", "5".into());
+        assert_eq!(cache.get(EntityKind::Team, "c"), None);
+        assert_eq!(cache.get(EntityKind::Team, "a").as_deref(), Some("4"));
+        let inner = cache.lock();
+        assert_eq!(inner.entries.len(), 2);
+        assert_eq!(inner.recency.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn concurrent_loads_share_one_fetch() {
+        let cache = EntityCache::new();
+        let calls = std::sync::atomic::AtomicUsize::new(0);
+        let fetch = |keys: Vec<String>| {
+            calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            lookup(keys)
+        };
+        let (first, second) = (["ENG-1".to_string()], ["ENG-2".to_string()]);
+        let (a, b) = tokio::join!(
+            cache.load_many(EntityKind::Issue, &first, fetch),
+            cache.load_many(EntityKind::Issue, &second, fetch),
+        );
+        assert_eq!(a.unwrap(), vec![Some("id-ENG-1".to_string())]);
+        assert_eq!(b.unwrap(), vec![Some("id-ENG-2".to_string())]);
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn a_cancelled_leader_does_not_strand_its_joiners() {
+        let cache = EntityCache::new();
+        let keys = ["ENG-1".to_string()];
+        let mut leader = Box::pin(cache.load_many(EntityKind::Issue, &keys, lookup));
+        let mut joiner = Box::pin(cache.load_many(EntityKind::Issue, &keys, lookup));
+        // Poll each once: the leader parks its batch, the joiner joins it.
+        tokio::select! {
+            biased;
+            _ = &mut leader => panic!("the leader should still be waiting"),
+            _ = std::future::ready(()) => {}
+        }
+        tokio::select! {
+            biased;
+            _ = &mut joiner => panic!("the joiner should still be waiting"),
+            _ = std::future::ready(()) => {}
+        }
+        drop(leader);
+
+        let found = tokio::time::timeout(Duration::from_secs(5), joiner)
+            .await
+            .expect("the joiner hung");
+        assert_eq!(found.unwrap(), vec![Some("id-ENG-1".to_string())]);
+        assert!(cache.lock().batches.is_empty());
+        // Later lookups of the kind are not stuck behind the old batch.
+        let other = ["ENG-2".to_string()];
+        let later = tokio::time::timeout(
+            Duration::from_secs(5),
+            cache.load_many(EntityKind::Issue, &other, lookup),
+        )
+        .await
+        .expect("a later lookup hung");
+        assert_eq!(later.unwrap(), vec![Some("id-ENG-2".to_string())]);
     }
 }
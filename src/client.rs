@@ -1,103 +1,279 @@
+use crate::cache::EntityCache;
 use crate::error::Error;
 use crate::graphql::response::{GraphQLError, GraphQLResponse};
-use reqwest::Client;
-use serde::{de::DeserializeOwned, Serialize};
+use crate::oauth::OAuthSession;
+use crate::pipeline::{Logging, Middleware, Next, Request};
+use crate::retry::{self, RateLimitBudget, Retry, RetryPolicy};
+use reqwest::{Certificate, Client, StatusCode};
+use serde::{Serialize, de::DeserializeOwned};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
+use tracing::Instrument;
 
 const LINEAR_API_URL: &str = "https://api.linear.app/graphql";
 
 #[derive(Clone)]
 pub struct LinearClient {
     http: Client,
+    api_url: Arc<str>,
     api_key: String,
+    /// OAuth tokens used instead of `api_key` when set.
+    oauth: Option<Arc<OAuthSession>>,
+    read_only: bool,
+    /// Outermost first; `transport` runs after the last one.
+    middleware: Arc<Vec<Arc<dyn Middleware>>>,
+    /// Budget from the latest `X-RateLimit-*` headers seen with this key.
+    rate_limit: Arc<Mutex<RateLimitBudget>>,
+    /// Set once Linear has answered a request made with this key.
+    authenticated: Arc<AtomicBool>,
+    /// Resolver lookups for this key's workspace.
+    cache: EntityCache,
 }
 
-#[derive(Serialize)]
-struct GraphQLRequest<V: Serialize> {
-    query: &'static str,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    variables: Option<V>,
+/// Connection settings for `LinearClient`.
+#[derive(Debug, Clone)]
+pub struct ClientOptions {
+    /// GraphQL endpoint; a local mock server in tests.
+    pub api_url: String,
+    /// Proxy URL for all requests. Without one, the standard proxy
+    /// environment variables apply.
+    pub proxy: Option<String>,
+    /// PEM bundle of root certificates trusted in addition to the built-in ones.
+    pub ca_bundle: Option<PathBuf>,
+    pub user_agent: String,
+    pub connect_timeout: Duration,
+    pub timeout: Duration,
+    pub retry: RetryPolicy,
+}
+
+impl Default for ClientOptions {
+    fn default() -> Self {
+        Self {
+            api_url: LINEAR_API_URL.to_string(),
+            proxy: None,
+            ca_bundle: None,
+            user_agent: concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION")).to_string(),
+            connect_timeout: Duration::from_secs(10),
+            timeout: Duration::from_secs(30),
+            retry: RetryPolicy::default(),
+        }
+    }
+}
+
+impl ClientOptions {
+    /// An HTTP client with these proxy, TLS, timeout, and user-agent
+    /// settings. Also used for OAuth token requests.
+    pub fn http_client(&self) -> Result<Client, Error> {
+        let mut builder = Client::builder()
+            .connect_timeout(self.connect_timeout)
+            .timeout(self.timeout)
+            .user_agent(&self.user_agent)
+            .redirect(reqwest::redirect::Policy::none());
+        if let Some(ref proxy) = self.proxy {
+            let proxy = reqwest::Proxy::all(proxy)
+                .map_err(|e| Error::Config(format!("invalid proxy '{}': {}", proxy, e)))?;
+            builder = builder.proxy(proxy);
+        }
+        if let Some(ref path) = self.ca_bundle {
+            let pem = std::fs::read(path)
+                .map_err(|e| Error::Config(format!("cannot read {}: {}", path.display(), e)))?;
+            let certs = Certificate::from_pem_bundle(&pem).map_err(|e| {
+                Error::Config(format!("invalid CA bundle {}: {}", path.display(), e))
+            })?;
+            if certs.is_empty() {
+                return Err(Error::Config(format!(
+                    "no certificates in CA bundle {}",
+                    path.display()
+                )));
+            }
+            for cert in certs {
+                builder = builder.add_root_certificate(cert);
+            }
+        }
+        Ok(builder.build()?)
+    }
 }
 
 impl LinearClient {
-    pub fn new(api_key: String) -> Result<Self, Error> {
-        let http = Client::builder()
-            .connect_timeout(Duration::from_secs(10))
-            .timeout(Duration::from_secs(30))
-            .redirect(reqwest::redirect::Policy::none())
-            .build()?;
-        Ok(Self { http, api_key })
-    }
-
-    /// Execute a GraphQL query/mutation and deserialize the response data.
-    pub async fn execute<V, T>(&self, query: &'static str, variables: Option<V>) -> Result<T, Error>
-    where
-        V: Serialize,
-        T: DeserializeOwned,
-    {
-        let body = GraphQLRequest { query, variables };
+    pub fn new(api_key: String, options: &ClientOptions) -> Result<Self, Error> {
+        let http = options.http_client()?;
+        Ok(Self {
+            http,
+            api_url: options.api_url.as_str().into(),
+            api_key,
+            oauth: None,
+            read_only: false,
+            middleware: Arc::new(vec![
+                Arc::new(Logging),
+                Arc::new(Retry::new(options.retry.clone())),
+            ]),
+            rate_limit: Arc::default(),
+            authenticated: Arc::default(),
+            cache: EntityCache::new(),
+        })
+    }
 
-        let resp = self
-            .http
-            .post(LINEAR_API_URL)
-            .header("Authorization", &self.api_key)
-            .header("Content-Type", "application/json")
-            .json(&body)
-            .send()
-            .await?;
+    /// Authenticate with OAuth tokens, refreshing them when Linear answers 401.
+    pub fn with_oauth(mut self, session: OAuthSession) -> Self {
+        self.oauth = Some(Arc::new(session));
+        self
+    }
 
-        let status = resp.status();
-        if !status.is_success() {
-            let text = resp.text().await.unwrap_or_default();
-            return Err(Error::GraphQL(format!("HTTP {status}: {text}")));
+    /// Use `cache` for resolver lookups.
+    pub fn with_cache(mut self, cache: EntityCache) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    /// Resolver lookups for this client's workspace. Entries our own
+    /// mutations make stale are dropped by `execute`.
+    pub fn cache(&self) -> &EntityCache {
+        &self.cache
+    }
+
+    /// Refuse to send any GraphQL mutation from this client.
+    pub fn read_only(mut self) -> Self {
+        self.read_only = true;
+        self
+    }
+
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// A client with the same settings and connection pool but a different key.
+    pub fn with_api_key(&self, api_key: String) -> Self {
+        Self {
+            api_key,
+            oauth: None,
+            rate_limit: Arc::default(),
+            authenticated: Arc::default(),
+            cache: self.cache.empty_like(),
+            ..self.clone()
         }
+    }
 
-        let text = resp.text().await?;
-        let gql_resp: GraphQLResponse<T> = serde_json::from_str(&text)
-            .map_err(|e| Error::GraphQL(format!("Deserialization error: {e}")))?;
+    /// Whether Linear has accepted this client's credentials at least once.
+    pub fn is_authenticated(&self) -> bool {
+        self.authenticated.load(Ordering::Relaxed)
+    }
 
-        if let Some(errors) = gql_resp.errors {
-            let msg = format_gql_errors(&errors);
-            return Err(Error::GraphQL(msg));
+    #[cfg(test)]
+    pub fn mark_authenticated(&self) {
+        self.authenticated.store(true, Ordering::Relaxed);
+    }
+
+    /// The rate-limit budget reported by the most recent response.
+    pub fn rate_limit_budget(&self) -> RateLimitBudget {
+        self.rate_limit
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    fn check_writable(&self, request: &Request) -> Result<(), Error> {
+        if self.read_only && request.is_mutation() {
+            return Err(Error::ReadOnly);
         }
+        Ok(())
+    }
 
-        gql_resp
-            .data
-            .ok_or_else(|| Error::GraphQL("No data in response".into()))
+    /// Add a middleware innermost in the pipeline, after logging and
+    /// retries and just before the request goes over the wire.
+    pub fn with_middleware(mut self, middleware: Arc<dyn Middleware>) -> Self {
+        Arc::make_mut(&mut self.middleware).push(middleware);
+        self
     }
 
-    /// Execute a query with JSON value variables (for dynamic filter construction).
-    pub async fn execute_json<T>(&self, query: &'static str, variables: serde_json::Value) -> Result<T, Error>
-    where
-        T: DeserializeOwned,
-    {
-        let body = serde_json::json!({
-            "query": query,
-            "variables": variables,
-        });
+    /// Send one request to Linear and return the body of a successful reply.
+    /// The end of the middleware pipeline.
+    pub async fn transport(&self, request: &Request) -> Result<String, Error> {
+        let (status, text) = self.send_once(request).await?;
+        if status.is_success() && !retry::is_rate_limited_body(&text) {
+            return Ok(text);
+        }
+        match api_error(status, &text) {
+            Error::RateLimited { message, .. } => Err(Error::RateLimited {
+                message,
+                retry_after: self
+                    .rate_limit_budget()
+                    .wait_for_reset()
+                    .map(|wait| wait.as_secs()),
+            }),
+            err => Err(err),
+        }
+    }
 
-        let resp = self
+    /// One POST, retried once with a refreshed OAuth token if the access
+    /// token was rejected. Records the rate-limit headers.
+    async fn send_once<B: Serialize>(&self, body: &B) -> Result<(StatusCode, String), Error> {
+        let authorization = match self.oauth {
+            Some(ref oauth) => oauth.authorization().await,
+            None => self.api_key.clone(),
+        };
+        let mut resp = self.post(&authorization, body).await?;
+        if let Some(ref oauth) = self.oauth
+            && resp.status() == StatusCode::UNAUTHORIZED
+        {
+            let authorization = oauth.refresh(&self.http, &authorization).await?;
+            resp = self.post(&authorization, body).await?;
+        }
+        self.rate_limit
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .record(resp.headers());
+        let status = resp.status();
+        let text = resp.text().await?;
+        Ok((status, text))
+    }
+
+    async fn post<B: Serialize>(
+        &self,
+        authorization: &str,
+        body: &B,
+    ) -> Result<reqwest::Response, Error> {
+        Ok(self
             .http
-            .post(LINEAR_API_URL)
-            .header("Authorization", &self.api_key)
+            .post(&*self.api_url)
+            .header("Authorization", authorization)
             .header("Content-Type", "application/json")
-            .json(&body)
+            .json(body)
             .send()
-            .await?;
+            .await?)
+    }
 
-        let status = resp.status();
-        if !status.is_success() {
-            let text = resp.text().await.unwrap_or_default();
-            return Err(Error::GraphQL(format!("HTTP {status}: {text}")));
-        }
+    /// Execute a GraphQL query/mutation through the middleware pipeline and
+    /// deserialize the response data. Pass `json!({})` for no variables.
+    pub async fn execute<T: DeserializeOwned>(
+        &self,
+        query: &'static str,
+        variables: serde_json::Value,
+    ) -> Result<T, Error> {
+        let request = Request::new(query, variables);
+        self.check_writable(&request)?;
+        let span = tracing::debug_span!(
+            "linear_request",
+            id = request.id,
+            operation = request.operation_name()
+        );
 
-        let text = resp.text().await?;
+        let text = Next::new(self, &self.middleware)
+            .run(&request)
+            .instrument(span)
+            .await?;
         let gql_resp: GraphQLResponse<T> = serde_json::from_str(&text)
             .map_err(|e| Error::GraphQL(format!("Deserialization error: {e}")))?;
 
-        if let Some(errors) = gql_resp.errors {
-            let msg = format_gql_errors(&errors);
-            return Err(Error::GraphQL(msg));
+        if let Some(errors) = gql_resp.errors
+            && !errors.is_empty()
+        {
+            return Err(classify_gql_errors(&errors, None));
+        }
+        self.authenticated.store(true, Ordering::Relaxed);
+        if request.is_mutation() {
+            self.cache.invalidate_after(&request);
         }
 
         gql_resp
@@ -106,10 +282,263 @@ impl LinearClient {
     }
 }
 
-fn format_gql_errors(errors: &[GraphQLError]) -> String {
-    errors
+/// Turn a non-2xx (or rate-limited) response into a typed error, using the
+/// GraphQL `errors` in the body when there are any.
+fn api_error(status: StatusCode, text: &str) -> Error {
+    let errors = serde_json::from_str::<GraphQLResponse<serde_json::Value>>(text)
+        .ok()
+        .and_then(|resp| resp.errors)
+        .unwrap_or_default();
+    if !errors.is_empty() {
+        return classify_gql_errors(&errors, Some(status));
+    }
+    let message = format!("HTTP {status}: {text}");
+    match status.as_u16() {
+        429 => Error::RateLimited {
+            message,
+            retry_after: None,
+        },
+        401 => Error::Authentication(message),
+        403 => Error::Forbidden(message),
+        404 => Error::NotFound(message),
+        400 | 422 => Error::Validation {
+            message,
+            field: None,
+        },
+        500.. => Error::Server(message),
+        _ => Error::GraphQL(message),
+    }
+}
+
+/// Classify GraphQL errors by Linear's `extensions.code`/`type`, falling back
+/// to the HTTP status. The first error decides the kind; the messages of all
+/// are kept, preferring Linear's `userPresentableMessage`.
+fn classify_gql_errors(errors: &[GraphQLError], status: Option<StatusCode>) -> Error {
+    let message = errors
         .iter()
-        .map(|e| e.message.as_str())
+        .map(|e| {
+            e.extensions
+                .user_presentable_message
+                .as_deref()
+                .unwrap_or(&e.message)
+        })
         .collect::<Vec<_>>()
-        .join("; ")
+        .join("; ");
+    let first = &errors[0];
+    let ext = &first.extensions;
+    let code = ext.code.as_deref().unwrap_or_default().to_ascii_uppercase();
+    let kind = ext.kind.as_deref().unwrap_or_default().to_ascii_lowercase();
+    let status = ext
+        .status_code
+        .or_else(|| status.map(|s| s.as_u16()))
+        .unwrap_or(200);
+
+    if code == "RATELIMITED" || kind == "ratelimited" || status == 429 {
+        Error::RateLimited {
+            message,
+            retry_after: None,
+        }
+    } else if code == "AUTHENTICATION_ERROR" || kind == "authentication error" || status == 401 {
+        Error::Authentication(message)
+    } else if code == "FORBIDDEN" || kind == "forbidden" || status == 403 {
+        Error::Forbidden(message)
+    } else if first.message.starts_with("Entity not found")
+        || code == "ENTITY_NOT_FOUND"
+        || status == 404
+    {
+        Error::NotFound(message)
+    } else if matches!(
+        code.as_str(),
+        "INVALID_INPUT" | "BAD_USER_INPUT" | "GRAPHQL_VALIDATION_FAILED" | "GRAPHQL_PARSE_FAILED"
+    ) || kind == "invalid input"
+        || kind == "graphql error"
+        || status == 400
+    {
+        Error::Validation {
+            message,
+            field: error_field(first),
+        }
+    } else if code == "INTERNAL_SERVER_ERROR" || kind == "internal error" || status >= 500 {
+        Error::Server(message)
+    } else {
+        Error::GraphQL(message)
+    }
+}
+
+/// Dotted path of the input a validation error is about: the explicit
+/// `field`/`argumentPath` extensions, a `at "input.title"` hint in the
+/// message, or else the response path.
+fn error_field(error: &GraphQLError) -> Option<String> {
+    let join = |path: &[serde_json::Value]| {
+        let parts: Vec<String> = path
+            .iter()
+            .map(|p| match p {
+                serde_json::Value::String(s) => s.clone(),
+                other => other.to_string(),
+            })
+            .collect();
+        (!parts.is_empty()).then(|| parts.join("."))
+    };
+    if let Some(ref field) = error.extensions.field {
+        return Some(field.clone());
+    }
+    if let Some(ref path) = error.extensions.argument_path
+        && let Some(field) = join(path)
+    {
+        return Some(field);
+    }
+    if let Some((_, rest)) = error.message.split_once(" at \"")
+        && let Some((field, _)) = rest.split_once('"')
+    {
+        return Some(field.to_string());
+    }
+    join(&error.path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn classify(errors: serde_json::Value, status: Option<u16>) -> Error {
+        let errors: Vec<GraphQLError> = serde_json::from_value(errors).unwrap();
+        classify_gql_errors(&errors, status.map(|s| StatusCode::from_u16(s).unwrap()))
+    }
+
+    fn with_extensions(extensions: serde_json::Value) -> serde_json::Value {
+        json!([{ "message": "Something failed", "extensions": extensions }])
+    }
+
+    #[test]
+    fn extension_codes_decide_the_kind() {
+        for (code, expected) in [
+            ("RATELIMITED", "rate_limited"),
+            ("AUTHENTICATION_ERROR", "authentication"),
+            ("FORBIDDEN", "forbidden"),
+            ("ENTITY_NOT_FOUND", "not_found"),
+            ("INVALID_INPUT", "validation"),
+            ("BAD_USER_INPUT", "validation"),
+            ("GRAPHQL_VALIDATION_FAILED", "validation"),
+            ("GRAPHQL_PARSE_FAILED", "validation"),
+            ("INTERNAL_SERVER_ERROR", "server"),
+            ("SOMETHING_NEW", "api_error"),
+        ] {
+            let error = classify(with_extensions(json!({ "code": code })), None);
+            assert_eq!(error.code(), expected, "{code}");
+        }
+        // Codes are compared ignoring case.
+        let error = classify(with_extensions(json!({ "code": "ratelimited" })), None);
+        assert_eq!(error.code(), "rate_limited");
+    }
+
+    #[test]
+    fn extension_types_decide_the_kind() {
+        for (kind, expected) in [
+            ("Ratelimited", "rate_limited"),
+            ("authentication error", "authentication"),
+            ("forbidden", "forbidden"),
+            ("invalid input", "validation"),
+            ("graphql error", "validation"),
+            ("internal error", "server"),
+            ("unknown", "api_error"),
+        ] {
+            let error = classify(with_extensions(json!({ "type": kind })), None);
+            assert_eq!(error.code(), expected, "{kind}");
+        }
+    }
+
+    #[test]
+    fn statuses_decide_the_kind_without_a_code() {
+        for (status, expected) in [
+            (429, "rate_limited"),
+            (401, "authentication"),
+            (403, "forbidden"),
+            (404, "not_found"),
+            (400, "validation"),
+            (500, "server"),
+            (503, "server"),
+            (200, "api_error"),
+        ] {
+            // Linear's own `statusCode` extension...
+            let error = classify(with_extensions(json!({ "statusCode": status })), None);
+            assert_eq!(error.code(), expected, "extension {status}");
+            // ...or the HTTP response status.
+            let error = classify(with_extensions(json!({})), Some(status));
+            assert_eq!(error.code(), expected, "HTTP {status}");
+        }
+    }
+
+    #[test]
+    fn entity_not_found_messages_are_not_found() {
+        let error = classify(json!([{ "message": "Entity not found: Issue" }]), Some(200));
+        assert!(matches!(error, Error::NotFound(ref m) if m == "Entity not found: Issue"));
+    }
+
+    #[test]
+    fn messages_prefer_the_presentable_text_of_every_error() {
+        let error = classify(
+            json!([
+                { "message": "raw", "extensions": { "code": "FORBIDDEN", "userPresentableMessage": "Not allowed" } },
+                { "message": "Second" },
+            ]),
+            None,
+        );
+        assert!(matches!(error, Error::Forbidden(ref m) if m == "Not allowed; Second"));
+    }
+
+    #[test]
+    fn validation_errors_name_the_field() {
+        let field = |error: Error| match error {
+            Error::Validation { field, .. } => field,
+            other => panic!("expected a validation error, got {other:?}"),
+        };
+        let explicit = with_extensions(json!({ "code": "INVALID_INPUT", "field": "input.title" }));
+        assert_eq!(
+            field(classify(explicit, None)).as_deref(),
+            Some("input.title")
+        );
+        let argument = with_extensions(
+            json!({ "code": "INVALID_INPUT", "argumentPath": ["input", "labelIds", 0] }),
+        );
+        assert_eq!(
+            field(classify(argument, None)).as_deref(),
+            Some("input.labelIds.0")
+        );
+        let hinted = json!([{
+            "message": "Variable \"$input\" got invalid value at \"input.priority\"",
+            "extensions": { "code": "GRAPHQL_VALIDATION_FAILED" },
+        }]);
+        assert_eq!(
+            field(classify(hinted, None)).as_deref(),
+            Some("input.priority")
+        );
+        let path = json!([{ "message": "Bad", "path": ["issueCreate"], "extensions": { "code": "BAD_USER_INPUT" } }]);
+        assert_eq!(field(classify(path, None)).as_deref(), Some("issueCreate"));
+    }
+
+    #[test]
+    fn http_errors_use_the_body_errors_when_there_are_any() {
+        let body = r#"{"errors":[{"message":"Slow down","extensions":{"code":"RATELIMITED"}}]}"#;
+        let error = api_error(StatusCode::BAD_REQUEST, body);
+        assert!(matches!(error, Error::RateLimited { ref message, .. } if message == "Slow down"));
+
+        for (status, expected) in [
+            (429, "rate_limited"),
+            (401, "authentication"),
+            (403, "forbidden"),
+            (404, "not_found"),
+            (400, "validation"),
+            (422, "validation"),
+            (500, "server"),
+            (502, "server"),
+            (418, "api_error"),
+        ] {
+            let error = api_error(StatusCode::from_u16(status).unwrap(), "<html>oops</html>");
+            assert_eq!(error.code(), expected, "HTTP {status}");
+            assert!(
+                error.to_string().contains(&format!("HTTP {status}")),
+                "{error}"
+            );
+        }
+    }
 }
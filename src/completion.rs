@@ -0,0 +1,121 @@
+//! Argument completion (`completion/complete`) from cached workspace names,
+//! so agents can pick real team keys, states and labels instead of guessing.
+
+use rmcp::model::CompletionInfo;
+
+/// What an argument takes, and so what is offered for it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Catalog {
+    Team,
+    /// Workflow states, scoped by the `team` argument.
+    State,
+    /// Team and workspace labels, scoped by the `team` argument.
+    Label,
+    Project,
+    User,
+    /// Cycles of the `team` argument's team.
+    Cycle,
+    Template,
+}
+
+impl Catalog {
+    /// Prefix of this catalog's name lists in the cache.
+    pub fn name(self) -> &'static str {
+        match self {
+            Catalog::Team => "team",
+            Catalog::State => "state",
+            Catalog::Label => "label",
+            Catalog::Project => "project",
+            Catalog::User => "user",
+            Catalog::Cycle => "cycle",
+            Catalog::Template => "template",
+        }
+    }
+
+    /// The catalog of argument `argument` of the tool or prompt `owner`.
+    /// Statuses and labels only mean workflow states and issue labels on
+    /// issue tools; elsewhere they are project statuses, webhook labels and
+    /// the like.
+    pub fn for_argument(owner: &str, argument: &str) -> Option<Catalog> {
+        let issues = owner.contains("issue");
+        Some(match argument {
+            "team" => Catalog::Team,
+            "state" | "status" if issues => Catalog::State,
+            "label" | "labels" if issues => Catalog::Label,
+            "project" => Catalog::Project,
+            "assignee" | "creator" | "subscriber" | "lead" | "owner" | "user" => Catalog::User,
+            "cycle" => Catalog::Cycle,
+            "templateId" => Catalog::Template,
+            _ => return None,
+        })
+    }
+
+    /// The catalog of a `{variable}` in a resource URI template.
+    pub fn for_resource(uri_template: &str, variable: &str) -> Option<Catalog> {
+        match variable {
+            "team" => Some(Catalog::Team),
+            "name" if uri_template.starts_with("linear://project/") => Some(Catalog::Project),
+            _ => None,
+        }
+    }
+
+    /// Whether the catalog depends on the team argument.
+    pub fn is_team_scoped(self) -> bool {
+        matches!(self, Catalog::State | Catalog::Label | Catalog::Cycle)
+    }
+
+    /// Labels are given as comma-separated lists; only the last one typed
+    /// is completed.
+    fn is_list(self) -> bool {
+        self == Catalog::Label
+    }
+}
+
+/// The names that start with what was typed, then those that merely
+/// contain it, ignoring case. A partly typed list keeps its earlier items,
+/// which are not offered again.
+pub fn complete(catalog: Catalog, names: &[String], typed: &str) -> CompletionInfo {
+    let (done, typed) = match typed.rfind(',') {
+        Some(comma) if catalog.is_list() => typed.split_at(comma + 1),
+        _ => ("", typed),
+    };
+    let prefix = if done.is_empty() {
+        String::new()
+    } else {
+        format!("{} ", done.trim_end())
+    };
+    let typed = typed.trim().to_lowercase();
+    let chosen: Vec<String> = done
+        .split(',')
+        .map(|item| item.trim().to_lowercase())
+        .collect();
+
+    let mut starts = Vec::new();
+    let mut contains = Vec::new();
+    for name in names {
+        let lower = name.to_lowercase();
+        if chosen.contains(&lower) {
+            continue;
+        }
+        if lower.starts_with(&typed) {
+            starts.push(name);
+        } else if lower.contains(&typed) {
+            contains.push(name);
+        }
+    }
+    let mut values: Vec<String> = Vec::new();
+    for name in starts.into_iter().chain(contains) {
+        let value = format!("{}{}", prefix, name);
+        if !values.contains(&value) {
+            values.push(value);
+        }
+    }
+
+    let total = values.len();
+    values.truncate(CompletionInfo::MAX_VALUES);
+    CompletionInfo {
+        has_more: Some(total > values.len()),
+        total: Some(total as u32),
+        values,
+    }
+}
@@ -0,0 +1,264 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use crate::cache::{CacheSettings, EntityKind, MAX_CACHE_ENTRIES};
+use crate::error::Error;
+
+/// Top-level configuration file (`config.toml`).
+///
+/// ```toml
+/// default_profile = "work"
+///
+/// [profiles.work]
+/// api_key_env = "LINEAR_WORK_API_KEY"
+/// default_team = "ENG"
+/// timezone = "Europe/Berlin"
+/// tools = ["core", "releases"]
+/// request_timeout_secs = 60
+/// proxy = "http://proxy.corp:3128"
+///
+/// [profiles.work.cache]
+/// max_entries = 5000
+/// persist = true
+/// ttl_secs = { state = 600 }
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Profile used when `--profile` / `LINEAR_MCP_PROFILE` is not given.
+    pub default_profile: Option<String>,
+    #[serde(default)]
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+/// Settings for one Linear workspace.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Profile {
+    /// Name of the profile in the config file (`default` when none is selected).
+    #[serde(skip)]
+    pub name: String,
+    /// Environment variable holding the API key (default `LINEAR_API_KEY`).
+    pub api_key_env: Option<String>,
+    /// Keychain / Secret Service `service` holding the API key (default
+    /// `linear-api-key`).
+    pub keychain_service: Option<String>,
+    /// Shell command that prints the API key, e.g. `pass show linear/api-key`.
+    pub key_command: Option<String>,
+    /// File containing the API key; must not be readable by group or others.
+    pub key_file: Option<PathBuf>,
+    /// OAuth application used by `--login`; its stored tokens are preferred
+    /// over API key sources other than the environment variable.
+    pub oauth_client_id: Option<String>,
+    /// Only needed for OAuth apps that are not set up for PKCE.
+    pub oauth_client_secret: Option<String>,
+    /// Scopes requested by `--login` (default `read`, `write`).
+    pub oauth_scopes: Vec<String>,
+    /// Port of the `http://localhost:<port>/callback` redirect URI (default 8974).
+    pub oauth_redirect_port: Option<u16>,
+    /// Where `--login` keeps OAuth tokens.
+    pub token_store: TokenStore,
+    /// Team key used by team-scoped tools when the caller omits `team`.
+    pub default_team: Option<String>,
+    /// IANA timezone for relative dates like `today` and `end of month`
+    /// (default: the system's). `LINEAR_MCP_TIMEZONE` overrides it.
+    pub timezone: Option<String>,
+    /// Tool names, globs, or profiles to expose (empty means all).
+    pub tools: Vec<String>,
+    /// Tool names, globs, or profiles to hide.
+    pub exclude_tools: Vec<String>,
+    pub read_only: bool,
+    pub output_format: OutputFormat,
+    /// GraphQL endpoint (default `https://api.linear.app/graphql`).
+    pub api_url: Option<String>,
+    /// Proxy for all requests to Linear, e.g. `http://proxy.corp:3128`.
+    /// Without it, `HTTPS_PROXY` / `ALL_PROXY` / `NO_PROXY` are honored.
+    pub proxy: Option<String>,
+    /// PEM file of extra root certificates to trust, e.g. a corporate
+    /// TLS-inspection CA.
+    pub ca_bundle: Option<PathBuf>,
+    /// `User-Agent` header (default `linear-mcp/<version>`).
+    pub user_agent: Option<String>,
+    pub connect_timeout_secs: Option<u64>,
+    pub request_timeout_secs: Option<u64>,
+    /// Retries for rate-limited and transient failures (default 3; 0 disables).
+    pub max_retries: Option<u32>,
+    /// How often subscribed resources are checked for changes (default 30).
+    pub poll_interval_secs: Option<u64>,
+    pub cache: CacheConfig,
+}
+
+/// How tool results are rendered.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    /// Markdown headings and emphasis (the default).
+    #[default]
+    Markdown,
+    /// The same text with markdown markup stripped.
+    Plain,
+}
+
+/// Credential store for OAuth tokens.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TokenStore {
+    /// A mode 600 JSON file in the config directory (the default).
+    #[default]
+    File,
+    /// macOS Keychain, or the Secret Service on Linux.
+    Keychain,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CacheConfig {
+    /// Maximum cached lookups; the least recently used are evicted beyond it.
+    pub max_entries: Option<usize>,
+    /// Keep the cache on disk between sessions.
+    pub persist: bool,
+    /// Cache file (default `cache-<profile>.json` in the config directory).
+    pub path: Option<PathBuf>,
+    /// Time to live of each kind of lookup, in seconds.
+    pub ttl_secs: CacheTtls,
+}
+
+/// Per-kind overrides of `cache::EntityKind::default_ttl`.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CacheTtls {
+    pub issue: Option<u64>,
+    pub issue_team: Option<u64>,
+    pub team: Option<u64>,
+    pub user: Option<u64>,
+    pub state: Option<u64>,
+    pub label: Option<u64>,
+    pub project: Option<u64>,
+    pub milestone: Option<u64>,
+    pub initiative: Option<u64>,
+    pub cycle: Option<u64>,
+    pub view: Option<u64>,
+    pub document: Option<u64>,
+}
+
+impl CacheTtls {
+    pub fn get(&self, kind: EntityKind) -> Option<u64> {
+        match kind {
+            EntityKind::Issue => self.issue,
+            EntityKind::IssueTeam => self.issue_team,
+            // Both directions of the key ↔ UUID mapping live as long.
+            EntityKind::Team | EntityKind::TeamKey => self.team,
+            EntityKind::User => self.user,
+            EntityKind::State => self.state,
+            EntityKind::Label => self.label,
+            EntityKind::Project => self.project,
+            EntityKind::Milestone => self.milestone,
+            EntityKind::Initiative => self.initiative,
+            EntityKind::Cycle => self.cycle,
+            EntityKind::View => self.view,
+            EntityKind::Document => self.document,
+        }
+    }
+}
+
+impl Profile {
+    /// Resolver cache settings for this profile.
+    pub fn cache_settings(&self) -> CacheSettings {
+        let path = match self.cache.path {
+            Some(ref path) => Some(path.clone()),
+            None if self.cache.persist => {
+                config_dir().map(|dir| dir.join(format!("cache-{}.json", self.name)))
+            }
+            None => None,
+        };
+        CacheSettings {
+            max_entries: self.cache.max_entries.unwrap_or(MAX_CACHE_ENTRIES),
+            ttls: EntityKind::ALL
+                .into_iter()
+                .filter_map(|kind| {
+                    Some((kind, Duration::from_secs(self.cache.ttl_secs.get(kind)?)))
+                })
+                .collect(),
+            path,
+        }
+    }
+}
+
+impl Config {
+    /// Load the config from `path`, or from the default location if `None`.
+    ///
+    /// An explicitly given file must exist; a missing default file just
+    /// yields an empty config.
+    pub fn load(path: Option<&Path>) -> Result<Self, Error> {
+        let (path, explicit) = match path {
+            Some(p) => (p.to_path_buf(), true),
+            None => match default_path() {
+                Some(p) => (p, false),
+                None => return Ok(Self::default()),
+            },
+        };
+
+        let text = match std::fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound && !explicit => {
+                return Ok(Self::default());
+            }
+            Err(e) => {
+                return Err(Error::Config(format!(
+                    "cannot read {}: {}",
+                    path.display(),
+                    e
+                )));
+            }
+        };
+
+        tracing::debug!("Loaded config from {}", path.display());
+        toml::from_str(&text)
+            .map_err(|e| Error::Config(format!("invalid config {}: {}", path.display(), e)))
+    }
+
+    /// Select a profile by name, falling back to `default_profile`.
+    ///
+    /// With neither, an empty profile (all defaults) is returned.
+    pub fn profile(&self, name: Option<&str>) -> Result<Profile, Error> {
+        let Some(name) = name.or(self.default_profile.as_deref()) else {
+            return Ok(Profile {
+                name: "default".into(),
+                ..Profile::default()
+            });
+        };
+        let profile = self.profiles.get(name).cloned().map(|profile| Profile {
+            name: name.to_string(),
+            ..profile
+        });
+        profile.ok_or_else(|| {
+            let known: Vec<&str> = self.profiles.keys().map(String::as_str).collect();
+            Error::Config(format!(
+                "unknown profile '{}' (configured: {})",
+                name,
+                if known.is_empty() {
+                    "none".to_string()
+                } else {
+                    known.join(", ")
+                }
+            ))
+        })
+    }
+}
+
+/// `$XDG_CONFIG_HOME/linear-mcp/config.toml`, or `~/.config/linear-mcp/config.toml`.
+pub fn default_path() -> Option<PathBuf> {
+    Some(config_dir()?.join("config.toml"))
+}
+
+/// `$XDG_CONFIG_HOME/linear-mcp`, or `~/.config/linear-mcp`.
+pub fn config_dir() -> Option<PathBuf> {
+    let base = std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|v| !v.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(base.join("linear-mcp"))
+}
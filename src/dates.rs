@@ -0,0 +1,509 @@
+//! Natural-language and relative dates (`tomorrow`, `next friday`, `-7d`,
+//! `Q3`, `end of cycle`), interpreted in the profile's timezone and
+//! normalised to the formats Linear expects.
+
+use chrono::{DateTime, Datelike, Days, Local, Months, NaiveDate, Offset, TimeZone, Utc, Weekday};
+use chrono_tz::Tz;
+
+use crate::error::Error;
+
+/// Which end of a period (`Q3`, `next week`) a date argument stands for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bound {
+    /// Lower bounds and start dates: the period's first day.
+    Start,
+    /// Deadlines and end dates: the period's last day.
+    End,
+}
+
+/// Which of a team's cycles `start of cycle` / `end of next cycle` refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CycleRef {
+    Current,
+    Next,
+    Previous,
+}
+
+impl CycleRef {
+    /// The word for this cycle, as `resolve_cycle_id` also accepts it.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CycleRef::Current => "current",
+            CycleRef::Next => "next",
+            CycleRef::Previous => "previous",
+        }
+    }
+}
+
+/// A parsed date argument.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateExpr {
+    /// A precise moment: `now`, `-12h`, or a full ISO timestamp.
+    Instant(DateTime<Utc>),
+    /// A calendar day in the profile's timezone.
+    Day(NaiveDate),
+    /// A span of days, first and last inclusive: `Q3`, `next month`.
+    Period(NaiveDate, NaiveDate),
+    /// The first or last day of one of a team's cycles, which only Linear knows.
+    Cycle { cycle: CycleRef, end: bool },
+}
+
+impl DateExpr {
+    /// The day this stands for; `None` for cycle dates, which need a lookup.
+    pub fn day(self, bound: Bound, dates: &Dates) -> Option<NaiveDate> {
+        match self {
+            DateExpr::Instant(at) => Some(at.with_timezone(&dates.offset_at(at)).date_naive()),
+            DateExpr::Day(day) => Some(day),
+            DateExpr::Period(start, _) if bound == Bound::Start => Some(start),
+            DateExpr::Period(_, end) => Some(end),
+            DateExpr::Cycle { .. } => None,
+        }
+    }
+}
+
+/// Parses date arguments relative to "now" in a fixed timezone (the system
+/// one unless configured).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Dates {
+    tz: Option<Tz>,
+}
+
+impl Dates {
+    /// Dates in the IANA timezone `name`, e.g. `Europe/Berlin`.
+    pub fn with_timezone(name: &str) -> Result<Self, Error> {
+        let tz = name.parse::<Tz>().map_err(|_| {
+            Error::Config(format!(
+                "Unknown timezone '{}' (expected e.g. 'Europe/Berlin')",
+                name
+            ))
+        })?;
+        Ok(Self { tz: Some(tz) })
+    }
+
+    /// Today's date in the configured timezone.
+    pub fn today(&self) -> NaiveDate {
+        let now = Utc::now();
+        now.with_timezone(&self.offset_at(now)).date_naive()
+    }
+
+    fn offset_at(&self, at: DateTime<Utc>) -> chrono::FixedOffset {
+        match self.tz {
+            Some(tz) => tz.offset_from_utc_datetime(&at.naive_utc()).fix(),
+            None => Local.offset_from_utc_datetime(&at.naive_utc()),
+        }
+    }
+
+    /// Midnight at the start of `day` in the configured timezone, as an ISO
+    /// timestamp in UTC.
+    pub fn start_of_day(&self, day: NaiveDate) -> String {
+        let midnight = day.and_hms_opt(0, 0, 0).expect("midnight exists");
+        let local = match self.tz {
+            Some(tz) => tz
+                .from_local_datetime(&midnight)
+                .earliest()
+                .map(|t| t.with_timezone(&Utc)),
+            None => Local
+                .from_local_datetime(&midnight)
+                .earliest()
+                .map(|t| t.with_timezone(&Utc)),
+        };
+        // A DST gap at midnight: fall back to midnight UTC.
+        timestamp(local.unwrap_or_else(|| midnight.and_utc()))
+    }
+
+    /// Parse a date argument. Accepts ISO dates and timestamps, `today`,
+    /// `tomorrow`, `yesterday`, `now`, weekdays (`friday`, `next friday`,
+    /// `last monday`), offsets (`-7d`, `2w`, `+3m`, `1y`, `12h`, `in 3 days`,
+    /// `2 weeks ago`), periods (`this week`, `next month`, `last quarter`,
+    /// `Q3`, `Q1 2026`, `2026-03`) and `start of …` / `end of …` any period
+    /// or cycle (`end of cycle`, `start of next cycle`). Errors are messages
+    /// for the caller.
+    pub fn parse(&self, input: &str) -> Result<DateExpr, String> {
+        let text = input.trim().to_lowercase();
+        let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        match self.parse_expr(&text) {
+            Some(Ok(expr)) => Ok(expr),
+            Some(Err(reason)) => Err(format!(
+                "'{}' is not a valid date: {}.",
+                input.trim(),
+                reason
+            )),
+            None => Err(format!(
+                "Unrecognized date '{}'. Use YYYY-MM-DD, an ISO timestamp, or e.g. 'tomorrow', 'next friday', '-7d', '2w', 'Q3', 'end of month', 'end of cycle'.",
+                input.trim()
+            )),
+        }
+    }
+
+    /// `None` when the text isn't a date expression at all; `Some(Err)` when
+    /// it is one but names an impossible date.
+    fn parse_expr(&self, text: &str) -> Option<Result<DateExpr, String>> {
+        let today = self.today();
+        if let Some(rest) = text
+            .strip_prefix("start of ")
+            .or_else(|| text.strip_prefix("beginning of "))
+        {
+            return self.parse_bounded(rest, false);
+        }
+        if let Some(rest) = text.strip_prefix("end of ") {
+            return self.parse_bounded(rest, true);
+        }
+        let day = |d: Option<NaiveDate>| {
+            Some(
+                d.map(DateExpr::Day)
+                    .ok_or_else(|| "out of range".to_string()),
+            )
+        };
+        match text {
+            "now" => return Some(Ok(DateExpr::Instant(Utc::now()))),
+            "today" => return day(Some(today)),
+            "tomorrow" => return day(today.checked_add_days(Days::new(1))),
+            "yesterday" => return day(today.checked_sub_days(Days::new(1))),
+            _ => {}
+        }
+        if let Some(parsed) = parse_iso(text) {
+            return Some(parsed);
+        }
+        if let Some(period) = self.parse_period(text) {
+            return Some(period);
+        }
+        if let Some(weekday) = parse_weekday_expr(text, today) {
+            return day(Some(weekday));
+        }
+        parse_offset(text).map(|offset| match offset {
+            Shift::Hours(hours) => chrono::TimeDelta::try_hours(hours)
+                .and_then(|delta| Utc::now().checked_add_signed(delta))
+                .map(DateExpr::Instant)
+                .ok_or_else(|| "out of range".into()),
+            Shift::Days(days) => shift_days(today, days)
+                .map(DateExpr::Day)
+                .ok_or_else(|| "out of range".into()),
+            Shift::Months(months) => shift_months(today, months)
+                .map(DateExpr::Day)
+                .ok_or_else(|| "out of range".into()),
+        })
+    }
+
+    /// The `…` in `start of …` / `end of …`.
+    fn parse_bounded(&self, text: &str, end: bool) -> Option<Result<DateExpr, String>> {
+        let cycle = match text {
+            "cycle" | "this cycle" | "the cycle" | "current cycle" | "the current cycle" => {
+                Some(CycleRef::Current)
+            }
+            "next cycle" | "the next cycle" => Some(CycleRef::Next),
+            "last cycle" | "previous cycle" | "the previous cycle" => Some(CycleRef::Previous),
+            _ => None,
+        };
+        if let Some(cycle) = cycle {
+            return Some(Ok(DateExpr::Cycle { cycle, end }));
+        }
+        let text = text.strip_prefix("the ").unwrap_or(text);
+        let text = match text {
+            "week" | "month" | "quarter" | "year" => format!("this {}", text),
+            _ => text.to_string(),
+        };
+        match self.parse_period(&text)? {
+            Ok(DateExpr::Period(start, last)) => {
+                Some(Ok(DateExpr::Day(if end { last } else { start })))
+            }
+            other => Some(other),
+        }
+    }
+
+    /// `this week`, `next month`, `last quarter`, `Q3`, `Q3 2026`, `2026-Q3`, `2026-03`, `2026`.
+    fn parse_period(&self, text: &str) -> Option<Result<DateExpr, String>> {
+        let today = self.today();
+        let (shift, unit) = match text.split_once(' ') {
+            Some(("this" | "current", unit)) => (0, unit),
+            Some(("next", unit)) => (1, unit),
+            Some(("last" | "previous", unit)) => (-1, unit),
+            _ => (0, ""),
+        };
+        let (start, months) = match unit {
+            "week" => {
+                let monday = shift_days(today, -i64::from(today.weekday().num_days_from_monday()))?;
+                let start = shift_days(monday, 7 * shift)?;
+                return Some(Ok(DateExpr::Period(start, shift_days(start, 6)?)));
+            }
+            "month" => (NaiveDate::from_ymd_opt(today.year(), today.month(), 1)?, 1),
+            "quarter" => (quarter_start(today.year(), quarter_of(today))?, 3),
+            "year" => (NaiveDate::from_ymd_opt(today.year(), 1, 1)?, 12),
+            "" => return parse_named_period(text, today.year()),
+            _ => return None,
+        };
+        let start = shift_months(start, shift * months)?;
+        Some(Ok(DateExpr::Period(start, period_end(start, months)?)))
+    }
+}
+
+enum Shift {
+    Hours(i64),
+    Days(i64),
+    Months(i64),
+}
+
+/// `-7d`, `+2w`, `3m`, `1y`, `12h`, `in 3 days`, `2 weeks ago`.
+fn parse_offset(text: &str) -> Option<Shift> {
+    let (sign, amount, unit) = if let Some(rest) = text.strip_prefix("in ") {
+        let (n, unit) = rest.split_once(' ')?;
+        (1, n.parse::<i64>().ok()?, unit)
+    } else if let Some(rest) = text.strip_suffix(" ago") {
+        let (n, unit) = rest.split_once(' ')?;
+        (-1, n.parse::<i64>().ok()?, unit)
+    } else {
+        let (sign, rest) = match text.as_bytes().first()? {
+            b'-' => (-1, &text[1..]),
+            b'+' => (1, &text[1..]),
+            _ => (1, text),
+        };
+        let split = rest.find(|c: char| !c.is_ascii_digit())?;
+        let (n, unit) = rest.split_at(split);
+        (sign, n.parse::<i64>().ok()?, unit.trim())
+    };
+    let n = sign * amount;
+    Some(match unit.trim_end_matches('s') {
+        "h" | "hour" | "hr" => Shift::Hours(n),
+        "d" | "day" => Shift::Days(n),
+        "w" | "week" | "wk" => Shift::Days(n.saturating_mul(7)),
+        "m" | "month" | "mo" => Shift::Months(n),
+        "y" | "year" | "yr" => Shift::Months(n.saturating_mul(12)),
+        _ => return None,
+    })
+}
+
+/// `friday`, `this friday` (the coming one, today included), `next friday`
+/// (the first after today), `last friday` (the latest before today).
+fn parse_weekday_expr(text: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let (direction, name) = match text.split_once(' ') {
+        Some(("this", name)) => (0, name),
+        Some(("next", name)) => (1, name),
+        Some(("last" | "previous", name)) => (-1, name),
+        None => (0, text),
+        _ => return None,
+    };
+    let weekday = name.parse::<Weekday>().ok()?;
+    let ahead = (7 + weekday.num_days_from_monday() as i64
+        - today.weekday().num_days_from_monday() as i64)
+        % 7;
+    let days = match direction {
+        0 => ahead,
+        1 if ahead == 0 => 7,
+        1 => ahead,
+        _ if ahead == 0 => -7,
+        _ => ahead - 7,
+    };
+    shift_days(today, days)
+}
+
+/// `Q3`, `Q3 2026`, `2026 Q3`, `2026-Q3`, `2026-03`, `2026`. `Some(Err)` for
+/// a month that doesn't exist (`2026-13`).
+fn parse_named_period(text: &str, this_year: i32) -> Option<Result<DateExpr, String>> {
+    let parts: Vec<&str> = text.split([' ', '-']).collect();
+    let quarter = |q: &str| -> Option<u32> {
+        let n = q.strip_prefix('q')?.parse().ok()?;
+        (1..=4).contains(&n).then_some(n)
+    };
+    let year = |y: &str| -> Option<i32> { (y.len() == 4).then(|| y.parse().ok()).flatten() };
+    let (start, months) = match parts.as_slice() {
+        [q] if quarter(q).is_some() => (quarter_start(this_year, quarter(q)?)?, 3),
+        [q, y] | [y, q] if quarter(q).is_some() => (quarter_start(year(y)?, quarter(q)?)?, 3),
+        [y, m] if m.len() == 2 => {
+            let month = m.parse().ok()?;
+            match NaiveDate::from_ymd_opt(year(y)?, month, 1) {
+                Some(start) => (start, 1),
+                None => return Some(Err("no such month".into())),
+            }
+        }
+        [y] => (NaiveDate::from_ymd_opt(year(y)?, 1, 1)?, 12),
+        _ => return None,
+    };
+    Some(Ok(DateExpr::Period(start, period_end(start, months)?)))
+}
+
+/// `YYYY-MM-DD` or a full ISO 8601 timestamp. `None` when it isn't shaped
+/// like either.
+fn parse_iso(text: &str) -> Option<Result<DateExpr, String>> {
+    let bytes = text.as_bytes();
+    let date_shaped = bytes.len() >= 10
+        && bytes[..10].iter().enumerate().all(|(i, b)| {
+            if i == 4 || i == 7 {
+                *b == b'-'
+            } else {
+                b.is_ascii_digit()
+            }
+        });
+    if !date_shaped {
+        return None;
+    }
+    if text.len() == 10 {
+        return Some(
+            NaiveDate::parse_from_str(text, "%Y-%m-%d")
+                .map(DateExpr::Day)
+                .map_err(|_| "no such day".into()),
+        );
+    }
+    Some(
+        DateTime::parse_from_rfc3339(&text.to_uppercase())
+            .map(|at| DateExpr::Instant(at.with_timezone(&Utc)))
+            .map_err(|e| format!("not a valid ISO 8601 timestamp ({})", e)),
+    )
+}
+
+fn quarter_of(day: NaiveDate) -> u32 {
+    (day.month() - 1) / 3 + 1
+}
+
+fn quarter_start(year: i32, quarter: u32) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(year, (quarter - 1) * 3 + 1, 1)
+}
+
+/// Last day of the `months`-long period starting on `start`.
+fn period_end(start: NaiveDate, months: i64) -> Option<NaiveDate> {
+    shift_months(start, months)?.pred_opt()
+}
+
+fn shift_days(day: NaiveDate, days: i64) -> Option<NaiveDate> {
+    if days >= 0 {
+        day.checked_add_days(Days::new(days as u64))
+    } else {
+        day.checked_sub_days(Days::new(days.unsigned_abs()))
+    }
+}
+
+fn shift_months(day: NaiveDate, months: i64) -> Option<NaiveDate> {
+    let n = u32::try_from(months.unsigned_abs()).ok()?;
+    if months >= 0 {
+        day.checked_add_months(Months::new(n))
+    } else {
+        day.checked_sub_months(Months::new(n))
+    }
+}
+
+/// An ISO 8601 timestamp the way Linear writes them.
+pub fn timestamp(at: DateTime<Utc>) -> String {
+    at.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc() -> Dates {
+        Dates::with_timezone("UTC").unwrap()
+    }
+
+    fn day(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn after_today(days: i64) -> DateExpr {
+        let dates = utc();
+        DateExpr::Day(shift_days(dates.today(), days).unwrap())
+    }
+
+    #[test]
+    fn offsets_shift_from_today() {
+        let dates = utc();
+        assert_eq!(dates.parse("-7d"), Ok(after_today(-7)));
+        assert_eq!(dates.parse("2w"), Ok(after_today(14)));
+        assert_eq!(dates.parse("in 3 days"), Ok(after_today(3)));
+        assert_eq!(dates.parse("2 weeks ago"), Ok(after_today(-14)));
+        assert_eq!(dates.parse("tomorrow"), Ok(after_today(1)));
+        assert!(matches!(dates.parse("-12h"), Ok(DateExpr::Instant(_))));
+    }
+
+    #[test]
+    fn huge_offsets_are_out_of_range() {
+        let dates = utc();
+        for input in [
+            "9999999999999h",
+            "-9999999999999h",
+            "9999999999999d",
+            "-9999999999999d",
+            "999999999999999w",
+            "99999999999m",
+            "in 9999999999 years",
+            "9223372036854775807w",
+            "9223372036854775807y",
+        ] {
+            let error = dates.parse(input).unwrap_err();
+            assert!(error.contains("out of range"), "{}: {}", input, error);
+        }
+        // Too large for a number at all: not an offset.
+        let error = dates.parse("99999999999999999999h").unwrap_err();
+        assert!(error.starts_with("Unrecognized"), "{}", error);
+    }
+
+    #[test]
+    fn periods_span_their_days() {
+        let dates = utc();
+        assert_eq!(
+            dates.parse("Q3 2026"),
+            Ok(DateExpr::Period(day(2026, 7, 1), day(2026, 9, 30)))
+        );
+        assert_eq!(
+            dates.parse("2028-02"),
+            Ok(DateExpr::Period(day(2028, 2, 1), day(2028, 2, 29)))
+        );
+        assert_eq!(
+            dates.parse("end of Q1 2026"),
+            Ok(DateExpr::Day(day(2026, 3, 31)))
+        );
+        let Ok(DateExpr::Period(start, end)) = dates.parse("next week") else {
+            panic!("next week is a period");
+        };
+        assert_eq!(start.weekday(), Weekday::Mon);
+        assert_eq!(end, shift_days(start, 6).unwrap());
+    }
+
+    #[test]
+    fn cycle_dates_are_left_to_linear() {
+        assert_eq!(
+            utc().parse("end of next cycle"),
+            Ok(DateExpr::Cycle {
+                cycle: CycleRef::Next,
+                end: true
+            })
+        );
+    }
+
+    #[test]
+    fn impossible_dates_are_rejected() {
+        let dates = utc();
+        assert!(
+            dates
+                .parse("2026-02-30")
+                .unwrap_err()
+                .contains("no such day")
+        );
+        for (input, reason) in [
+            ("2026-13", "no such month"),
+            ("2026-00", "no such month"),
+            ("end of 2026-13", "no such month"),
+            ("2026-13-01", "no such day"),
+            ("2026-04-31", "no such day"),
+        ] {
+            let error = dates.parse(input).unwrap_err();
+            assert_eq!(
+                error,
+                format!("'{input}' is not a valid date: {reason}."),
+                "{input}"
+            );
+        }
+        assert!(
+            dates
+                .parse("someday")
+                .unwrap_err()
+                .starts_with("Unrecognized")
+        );
+    }
+
+    #[test]
+    fn shifts_stop_at_the_calendar_limits() {
+        assert_eq!(shift_days(NaiveDate::MAX, 1), None);
+        assert_eq!(shift_days(NaiveDate::MIN, -1), None);
+        assert_eq!(shift_months(NaiveDate::MAX, 1), None);
+        assert_eq!(shift_months(day(2026, 1, 31), 1), Some(day(2026, 2, 28)));
+        assert_eq!(shift_months(day(2026, 1, 1), i64::MAX), None);
+    }
+}
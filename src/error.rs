@@ -8,12 +8,88 @@ pub enum Error {
     #[error("GraphQL error: {0}")]
     GraphQL(String),
 
+    #[error("Rate limited by Linear: {message}")]
+    RateLimited {
+        message: String,
+        /// Seconds until the budget resets, when Linear said so.
+        retry_after: Option<u64>,
+    },
+
+    #[error("Linear rejected the credentials: {0}")]
+    Authentication(String),
+
+    #[error("Not allowed: {0}")]
+    Forbidden(String),
+
     #[error("{0}")]
     NotFound(String),
 
+    #[error("Invalid input{}: {message}", field.as_deref().map(|f| format!(" for '{}'", f)).unwrap_or_default())]
+    Validation {
+        message: String,
+        /// Dotted path of the offending field or argument, if known.
+        field: Option<String>,
+    },
+
+    #[error("Linear server error: {0}")]
+    Server(String),
+
     #[error("{0}")]
     InvalidInput(String),
 
-    #[error("LINEAR_API_KEY not set. Set it as an environment variable or store it in macOS Keychain as 'linear-api-key'.")]
-    Auth,
+    #[error("{message} Candidates: {}", candidates.join(", "))]
+    Ambiguous {
+        message: String,
+        /// The best matches, for the caller to pick from.
+        candidates: Vec<String>,
+    },
+
+    #[error(
+        "No Linear API key found. Tried:\n  {0}\nSet LINEAR_API_KEY or configure a key source (see README)."
+    )]
+    Auth(String),
+
+    #[error(
+        "No Linear API key for this session. Send it in the Authorization header or as 'linearApiKey' in the initialize request's _meta."
+    )]
+    SessionAuth,
+
+    #[error("The server is running in read-only mode; mutations are disabled.")]
+    ReadOnly,
+
+    #[error("OAuth error: {0}")]
+    OAuth(String),
+
+    #[error("Config error: {0}")]
+    Config(String),
+}
+
+impl Error {
+    /// Stable, machine-readable error code reported to MCP clients.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Error::Http(_) => "network",
+            Error::GraphQL(_) => "api_error",
+            Error::RateLimited { .. } => "rate_limited",
+            Error::Authentication(_) | Error::Auth(_) | Error::SessionAuth | Error::OAuth(_) => {
+                "authentication"
+            }
+            Error::Forbidden(_) | Error::ReadOnly => "forbidden",
+            Error::NotFound(_) => "not_found",
+            Error::Ambiguous { .. } => "ambiguous",
+            Error::Validation { .. } | Error::InvalidInput(_) => "validation",
+            Error::Server(_) => "server",
+            Error::Config(_) => "config",
+        }
+    }
+
+    /// Whether sending the same request again later may succeed; otherwise
+    /// the caller has to change its input or credentials.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::Http(e) => e.is_timeout() || e.is_connect() || e.is_request(),
+            Error::RateLimited { .. } | Error::Server(_) => true,
+            _ => false,
+        }
+    }
 }
@@ -25,17 +25,17 @@ pub fn format_issue_summary(issue: &Issue) -> String {
     if let Some(ref due) = issue.due_date {
         meta.push(format!("due:{}", due));
     }
-    if let Some(ref labels) = issue.labels {
-        if !labels.nodes.is_empty() {
-            meta.push(
-                labels
-                    .nodes
-                    .iter()
-                    .map(|l| l.name.as_str())
-                    .collect::<Vec<_>>()
-                    .join(", "),
-            );
-        }
+    if let Some(ref labels) = issue.labels
+        && !labels.nodes.is_empty()
+    {
+        meta.push(
+            labels
+                .nodes
+                .iter()
+                .map(|l| l.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", "),
+        );
     }
 
     parts.push(format!("({})", meta.join(", ")));
@@ -70,16 +70,16 @@ pub fn format_issue_detail(issue: &Issue) -> String {
     if let Some(ref project) = issue.project {
         lines.push(format!("**Project:** {}", project.name));
     }
-    if let Some(ref labels) = issue.labels {
-        if !labels.nodes.is_empty() {
-            let label_str = labels
-                .nodes
-                .iter()
-                .map(|l| l.name.as_str())
-                .collect::<Vec<_>>()
-                .join(", ");
-            lines.push(format!("**Labels:** {}", label_str));
-        }
+    if let Some(ref labels) = issue.labels
+        && !labels.nodes.is_empty()
+    {
+        let label_str = labels
+            .nodes
+            .iter()
+            .map(|l| l.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        lines.push(format!("**Labels:** {}", label_str));
     }
     if let Some(estimate) = issue.estimate {
         lines.push(format!("**Estimate:** {}", estimate));
@@ -93,10 +93,7 @@ pub fn format_issue_detail(issue: &Issue) -> String {
 
     if let Some(ref creator) = issue.creator {
         if let Some(ref email) = creator.email {
-            lines.push(format!(
-                "**Creator:** {} <{}>",
-                creator.display_name, email
-            ));
+            lines.push(format!("**Creator:** {} <{}>", creator.display_name, email));
         } else {
             lines.push(format!("**Creator:** {}", creator.display_name));
         }
@@ -133,20 +130,23 @@ pub fn format_issue_detail(issue: &Issue) -> String {
         lines.push(format!("**SLA Type:** {}", sla_type));
     }
     if let Some(ref sla_breaches) = issue.sla_breaches_at {
-        lines.push(format!("**SLA Breaches At:** {}", format_date(sla_breaches)));
+        lines.push(format!(
+            "**SLA Breaches At:** {}",
+            format_date(sla_breaches)
+        ));
     }
     if let Some(ref sla_started) = issue.sla_started_at {
         lines.push(format!("**SLA Started At:** {}", format_date(sla_started)));
     }
-    if let Some(count) = issue.customer_ticket_count {
-        if count > 0 {
-            lines.push(format!("**Customer Tickets:** {}", count));
-        }
+    if let Some(count) = issue.customer_ticket_count
+        && count > 0
+    {
+        lines.push(format!("**Customer Tickets:** {}", count));
     }
-    if let Some(ref prev) = issue.previous_identifiers {
-        if !prev.is_empty() {
-            lines.push(format!("**Previous IDs:** {}", prev.join(", ")));
-        }
+    if let Some(ref prev) = issue.previous_identifiers
+        && !prev.is_empty()
+    {
+        lines.push(format!("**Previous IDs:** {}", prev.join(", ")));
     }
     if let Some(true) = issue.trashed {
         lines.push("**Trashed:** yes".to_string());
@@ -169,69 +169,69 @@ pub fn format_issue_detail(issue: &Issue) -> String {
     }
 
     // Relations
-    if let Some(ref relations) = issue.relations {
-        if !relations.nodes.is_empty() {
-            lines.push(String::new());
-            lines.push("## Relations".to_string());
-            for relation in &relations.nodes {
-                let related = relation
-                    .related_issue
-                    .as_ref()
-                    .map(|i| format!("[{}] {}", i.identifier, i.title))
-                    .unwrap_or_else(|| "?".to_string());
-                lines.push(format!("- {} {}", relation.relation_type, related));
-            }
+    if let Some(ref relations) = issue.relations
+        && !relations.nodes.is_empty()
+    {
+        lines.push(String::new());
+        lines.push("## Relations".to_string());
+        for relation in &relations.nodes {
+            let related = relation
+                .related_issue
+                .as_ref()
+                .map(|i| format!("[{}] {}", i.identifier, i.title))
+                .unwrap_or_else(|| "?".to_string());
+            lines.push(format!("- {} {}", relation.relation_type, related));
         }
     }
 
     // Subscribers
-    if let Some(ref subscribers) = issue.subscribers {
-        if !subscribers.nodes.is_empty() {
-            lines.push(String::new());
-            let sub_names: Vec<String> = subscribers
-                .nodes
-                .iter()
-                .map(|u| {
-                    if let Some(ref email) = u.email {
-                        format!("{} <{}>", u.display_name, email)
-                    } else {
-                        u.display_name.clone()
-                    }
-                })
-                .collect();
-            lines.push(format!("**Subscribers:** {}", sub_names.join(", ")));
-        }
+    if let Some(ref subscribers) = issue.subscribers
+        && !subscribers.nodes.is_empty()
+    {
+        lines.push(String::new());
+        let sub_names: Vec<String> = subscribers
+            .nodes
+            .iter()
+            .map(|u| {
+                if let Some(ref email) = u.email {
+                    format!("{} <{}>", u.display_name, email)
+                } else {
+                    u.display_name.clone()
+                }
+            })
+            .collect();
+        lines.push(format!("**Subscribers:** {}", sub_names.join(", ")));
     }
 
     // Description
-    if let Some(ref desc) = issue.description {
-        if !desc.is_empty() {
-            lines.push(String::new());
-            lines.push("## Description".to_string());
-            lines.push(desc.clone());
-        }
+    if let Some(ref desc) = issue.description
+        && !desc.is_empty()
+    {
+        lines.push(String::new());
+        lines.push("## Description".to_string());
+        lines.push(desc.clone());
     }
 
     // Sub-issues
-    if let Some(ref children) = issue.children {
-        if !children.nodes.is_empty() {
-            lines.push(String::new());
-            lines.push("## Sub-issues".to_string());
-            for child in &children.nodes {
-                lines.push(format!("- [{}] {}", child.identifier, child.title));
-            }
+    if let Some(ref children) = issue.children
+        && !children.nodes.is_empty()
+    {
+        lines.push(String::new());
+        lines.push("## Sub-issues".to_string());
+        for child in &children.nodes {
+            lines.push(format!("- [{}] {}", child.identifier, child.title));
         }
     }
 
     // Comments
-    if let Some(ref comments) = issue.comments {
-        if !comments.nodes.is_empty() {
+    if let Some(ref comments) = issue.comments
+        && !comments.nodes.is_empty()
+    {
+        lines.push(String::new());
+        lines.push("## Comments".to_string());
+        for comment in &comments.nodes {
+            lines.push(format_comment(comment));
             lines.push(String::new());
-            lines.push("## Comments".to_string());
-            for comment in &comments.nodes {
-                lines.push(format_comment(comment));
-                lines.push(String::new());
-            }
         }
     }
 
@@ -274,16 +274,16 @@ pub fn format_team(team: &crate::types::Team, member_count: Option<usize>) -> St
     if !meta.is_empty() {
         parts.push(format!("({})", meta.join(", ")));
     }
-    if let Some(ref desc) = team.description {
-        if !desc.is_empty() {
-            let truncated = if desc.chars().count() > 80 {
-                let t: String = desc.chars().take(80).collect();
-                format!("{}...", t)
-            } else {
-                desc.clone()
-            };
-            parts.push(format!("- {}", truncated));
-        }
+    if let Some(ref desc) = team.description
+        && !desc.is_empty()
+    {
+        let truncated = if desc.chars().count() > 80 {
+            let t: String = desc.chars().take(80).collect();
+            format!("{}...", t)
+        } else {
+            desc.clone()
+        };
+        parts.push(format!("- {}", truncated));
     }
     parts.join(" ")
 }
@@ -297,7 +297,10 @@ pub fn format_project(project: &Project) -> String {
         .as_ref()
         .map(|h| format!(" [{}]", h))
         .unwrap_or_default();
-    let mut parts = vec![format!("{} [{}] - {}% complete{}", project.name, state, pct, health)];
+    let mut parts = vec![format!(
+        "{} [{}] - {}% complete{}",
+        project.name, state, pct, health
+    )];
 
     if let Some(ref lead) = project.lead {
         let lead_str = if let Some(ref email) = lead.email {
@@ -307,21 +310,21 @@ pub fn format_project(project: &Project) -> String {
         };
         parts.push(format!("  Lead: {}", lead_str));
     }
-    if let Some(ref teams) = project.teams {
-        if !teams.nodes.is_empty() {
-            let team_keys: Vec<&str> = teams.nodes.iter().map(|t| t.key.as_str()).collect();
-            parts.push(format!("  Teams: {}", team_keys.join(", ")));
-        }
-    }
-    if let Some(ref members) = project.members {
-        if !members.nodes.is_empty() {
-            let member_names: Vec<&str> = members
-                .nodes
-                .iter()
-                .map(|m| m.display_name.as_str())
-                .collect();
-            parts.push(format!("  Members: {}", member_names.join(", ")));
-        }
+    if let Some(ref teams) = project.teams
+        && !teams.nodes.is_empty()
+    {
+        let team_keys: Vec<&str> = teams.nodes.iter().map(|t| t.key.as_str()).collect();
+        parts.push(format!("  Teams: {}", team_keys.join(", ")));
+    }
+    if let Some(ref members) = project.members
+        && !members.nodes.is_empty()
+    {
+        let member_names: Vec<&str> = members
+            .nodes
+            .iter()
+            .map(|m| m.display_name.as_str())
+            .collect();
+        parts.push(format!("  Members: {}", member_names.join(", ")));
     }
     if let Some(ref start) = project.start_date {
         parts.push(format!("  Start: {}", start));
@@ -332,17 +335,17 @@ pub fn format_project(project: &Project) -> String {
     if let Some(ref url) = project.url {
         parts.push(format!("  URL: {}", url));
     }
-    if let Some(ref desc) = project.description {
-        if !desc.is_empty() {
-            // Truncate long descriptions for list view (char-safe)
-            let truncated = if desc.chars().count() > 100 {
-                let t: String = desc.chars().take(100).collect();
-                format!("{}...", t)
-            } else {
-                desc.clone()
-            };
-            parts.push(format!("  Description: {}", truncated));
-        }
+    if let Some(ref desc) = project.description
+        && !desc.is_empty()
+    {
+        // Truncate long descriptions for list view (char-safe)
+        let truncated = if desc.chars().count() > 100 {
+            let t: String = desc.chars().take(100).collect();
+            format!("{}...", t)
+        } else {
+            desc.clone()
+        };
+        parts.push(format!("  Description: {}", truncated));
     }
 
     parts.join("\n")
@@ -428,32 +431,32 @@ pub fn format_cycle_detail(cycle: &Cycle) -> String {
     let progress = cycle.progress.unwrap_or(0.0);
     let pct = (progress * 100.0).round() as i32;
     lines.push(format!("**Progress:** {}%", pct));
-    if let Some(ref desc) = cycle.description {
-        if !desc.is_empty() {
-            lines.push(format!("**Description:** {}", desc));
-        }
-    }
-    if let Some(ref issues) = cycle.issues {
-        if !issues.nodes.is_empty() {
-            lines.push(String::new());
-            lines.push("## Issues".to_string());
-            for issue in &issues.nodes {
-                let state = issue
-                    .state
-                    .as_ref()
-                    .map(|s| format!(" ({})", s.name))
-                    .unwrap_or_default();
-                lines.push(format!("- [{}] {}{}", issue.identifier, issue.title, state));
-            }
+    if let Some(ref desc) = cycle.description
+        && !desc.is_empty()
+    {
+        lines.push(format!("**Description:** {}", desc));
+    }
+    if let Some(ref issues) = cycle.issues
+        && !issues.nodes.is_empty()
+    {
+        lines.push(String::new());
+        lines.push("## Issues".to_string());
+        for issue in &issues.nodes {
+            let state = issue
+                .state
+                .as_ref()
+                .map(|s| format!(" ({})", s.name))
+                .unwrap_or_default();
+            lines.push(format!("- [{}] {}{}", issue.identifier, issue.title, state));
         }
     }
-    if let Some(ref uncompleted) = cycle.uncompleted_issues_upon_close {
-        if !uncompleted.nodes.is_empty() {
-            lines.push(String::new());
-            lines.push("## Uncompleted Issues (upon close)".to_string());
-            for issue in &uncompleted.nodes {
-                lines.push(format!("- [{}] {}", issue.identifier, issue.title));
-            }
+    if let Some(ref uncompleted) = cycle.uncompleted_issues_upon_close
+        && !uncompleted.nodes.is_empty()
+    {
+        lines.push(String::new());
+        lines.push("## Uncompleted Issues (upon close)".to_string());
+        for issue in &uncompleted.nodes {
+            lines.push(format!("- [{}] {}", issue.identifier, issue.title));
         }
     }
     lines.join("\n")
@@ -495,11 +498,7 @@ pub fn format_issue_relation(relation: &IssueRelation) -> String {
 
 fn format_date(iso: &str) -> &str {
     // Extract YYYY-MM-DD from ISO timestamp
-    if iso.len() >= 10 {
-        &iso[..10]
-    } else {
-        iso
-    }
+    if iso.len() >= 10 { &iso[..10] } else { iso }
 }
 
 // ---- #17: Documents ----
@@ -540,12 +539,12 @@ pub fn format_document_detail(doc: &Document) -> String {
         lines.push(format!("**Updated:** {}", format_date(updated)));
     }
 
-    if let Some(ref content) = doc.content {
-        if !content.is_empty() {
-            lines.push(String::new());
-            lines.push("## Content".to_string());
-            lines.push(content.clone());
-        }
+    if let Some(ref content) = doc.content
+        && !content.is_empty()
+    {
+        lines.push(String::new());
+        lines.push("## Content".to_string());
+        lines.push(content.clone());
     }
 
     lines.join("\n")
@@ -561,7 +560,10 @@ pub fn format_project_detail(project: &ProjectDetail) -> String {
     lines.push(format!("# {}", project.name));
     lines.push(String::new());
     lines.push(format!("**ID:** {}", project.id));
-    lines.push(format!("**State:** {}", project.state.as_deref().unwrap_or("unknown")));
+    lines.push(format!(
+        "**State:** {}",
+        project.state.as_deref().unwrap_or("unknown")
+    ));
     lines.push(format!("**Progress:** {}%", pct));
     if let Some(ref health) = project.health {
         lines.push(format!("**Health:** {}", health));
@@ -570,10 +572,10 @@ pub fn format_project_detail(project: &ProjectDetail) -> String {
         lines.push(format!("**URL:** {}", url));
     }
 
-    if let Some(ref desc) = project.description {
-        if !desc.is_empty() {
-            lines.push(format!("**Description:** {}", desc));
-        }
+    if let Some(ref desc) = project.description
+        && !desc.is_empty()
+    {
+        lines.push(format!("**Description:** {}", desc));
     }
     if let Some(ref lead) = project.lead {
         lines.push(format!("**Lead:** {}", lead.display_name));
@@ -591,28 +593,28 @@ pub fn format_project_detail(project: &ProjectDetail) -> String {
         lines.push(format!("**Updated:** {}", format_date(updated)));
     }
 
-    if let Some(ref teams) = project.teams {
-        if !teams.nodes.is_empty() {
-            let team_str = teams
-                .nodes
-                .iter()
-                .map(|t| format!("{} ({})", t.name, t.key))
-                .collect::<Vec<_>>()
-                .join(", ");
-            lines.push(format!("**Teams:** {}", team_str));
-        }
+    if let Some(ref teams) = project.teams
+        && !teams.nodes.is_empty()
+    {
+        let team_str = teams
+            .nodes
+            .iter()
+            .map(|t| format!("{} ({})", t.name, t.key))
+            .collect::<Vec<_>>()
+            .join(", ");
+        lines.push(format!("**Teams:** {}", team_str));
     }
 
-    if let Some(ref members) = project.members {
-        if !members.nodes.is_empty() {
-            let member_str = members
-                .nodes
-                .iter()
-                .map(|m| m.display_name.as_str())
-                .collect::<Vec<_>>()
-                .join(", ");
-            lines.push(format!("**Members:** {}", member_str));
-        }
+    if let Some(ref members) = project.members
+        && !members.nodes.is_empty()
+    {
+        let member_str = members
+            .nodes
+            .iter()
+            .map(|m| m.display_name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        lines.push(format!("**Members:** {}", member_str));
     }
 
     lines.join("\n")
@@ -646,10 +648,10 @@ pub fn format_project_milestone(milestone: &ProjectMilestone) -> String {
     if let Some(ref target) = milestone.target_date {
         meta.push(format!("target: {}", format_date(target)));
     }
-    if let Some(ref desc) = milestone.description {
-        if !desc.is_empty() {
-            meta.push(desc.clone());
-        }
+    if let Some(ref desc) = milestone.description
+        && !desc.is_empty()
+    {
+        meta.push(desc.clone());
     }
     if !meta.is_empty() {
         parts.push(format!("({})", meta.join(", ")));
@@ -675,22 +677,22 @@ pub fn format_initiative(initiative: &Initiative) -> String {
     if let Some(ref target) = initiative.target_date {
         parts.push(format!("target: {}", target));
     }
-    if let Some(ref projects) = initiative.projects {
-        if !projects.nodes.is_empty() {
-            let names: Vec<&str> = projects.nodes.iter().map(|p| p.name.as_str()).collect();
-            parts.push(format!("projects: {}", names.join(", ")));
-        }
-    }
-    if let Some(ref desc) = initiative.description {
-        if !desc.is_empty() {
-            let truncated = if desc.chars().count() > 100 {
-                let t: String = desc.chars().take(100).collect();
-                format!("{}...", t)
-            } else {
-                desc.clone()
-            };
-            parts.push(format!("- {}", truncated));
-        }
+    if let Some(ref projects) = initiative.projects
+        && !projects.nodes.is_empty()
+    {
+        let names: Vec<&str> = projects.nodes.iter().map(|p| p.name.as_str()).collect();
+        parts.push(format!("projects: {}", names.join(", ")));
+    }
+    if let Some(ref desc) = initiative.description
+        && !desc.is_empty()
+    {
+        let truncated = if desc.chars().count() > 100 {
+            let t: String = desc.chars().take(100).collect();
+            format!("{}...", t)
+        } else {
+            desc.clone()
+        };
+        parts.push(format!("- {}", truncated));
     }
     parts.join(" ")
 }
@@ -720,10 +722,7 @@ pub fn format_notification(notification: &Notification) -> String {
 // ---- #23: Attachments ----
 
 pub fn format_attachment(attachment: &Attachment) -> String {
-    let title = attachment
-        .title
-        .as_deref()
-        .unwrap_or("Untitled");
+    let title = attachment.title.as_deref().unwrap_or("Untitled");
     let url = attachment.url.as_deref().unwrap_or("no URL");
     let mut parts = vec![format!("{} - {}", title, url)];
     if let Some(ref created) = attachment.created_at {
@@ -737,10 +736,10 @@ pub fn format_attachment(attachment: &Attachment) -> String {
 
 pub fn format_custom_view(view: &CustomView) -> String {
     let mut parts = vec![format!("{} [{}]", view.name, view.id)];
-    if let Some(ref desc) = view.description {
-        if !desc.is_empty() {
-            parts.push(format!("- {}", desc));
-        }
+    if let Some(ref desc) = view.description
+        && !desc.is_empty()
+    {
+        parts.push(format!("- {}", desc));
     }
     parts.join(" ")
 }
@@ -748,10 +747,7 @@ pub fn format_custom_view(view: &CustomView) -> String {
 // ---- #26: Favorites ----
 
 pub fn format_favorite(favorite: &Favorite) -> String {
-    let fav_type = favorite
-        .favorite_type
-        .as_deref()
-        .unwrap_or("unknown");
+    let fav_type = favorite.favorite_type.as_deref().unwrap_or("unknown");
     let mut parts = vec![format!("[{}]", fav_type)];
     if let Some(ref issue) = favorite.issue {
         parts.push(format!("{}: {}", issue.identifier, issue.title));
@@ -767,10 +763,10 @@ pub fn format_favorite(favorite: &Favorite) -> String {
 
 pub fn format_template(template: &Template) -> String {
     let mut parts = vec![format!("{} [{}]", template.name, template.id)];
-    if let Some(ref desc) = template.description {
-        if !desc.is_empty() {
-            parts.push(format!("- {}", desc));
-        }
+    if let Some(ref desc) = template.description
+        && !desc.is_empty()
+    {
+        parts.push(format!("- {}", desc));
     }
     parts.join(" ")
 }
@@ -796,25 +792,25 @@ pub fn format_history_entry(entry: &IssueHistoryEntry) -> String {
     }
 
     // Label changes
-    if let Some(ref added) = entry.added_labels {
-        if !added.is_empty() {
-            let labels = added
-                .iter()
-                .map(|l| l.name.as_str())
-                .collect::<Vec<_>>()
-                .join(", ");
-            parts.push(format!("+labels: {}", labels));
-        }
-    }
-    if let Some(ref removed) = entry.removed_labels {
-        if !removed.is_empty() {
-            let labels = removed
-                .iter()
-                .map(|l| l.name.as_str())
-                .collect::<Vec<_>>()
-                .join(", ");
-            parts.push(format!("-labels: {}", labels));
-        }
+    if let Some(ref added) = entry.added_labels
+        && !added.is_empty()
+    {
+        let labels = added
+            .iter()
+            .map(|l| l.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        parts.push(format!("+labels: {}", labels));
+    }
+    if let Some(ref removed) = entry.removed_labels
+        && !removed.is_empty()
+    {
+        let labels = removed
+            .iter()
+            .map(|l| l.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        parts.push(format!("-labels: {}", labels));
     }
 
     parts.join(" | ")
@@ -873,10 +869,10 @@ pub fn format_audit_entry(entry: &AuditEntry) -> String {
 pub fn format_team_detail(team: &TeamDetail) -> String {
     let mut parts = vec![format!("{} | {}", team.key, team.name)];
     let mut meta = Vec::new();
-    if let Some(ref desc) = team.description {
-        if !desc.is_empty() {
-            meta.push(desc.clone());
-        }
+    if let Some(ref desc) = team.description
+        && !desc.is_empty()
+    {
+        meta.push(desc.clone());
     }
     if let Some(ref tz) = team.timezone {
         meta.push(format!("tz: {}", tz));
@@ -889,24 +885,10 @@ pub fn format_team_detail(team: &TeamDetail) -> String {
 }
 
 pub fn format_cycle_created(cycle: &CycleDetail) -> String {
-    let name = cycle
-        .name
-        .as_deref()
-        .unwrap_or("(unnamed)");
-    let number = cycle
-        .number
-        .map(|n| format!(" #{}", n))
-        .unwrap_or_default();
-    let starts = cycle
-        .starts_at
-        .as_deref()
-        .map(|d| format_date(d))
-        .unwrap_or("?");
-    let ends = cycle
-        .ends_at
-        .as_deref()
-        .map(|d| format_date(d))
-        .unwrap_or("?");
+    let name = cycle.name.as_deref().unwrap_or("(unnamed)");
+    let number = cycle.number.map(|n| format!(" #{}", n)).unwrap_or_default();
+    let starts = cycle.starts_at.as_deref().map(format_date).unwrap_or("?");
+    let ends = cycle.ends_at.as_deref().map(format_date).unwrap_or("?");
     format!(
         "**{}{}** [id: {}]\n{} → {}",
         name, number, cycle.id, starts, ends
@@ -956,16 +938,16 @@ pub fn format_initiative_detail(initiative: &InitiativeDetail) -> String {
     if let Some(ref url) = initiative.url {
         lines.push(format!("URL: {}", url));
     }
-    if let Some(ref projects) = initiative.projects {
-        if !projects.nodes.is_empty() {
-            let names: Vec<&str> = projects.nodes.iter().map(|p| p.name.as_str()).collect();
-            lines.push(format!("Projects: {}", names.join(", ")));
-        }
+    if let Some(ref projects) = initiative.projects
+        && !projects.nodes.is_empty()
+    {
+        let names: Vec<&str> = projects.nodes.iter().map(|p| p.name.as_str()).collect();
+        lines.push(format!("Projects: {}", names.join(", ")));
     }
-    if let Some(ref desc) = initiative.description {
-        if !desc.is_empty() {
-            lines.push(format!("Description: {}", desc));
-        }
+    if let Some(ref desc) = initiative.description
+        && !desc.is_empty()
+    {
+        lines.push(format!("Description: {}", desc));
     }
     lines.join("\n")
 }
@@ -1050,23 +1032,23 @@ pub fn format_agent_session_detail(session: &AgentSession) -> String {
     if let Some(ref summary) = session.summary {
         lines.push(format!("**Summary:** {}", summary));
     }
-    if let Some(ref activities) = session.activities {
-        if !activities.nodes.is_empty() {
-            lines.push(String::new());
-            lines.push(format!("## Activities ({})", activities.nodes.len()));
-            for activity in &activities.nodes {
-                let date = activity
-                    .created_at
-                    .as_deref()
-                    .map(|d| format_date(d))
-                    .unwrap_or("?");
-                let ephemeral = if activity.ephemeral.unwrap_or(false) {
-                    " [ephemeral]"
-                } else {
-                    ""
-                };
-                lines.push(format!("- {} {}{}", date, activity.id, ephemeral));
-            }
+    if let Some(ref activities) = session.activities
+        && !activities.nodes.is_empty()
+    {
+        lines.push(String::new());
+        lines.push(format!("## Activities ({})", activities.nodes.len()));
+        for activity in &activities.nodes {
+            let date = activity
+                .created_at
+                .as_deref()
+                .map(format_date)
+                .unwrap_or("?");
+            let ephemeral = if activity.ephemeral.unwrap_or(false) {
+                " [ephemeral]"
+            } else {
+                ""
+            };
+            lines.push(format!("- {} {}{}", date, activity.id, ephemeral));
         }
     }
     lines.join("\n")
@@ -1075,20 +1057,20 @@ pub fn format_agent_session_detail(session: &AgentSession) -> String {
 /// Format a customer as a one-line summary.
 pub fn format_customer_summary(customer: &Customer) -> String {
     let mut parts = vec![customer.name.clone()];
-    if let Some(ref status) = customer.status {
-        if let Some(ref name) = status.display_name {
-            parts.push(format!("[{}]", name));
-        }
+    if let Some(ref status) = customer.status
+        && let Some(ref name) = status.display_name
+    {
+        parts.push(format!("[{}]", name));
     }
-    if let Some(ref domains) = customer.domains {
-        if !domains.is_empty() {
-            parts.push(format!("({})", domains.join(", ")));
-        }
+    if let Some(ref domains) = customer.domains
+        && !domains.is_empty()
+    {
+        parts.push(format!("({})", domains.join(", ")));
     }
-    if let Some(rev) = customer.revenue {
-        if rev > 0.0 {
-            parts.push(format!("rev: ${}", rev));
-        }
+    if let Some(rev) = customer.revenue
+        && rev > 0.0
+    {
+        parts.push(format!("rev: ${}", rev));
     }
     if let Some(ref owner) = customer.owner {
         parts.push(format!("owner: {}", owner.display_name));
@@ -1102,20 +1084,20 @@ pub fn format_customer_detail(customer: &Customer) -> String {
     let mut lines = vec![format!("# {}", customer.name)];
     lines.push(String::new());
     lines.push(format!("**ID:** {}", customer.id));
-    if let Some(ref status) = customer.status {
-        if let Some(ref name) = status.display_name {
-            lines.push(format!("**Status:** {}", name));
-        }
+    if let Some(ref status) = customer.status
+        && let Some(ref name) = status.display_name
+    {
+        lines.push(format!("**Status:** {}", name));
     }
-    if let Some(ref tier) = customer.tier {
-        if let Some(ref name) = tier.name {
-            lines.push(format!("**Tier:** {}", name));
-        }
+    if let Some(ref tier) = customer.tier
+        && let Some(ref name) = tier.name
+    {
+        lines.push(format!("**Tier:** {}", name));
     }
-    if let Some(ref domains) = customer.domains {
-        if !domains.is_empty() {
-            lines.push(format!("**Domains:** {}", domains.join(", ")));
-        }
+    if let Some(ref domains) = customer.domains
+        && !domains.is_empty()
+    {
+        lines.push(format!("**Domains:** {}", domains.join(", ")));
     }
     if let Some(ref owner) = customer.owner {
         lines.push(format!("**Owner:** {}", owner.display_name));
@@ -1126,13 +1108,13 @@ pub fn format_customer_detail(customer: &Customer) -> String {
     if let Some(size) = customer.size {
         lines.push(format!("**Size:** {}", size));
     }
-    if let Some(ref needs) = customer.needs {
-        if !needs.is_empty() {
-            lines.push(String::new());
-            lines.push("## Needs".to_string());
-            for need in needs {
-                lines.push(format_customer_need(need));
-            }
+    if let Some(ref needs) = customer.needs
+        && !needs.is_empty()
+    {
+        lines.push(String::new());
+        lines.push("## Needs".to_string());
+        for need in needs {
+            lines.push(format_customer_need(need));
         }
     }
     lines.join("\n")
@@ -1208,7 +1190,10 @@ pub fn format_project_relation(relation: &ProjectRelation) -> String {
         .map(|p| p.name.as_str())
         .unwrap_or("?");
     let rel_type = relation.relation_type.as_deref().unwrap_or("related");
-    format!("{} --{}-- {} [id: {}]", proj, rel_type, related, relation.id)
+    format!(
+        "{} --{}-- {} [id: {}]",
+        proj, rel_type, related, relation.id
+    )
 }
 
 /// Format a release as a one-line summary.
@@ -1319,10 +1304,10 @@ pub fn format_release_pipeline(p: &ReleasePipelineFull) -> String {
     if let Some(ref slug) = p.slug_id {
         parts.push(format!("Slug: {}", slug));
     }
-    if let Some(ref patterns) = p.include_path_patterns {
-        if !patterns.is_empty() {
-            parts.push(format!("Paths: {}", patterns.join(", ")));
-        }
+    if let Some(ref patterns) = p.include_path_patterns
+        && !patterns.is_empty()
+    {
+        parts.push(format!("Paths: {}", patterns.join(", ")));
     }
     parts.join("\n")
 }
@@ -1345,8 +1330,16 @@ pub fn format_release_stage(s: &ReleaseStageFull) -> String {
 }
 
 pub fn format_issue_to_release(link: &IssueToRelease) -> String {
-    let issue = link.issue.as_ref().map(|i| i.identifier.as_str()).unwrap_or("?");
-    let release = link.release.as_ref().and_then(|r| r.name.as_deref()).unwrap_or("?");
+    let issue = link
+        .issue
+        .as_ref()
+        .map(|i| i.identifier.as_str())
+        .unwrap_or("?");
+    let release = link
+        .release
+        .as_ref()
+        .and_then(|r| r.name.as_deref())
+        .unwrap_or("?");
     format!("{} → {} [id: {}]", issue, release, link.id)
 }
 
@@ -1388,17 +1381,28 @@ pub fn format_project_label(l: &ProjectLabel) -> String {
 }
 
 pub fn format_team_membership(m: &TeamMembership) -> String {
-    let user = m.user.as_ref().map(|u| u.display_name.as_str()).unwrap_or("?");
+    let user = m
+        .user
+        .as_ref()
+        .map(|u| u.display_name.as_str())
+        .unwrap_or("?");
     let team = m.team.as_ref().map(|t| t.key.as_str()).unwrap_or("?");
     let owner = m.owner.unwrap_or(false);
     format!("{} → {} (owner: {}) [id: {}]", user, team, owner, m.id)
 }
 
 pub fn format_notification_subscription(s: &NotificationSubscription) -> String {
-    let subscriber = s.subscriber.as_ref().map(|u| u.display_name.as_str()).unwrap_or("?");
+    let subscriber = s
+        .subscriber
+        .as_ref()
+        .map(|u| u.display_name.as_str())
+        .unwrap_or("?");
     let active = s.active.unwrap_or(true);
     let view_type = s.context_view_type.as_deref().unwrap_or("?");
-    format!("{} - context: {} (active: {}) [id: {}]", subscriber, view_type, active, s.id)
+    format!(
+        "{} - context: {} (active: {}) [id: {}]",
+        subscriber, view_type, active, s.id
+    )
 }
 
 pub fn format_entity_external_link(l: &EntityExternalLink) -> String {
@@ -1424,8 +1428,16 @@ pub fn format_emoji(e: &Emoji) -> String {
 }
 
 pub fn format_initiative_relation(r: &InitiativeRelation) -> String {
-    let init = r.initiative.as_ref().map(|i| i.name.as_str()).unwrap_or("?");
-    let related = r.related_initiative.as_ref().map(|i| i.name.as_str()).unwrap_or("?");
+    let init = r
+        .initiative
+        .as_ref()
+        .map(|i| i.name.as_str())
+        .unwrap_or("?");
+    let related = r
+        .related_initiative
+        .as_ref()
+        .map(|i| i.name.as_str())
+        .unwrap_or("?");
     format!("{} ↔ {} [id: {}]", init, related, r.id)
 }
 
@@ -1451,20 +1463,29 @@ pub fn format_git_automation_state(s: &GitAutomationState) -> String {
     let event = s.event.as_deref().unwrap_or("?");
     let team = s.team.as_ref().map(|t| t.key.as_str()).unwrap_or("?");
     let state = s.state.as_ref().map(|s| s.name.as_str()).unwrap_or("none");
-    format!("Team {} - event: {} → state: {} [id: {}]", team, event, state, s.id)
+    format!(
+        "Team {} - event: {} → state: {} [id: {}]",
+        team, event, state, s.id
+    )
 }
 
 pub fn format_git_automation_target_branch(b: &GitAutomationTargetBranch) -> String {
     let pattern = b.branch_pattern.as_deref().unwrap_or("?");
     let team = b.team.as_ref().map(|t| t.key.as_str()).unwrap_or("?");
     let regex = b.is_regex.unwrap_or(false);
-    format!("Team {} - pattern: {} (regex: {}) [id: {}]", team, pattern, regex, b.id)
+    format!(
+        "Team {} - pattern: {} (regex: {}) [id: {}]",
+        team, pattern, regex, b.id
+    )
 }
 
 pub fn format_email_intake_address(a: &EmailIntakeAddress) -> String {
     let addr = a.address.as_deref().unwrap_or("?");
     let enabled = a.enabled.unwrap_or(false);
-    let mut parts = vec![format!("**{}** (enabled: {}) [id: {}]", addr, enabled, a.id)];
+    let mut parts = vec![format!(
+        "**{}** (enabled: {}) [id: {}]",
+        addr, enabled, a.id
+    )];
     if let Some(ref name) = a.sender_name {
         parts.push(format!("Sender: {}", name));
     }
@@ -1506,6 +1527,51 @@ pub fn format_rate_limit_status(r: &RateLimitStatus) -> String {
     parts.join("\n")
 }
 
+/// Budget from the `X-RateLimit-*` headers of the latest response.
+pub fn format_rate_limit_budget(b: &crate::retry::RateLimitBudget) -> String {
+    let mut parts = vec!["Response headers:".to_string()];
+    let mut push = |label: &str, remaining: Option<u64>, limit: Option<u64>, reset: Option<u64>| {
+        if remaining.is_none() && limit.is_none() {
+            return;
+        }
+        let mut line = format!("  {} — remaining: {}", label, opt_num(remaining));
+        if let Some(limit) = limit {
+            line.push_str(&format!(" / {}", limit));
+        }
+        if let Some(reset) = reset {
+            let now_ms = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_millis() as u64)
+                .unwrap_or(0);
+            line.push_str(&format!(
+                " (resets in {}s)",
+                reset.saturating_sub(now_ms) / 1000
+            ));
+        }
+        parts.push(line);
+    };
+    push(
+        "requests",
+        b.requests_remaining,
+        b.requests_limit,
+        b.requests_reset,
+    );
+    push(
+        "complexity",
+        b.complexity_remaining,
+        b.complexity_limit,
+        b.complexity_reset,
+    );
+    if let Some(c) = b.last_complexity {
+        parts.push(format!("  last request complexity: {}", c));
+    }
+    parts.join("\n")
+}
+
+fn opt_num(n: Option<u64>) -> String {
+    n.map(|n| n.to_string()).unwrap_or_else(|| "?".into())
+}
+
 pub fn format_application_info(a: &ApplicationInfo) -> String {
     let mut parts = vec![format!("**{}** [client: {}]", a.name, a.client_id)];
     if let Some(ref desc) = a.description {
@@ -1524,7 +1590,11 @@ pub fn format_application_info(a: &ApplicationInfo) -> String {
 }
 
 pub fn format_external_user(u: &ExternalUser) -> String {
-    let name = u.display_name.as_deref().or(u.name.as_deref()).unwrap_or("?");
+    let name = u
+        .display_name
+        .as_deref()
+        .or(u.name.as_deref())
+        .unwrap_or("?");
     let email = u.email.as_deref().unwrap_or("");
     if email.is_empty() {
         format!("{} [id: {}]", name, u.id)
@@ -1534,20 +1604,44 @@ pub fn format_external_user(u: &ExternalUser) -> String {
 }
 
 pub fn format_priority_value(p: &IssuePriorityValue) -> String {
-    format!("{}: {} ({})", p.priority, p.label, match p.priority {
-        0 => "No priority",
-        1 => "Urgent",
-        2 => "High",
-        3 => "Medium",
-        4 => "Low",
-        _ => "Unknown",
-    })
+    format!(
+        "{}: {} ({})",
+        p.priority,
+        p.label,
+        match p.priority {
+            0 => "No priority",
+            1 => "Urgent",
+            2 => "High",
+            3 => "Medium",
+            4 => "Low",
+            _ => "Unknown",
+        }
+    )
 }
 
 pub fn format_document_content_history_entry(e: &DocumentContentHistoryEntry) -> String {
     let date = e.created_at.as_deref().map(format_date).unwrap_or("?");
-    let actors = e.actor_ids.as_ref()
+    let actors = e
+        .actor_ids
+        .as_ref()
         .map(|ids| ids.join(", "))
         .unwrap_or_else(|| "unknown".into());
     format!("{} by {} [id: {}]", date, actors, e.id)
 }
+
+/// Strip the markdown markup these formatters add (`#` headings and `**`
+/// emphasis) for clients that render plain text.
+pub fn to_plain_text(text: &str) -> String {
+    text.lines()
+        .map(|line| {
+            let trimmed = line.trim_start_matches('#');
+            let line = if trimmed.len() != line.len() && trimmed.starts_with(' ') {
+                &trimmed[1..]
+            } else {
+                line
+            };
+            line.replace("**", "")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
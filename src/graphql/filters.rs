@@ -1,61 +1,131 @@
 use serde::Serialize;
 
 /// Generic string comparison filter used throughout Linear's API.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct StringFilter {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub eq: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub neq: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub eq_ignore_case: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub neq_ignore_case: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub contains_ignore_case: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub not_contains_ignore_case: Option<String>,
 }
 
 impl StringFilter {
     pub fn eq_ignore_case(val: impl Into<String>) -> Self {
         Self {
-            eq: None,
             eq_ignore_case: Some(val.into()),
-            contains_ignore_case: None,
+            ..Default::default()
+        }
+    }
+
+    pub fn neq_ignore_case(val: impl Into<String>) -> Self {
+        Self {
+            neq_ignore_case: Some(val.into()),
+            ..Default::default()
         }
     }
 
     pub fn eq_exact(val: impl Into<String>) -> Self {
         Self {
             eq: Some(val.into()),
-            eq_ignore_case: None,
-            contains_ignore_case: None,
+            ..Default::default()
         }
     }
 
     pub fn contains_ignore_case(val: impl Into<String>) -> Self {
         Self {
-            eq: None,
-            eq_ignore_case: None,
             contains_ignore_case: Some(val.into()),
+            ..Default::default()
+        }
+    }
+
+    pub fn not_contains_ignore_case(val: impl Into<String>) -> Self {
+        Self {
+            not_contains_ignore_case: Some(val.into()),
+            ..Default::default()
         }
     }
 }
 
+/// Comparison filter for numbers and dates (Linear's `NumberComparator`,
+/// `DateComparator`, ...).
 #[derive(Debug, Clone, Serialize)]
-pub struct NumberFilter {
-    pub eq: i32,
+pub struct Comparator<T> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eq: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub neq: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lt: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lte: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gt: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gte: Option<T>,
+    #[serde(rename = "in", skip_serializing_if = "Option::is_none")]
+    pub in_: Option<Vec<T>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nin: Option<Vec<T>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub null: Option<bool>,
 }
 
-/// Float comparison filter for estimate fields.
-#[derive(Debug, Clone, Serialize)]
-pub struct EstimateFilter {
-    pub eq: f64,
+// Not derived: that would require `T: Default`.
+impl<T> Default for Comparator<T> {
+    fn default() -> Self {
+        Self {
+            eq: None,
+            neq: None,
+            lt: None,
+            lte: None,
+            gt: None,
+            gte: None,
+            in_: None,
+            nin: None,
+            null: None,
+        }
+    }
 }
 
+impl<T> Comparator<T> {
+    pub fn eq(val: T) -> Self {
+        Self {
+            eq: Some(val),
+            ..Default::default()
+        }
+    }
+
+    /// Matches when the field is (`true`) or is not (`false`) set.
+    pub fn null(is_null: bool) -> Self {
+        Self {
+            null: Some(is_null),
+            ..Default::default()
+        }
+    }
+}
+
+/// Priority comparison filter.
+pub type NumberFilter = Comparator<i32>;
+
+/// Float comparison filter for estimate fields.
+pub type EstimateFilter = Comparator<f64>;
+
 /// Date comparator filter for Linear's DateComparator input type.
-#[derive(Debug, Clone, Default, Serialize)]
-pub struct DateComparator {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub lt: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub gt: Option<String>,
+pub type DateComparator = Comparator<String>;
+
+/// Boolean comparison filter, e.g. a cycle's `isActive`.
+#[derive(Debug, Clone, Serialize)]
+pub struct BooleanFilter {
+    pub eq: bool,
 }
 
 /// Relation existence filter.
@@ -65,23 +135,44 @@ pub struct RelationExistsFilter {
     pub some: Option<serde_json::Value>,
 }
 
-/// Issue filter matching Linear's IssueFilter input type.
+/// Issue filter matching Linear's IssueFilter input type. Used as is for
+/// the `parent` relation, where `null` applies.
 #[derive(Debug, Clone, Default, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct IssueFilter {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub team: Option<TeamFilter>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub assignee: Option<AssigneeFilter>,
+    pub assignee: Option<UserFilter>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creator: Option<CreatorFilter>,
+    pub creator: Option<UserFilter>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub state: Option<StateFilter>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub project: Option<ProjectNameFilter>,
+    pub project: Option<IssueProjectFilter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<CycleFilter>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub labels: Option<LabelsFilter>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_milestone: Option<MilestoneFilter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscribers: Option<CollectionFilter<UserFilter>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<AttachmentsFilter>,
+    /// Customer requests linked to the issue.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub needs: Option<CollectionFilter<CustomerNeedFilter>>,
+    /// `Breached`, `HighRisk`, `MediumRisk`, `LowRisk`, `Completed` or `Failed`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sla_status: Option<Comparator<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Box<IssueFilter>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<StringFilter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number: Option<NumberFilter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub priority: Option<NumberFilter>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub estimate: Option<EstimateFilter>,
@@ -92,6 +183,8 @@ pub struct IssueFilter {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub updated_at: Option<DateComparator>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<DateComparator>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub completed_at: Option<DateComparator>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub canceled_at: Option<DateComparator>,
@@ -100,7 +193,15 @@ pub struct IssueFilter {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub relations: Option<RelationExistsFilter>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_blocked_by_relations: Option<BooleanFilter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_blocking_relations: Option<BooleanFilter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub null: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub and: Option<Vec<IssueFilter>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<IssueFilter>>,
 }
 
 impl IssueFilter {
@@ -114,6 +215,22 @@ impl IssueFilter {
             }),
         }
     }
+
+    /// Matches issues matching any of `filters`.
+    pub fn any(mut filters: Vec<IssueFilter>) -> IssueFilter {
+        if filters.len() == 1 {
+            return filters.remove(0);
+        }
+        IssueFilter {
+            or: Some(filters),
+            ..Default::default()
+        }
+    }
+
+    /// Matches issues matching all of `filters`.
+    pub fn all(filters: Vec<IssueFilter>) -> IssueFilter {
+        Self::combine(filters).unwrap_or_default()
+    }
 }
 
 #[derive(Debug, Clone, Default, Serialize)]
@@ -124,65 +241,174 @@ pub struct TeamFilter {
     pub key: Option<StringFilter>,
 }
 
-#[derive(Debug, Clone, Serialize)]
-pub struct AssigneeFilter {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub or: Option<Vec<AssigneeFieldFilter>>,
+/// Filter on a user relation: assignee, creator, project lead.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserFilter {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<StringFilter>,
-}
-
-#[derive(Debug, Clone, Serialize)]
-pub struct AssigneeFieldFilter {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub email: Option<StringFilter>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub display_name: Option<StringFilter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_me: Option<BooleanFilter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub null: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<UserFilter>>,
 }
 
-#[derive(Debug, Clone, Serialize)]
-pub struct CreatorFilter {
+impl UserFilter {
+    /// The user with this email or display name, ignoring case.
+    pub fn named(name: &str) -> Self {
+        UserFilter {
+            or: Some(vec![
+                UserFilter {
+                    email: Some(StringFilter::eq_ignore_case(name)),
+                    ..Default::default()
+                },
+                UserFilter {
+                    display_name: Some(StringFilter::eq_ignore_case(name)),
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        }
+    }
+
+    /// Any user but the one with this email or display name.
+    pub fn not_named(name: &str) -> Self {
+        UserFilter {
+            email: Some(StringFilter::neq_ignore_case(name)),
+            display_name: Some(StringFilter::neq_ignore_case(name)),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct StateFilter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<StringFilter>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub or: Option<Vec<CreatorFieldFilter>>,
+    #[serde(rename = "type")]
+    pub state_type: Option<StateTypeFilter>,
 }
 
-#[derive(Debug, Clone, Serialize)]
-pub struct CreatorFieldFilter {
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct StateTypeFilter {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub email: Option<StringFilter>,
+    pub eq: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub display_name: Option<StringFilter>,
+    pub neq: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nin: Option<Vec<String>>,
 }
 
-#[derive(Debug, Clone, Serialize)]
-pub struct StateFilter {
+/// Filter on an issue's project.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueProjectFilter {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<StringFilter>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "type")]
-    pub state_type: Option<StateTypeFilter>,
+    pub state: Option<StringFilter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lead: Option<UserFilter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub null: Option<bool>,
+}
+
+/// Filter on an issue's cycle.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CycleFilter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number: Option<NumberFilter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<StringFilter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_active: Option<BooleanFilter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_next: Option<BooleanFilter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_previous: Option<BooleanFilter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub null: Option<bool>,
 }
 
+/// Filter on one of an issue's collections (labels, subscribers, customer
+/// needs): `some` item matches, `every` one does, or how many there are.
 #[derive(Debug, Clone, Serialize)]
-pub struct StateTypeFilter {
-    pub nin: Vec<String>,
+pub struct CollectionFilter<T> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub some: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub every: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub length: Option<NumberFilter>,
 }
 
+// Not derived: that would require `T: Default`.
+impl<T> Default for CollectionFilter<T> {
+    fn default() -> Self {
+        Self {
+            some: None,
+            every: None,
+            length: None,
+        }
+    }
+}
+
+impl<T> CollectionFilter<T> {
+    /// Matches collections that are (`true`) or are not (`false`) empty.
+    pub fn empty(is_empty: bool) -> Self {
+        let length = if is_empty {
+            NumberFilter::eq(0)
+        } else {
+            NumberFilter {
+                gt: Some(0),
+                ..Default::default()
+            }
+        };
+        Self {
+            length: Some(length),
+            ..Default::default()
+        }
+    }
+}
+
+pub type LabelsFilter = CollectionFilter<LabelNameFilter>;
+
 #[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct ProjectNameFilter {
+pub struct LabelNameFilter {
     pub name: StringFilter,
 }
 
+/// Only ever filtered on by count, so items are left untyped.
+pub type AttachmentsFilter = CollectionFilter<serde_json::Value>;
+
+/// Filter on a customer request by its customer.
 #[derive(Debug, Clone, Serialize)]
-pub struct LabelsFilter {
-    pub some: LabelNameFilter,
+pub struct CustomerNeedFilter {
+    pub customer: CustomerNameFilter,
 }
 
 #[derive(Debug, Clone, Serialize)]
-pub struct LabelNameFilter {
+pub struct CustomerNameFilter {
     pub name: StringFilter,
 }
 
+/// Filter on an issue's project milestone.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MilestoneFilter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<StringFilter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub null: Option<bool>,
+}
+
 /// Workflow state filter for resolve and list queries.
 #[derive(Debug, Clone, Default, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -193,12 +419,6 @@ pub struct WorkflowStateFilter {
     pub team: Option<TeamFilter>,
 }
 
-/// User filter for resolve queries.
-#[derive(Debug, Clone, Serialize)]
-pub struct UserFilter {
-    pub email: StringFilter,
-}
-
 /// Project filter for list queries.
 #[derive(Debug, Clone, Default, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -206,7 +426,7 @@ pub struct ProjectFilter {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub state: Option<StringFilter>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub lead: Option<ProjectLeadFilter>,
+    pub lead: Option<UserFilter>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub accessible_teams: Option<TeamCollectionFilter>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -233,21 +453,6 @@ impl ProjectFilter {
     }
 }
 
-/// Lead filter for projects.
-#[derive(Debug, Clone, Serialize)]
-pub struct ProjectLeadFilter {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub or: Option<Vec<ProjectLeadFieldFilter>>,
-}
-
-#[derive(Debug, Clone, Serialize)]
-pub struct ProjectLeadFieldFilter {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub email: Option<StringFilter>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub display_name: Option<StringFilter>,
-}
-
 // Builder helpers for common filter patterns.
 
 pub fn team_filter(key: &str) -> IssueFilter {
@@ -262,19 +467,7 @@ pub fn team_filter(key: &str) -> IssueFilter {
 
 pub fn assignee_filter(assignee: &str) -> IssueFilter {
     IssueFilter {
-        assignee: Some(AssigneeFilter {
-            or: Some(vec![
-                AssigneeFieldFilter {
-                    email: Some(StringFilter::eq_ignore_case(assignee)),
-                    display_name: None,
-                },
-                AssigneeFieldFilter {
-                    email: None,
-                    display_name: Some(StringFilter::eq_ignore_case(assignee)),
-                },
-            ]),
-            id: None,
-        }),
+        assignee: Some(UserFilter::named(assignee)),
         ..Default::default()
     }
 }
@@ -289,56 +482,18 @@ pub fn status_filter(status: &str) -> IssueFilter {
     }
 }
 
-pub fn project_filter(project: &str) -> IssueFilter {
-    IssueFilter {
-        project: Some(ProjectNameFilter {
-            name: StringFilter::contains_ignore_case(project),
-        }),
-        ..Default::default()
-    }
-}
-
-pub fn label_filter(label: &str) -> IssueFilter {
-    IssueFilter {
-        labels: Some(LabelsFilter {
-            some: LabelNameFilter {
-                name: StringFilter::eq_ignore_case(label),
-            },
-        }),
-        ..Default::default()
-    }
-}
-
 pub fn priority_filter(priority: i32) -> IssueFilter {
     IssueFilter {
-        priority: Some(NumberFilter { eq: priority }),
+        priority: Some(NumberFilter::eq(priority)),
         ..Default::default()
     }
 }
 
 pub fn viewer_filter(viewer_id: &str) -> IssueFilter {
     IssueFilter {
-        assignee: Some(AssigneeFilter {
-            or: None,
+        assignee: Some(UserFilter {
             id: Some(StringFilter::eq_exact(viewer_id)),
-        }),
-        ..Default::default()
-    }
-}
-
-pub fn creator_filter(creator: &str) -> IssueFilter {
-    IssueFilter {
-        creator: Some(CreatorFilter {
-            or: Some(vec![
-                CreatorFieldFilter {
-                    email: Some(StringFilter::eq_ignore_case(creator)),
-                    display_name: None,
-                },
-                CreatorFieldFilter {
-                    email: None,
-                    display_name: Some(StringFilter::eq_ignore_case(creator)),
-                },
-            ]),
+            ..Default::default()
         }),
         ..Default::default()
     }
@@ -346,17 +501,24 @@ pub fn creator_filter(creator: &str) -> IssueFilter {
 
 pub fn estimate_filter(estimate: f64) -> IssueFilter {
     IssueFilter {
-        estimate: Some(EstimateFilter { eq: estimate }),
+        estimate: Some(EstimateFilter::eq(estimate)),
         ..Default::default()
     }
 }
 
-pub fn has_relation_filter() -> IssueFilter {
-    IssueFilter {
-        relations: Some(RelationExistsFilter {
-            some: Some(serde_json::json!({})),
-        }),
-        ..Default::default()
+/// Issues blocked by another issue (`blocked_by`) or blocking one.
+pub fn blocking_relation_filter(blocked_by: bool) -> IssueFilter {
+    let has = Some(BooleanFilter { eq: true });
+    if blocked_by {
+        IssueFilter {
+            has_blocked_by_relations: has,
+            ..Default::default()
+        }
+    } else {
+        IssueFilter {
+            has_blocking_relations: has,
+            ..Default::default()
+        }
     }
 }
 
@@ -365,7 +527,8 @@ pub fn exclude_completed_filter() -> IssueFilter {
         state: Some(StateFilter {
             name: None,
             state_type: Some(StateTypeFilter {
-                nin: vec!["completed".to_string(), "canceled".to_string()],
+                nin: Some(vec!["completed".to_string(), "canceled".to_string()]),
+                ..Default::default()
             }),
         }),
         ..Default::default()
@@ -377,6 +540,7 @@ pub fn due_date_filter(before: Option<&str>, after: Option<&str>) -> IssueFilter
         due_date: Some(DateComparator {
             lt: before.map(|s| s.to_string()),
             gt: after.map(|s| s.to_string()),
+            ..Default::default()
         }),
         ..Default::default()
     }
@@ -387,6 +551,7 @@ pub fn created_at_filter(before: Option<&str>, after: Option<&str>) -> IssueFilt
         created_at: Some(DateComparator {
             lt: before.map(|s| s.to_string()),
             gt: after.map(|s| s.to_string()),
+            ..Default::default()
         }),
         ..Default::default()
     }
@@ -397,6 +562,7 @@ pub fn updated_at_filter(before: Option<&str>, after: Option<&str>) -> IssueFilt
         updated_at: Some(DateComparator {
             lt: before.map(|s| s.to_string()),
             gt: after.map(|s| s.to_string()),
+            ..Default::default()
         }),
         ..Default::default()
     }
@@ -425,6 +591,7 @@ pub fn completed_at_filter(before: Option<&str>, after: Option<&str>) -> IssueFi
         completed_at: Some(DateComparator {
             lt: before.map(|s| s.to_string()),
             gt: after.map(|s| s.to_string()),
+            ..Default::default()
         }),
         ..Default::default()
     }
@@ -435,6 +602,7 @@ pub fn canceled_at_filter(before: Option<&str>, after: Option<&str>) -> IssueFil
         canceled_at: Some(DateComparator {
             lt: before.map(|s| s.to_string()),
             gt: after.map(|s| s.to_string()),
+            ..Default::default()
         }),
         ..Default::default()
     }
@@ -443,8 +611,8 @@ pub fn canceled_at_filter(before: Option<&str>, after: Option<&str>) -> IssueFil
 pub fn snoozed_until_at_filter(after: &str) -> IssueFilter {
     IssueFilter {
         snoozed_until_at: Some(DateComparator {
-            lt: None,
             gt: Some(after.to_string()),
+            ..Default::default()
         }),
         ..Default::default()
     }
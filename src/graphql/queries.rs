@@ -1,6 +1,60 @@
+/// Mutations that can be resent after a timeout or 5xx without duplicating
+/// their effect: they set fields to given values rather than creating
+/// entities or appending. Matched by operation name.
+pub const RETRY_SAFE_MUTATIONS: &[&str] = &[
+    "BatchUpdateIssues",
+    "IssueAddLabel",
+    "IssueRemoveLabel",
+    "MarkNotificationRead",
+    "ResolveComment",
+    "SubscribeToIssue",
+    "UnresolveComment",
+    "UnsubscribeFromIssue",
+    "UpdateAgentSession",
+    "UpdateAttachment",
+    "UpdateComment",
+    "UpdateCustomer",
+    "UpdateCustomerNeed",
+    "UpdateCustomerStatus",
+    "UpdateCustomerTier",
+    "UpdateCycle",
+    "UpdateDocument",
+    "UpdateEmailIntakeAddress",
+    "UpdateEntityExternalLink",
+    "UpdateFavorite",
+    "UpdateGitAutomationState",
+    "UpdateGitAutomationTargetBranch",
+    "UpdateInitiative",
+    "UpdateInitiativeRelation",
+    "UpdateInitiativeToProject",
+    "UpdateInitiativeUpdate",
+    "UpdateIssue",
+    "UpdateIssueRelation",
+    "UpdateLabel",
+    "UpdateNotificationSubscription",
+    "UpdateProject",
+    "UpdateProjectLabel",
+    "UpdateProjectMilestone",
+    "UpdateProjectRelation",
+    "UpdateProjectStatus",
+    "UpdateProjectUpdate",
+    "UpdateRelease",
+    "UpdateReleasePipeline",
+    "UpdateReleaseStage",
+    "UpdateTeam",
+    "UpdateTeamMembership",
+    "UpdateTemplate",
+    "UpdateTimeSchedule",
+    "UpdateTriageResponsibility",
+    "UpdateUser",
+    "UpdateView",
+    "UpdateWebhook",
+    "UpdateWorkflowState",
+];
+
 /// Viewer (authenticated user) query.
 pub const VIEWER: &str = r#"
-query {
+query Viewer {
     viewer {
         id
         displayName
@@ -57,11 +111,11 @@ query SearchIssues($query: String!, $first: Int, $after: String, $filter: IssueF
 }
 "#;
 
-/// Lightweight query to get the team ID for an issue.
-pub const GET_ISSUE_TEAM: &str = r#"
-query GetIssueTeam($id: String!) {
-    issue(id: $id) {
-        team { id key }
+/// Team keys for a batch of issues, filtered by `id: { in: [...] }`.
+pub const GET_ISSUES_TEAMS: &str = r#"
+query GetIssuesTeams($first: Int!, $filter: IssueFilter) {
+    issues(first: $first, filter: $filter) {
+        nodes { id team { id key } }
     }
 }
 "#;
@@ -228,20 +282,47 @@ query ResolveTeam($filter: TeamFilter!) {
 }
 "#;
 
-/// Resolve user email to ID.
-pub const RESOLVE_USER: &str = r#"
-query ResolveUser($filter: UserFilter!) {
-    users(filter: $filter) {
-        nodes { id displayName email }
+/// Batch-resolve issue identifiers; filtered by an `or` of team key + number.
+pub const RESOLVE_ISSUES: &str = r#"
+query ResolveIssues($filter: IssueFilter!, $first: Int!) {
+    issues(filter: $filter, first: $first, includeArchived: true) {
+        nodes { id identifier team { id key } }
+    }
+}
+"#;
+
+/// Resolve one issue by identifier; follows identifiers of moved issues.
+pub const RESOLVE_ISSUE: &str = r#"
+query ResolveIssue($id: String!) {
+    issue(id: $id) {
+        id identifier team { id key }
     }
 }
 "#;
 
-/// Resolve workflow state by name and team.
-pub const RESOLVE_STATE: &str = r#"
-query ResolveState($filter: WorkflowStateFilter!) {
-    workflowStates(filter: $filter) {
-        nodes { id name type }
+/// Batch-resolve user emails, filtered by an `or` of emails.
+pub const RESOLVE_USERS: &str = r#"
+query ResolveUsers($filter: UserFilter!, $first: Int!) {
+    users(filter: $filter, first: $first) {
+        nodes { id email }
+    }
+}
+"#;
+
+/// Every active user, for fuzzy name matching.
+pub const USER_CANDIDATES: &str = r#"
+query UserCandidates {
+    users(first: 250) {
+        nodes { id name displayName email }
+    }
+}
+"#;
+
+/// Batch-resolve workflow states, filtered by an `or` of name + team key.
+pub const RESOLVE_STATES: &str = r#"
+query ResolveStates($filter: WorkflowStateFilter!, $first: Int!) {
+    workflowStates(filter: $filter, first: $first) {
+        nodes { id name team { id key } }
     }
 }
 "#;
@@ -425,10 +506,11 @@ mutation CreateLabel($input: IssueLabelCreateInput!) {
 /// Resolve labels by name (for matching comma-separated label names to IDs).
 pub const RESOLVE_LABELS: &str = r#"
 query ResolveLabels($filter: IssueLabelFilter) {
-    issueLabels(first: 100, filter: $filter) {
+    issueLabels(first: 250, filter: $filter) {
         nodes {
             id
             name
+            team { id key }
         }
     }
 }
@@ -446,6 +528,44 @@ query ResolveProject($filter: ProjectFilter) {
 }
 "#;
 
+/// A team's cycles, for resolving `current`/`next`/`previous`, numbers and names.
+pub const RESOLVE_CYCLES: &str = r#"
+query ResolveCycles($teamId: String!) {
+    team(id: $teamId) {
+        cycles(first: 250) {
+            nodes { id number name isActive isNext isPrevious startsAt endsAt }
+        }
+    }
+}
+"#;
+
+/// Custom views by name, for resolving a view name.
+pub const RESOLVE_VIEWS: &str = r#"
+query ResolveViews($filter: CustomViewFilter) {
+    customViews(first: 50, filter: $filter) {
+        nodes { id name }
+    }
+}
+"#;
+
+/// Documents by title, for resolving a document title.
+pub const RESOLVE_DOCUMENTS: &str = r#"
+query ResolveDocuments($filter: DocumentFilter) {
+    documents(first: 50, filter: $filter) {
+        nodes { id title project { name } }
+    }
+}
+"#;
+
+/// Project milestones by name across all projects.
+pub const RESOLVE_MILESTONES: &str = r#"
+query ResolveMilestones($filter: ProjectMilestoneFilter) {
+    projectMilestones(first: 50, filter: $filter) {
+        nodes { id name project { name } }
+    }
+}
+"#;
+
 /// Create an issue relation.
 pub const CREATE_ISSUE_RELATION: &str = r#"
 mutation CreateIssueRelation($input: IssueRelationCreateInput!) {
@@ -3127,3 +3247,40 @@ query ListExternalUsers($first: Int!) {
     }
 }
 "#;
+
+// ---- Resource subscriptions ----
+// When an entity last changed, along with the most recently changed of its
+// comments or issues, aliased `latest`.
+
+pub const ISSUE_VERSION: &str = r#"
+query IssueVersion($id: String!) {
+    issue(id: $id) {
+        id updatedAt
+        latest: comments(first: 1, orderBy: updatedAt) { nodes { updatedAt } }
+    }
+}
+"#;
+
+pub const PROJECT_VERSION: &str = r#"
+query ProjectVersion($id: String!) {
+    project(id: $id) {
+        id updatedAt
+        latest: issues(first: 1, orderBy: updatedAt) { nodes { updatedAt } }
+    }
+}
+"#;
+
+pub const DOCUMENT_VERSION: &str = r#"
+query DocumentVersion($id: String!) {
+    document(id: $id) { id updatedAt }
+}
+"#;
+
+pub const CYCLE_VERSION: &str = r#"
+query CycleVersion($id: String!) {
+    cycle(id: $id) {
+        id updatedAt
+        latest: issues(first: 1, orderBy: updatedAt) { nodes { updatedAt } }
+    }
+}
+"#;
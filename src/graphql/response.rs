@@ -1,5 +1,6 @@
 #![allow(dead_code)]
-use serde::Deserialize;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
 /// Top-level GraphQL response envelope.
 #[derive(Debug, Deserialize)]
@@ -11,17 +12,38 @@ pub struct GraphQLResponse<T> {
 #[derive(Debug, Deserialize)]
 pub struct GraphQLError {
     pub message: String,
+    /// Response path of the field that failed, e.g. `["issueCreate"]`.
+    #[serde(default)]
+    pub path: Vec<serde_json::Value>,
+    #[serde(default)]
+    pub extensions: GraphQLErrorExtensions,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GraphQLErrorExtensions {
+    /// e.g. `RATELIMITED`, `AUTHENTICATION_ERROR`, `FORBIDDEN`, `INVALID_INPUT`.
+    pub code: Option<String>,
+    /// Linear's human-readable category, e.g. `invalid input`.
+    #[serde(rename = "type")]
+    pub kind: Option<String>,
+    pub user_presentable_message: Option<String>,
+    pub status_code: Option<u16>,
+    /// Offending argument for input validation errors, when given.
+    pub argument_path: Option<Vec<serde_json::Value>>,
+    pub field: Option<String>,
 }
 
 // ---- Query response data shapes ----
 
 #[derive(Debug, Deserialize)]
-pub struct IssueTeamData {
-    pub issue: IssueTeamRef,
+pub struct IssueTeamsData {
+    pub issues: crate::types::NodeList<IssueTeamNode>,
 }
 
 #[derive(Debug, Deserialize)]
-pub struct IssueTeamRef {
+pub struct IssueTeamNode {
+    pub id: String,
     pub team: crate::types::TeamRef,
 }
 
@@ -56,7 +78,7 @@ pub struct TeamsWithMembersData {
     pub teams: crate::types::NodeList<TeamWithMembers>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
 pub struct TeamWithMembers {
     pub id: String,
     pub key: String,
@@ -68,14 +90,13 @@ pub struct TeamWithMembers {
     pub members: Option<MemberCountList>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
 pub struct MemberCountList {
     pub nodes: Vec<MemberId>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
 pub struct MemberId {
-    #[allow(dead_code)]
     pub id: String,
 }
 
@@ -96,7 +117,7 @@ pub struct WorkflowStatesData {
 }
 
 /// Workflow state with embedded team info (for list_states grouping).
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
 pub struct WorkflowStateWithTeam {
     pub id: String,
     pub name: String,
@@ -106,19 +127,134 @@ pub struct WorkflowStateWithTeam {
     pub team: crate::types::Team,
 }
 
-/// Response type for resolve queries (simpler than full list queries).
+/// Response types for resolve queries (simpler than full list queries).
+#[derive(Debug, Deserialize)]
+pub struct ResolveIssuesData {
+    pub issues: crate::types::NodeList<ResolvedIssue>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ResolveIssueData {
+    pub issue: ResolvedIssue,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ResolvedIssue {
+    pub id: String,
+    pub identifier: String,
+    pub team: crate::types::TeamRef,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ResolveUsersData {
+    pub users: crate::types::NodeList<ResolvedUser>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ResolvedUser {
+    pub id: String,
+    pub email: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ResolveCyclesData {
+    pub team: ResolveCyclesTeam,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ResolveCyclesTeam {
+    pub cycles: crate::types::NodeList<ResolvedCycle>,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct ResolveStateData {
-    pub workflow_states: crate::types::NodeList<ResolvedState>,
+pub struct ResolvedCycle {
+    pub id: String,
+    pub number: i32,
+    pub name: Option<String>,
+    pub is_active: bool,
+    pub is_next: bool,
+    pub is_previous: bool,
+    pub starts_at: String,
+    pub ends_at: String,
 }
 
 #[derive(Debug, Deserialize)]
-pub struct ResolvedState {
+#[serde(rename_all = "camelCase")]
+pub struct ResolveViewsData {
+    pub custom_views: crate::types::NodeList<ResolvedView>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ResolvedView {
     pub id: String,
     pub name: String,
-    #[serde(rename = "type")]
-    pub state_type: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ResolveDocumentsData {
+    pub documents: crate::types::NodeList<ResolvedDocument>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ResolvedDocument {
+    pub id: String,
+    pub title: String,
+    pub project: Option<crate::types::DocumentProject>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolveMilestonesData {
+    pub project_milestones: crate::types::NodeList<ResolvedMilestone>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ResolvedMilestone {
+    pub id: String,
+    pub name: String,
+    pub project: Option<crate::types::DocumentProject>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UserCandidatesData {
+    pub users: crate::types::NodeList<UserCandidate>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserCandidate {
+    pub id: String,
+    pub name: String,
+    pub display_name: String,
+    pub email: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolveStatesData {
+    pub workflow_states: crate::types::NodeList<ResolvedTeamState>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ResolvedTeamState {
+    pub id: String,
+    pub name: String,
+    pub team: crate::types::TeamRef,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolveLabelsData {
+    pub issue_labels: crate::types::NodeList<ResolvedLabel>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ResolvedLabel {
+    pub id: String,
+    pub name: String,
+    /// `None` for workspace labels.
+    pub team: Option<crate::types::TeamRef>,
 }
 
 // ---- Mutation response data shapes ----
@@ -160,7 +296,7 @@ pub struct TeamCyclesData {
     pub team: TeamCycles,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
 pub struct TeamCycles {
     pub cycles: crate::types::NodeList<crate::types::Cycle>,
 }
@@ -263,7 +399,7 @@ pub struct ProjectUpdatesData {
     pub project: ProjectWithUpdates,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ProjectWithUpdates {
     pub project_updates: crate::types::NodeList<crate::types::ProjectUpdate>,
@@ -282,7 +418,7 @@ pub struct ProjectMilestonesData {
     pub project: ProjectWithMilestones,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ProjectWithMilestones {
     pub project_milestones: crate::types::NodeList<crate::types::ProjectMilestone>,
@@ -321,7 +457,7 @@ pub struct AttachmentsData {
     pub issue: IssueWithAttachments,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
 pub struct IssueWithAttachments {
     pub attachments: crate::types::NodeList<crate::types::Attachment>,
 }
@@ -387,7 +523,7 @@ pub struct IssueHistoryData {
     pub issue: IssueWithHistory,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
 pub struct IssueWithHistory {
     pub history: crate::types::NodeList<crate::types::IssueHistoryEntry>,
 }
@@ -609,7 +745,7 @@ pub struct ListCommentsData {
     pub issue: IssueWithComments,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
 pub struct IssueWithComments {
     pub comments: crate::types::NodeList<crate::types::Comment>,
 }
@@ -755,7 +891,7 @@ pub struct InitiativeUpdatesData {
     pub initiative: InitiativeWithUpdates,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct InitiativeWithUpdates {
     pub initiative_updates: crate::types::NodeList<crate::types::InitiativeStatusUpdate>,
@@ -798,7 +934,7 @@ pub struct ProjectRelationsData {
     pub project: ProjectWithRelations,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
 pub struct ProjectWithRelations {
     pub relations: crate::types::NodeList<crate::types::ProjectRelation>,
 }
@@ -938,11 +1074,12 @@ pub struct UnarchiveDocumentData {
 }
 
 #[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct DocumentContentHistoryData {
     pub document_content_history: DocumentContentHistoryPayload,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct DocumentContentHistoryPayload {
     pub success: bool,
@@ -1371,7 +1508,7 @@ pub struct UpdateNotificationSubscriptionData {
     pub notification_subscription_update: crate::types::NotificationSubscriptionMutationResult,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct NotificationsUnreadCountData {
     pub notifications_unread_count: i32,
@@ -1643,12 +1780,12 @@ pub struct SemanticSearchData {
     pub semantic_search: SemanticSearchPayload,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
 pub struct SemanticSearchPayload {
     pub results: Vec<SemanticSearchResult>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct SemanticSearchResult {
     #[serde(rename = "type")]
@@ -1659,13 +1796,13 @@ pub struct SemanticSearchResult {
     pub document: Option<DocumentBasic>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
 pub struct ProjectBasic {
     pub id: String,
     pub name: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
 pub struct DocumentBasic {
     pub id: String,
     pub title: String,
@@ -1697,7 +1834,7 @@ pub struct ProjectFilterSuggestionData {
     pub project_filter_suggestion: FilterSuggestionResult,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
 pub struct FilterSuggestionResult {
     pub filter: Option<serde_json::Value>,
 }
@@ -1708,7 +1845,7 @@ pub struct CustomViewSuggestionData {
     pub custom_view_details_suggestion: CustomViewSuggestionResult,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CustomViewSuggestionResult {
     pub name: Option<String>,
@@ -1722,7 +1859,7 @@ pub struct CustomViewHasSubscribersData {
     pub custom_view_has_subscribers: CustomViewHasSubscribersPayload,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CustomViewHasSubscribersPayload {
     pub has_subscribers: bool,
@@ -1768,3 +1905,29 @@ pub struct ListIssueRelationsData {
 pub struct ExternalUsersData {
     pub external_users: crate::types::NodeList<crate::types::ExternalUser>,
 }
+
+/// Response of the `*_VERSION` queries, whichever entity they ask for.
+#[derive(Debug, Deserialize)]
+pub struct VersionData {
+    #[serde(
+        alias = "issue",
+        alias = "project",
+        alias = "document",
+        alias = "cycle"
+    )]
+    pub entity: EntityVersion,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntityVersion {
+    pub id: String,
+    pub updated_at: String,
+    pub latest: Option<crate::types::NodeList<UpdatedAt>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatedAt {
+    pub updated_at: String,
+}
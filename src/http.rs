@@ -0,0 +1,68 @@
+use rmcp::transport::streamable_http_server::{
+    StreamableHttpServerConfig, StreamableHttpService, session::local::LocalSessionManager,
+};
+use tokio_util::sync::CancellationToken;
+
+use crate::server::LinearMcp;
+
+/// Path the MCP endpoint is mounted at.
+const MCP_PATH: &str = "/mcp";
+
+/// Serve the MCP router over streamable HTTP until SIGINT/SIGTERM.
+///
+/// Each MCP session gets its own copy of `server`; copies share the
+/// underlying HTTP clients and caches.
+pub async fn serve(addr: &str, server: LinearMcp) -> Result<(), Box<dyn std::error::Error>> {
+    let shutdown = CancellationToken::new();
+    let config = StreamableHttpServerConfig {
+        cancellation_token: shutdown.child_token(),
+        ..Default::default()
+    };
+    let service = StreamableHttpService::new(
+        move || Ok(server.new_session()),
+        LocalSessionManager::default().into(),
+        config,
+    );
+
+    let router = axum::Router::new().nest_service(MCP_PATH, service);
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    tracing::info!("Listening on http://{}{}", listener.local_addr()?, MCP_PATH);
+
+    axum::serve(listener, router)
+        .with_graceful_shutdown(async move {
+            shutdown_signal().await;
+            tracing::info!("Shutting down, closing open sessions");
+            // Terminates open sessions (and their SSE streams) so the
+            // graceful shutdown does not wait on long-lived connections.
+            shutdown.cancel();
+        })
+        .await?;
+
+    Ok(())
+}
+
+/// Resolve when the process receives Ctrl-C or (on Unix) SIGTERM.
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        let _ = tokio::signal::ctrl_c().await;
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        use tokio::signal::unix::{SignalKind, signal};
+        match signal(SignalKind::terminate()) {
+            Ok(mut sig) => {
+                sig.recv().await;
+            }
+            Err(_) => std::future::pending::<()>().await,
+        }
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {}
+        _ = terminate => {}
+    }
+}
@@ -0,0 +1,121 @@
+//! Recognizing issue references in the forms people paste them: identifiers
+//! (`ENG-123`), Linear issue URLs, and git branch names.
+
+/// An issue identifier split into its team key and number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Identifier {
+    pub team_key: String,
+    pub number: u32,
+}
+
+impl Identifier {
+    /// Find the identifier in `input`, which may be a bare identifier
+    /// (`eng-123`), an issue URL (`https://linear.app/acme/issue/ENG-123/fix-login`)
+    /// or a branch name (`alice/eng-123-fix-login`).
+    pub fn parse(input: &str) -> Option<Self> {
+        let input = input.trim();
+        if let Some(path) = input
+            .strip_prefix("https://")
+            .or_else(|| input.strip_prefix("http://"))
+        {
+            let mut segments = path.split(['/', '?', '#']);
+            segments.find(|s| *s == "issue")?;
+            return segments.next().and_then(Self::parse_prefix);
+        }
+        // Branch names put the identifier at the start of the last segment.
+        input.rsplit('/').next().and_then(Self::parse_prefix)
+    }
+
+    /// Parse `KEY-NUMBER` at the start of `s`, allowing a `-slug` after it.
+    fn parse_prefix(s: &str) -> Option<Self> {
+        let (key, rest) = s.split_once('-')?;
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let (number, tail) = rest.split_at(digits);
+        let valid_key = key.starts_with(|c: char| c.is_ascii_alphabetic())
+            && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !valid_key || !(tail.is_empty() || tail.starts_with('-')) {
+            return None;
+        }
+        Some(Self {
+            team_key: key.to_uppercase(),
+            number: number.parse().ok()?,
+        })
+    }
+}
+
+impl std::fmt::Display for Identifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.team_key, self.number)
+    }
+}
+
+/// Whether `s` looks like a Linear UUID.
+pub fn is_uuid(s: &str) -> bool {
+    s.len() == 36 && s.chars().all(|c| c.is_ascii_hexdigit() || c == '-')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parsed(input: &str) -> Option<String> {
+        Identifier::parse(input).map(|id| id.to_string())
+    }
+
+    #[test]
+    fn bare_identifiers_are_uppercased() {
+        assert_eq!(parsed("eng-123").as_deref(), Some("ENG-123"));
+        assert_eq!(parsed("  Web2-7 ").as_deref(), Some("WEB2-7"));
+        assert_eq!(parsed("OPS_X-1").as_deref(), Some("OPS_X-1"));
+    }
+
+    #[test]
+    fn urls_use_the_segment_after_issue() {
+        let url = "https://linear.app/acme/issue/ENG-123/fix-login";
+        assert_eq!(parsed(url).as_deref(), Some("ENG-123"));
+        assert_eq!(
+            parsed("http://linear.app/acme/issue/eng-9?foo=bar").as_deref(),
+            Some("ENG-9")
+        );
+        assert_eq!(
+            parsed("https://linear.app/acme/issue/eng-9#comment-1").as_deref(),
+            Some("ENG-9")
+        );
+        assert_eq!(parsed("https://linear.app/acme/project/eng-9"), None);
+    }
+
+    #[test]
+    fn branches_use_the_last_segment() {
+        assert_eq!(
+            parsed("alice/eng-123-fix-login").as_deref(),
+            Some("ENG-123")
+        );
+        assert_eq!(parsed("eng-42-add-sso").as_deref(), Some("ENG-42"));
+        assert_eq!(parsed("feature/fix-login"), None);
+    }
+
+    #[test]
+    fn malformed_references_are_rejected() {
+        for input in [
+            "",
+            "ENG",
+            "ENG-",
+            "-12",
+            "123-45",
+            "ENG-12a",
+            "EN G-1",
+            "ENG-99999999999",
+        ] {
+            assert_eq!(parsed(input), None, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn uuids_are_36_hex_digits_and_dashes() {
+        assert!(is_uuid("00000000-0000-4000-8000-000000000001"));
+        assert!(!is_uuid("00000000-0000-4000-8000-00000000000g"));
+        assert!(!is_uuid("00000000-0000-4000-8000-0000000001"));
+    }
+}
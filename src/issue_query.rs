@@ -0,0 +1,1534 @@
+//! A small boolean query language for issues, compiled to Linear's
+//! `IssueFilter`:
+//!
+//! ```text
+//! priority >= high and (label in (Bug, P0) or parent.state = "In Review")
+//!     and not assignee = me and due < "end of cycle"
+//! ```
+
+use std::collections::HashMap;
+
+use crate::error::Error;
+use crate::graphql::filters::{
+    BooleanFilter, CollectionFilter, Comparator, CustomerNameFilter, CustomerNeedFilter,
+    CycleFilter, DateComparator, EstimateFilter, IssueFilter, IssueProjectFilter, LabelNameFilter,
+    LabelsFilter, MilestoneFilter, NumberFilter, StateFilter, StateTypeFilter, StringFilter,
+    TeamFilter, UserFilter,
+};
+use crate::identifier::Identifier;
+use crate::matching::edit_distance;
+
+/// A parsed filter expression.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    And(Vec<Expr>),
+    Or(Vec<Expr>),
+    Not(Box<Expr>),
+    Test(Test),
+}
+
+/// One comparison, e.g. `priority >= high`. A `value` of `None` tests for
+/// the field being unset (`assignee = none`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Test {
+    pub field: Field,
+    pub op: Op,
+    pub value: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Contains,
+    NotContains,
+}
+
+impl Op {
+    fn negate(self) -> Op {
+        match self {
+            Op::Eq => Op::Ne,
+            Op::Ne => Op::Eq,
+            Op::Lt => Op::Ge,
+            Op::Le => Op::Gt,
+            Op::Gt => Op::Le,
+            Op::Ge => Op::Lt,
+            Op::Contains => Op::NotContains,
+            Op::NotContains => Op::Contains,
+        }
+    }
+
+    fn is_negative(self) -> bool {
+        matches!(self, Op::Ne | Op::NotContains)
+    }
+
+    fn symbol(self) -> &'static str {
+        match self {
+            Op::Eq => "=",
+            Op::Ne => "!=",
+            Op::Lt => "<",
+            Op::Le => "<=",
+            Op::Gt => ">",
+            Op::Ge => ">=",
+            Op::Contains => "~",
+            Op::NotContains => "!~",
+        }
+    }
+}
+
+/// Issue date fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DateField {
+    Due,
+    Created,
+    Updated,
+    Started,
+    Completed,
+    Canceled,
+}
+
+impl DateField {
+    /// Whether Linear stores the field as a calendar day rather than a timestamp.
+    pub fn is_timeless(self) -> bool {
+        self == DateField::Due
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Field {
+    Title,
+    Number,
+    Priority,
+    Estimate,
+    Date(DateField),
+    Team,
+    Assignee,
+    Creator,
+    State,
+    StateType,
+    Project,
+    ProjectState,
+    ProjectLead,
+    Label,
+    Cycle,
+    Milestone,
+    Subscriber,
+    Customer,
+    Sla,
+    Attachments,
+    Triage,
+    Blocked,
+    Blocking,
+    /// `parent` itself, compared with an issue identifier.
+    ParentIssue,
+    /// A field of the parent issue, e.g. `parent.state`.
+    Parent(Box<Field>),
+}
+
+/// What values and operators a field takes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Text,
+    /// Compared for equality only (`state.type`, `sla`, `parent`).
+    Exact,
+    Number,
+    Priority,
+    Date,
+    User,
+    Bool,
+}
+
+const FIELDS: &[&str] = &[
+    "title",
+    "number",
+    "priority",
+    "estimate",
+    "due",
+    "created",
+    "updated",
+    "started",
+    "completed",
+    "canceled",
+    "team",
+    "assignee",
+    "creator",
+    "state",
+    "state.type",
+    "project",
+    "project.state",
+    "project.lead",
+    "label",
+    "cycle",
+    "milestone",
+    "subscriber",
+    "customer",
+    "sla",
+    "attachments",
+    "triage",
+    "blocked",
+    "blocking",
+    "parent",
+    "parent.<field>",
+];
+
+impl Field {
+    fn from_path(path: &str) -> Option<Field> {
+        let path = path.to_lowercase();
+        if let Some(rest) = path.strip_prefix("parent.") {
+            return Some(Field::Parent(Box::new(Field::from_path(rest)?)));
+        }
+        Some(match path.as_str() {
+            "title" => Field::Title,
+            "number" => Field::Number,
+            "priority" => Field::Priority,
+            "estimate" => Field::Estimate,
+            "due" | "duedate" | "due_date" => Field::Date(DateField::Due),
+            "created" | "createdat" | "created_at" => Field::Date(DateField::Created),
+            "updated" | "updatedat" | "updated_at" => Field::Date(DateField::Updated),
+            "started" | "startedat" | "started_at" => Field::Date(DateField::Started),
+            "completed" | "completedat" | "completed_at" => Field::Date(DateField::Completed),
+            "canceled" | "cancelled" | "canceledat" | "canceled_at" => {
+                Field::Date(DateField::Canceled)
+            }
+            "team" => Field::Team,
+            "assignee" => Field::Assignee,
+            "creator" => Field::Creator,
+            "state" | "status" | "state.name" | "status.name" => Field::State,
+            "state.type" | "status.type" => Field::StateType,
+            "project" | "project.name" => Field::Project,
+            "project.state" | "project.status" => Field::ProjectState,
+            "project.lead" => Field::ProjectLead,
+            "label" | "labels" => Field::Label,
+            "cycle" => Field::Cycle,
+            "milestone" | "milestone.name" | "projectmilestone" | "project_milestone" => {
+                Field::Milestone
+            }
+            "subscriber" | "subscribers" => Field::Subscriber,
+            "customer" | "customers" => Field::Customer,
+            "sla" | "sla.status" | "slastatus" | "sla_status" => Field::Sla,
+            "attachments" | "attachment" => Field::Attachments,
+            "triage" => Field::Triage,
+            "blocked" => Field::Blocked,
+            "blocking" => Field::Blocking,
+            "parent" => Field::ParentIssue,
+            _ => return None,
+        })
+    }
+
+    fn kind(&self) -> Kind {
+        match self {
+            Field::Title | Field::Team | Field::State | Field::Project | Field::ProjectState => {
+                Kind::Text
+            }
+            Field::Label | Field::Cycle | Field::Milestone | Field::Customer => Kind::Text,
+            Field::StateType | Field::Sla | Field::ParentIssue => Kind::Exact,
+            Field::Number | Field::Estimate => Kind::Number,
+            Field::Priority => Kind::Priority,
+            Field::Date(_) => Kind::Date,
+            Field::Assignee | Field::Creator | Field::ProjectLead | Field::Subscriber => Kind::User,
+            Field::Attachments | Field::Triage | Field::Blocked | Field::Blocking => Kind::Bool,
+            Field::Parent(inner) => inner.kind(),
+        }
+    }
+
+    /// Whether the field can be unset, i.e. compared with `none`.
+    fn is_nullable(&self) -> bool {
+        match self {
+            Field::Estimate
+            | Field::Assignee
+            | Field::Creator
+            | Field::Project
+            | Field::ProjectState
+            | Field::ProjectLead
+            | Field::Label
+            | Field::Cycle
+            | Field::Milestone
+            | Field::Subscriber
+            | Field::Customer
+            | Field::Sla
+            | Field::ParentIssue => true,
+            Field::Date(date) => !matches!(date, DateField::Created | DateField::Updated),
+            Field::Parent(inner) => inner.is_nullable(),
+            _ => false,
+        }
+    }
+
+    /// The field itself, or for `parent.x` the parent's `x`.
+    fn leaf(&self) -> &Field {
+        match self {
+            Field::Parent(inner) => inner.leaf(),
+            field => field,
+        }
+    }
+
+    fn date(&self) -> Option<DateField> {
+        match self {
+            Field::Date(date) => Some(*date),
+            Field::Parent(inner) => inner.date(),
+            _ => None,
+        }
+    }
+}
+
+impl Expr {
+    /// Every date compared against, with the field it is compared to, for
+    /// resolving them before [`compile`].
+    pub fn dates(&self) -> Vec<(DateField, &str)> {
+        let mut dates = Vec::new();
+        self.collect_dates(&mut dates);
+        dates
+    }
+
+    fn collect_dates<'a>(&'a self, dates: &mut Vec<(DateField, &'a str)>) {
+        match self {
+            Expr::And(exprs) | Expr::Or(exprs) => exprs.iter().for_each(|e| e.collect_dates(dates)),
+            Expr::Not(expr) => expr.collect_dates(dates),
+            Expr::Test(test) => {
+                if let (Some(field), Some(value)) = (test.field.date(), &test.value) {
+                    dates.push((field, value));
+                }
+            }
+        }
+    }
+}
+
+/// A resolved date value: `YYYY-MM-DD` days, first and last inclusive, for
+/// calendar-day fields; ISO timestamps, end exclusive, for the others.
+#[derive(Debug, Clone)]
+pub struct DateRange {
+    pub start: String,
+    pub end: String,
+}
+
+/// Resolved dates of an expression, keyed by field kind and value.
+pub type DateValues = HashMap<(bool, String), DateRange>;
+
+// ---- Parsing ----
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Quoted(String),
+    Op(Op),
+    LParen,
+    RParen,
+    Comma,
+    And,
+    Or,
+    Not,
+}
+
+/// Longest filter expression accepted, in bytes.
+pub const MAX_LENGTH: usize = 4096;
+
+/// How deeply parentheses and `not`s may nest.
+pub const MAX_DEPTH: usize = 64;
+
+struct Parser<'a> {
+    input: &'a str,
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+    /// Parentheses and `not`s open around the current token.
+    depth: usize,
+}
+
+/// Parse a filter expression, reporting the column of any syntax error.
+pub fn parse(input: &str) -> Result<Expr, Error> {
+    if input.len() > MAX_LENGTH {
+        return Err(Error::Validation {
+            message: format!(
+                "The filter is {} bytes long; at most {} are allowed.",
+                input.len(),
+                MAX_LENGTH
+            ),
+            field: Some("filter".into()),
+        });
+    }
+    let tokens = tokenize(input).map_err(|(message, at)| syntax_error(input, &message, at))?;
+    let mut parser = Parser {
+        input,
+        tokens,
+        pos: 0,
+        depth: 0,
+    };
+    let expr = parser
+        .expr()
+        .map_err(|(message, at)| syntax_error(input, &message, at))?;
+    if let Some((token, at)) = parser.tokens.get(parser.pos) {
+        let message = match token {
+            Token::RParen => "unmatched ')'".to_string(),
+            _ => format!("expected 'and', 'or' or the end, found {}", describe(token)),
+        };
+        return Err(syntax_error(input, &message, *at));
+    }
+    Ok(expr)
+}
+
+fn syntax_error(input: &str, message: &str, at: usize) -> Error {
+    let column = input[..at].chars().count();
+    Error::Validation {
+        message: format!(
+            "{} (column {})\n  {}\n  {}^",
+            message,
+            column + 1,
+            input,
+            " ".repeat(column)
+        ),
+        field: Some("filter".into()),
+    }
+}
+
+fn describe(token: &Token) -> String {
+    match token {
+        Token::Word(w) => format!("'{}'", w),
+        Token::Quoted(q) => format!("\"{}\"", q),
+        Token::Op(op) => format!("'{}'", op.symbol()),
+        Token::LParen => "'('".into(),
+        Token::RParen => "')'".into(),
+        Token::Comma => "','".into(),
+        Token::And => "'and'".into(),
+        Token::Or => "'or'".into(),
+        Token::Not => "'not'".into(),
+    }
+}
+
+fn tokenize(input: &str) -> Result<Vec<(Token, usize)>, (String, usize)> {
+    let mut tokens = Vec::new();
+    let mut chars = input.char_indices().peekable();
+    while let Some(&(at, c)) = chars.peek() {
+        let two: String = input[at..].chars().take(2).collect();
+        let symbol = match two.as_str() {
+            "!=" => Some((Token::Op(Op::Ne), 2)),
+            "!~" => Some((Token::Op(Op::NotContains), 2)),
+            "<=" => Some((Token::Op(Op::Le), 2)),
+            ">=" => Some((Token::Op(Op::Ge), 2)),
+            "==" => Some((Token::Op(Op::Eq), 2)),
+            "&&" => Some((Token::And, 2)),
+            "||" => Some((Token::Or, 2)),
+            _ => match c {
+                '=' => Some((Token::Op(Op::Eq), 1)),
+                '<' => Some((Token::Op(Op::Lt), 1)),
+                '>' => Some((Token::Op(Op::Gt), 1)),
+                '~' => Some((Token::Op(Op::Contains), 1)),
+                '!' => Some((Token::Not, 1)),
+                '(' => Some((Token::LParen, 1)),
+                ')' => Some((Token::RParen, 1)),
+                ',' => Some((Token::Comma, 1)),
+                _ => None,
+            },
+        };
+        if let Some((token, len)) = symbol {
+            tokens.push((token, at));
+            for _ in 0..len {
+                chars.next();
+            }
+        } else if c.is_whitespace() {
+            chars.next();
+        } else if c == '"' || c == '\'' {
+            chars.next();
+            let mut text = String::new();
+            loop {
+                match chars.next() {
+                    Some((_, q)) if q == c => break,
+                    Some((_, '\\')) => match chars.next() {
+                        Some((_, escaped)) => text.push(escaped),
+                        None => return Err(("unterminated string".into(), at)),
+                    },
+                    Some((_, ch)) => text.push(ch),
+                    None => return Err(("unterminated string".into(), at)),
+                }
+            }
+            tokens.push((Token::Quoted(text), at));
+        } else {
+            let mut word = String::new();
+            while let Some(&(_, ch)) = chars.peek() {
+                if ch.is_whitespace() || "=!<>~(),\"'".contains(ch) {
+                    break;
+                }
+                word.push(ch);
+                chars.next();
+            }
+            let token = match word.to_lowercase().as_str() {
+                "and" => Token::And,
+                "or" => Token::Or,
+                "not" => Token::Not,
+                _ => Token::Word(word),
+            };
+            tokens.push((token, at));
+        }
+    }
+    Ok(tokens)
+}
+
+type ParseResult<T> = Result<T, (String, usize)>;
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(t, _)| t)
+    }
+
+    /// Byte offset of the next token, or the end of the input.
+    fn at(&self) -> usize {
+        self.tokens
+            .get(self.pos)
+            .map_or(self.input.len(), |(_, at)| *at)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).map(|(t, _)| t.clone());
+        self.pos += 1;
+        token
+    }
+
+    fn eat(&mut self, token: &Token) -> bool {
+        if self.peek() == Some(token) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn peek_word(&self, word: &str) -> bool {
+        matches!(self.peek(), Some(Token::Word(w)) if w.eq_ignore_ascii_case(word))
+    }
+
+    fn expr(&mut self) -> ParseResult<Expr> {
+        let mut terms = vec![self.and_expr()?];
+        while self.eat(&Token::Or) {
+            terms.push(self.and_expr()?);
+        }
+        Ok(if terms.len() == 1 {
+            terms.remove(0)
+        } else {
+            Expr::Or(terms)
+        })
+    }
+
+    fn and_expr(&mut self) -> ParseResult<Expr> {
+        let mut terms = vec![self.unary()?];
+        while self.eat(&Token::And) {
+            terms.push(self.unary()?);
+        }
+        Ok(if terms.len() == 1 {
+            terms.remove(0)
+        } else {
+            Expr::And(terms)
+        })
+    }
+
+    fn unary(&mut self) -> ParseResult<Expr> {
+        if !matches!(self.peek(), Some(Token::Not | Token::LParen)) {
+            return self.test();
+        }
+        if self.depth == MAX_DEPTH {
+            return Err((
+                format!("parentheses and 'not' nest more than {} deep", MAX_DEPTH),
+                self.at(),
+            ));
+        }
+        self.depth += 1;
+        let expr = if self.eat(&Token::Not) {
+            Expr::Not(Box::new(self.unary()?))
+        } else {
+            self.pos += 1;
+            let open = self.tokens[self.pos - 1].1;
+            let expr = self.expr()?;
+            if !self.eat(&Token::RParen) {
+                return Err(("'(' is never closed".into(), open));
+            }
+            expr
+        };
+        self.depth -= 1;
+        Ok(expr)
+    }
+
+    fn test(&mut self) -> ParseResult<Expr> {
+        let at = self.at();
+        let path = match self.next() {
+            Some(Token::Word(w)) => w,
+            Some(token) => {
+                return Err((format!("expected a field, found {}", describe(&token)), at));
+            }
+            None => return Err(("expected a field".into(), at)),
+        };
+        let field = Field::from_path(&path).ok_or_else(|| (unknown_field(&path), at))?;
+        let kind = field.kind();
+
+        // A boolean field on its own: `blocked`, `not blocking`.
+        if kind == Kind::Bool && !matches!(self.peek(), Some(Token::Op(_))) && !self.peek_word("is")
+        {
+            return Ok(Expr::Test(Test {
+                field,
+                op: Op::Eq,
+                value: Some("true".into()),
+            }));
+        }
+
+        let op_at = self.at();
+        let (op, set) = if self.peek_word("in") {
+            self.pos += 1;
+            (Op::Eq, true)
+        } else if self.peek() == Some(&Token::Not) {
+            self.pos += 1;
+            if !self.peek_word("in") {
+                return Err(("expected 'in' after 'not'".into(), self.at()));
+            }
+            self.pos += 1;
+            (Op::Ne, true)
+        } else if self.peek_word("is") {
+            self.pos += 1;
+            (
+                if self.eat(&Token::Not) {
+                    Op::Ne
+                } else {
+                    Op::Eq
+                },
+                false,
+            )
+        } else {
+            match self.next() {
+                Some(Token::Op(op)) => (op, false),
+                Some(token) => {
+                    return Err((
+                        format!(
+                            "expected an operator after '{}' (=, !=, <, <=, >, >=, ~, !~, in, is), found {}",
+                            path,
+                            describe(&token)
+                        ),
+                        op_at,
+                    ));
+                }
+                None => return Err((format!("expected an operator after '{}'", path), op_at)),
+            }
+        };
+        check_op(&field, kind, op, &path).map_err(|message| (message, op_at))?;
+
+        let values = if set {
+            let open = self.at();
+            if !self.eat(&Token::LParen) {
+                return Err(("expected '(' to start the list".into(), open));
+            }
+            let mut values = vec![self.value(&field, kind)?];
+            while self.eat(&Token::Comma) {
+                values.push(self.value(&field, kind)?);
+            }
+            if !self.eat(&Token::RParen) {
+                return Err(("expected ',' or ')' in the list".into(), self.at()));
+            }
+            values
+        } else {
+            vec![self.value(&field, kind)?]
+        };
+        if values.len() > 1 {
+            // `x in (a, b)` is `x = a or x = b`; `x not in (a, b)` is
+            // `x != a and x != b`.
+            let tests: Vec<Expr> = values
+                .into_iter()
+                .map(|value| {
+                    Expr::Test(Test {
+                        field: field.clone(),
+                        op,
+                        value,
+                    })
+                })
+                .collect();
+            return Ok(if op == Op::Eq {
+                Expr::Or(tests)
+            } else {
+                Expr::And(tests)
+            });
+        }
+        Ok(Expr::Test(Test {
+            field,
+            op,
+            value: values.into_iter().next().flatten(),
+        }))
+    }
+
+    /// A value: a quoted string, or unquoted words up to the next `and`,
+    /// `or`, `,` or `)`. `none` / `null` / `empty` mean "not set".
+    fn value(&mut self, field: &Field, kind: Kind) -> ParseResult<Option<String>> {
+        let at = self.at();
+        let (value, quoted) = match self.peek() {
+            Some(Token::Quoted(q)) => {
+                let q = q.clone();
+                self.pos += 1;
+                (q, true)
+            }
+            _ => {
+                let mut words = Vec::new();
+                while let Some(Token::Word(w)) = self.peek() {
+                    words.push(w.clone());
+                    self.pos += 1;
+                }
+                if words.is_empty() {
+                    let found = self
+                        .peek()
+                        .map(describe)
+                        .unwrap_or_else(|| "the end".into());
+                    return Err((format!("expected a value, found {}", found), at));
+                }
+                (words.join(" "), false)
+            }
+        };
+        let lower = value.to_lowercase();
+        if !quoted && matches!(lower.as_str(), "none" | "null" | "empty") {
+            if !field.is_nullable() {
+                return Err((
+                    format!(
+                        "this field is always set, so it can't be compared with '{}'",
+                        value
+                    ),
+                    at,
+                ));
+            }
+            return Ok(None);
+        }
+        check_value(field, kind, &value).map_err(|message| (message, at))?;
+        Ok(Some(value))
+    }
+}
+
+/// Check that `value` suits the field, e.g. is a number for `estimate`.
+fn check_value(field: &Field, kind: Kind, value: &str) -> Result<(), String> {
+    let lower = value.to_lowercase();
+    let expected = match (field.leaf(), kind) {
+        (_, Kind::Number) if value.parse::<f64>().is_err() => "a number",
+        (Field::Number, _) if value.parse::<i32>().is_err() => "a whole number",
+        (_, Kind::Priority) if priority_value(&lower).is_none() => {
+            "a priority (urgent, high, medium, low, none or 0-4)"
+        }
+        (_, Kind::Bool) if !matches!(lower.as_str(), "true" | "false" | "yes" | "no") => {
+            "true or false"
+        }
+        (Field::ParentIssue, _) if Identifier::parse(value).is_none() => {
+            "an issue identifier like ENG-123"
+        }
+        (Field::Sla, _) if sla_status(value).is_none() => {
+            "an SLA status (breached, high risk, medium risk, low risk, completed or failed)"
+        }
+        _ => return Ok(()),
+    };
+    Err(format!("expected {}, found '{}'", expected, value))
+}
+
+/// A filter parameter of a list tool (`assignee`, `label`, ...) as a test
+/// of `field`: `none` matches issues without one, `any` issues with one,
+/// and `not X` issues without X. Errors name the parameter.
+pub fn param(param: &str, field: &str, value: &str) -> Result<Expr, Error> {
+    let field = Field::from_path(field).expect("parameters map to known fields");
+    let kind = field.kind();
+    let invalid = |message: String| Error::Validation {
+        message,
+        field: Some(param.to_string()),
+    };
+    let value = value.trim();
+    let (negated, value) = match value.get(..4) {
+        Some(prefix) if prefix.eq_ignore_ascii_case("not ") => (true, value[4..].trim()),
+        _ => (false, value),
+    };
+    let lower = value.to_lowercase();
+    let (op, value) = match lower.as_str() {
+        "none" | "any" if !field.is_nullable() => {
+            return Err(invalid(format!(
+                "'{}' is always set, so it can't be '{}'",
+                param, value
+            )));
+        }
+        "none" => (Op::Eq, None),
+        "any" => (Op::Ne, None),
+        _ => {
+            check_value(&field, kind, value).map_err(invalid)?;
+            // Projects have always matched by part of their name here.
+            let op = if field == Field::Project {
+                Op::Contains
+            } else {
+                Op::Eq
+            };
+            (op, Some(value.to_string()))
+        }
+    };
+    let test = Expr::Test(Test { field, op, value });
+    Ok(if negated {
+        Expr::Not(Box::new(test))
+    } else {
+        test
+    })
+}
+
+fn check_op(field: &Field, kind: Kind, op: Op, path: &str) -> Result<(), String> {
+    let ordered = matches!(op, Op::Lt | Op::Le | Op::Gt | Op::Ge);
+    let contains = matches!(op, Op::Contains | Op::NotContains);
+    let allowed = match kind {
+        Kind::Text => !ordered,
+        Kind::Exact | Kind::User | Kind::Bool => !ordered && !contains,
+        Kind::Number | Kind::Priority | Kind::Date => !contains,
+    };
+    // Cycles compare by name or number, never by order.
+    if allowed && !(matches!(field, Field::Cycle) && ordered) {
+        return Ok(());
+    }
+    Err(format!("'{}' can't be used with '{}'", op.symbol(), path))
+}
+
+fn unknown_field(path: &str) -> String {
+    let closest = FIELDS
+        .iter()
+        .map(|f| (edit_distance(path, f), f))
+        .min_by_key(|(d, _)| *d)
+        .filter(|(d, _)| *d <= 2);
+    match closest {
+        Some((_, f)) => format!("unknown field '{}'; did you mean '{}'?", path, f),
+        None => format!("unknown field '{}'; fields are {}", path, FIELDS.join(", ")),
+    }
+}
+
+/// Linear's priority number for a name or number.
+fn priority_value(value: &str) -> Option<i32> {
+    Some(match value {
+        "urgent" | "1" => 1,
+        "high" | "2" => 2,
+        "medium" | "normal" | "3" => 3,
+        "low" | "4" => 4,
+        "none" | "no priority" | "0" => 0,
+        _ => return None,
+    })
+}
+
+/// Linear's SLA status for a name like `high risk` or `HighRisk`.
+fn sla_status(value: &str) -> Option<&'static str> {
+    let key: String = value
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .collect::<String>()
+        .to_lowercase();
+    Some(match key.as_str() {
+        "breached" => "Breached",
+        "highrisk" => "HighRisk",
+        "mediumrisk" => "MediumRisk",
+        "lowrisk" => "LowRisk",
+        "completed" => "Completed",
+        "failed" => "Failed",
+        _ => return None,
+    })
+}
+
+/// How urgent a priority is: no priority lowest, urgent highest.
+fn urgency(priority: i32) -> i32 {
+    if priority == 0 { 0 } else { 5 - priority }
+}
+
+// ---- Compiling ----
+
+/// Compile a parsed expression, with its dates resolved in `dates`.
+///
+/// Linear's filters have no `not`, so negations are pushed down to the
+/// comparisons (De Morgan). A negated comparison on something an issue may
+/// not have (`assignee != alice`, `not parent.state = Done`) also matches
+/// issues without it.
+pub fn compile(expr: &Expr, dates: &DateValues) -> IssueFilter {
+    compile_expr(expr, false, dates)
+}
+
+fn compile_expr(expr: &Expr, negate: bool, dates: &DateValues) -> IssueFilter {
+    match expr {
+        Expr::And(exprs) | Expr::Or(exprs) => {
+            let parts = exprs
+                .iter()
+                .map(|e| compile_expr(e, negate, dates))
+                .collect();
+            if matches!(expr, Expr::And(_)) != negate {
+                IssueFilter::all(parts)
+            } else {
+                IssueFilter::any(parts)
+            }
+        }
+        Expr::Not(expr) => compile_expr(expr, !negate, dates),
+        Expr::Test(test) => {
+            let op = if negate { test.op.negate() } else { test.op };
+            let negated = negate != test.op.is_negative();
+            compile_test(&test.field, op, test.value.as_deref(), negated, dates)
+        }
+    }
+}
+
+/// One comparison; `negated` when it came from `!=`, `!~` or `not`.
+fn compile_test(
+    field: &Field,
+    op: Op,
+    value: Option<&str>,
+    negated: bool,
+    dates: &DateValues,
+) -> IssueFilter {
+    let Some(value) = value else {
+        return null_filter(field, op == Op::Eq);
+    };
+    let filter = match field {
+        Field::Title => IssueFilter {
+            title: Some(string_filter(op, value)),
+            ..Default::default()
+        },
+        Field::Team => IssueFilter {
+            team: Some(TeamFilter {
+                key: Some(string_filter(op, value)),
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+        Field::State => IssueFilter {
+            state: Some(StateFilter {
+                name: Some(string_filter(op, value)),
+                state_type: None,
+            }),
+            ..Default::default()
+        },
+        Field::StateType => {
+            let value = value.to_lowercase();
+            let state_type = if op == Op::Eq {
+                StateTypeFilter {
+                    eq: Some(value),
+                    ..Default::default()
+                }
+            } else {
+                StateTypeFilter {
+                    neq: Some(value),
+                    ..Default::default()
+                }
+            };
+            IssueFilter {
+                state: Some(StateFilter {
+                    name: None,
+                    state_type: Some(state_type),
+                }),
+                ..Default::default()
+            }
+        }
+        Field::Project => project(IssueProjectFilter {
+            name: Some(string_filter(op, value)),
+            ..Default::default()
+        }),
+        Field::ProjectState => project(IssueProjectFilter {
+            state: Some(string_filter(op, value)),
+            ..Default::default()
+        }),
+        Field::ProjectLead => project(IssueProjectFilter {
+            lead: Some(user_filter(op, value)),
+            ..Default::default()
+        }),
+        Field::Assignee => IssueFilter {
+            assignee: Some(user_filter(op, value)),
+            ..Default::default()
+        },
+        Field::Creator => IssueFilter {
+            creator: Some(user_filter(op, value)),
+            ..Default::default()
+        },
+        Field::Label => {
+            let name = LabelNameFilter {
+                name: string_filter(op, value),
+            };
+            IssueFilter {
+                labels: Some(some_or_every(op, name)),
+                ..Default::default()
+            }
+        }
+        Field::Cycle => IssueFilter {
+            cycle: Some(cycle_filter(op, value)),
+            ..Default::default()
+        },
+        Field::Milestone => IssueFilter {
+            project_milestone: Some(MilestoneFilter {
+                name: Some(string_filter(op, value)),
+                null: None,
+            }),
+            ..Default::default()
+        },
+        Field::Subscriber => IssueFilter {
+            subscribers: Some(some_or_every(op, user_filter(op, value))),
+            ..Default::default()
+        },
+        Field::Customer => {
+            let need = CustomerNeedFilter {
+                customer: CustomerNameFilter {
+                    name: string_filter(op, value),
+                },
+            };
+            IssueFilter {
+                needs: Some(some_or_every(op, need)),
+                ..Default::default()
+            }
+        }
+        Field::Sla => IssueFilter {
+            sla_status: Some(comparator(
+                op,
+                sla_status(value).expect("checked when parsed").to_string(),
+            )),
+            ..Default::default()
+        },
+        Field::Attachments => IssueFilter {
+            attachments: Some(CollectionFilter::empty(!is_true(op, value))),
+            ..Default::default()
+        },
+        Field::Triage => {
+            let triage = Some("triage".to_string());
+            let state_type = if is_true(op, value) {
+                StateTypeFilter {
+                    eq: triage,
+                    ..Default::default()
+                }
+            } else {
+                StateTypeFilter {
+                    neq: triage,
+                    ..Default::default()
+                }
+            };
+            IssueFilter {
+                state: Some(StateFilter {
+                    name: None,
+                    state_type: Some(state_type),
+                }),
+                ..Default::default()
+            }
+        }
+        Field::Number => IssueFilter {
+            number: Some(comparator(
+                op,
+                value.parse::<i32>().expect("checked when parsed"),
+            )),
+            ..Default::default()
+        },
+        Field::Estimate => IssueFilter {
+            estimate: Some(comparator::<f64>(
+                op,
+                value.parse().expect("checked when parsed"),
+            )),
+            ..Default::default()
+        },
+        Field::Priority => IssueFilter {
+            priority: Some(priority_filter(op, &value.to_lowercase())),
+            ..Default::default()
+        },
+        Field::Date(date) => {
+            let range = &dates[&(date.is_timeless(), value.to_string())];
+            return date_filter(*date, op, range);
+        }
+        Field::Blocked | Field::Blocking => {
+            let has = Some(BooleanFilter {
+                eq: is_true(op, value),
+            });
+            if *field == Field::Blocked {
+                IssueFilter {
+                    has_blocked_by_relations: has,
+                    ..Default::default()
+                }
+            } else {
+                IssueFilter {
+                    has_blocking_relations: has,
+                    ..Default::default()
+                }
+            }
+        }
+        Field::ParentIssue => {
+            let id = Identifier::parse(value).expect("checked when parsed");
+            let parent = if op == Op::Eq {
+                IssueFilter {
+                    team: Some(TeamFilter {
+                        key: Some(StringFilter::eq_ignore_case(&id.team_key)),
+                        ..Default::default()
+                    }),
+                    number: Some(NumberFilter::eq(id.number as i32)),
+                    ..Default::default()
+                }
+            } else {
+                IssueFilter::any(vec![
+                    IssueFilter {
+                        team: Some(TeamFilter {
+                            key: Some(StringFilter::neq_ignore_case(&id.team_key)),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                    IssueFilter {
+                        number: Some(NumberFilter {
+                            neq: Some(id.number as i32),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                ])
+            };
+            IssueFilter {
+                parent: Some(Box::new(parent)),
+                ..Default::default()
+            }
+        }
+        Field::Parent(inner) => {
+            let filter = IssueFilter {
+                parent: Some(Box::new(compile_test(
+                    inner,
+                    op,
+                    Some(value),
+                    negated,
+                    dates,
+                ))),
+                ..Default::default()
+            };
+            return if negated {
+                IssueFilter::any(vec![null_filter(&Field::ParentIssue, true), filter])
+            } else {
+                filter
+            };
+        }
+    };
+    let collection = matches!(field, Field::Label | Field::Subscriber | Field::Customer);
+    if negated && field.is_nullable() && !collection {
+        IssueFilter::any(vec![null_filter(field, true), filter])
+    } else {
+        filter
+    }
+}
+
+/// Issues where `field` is unset (`is_null`) or set.
+fn null_filter(field: &Field, is_null: bool) -> IssueFilter {
+    let null = Some(is_null);
+    match field {
+        Field::Estimate => IssueFilter {
+            estimate: Some(EstimateFilter::null(is_null)),
+            ..Default::default()
+        },
+        Field::Date(date) => date_field(*date, DateComparator::null(is_null)),
+        Field::Assignee => IssueFilter {
+            assignee: Some(UserFilter {
+                null,
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+        Field::Creator => IssueFilter {
+            creator: Some(UserFilter {
+                null,
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+        Field::Project => project(IssueProjectFilter {
+            null,
+            ..Default::default()
+        }),
+        // A project always has a state, so only a missing project lacks one.
+        Field::ProjectState => project(IssueProjectFilter {
+            null,
+            ..Default::default()
+        }),
+        // Without a project there is no lead either.
+        Field::ProjectLead if is_null => IssueFilter::any(vec![
+            project(IssueProjectFilter {
+                null,
+                ..Default::default()
+            }),
+            project(IssueProjectFilter {
+                lead: Some(UserFilter {
+                    null,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+        ]),
+        Field::ProjectLead => project(IssueProjectFilter {
+            lead: Some(UserFilter {
+                null,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+        Field::Label => IssueFilter {
+            labels: Some(LabelsFilter::empty(is_null)),
+            ..Default::default()
+        },
+        Field::Subscriber => IssueFilter {
+            subscribers: Some(CollectionFilter::empty(is_null)),
+            ..Default::default()
+        },
+        Field::Customer => IssueFilter {
+            needs: Some(CollectionFilter::empty(is_null)),
+            ..Default::default()
+        },
+        Field::Milestone => IssueFilter {
+            project_milestone: Some(MilestoneFilter { name: None, null }),
+            ..Default::default()
+        },
+        Field::Sla => IssueFilter {
+            sla_status: Some(Comparator::null(is_null)),
+            ..Default::default()
+        },
+        Field::Cycle => IssueFilter {
+            cycle: Some(CycleFilter {
+                null,
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+        Field::ParentIssue => IssueFilter {
+            parent: Some(Box::new(IssueFilter {
+                null,
+                ..Default::default()
+            })),
+            ..Default::default()
+        },
+        Field::Parent(inner) => IssueFilter {
+            parent: Some(Box::new(null_filter(inner, is_null))),
+            ..Default::default()
+        },
+        _ => unreachable!("only nullable fields are compared with none"),
+    }
+}
+
+fn project(filter: IssueProjectFilter) -> IssueFilter {
+    IssueFilter {
+        project: Some(filter),
+        ..Default::default()
+    }
+}
+
+/// Some item must match a positive test; none may match a negated one.
+fn some_or_every<T>(op: Op, item: T) -> CollectionFilter<T> {
+    if op.is_negative() {
+        CollectionFilter {
+            every: Some(item),
+            ..Default::default()
+        }
+    } else {
+        CollectionFilter {
+            some: Some(item),
+            ..Default::default()
+        }
+    }
+}
+
+/// Whether a boolean test holds for `true`: `blocked = yes`, `triage != false`.
+fn is_true(op: Op, value: &str) -> bool {
+    matches!(value.to_lowercase().as_str(), "true" | "yes") == (op == Op::Eq)
+}
+
+fn string_filter(op: Op, value: &str) -> StringFilter {
+    match op {
+        Op::Ne => StringFilter::neq_ignore_case(value),
+        Op::Contains => StringFilter::contains_ignore_case(value),
+        Op::NotContains => StringFilter::not_contains_ignore_case(value),
+        _ => StringFilter::eq_ignore_case(value),
+    }
+}
+
+/// A user by email or display name; `me` is the caller.
+fn user_filter(op: Op, value: &str) -> UserFilter {
+    if value.eq_ignore_ascii_case("me") {
+        return UserFilter {
+            is_me: Some(BooleanFilter { eq: op == Op::Eq }),
+            ..Default::default()
+        };
+    }
+    if op == Op::Eq {
+        UserFilter::named(value)
+    } else {
+        UserFilter::not_named(value)
+    }
+}
+
+/// A cycle by `current` / `next` / `previous`, number or name.
+fn cycle_filter(op: Op, value: &str) -> CycleFilter {
+    let positive = !op.is_negative();
+    let flag = Some(BooleanFilter { eq: positive });
+    let lower = value.to_lowercase();
+    let number = lower
+        .trim_start_matches("cycle")
+        .trim()
+        .trim_start_matches('#')
+        .parse::<i32>()
+        .ok();
+    match (lower.as_str(), number) {
+        ("current" | "active", _) => CycleFilter {
+            is_active: flag,
+            ..Default::default()
+        },
+        ("next" | "upcoming", _) => CycleFilter {
+            is_next: flag,
+            ..Default::default()
+        },
+        ("previous" | "last", _) => CycleFilter {
+            is_previous: flag,
+            ..Default::default()
+        },
+        (_, Some(n)) if matches!(op, Op::Eq | Op::Ne) => CycleFilter {
+            number: Some(if positive {
+                NumberFilter::eq(n)
+            } else {
+                NumberFilter {
+                    neq: Some(n),
+                    ..Default::default()
+                }
+            }),
+            ..Default::default()
+        },
+        _ => CycleFilter {
+            name: Some(string_filter(op, value)),
+            ..Default::default()
+        },
+    }
+}
+
+fn comparator<T>(op: Op, value: T) -> Comparator<T> {
+    let mut c = Comparator::default();
+    match op {
+        Op::Eq | Op::Contains => c.eq = Some(value),
+        Op::Ne | Op::NotContains => c.neq = Some(value),
+        Op::Lt => c.lt = Some(value),
+        Op::Le => c.lte = Some(value),
+        Op::Gt => c.gt = Some(value),
+        Op::Ge => c.gte = Some(value),
+    }
+    c
+}
+
+/// Priorities compare by urgency: `priority >= high` is high or urgent,
+/// `priority < medium` is low or no priority.
+fn priority_filter(op: Op, value: &str) -> NumberFilter {
+    let wanted = priority_value(value).expect("checked when parsed");
+    match op {
+        Op::Eq | Op::Ne | Op::Contains | Op::NotContains => comparator(op, wanted),
+        _ => {
+            let rank = urgency(wanted);
+            let matching = (0..=4)
+                .filter(|p| match op {
+                    Op::Lt => urgency(*p) < rank,
+                    Op::Le => urgency(*p) <= rank,
+                    Op::Gt => urgency(*p) > rank,
+                    _ => urgency(*p) >= rank,
+                })
+                .collect();
+            NumberFilter {
+                in_: Some(matching),
+                ..Default::default()
+            }
+        }
+    }
+}
+
+/// A date comparison. `due = this week` is any day of the week, `due <
+/// this week` before its first day, `due > this week` after its last.
+fn date_filter(field: DateField, op: Op, range: &DateRange) -> IssueFilter {
+    let start = || Some(range.start.clone());
+    let end = || Some(range.end.clone());
+    let timeless = field.is_timeless();
+    let mut c = DateComparator::default();
+    match op {
+        Op::Lt => c.lt = start(),
+        Op::Ge => c.gte = start(),
+        Op::Le if timeless => c.lte = end(),
+        Op::Le => c.lt = end(),
+        Op::Gt if timeless => c.gt = end(),
+        Op::Gt => c.gte = end(),
+        Op::Eq | Op::Contains => {
+            c.gte = start();
+            if timeless {
+                c.lte = end();
+            } else {
+                c.lt = end();
+            }
+        }
+        Op::Ne | Op::NotContains => {
+            let before = DateComparator {
+                lt: start(),
+                ..Default::default()
+            };
+            let after = if timeless {
+                DateComparator {
+                    gt: end(),
+                    ..Default::default()
+                }
+            } else {
+                DateComparator {
+                    gte: end(),
+                    ..Default::default()
+                }
+            };
+            return IssueFilter::any(vec![date_field(field, before), date_field(field, after)]);
+        }
+    }
+    date_field(field, c)
+}
+
+fn date_field(field: DateField, c: DateComparator) -> IssueFilter {
+    let c = Some(c);
+    match field {
+        DateField::Due => IssueFilter {
+            due_date: c,
+            ..Default::default()
+        },
+        DateField::Created => IssueFilter {
+            created_at: c,
+            ..Default::default()
+        },
+        DateField::Updated => IssueFilter {
+            updated_at: c,
+            ..Default::default()
+        },
+        DateField::Started => IssueFilter {
+            started_at: c,
+            ..Default::default()
+        },
+        DateField::Completed => IssueFilter {
+            completed_at: c,
+            ..Default::default()
+        },
+        DateField::Canceled => IssueFilter {
+            canceled_at: c,
+            ..Default::default()
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test(field: Field, op: Op, value: &str) -> Expr {
+        Expr::Test(Test {
+            field,
+            op,
+            value: Some(value.into()),
+        })
+    }
+
+    fn compiled(input: &str) -> serde_json::Value {
+        let expr = parse(input).unwrap();
+        serde_json::to_value(compile(&expr, &DateValues::new())).unwrap()
+    }
+
+    fn error(input: &str) -> String {
+        parse(input).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn and_binds_tighter_than_or() {
+        assert_eq!(
+            parse("team = ENG or team = OPS and title ~ crash").unwrap(),
+            Expr::Or(vec![
+                test(Field::Team, Op::Eq, "ENG"),
+                Expr::And(vec![
+                    test(Field::Team, Op::Eq, "OPS"),
+                    test(Field::Title, Op::Contains, "crash"),
+                ]),
+            ])
+        );
+        assert_eq!(
+            parse("(team = ENG or team = OPS) and title ~ crash").unwrap(),
+            Expr::And(vec![
+                Expr::Or(vec![
+                    test(Field::Team, Op::Eq, "ENG"),
+                    test(Field::Team, Op::Eq, "OPS"),
+                ]),
+                test(Field::Title, Op::Contains, "crash"),
+            ])
+        );
+    }
+
+    #[test]
+    fn not_applies_to_the_next_term_only() {
+        assert_eq!(
+            parse("not team = ENG and title ~ crash").unwrap(),
+            Expr::And(vec![
+                Expr::Not(Box::new(test(Field::Team, Op::Eq, "ENG"))),
+                test(Field::Title, Op::Contains, "crash"),
+            ])
+        );
+    }
+
+    #[test]
+    fn in_lists_expand_to_alternatives() {
+        assert_eq!(
+            parse("team in (ENG, OPS)").unwrap(),
+            Expr::Or(vec![
+                test(Field::Team, Op::Eq, "ENG"),
+                test(Field::Team, Op::Eq, "OPS"),
+            ])
+        );
+        assert_eq!(
+            parse("team not in (ENG, OPS)").unwrap(),
+            Expr::And(vec![
+                test(Field::Team, Op::Ne, "ENG"),
+                test(Field::Team, Op::Ne, "OPS"),
+            ])
+        );
+    }
+
+    #[test]
+    fn not_is_pushed_down_to_the_comparisons() {
+        assert_eq!(
+            compiled("not (team = ENG or title ~ crash)"),
+            serde_json::json!({ "and": [
+                { "team": { "key": { "neqIgnoreCase": "ENG" } } },
+                { "title": { "notContainsIgnoreCase": "crash" } },
+            ] })
+        );
+        assert_eq!(compiled("not not team = ENG"), compiled("team = ENG"));
+    }
+
+    #[test]
+    fn negated_tests_match_issues_without_the_field() {
+        assert_eq!(
+            compiled("assignee != me"),
+            serde_json::json!({ "or": [
+                { "assignee": { "null": true } },
+                { "assignee": { "isMe": { "eq": false } } },
+            ] })
+        );
+    }
+
+    #[test]
+    fn priority_compares_by_urgency() {
+        assert_eq!(
+            compiled("priority >= high"),
+            serde_json::json!({ "priority": { "in": [1, 2] } })
+        );
+    }
+
+    #[test]
+    fn nesting_is_limited() {
+        let nested =
+            |depth: usize| format!("{}priority = high{}", "(".repeat(depth), ")".repeat(depth));
+        assert!(parse(&nested(MAX_DEPTH)).is_ok());
+        assert!(error(&nested(MAX_DEPTH + 1)).contains("nest more than 64 deep"));
+        assert!(error(&format!("{}blocked", "not ".repeat(MAX_DEPTH + 1))).contains("nest"));
+        // Siblings don't add up.
+        let siblings = vec![nested(MAX_DEPTH); 3].join(" and ");
+        assert!(parse(&siblings).is_ok());
+    }
+
+    #[test]
+    fn long_filters_are_rejected_before_parsing() {
+        let huge = format!(
+            "{}priority = high{}",
+            "(".repeat(100_000),
+            ")".repeat(100_000)
+        );
+        assert!(error(&huge).contains("at most 4096"));
+    }
+
+    #[test]
+    fn syntax_errors_point_at_the_column() {
+        let message = error("team = ENG and (title ~ crash");
+        assert!(
+            message.contains("'(' is never closed (column 16)"),
+            "{}",
+            message
+        );
+        assert!(error("team = ENG)").contains("unmatched ')'"));
+        assert!(error("teem = ENG").contains("team"));
+        assert!(error("estimate > lots").contains("expected a number"));
+    }
+}
@@ -1,13 +1,32 @@
 mod auth;
 mod cache;
 mod client;
+mod completion;
+mod config;
+mod dates;
 mod error;
 mod format;
 mod graphql;
+mod http;
+mod identifier;
+mod issue_query;
+mod matching;
+mod oauth;
+mod output;
+mod pipeline;
+mod prompts;
+mod record;
+mod resources;
+mod retry;
 mod server;
+mod subscriptions;
+mod tenant;
+mod tool_filter;
 mod tools;
 mod types;
 
+use std::time::Duration;
+
 use rmcp::ServiceExt;
 use tracing_subscriber::EnvFilter;
 
@@ -26,6 +45,31 @@ const HELP_TEXT: &str = concat!(
     "With no flags, starts the MCP server on stdio (the default behavior).\n",
     "\n",
     "Flags:\n",
+    "  --http <ADDR> Serve MCP over streamable HTTP on ADDR (e.g. 127.0.0.1:8080)\n",
+    "                instead of stdio. The endpoint is mounted at /mcp. Clients may\n",
+    "                send their own Linear key in the Authorization header (or as\n",
+    "                'linearApiKey' in the initialize _meta) to act as themselves.\n",
+    "  --read-only   Only expose read-only tools and refuse every mutation.\n",
+    "                Also enabled by LINEAR_MCP_READ_ONLY=1.\n",
+    "  --tools <LIST>\n",
+    "                Only expose these tools. Comma-separated tool names, globs\n",
+    "                (e.g. '*_customer_*'), or profiles: core, triage, admin,\n",
+    "                releases. Also read from LINEAR_MCP_TOOLS.\n",
+    "  --exclude-tools <LIST>\n",
+    "                Hide these tools (same syntax). Also read from\n",
+    "                LINEAR_MCP_EXCLUDE_TOOLS.\n",
+    "  --config <PATH>\n",
+    "                Read settings from this TOML file instead of\n",
+    "                $XDG_CONFIG_HOME/linear-mcp/config.toml. Also read from\n",
+    "                LINEAR_MCP_CONFIG.\n",
+    "  --profile <NAME>\n",
+    "                Use this workspace profile from the config file instead of\n",
+    "                its default_profile. Also read from LINEAR_MCP_PROFILE.\n",
+    "  --login       Authorize with the profile's OAuth app (oauth_client_id) in\n",
+    "                the browser, store the tokens, and exit.\n",
+    "  --require-session-key\n",
+    "                With --http, do not load a server-wide key; reject sessions\n",
+    "                that do not supply their own.\n",
     "  --licenses    Print third-party license attributions and exit\n",
     "  --version     Print version and exit\n",
     "  --help, -h    Print this help message and exit\n",
@@ -34,31 +78,56 @@ const HELP_TEXT: &str = concat!(
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Handle CLI flags before any async work, logging, or auth initialization.
-    // Only the first positional flag is considered.
+    // Informational flags exit immediately; the rest configure the server.
+    let mut http_addr: Option<String> = None;
+    let mut login = false;
+    let mut require_session_key = false;
+    let mut read_only = env_flag("LINEAR_MCP_READ_ONLY");
+    let mut include_tools = std::env::var("LINEAR_MCP_TOOLS").unwrap_or_default();
+    let mut exclude_tools = std::env::var("LINEAR_MCP_EXCLUDE_TOOLS").unwrap_or_default();
+    let mut config_path = std::env::var_os("LINEAR_MCP_CONFIG").map(std::path::PathBuf::from);
+    let mut profile_name = std::env::var("LINEAR_MCP_PROFILE")
+        .ok()
+        .filter(|p| !p.is_empty());
     let mut args = std::env::args().skip(1);
-    if let Some(arg) = args.next() {
+    while let Some(arg) = args.next() {
         match arg.as_str() {
             "--licenses" => {
                 print!("{}", THIRD_PARTY_LICENSES);
                 return Ok(());
             }
             "--version" => {
-                println!(
-                    "{} {}",
-                    env!("CARGO_PKG_NAME"),
-                    env!("CARGO_PKG_VERSION")
-                );
+                println!("{} {}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
                 return Ok(());
             }
             "--help" | "-h" => {
                 print!("{}", HELP_TEXT);
                 return Ok(());
             }
-            other => {
-                eprintln!("error: unknown flag '{}'", other);
-                eprintln!("Try '--help' for usage.");
-                std::process::exit(2);
-            }
+            "--http" => match args.next() {
+                Some(addr) => http_addr = Some(addr),
+                None => usage_error("'--http' requires an address (e.g. 127.0.0.1:8080)"),
+            },
+            "--login" => login = true,
+            "--require-session-key" => require_session_key = true,
+            "--read-only" => read_only = true,
+            "--tools" => match args.next() {
+                Some(list) => include_tools = list,
+                None => usage_error("'--tools' requires a list (e.g. core,*_customer_*)"),
+            },
+            "--exclude-tools" => match args.next() {
+                Some(list) => exclude_tools = list,
+                None => usage_error("'--exclude-tools' requires a list (e.g. delete_*)"),
+            },
+            "--config" => match args.next() {
+                Some(path) => config_path = Some(path.into()),
+                None => usage_error("'--config' requires a file path"),
+            },
+            "--profile" => match args.next() {
+                Some(name) => profile_name = Some(name),
+                None => usage_error("'--profile' requires a profile name"),
+            },
+            other => usage_error(&format!("unknown flag '{}'", other)),
         }
     }
 
@@ -68,18 +137,154 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .with_writer(std::io::stderr)
         .init();
 
-    let api_key = auth::load_api_key().map_err(|e| {
+    if require_session_key && http_addr.is_none() {
+        usage_error("'--require-session-key' only applies with '--http'");
+    }
+
+    let profile = config::Config::load(config_path.as_deref())
+        .and_then(|config| config.profile(profile_name.as_deref()))
+        .map_err(|e| {
+            eprintln!("{}", e);
+            e
+        })?;
+
+    let mut client_options = client::ClientOptions::default();
+    if let Some(url) = std::env::var("LINEAR_API_URL")
+        .ok()
+        .filter(|u| !u.is_empty())
+        .or(profile.api_url.clone())
+    {
+        client_options.api_url = url;
+    }
+    client_options.proxy = profile.proxy.clone();
+    client_options.ca_bundle = profile.ca_bundle.clone();
+    if let Some(ref user_agent) = profile.user_agent {
+        client_options.user_agent = user_agent.clone();
+    }
+    if let Some(secs) = profile.connect_timeout_secs {
+        client_options.connect_timeout = Duration::from_secs(secs);
+    }
+    if let Some(secs) = profile.request_timeout_secs {
+        client_options.timeout = Duration::from_secs(secs);
+    }
+    if let Some(retries) = profile.max_retries {
+        client_options.retry.max_retries = retries;
+    }
+
+    if login {
+        let app = match oauth::OAuthApp::from_profile(&profile) {
+            Ok(Some(app)) => app,
+            Ok(None) => usage_error(&format!(
+                "'--login' needs oauth_client_id in profile '{}'",
+                profile.name
+            )),
+            Err(e) => {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        };
+        let result = match client_options.http_client() {
+            Ok(http) => oauth::login(&app, &http).await,
+            Err(e) => Err(e),
+        };
+        if let Err(e) = result {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    // Command-line and environment settings win over the profile.
+    read_only |= profile.read_only;
+    if include_tools.is_empty() {
+        include_tools = profile.tools.join(",");
+    }
+    if exclude_tools.is_empty() {
+        exclude_tools = profile.exclude_tools.join(",");
+    }
+
+    // With per-session keys only, the server-wide client is never used to
+    // talk to Linear, so no key is loaded for it.
+    let credential = if require_session_key {
+        auth::Credential::ApiKey(String::new())
+    } else {
+        auth::load_credential(&profile).map_err(|e| {
+            eprintln!("{}", e);
+            e
+        })?
+    };
+
+    tracing::info!("Starting linear-mcp server");
+
+    let client = match credential {
+        auth::Credential::ApiKey(key) => client::LinearClient::new(key, &client_options),
+        auth::Credential::OAuth(session) => {
+            client::LinearClient::new(String::new(), &client_options).map(|c| c.with_oauth(session))
+        }
+    };
+    let mut client = client.map_err(|e| {
         eprintln!("{}", e);
         e
     })?;
+    let cache = cache::EntityCache::with_settings(profile.cache_settings());
+    client = client.with_cache(cache.clone());
+    if let Some(dir) = std::env::var_os("LINEAR_MCP_RECORD_DIR").filter(|d| !d.is_empty()) {
+        let dir = std::path::PathBuf::from(dir);
+        tracing::info!("Recording Linear responses to {}", dir.display());
+        client = client.with_middleware(std::sync::Arc::new(record::Recorder::new(dir)?));
+    }
+    if read_only {
+        tracing::info!("Read-only mode: mutating tools are disabled");
+        client = client.read_only();
+    }
+    let timezone = std::env::var("LINEAR_MCP_TIMEZONE")
+        .ok()
+        .filter(|tz| !tz.is_empty())
+        .or(profile.timezone.clone());
+    let dates = match timezone {
+        Some(ref name) => dates::Dates::with_timezone(name).map_err(|e| {
+            eprintln!("{}", e);
+            e
+        })?,
+        None => dates::Dates::default(),
+    };
+    let tool_filter = tool_filter::ToolFilter::new(
+        tool_filter::split_list(&include_tools),
+        tool_filter::split_list(&exclude_tools),
+    );
+    let mut server = server::LinearMcp::new(client)
+        .with_tool_filter(&tool_filter)
+        .with_default_team(profile.default_team)
+        .with_output_format(profile.output_format)
+        .with_dates(dates);
+    if let Some(secs) = profile.poll_interval_secs {
+        server = server.with_poll_interval(Duration::from_secs(secs.max(1)));
+    }
 
-    tracing::info!("Starting linear-mcp server");
+    match http_addr {
+        Some(addr) => http::serve(&addr, server.with_tenants(require_session_key)).await?,
+        None => {
+            let service = server.serve(rmcp::transport::io::stdio()).await?;
+            service.waiting().await?;
+        }
+    }
+    cache.save();
 
-    let client = client::LinearClient::new(api_key)?;
-    let server = server::LinearMcp::new(client);
+    Ok(())
+}
 
-    let service = server.serve(rmcp::transport::io::stdio()).await?;
-    service.waiting().await?;
+/// Whether an environment variable is set to a truthy value (`1`, `true`, `yes`, `on`).
+fn env_flag(name: &str) -> bool {
+    std::env::var(name).is_ok_and(|v| {
+        matches!(
+            v.trim().to_ascii_lowercase().as_str(),
+            "1" | "true" | "yes" | "on"
+        )
+    })
+}
 
-    Ok(())
+fn usage_error(msg: &str) -> ! {
+    eprintln!("error: {}", msg);
+    eprintln!("Try '--help' for usage.");
+    std::process::exit(2);
 }
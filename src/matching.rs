@@ -0,0 +1,364 @@
+//! Fuzzy matching of human-typed names (users, teams, states, labels,
+//! cycles, views, documents, milestones)
+//! against the candidates Linear returns.
+
+use crate::error::Error;
+use crate::graphql::filters::StringFilter;
+
+/// Something a name can resolve to.
+pub struct Candidate {
+    pub id: String,
+    /// How to show the candidate in suggestions, e.g. `Alice Smith <alice@acme.com>`.
+    pub label: String,
+    /// Every name it answers to (name, display name, email, key...).
+    pub names: Vec<String>,
+}
+
+impl Candidate {
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            names: Vec::new(),
+        }
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.names.push(name.into());
+        self
+    }
+}
+
+/// How many candidates an ambiguity or "did you mean" error lists.
+const MAX_SUGGESTIONS: usize = 5;
+
+/// How closely a typed name must match a candidate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Match {
+    /// Any of the steps of [`pick`], for reading and updating.
+    Fuzzy,
+    /// A whole name, ignoring case only, for deleting and archiving: a typo
+    /// or partial name must not act on some other entity.
+    Exact,
+}
+
+impl Match {
+    pub fn pick<'a>(
+        self,
+        kind: &str,
+        input: &str,
+        candidates: &'a [Candidate],
+    ) -> Result<&'a Candidate, Error> {
+        match self {
+            Match::Fuzzy => pick(kind, input, candidates),
+            Match::Exact => pick_exact(kind, input, candidates),
+        }
+    }
+
+    /// A name filter that narrows Linear's candidates to those that can match.
+    pub fn filter(self, input: &str) -> StringFilter {
+        match self {
+            Match::Fuzzy => StringFilter::contains_ignore_case(input.trim()),
+            Match::Exact => StringFilter::eq_ignore_case(input.trim()),
+        }
+    }
+}
+
+/// Pick the candidate `input` refers to, trying in turn:
+///
+/// 1. an exact match of any name, ignoring case, punctuation and spacing
+///    (`in_progress` is `In Progress`);
+/// 2. `input` as the start of a name or of one of its words (`alice` is
+///    `Alice Smith`, `prog` is `In Progress`);
+/// 3. `input` anywhere in a name;
+/// 4. the closest name by edit distance, if close enough to be a typo.
+///
+/// Several equally good matches give [`Error::Ambiguous`] listing them; no
+/// match gives [`Error::NotFound`] with the closest names as suggestions.
+/// A blank `input` would start every name, so it is rejected.
+pub fn pick<'a>(
+    kind: &str,
+    input: &str,
+    candidates: &'a [Candidate],
+) -> Result<&'a Candidate, Error> {
+    let wanted = normalize(input);
+    if wanted.is_empty() {
+        return Err(blank(kind));
+    }
+    let exact: Vec<&Candidate> = candidates
+        .iter()
+        .filter(|c| c.names.iter().any(|n| normalize(n) == wanted))
+        .collect();
+    let prefix = || -> Vec<&Candidate> {
+        candidates
+            .iter()
+            .filter(|c| {
+                c.names.iter().any(|n| {
+                    let n = normalize(n);
+                    n.starts_with(&wanted) || n.split(' ').any(|w| w.starts_with(&wanted))
+                })
+            })
+            .collect()
+    };
+    let contains = || -> Vec<&Candidate> {
+        candidates
+            .iter()
+            .filter(|c| c.names.iter().any(|n| normalize(n).contains(&wanted)))
+            .collect()
+    };
+    for matches in [exact, prefix(), contains()] {
+        match unique(&matches) {
+            Some(0) => {}
+            Some(_) => return Ok(matches[0]),
+            None => return Err(ambiguous(kind, input, &matches)),
+        }
+    }
+
+    let mut ranked: Vec<(usize, &Candidate)> = candidates
+        .iter()
+        .map(|c| (distance(&wanted, c), c))
+        .collect();
+    ranked.sort_by_key(|(d, _)| *d);
+    let threshold = (wanted.chars().count() / 4).max(1);
+    if let Some(&(best, _)) = ranked.first()
+        && best <= threshold
+    {
+        let closest: Vec<&Candidate> = ranked
+            .iter()
+            .take_while(|(d, _)| *d == best)
+            .map(|(_, c)| *c)
+            .collect();
+        return match unique(&closest) {
+            Some(_) => Ok(closest[0]),
+            None => Err(ambiguous(kind, input, &closest)),
+        };
+    }
+
+    let mut message = format!("{} '{}' not found.", kind, input);
+    let suggestions: Vec<&str> = ranked
+        .iter()
+        .filter(|(d, _)| *d <= threshold + 3)
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, c)| c.label.as_str())
+        .collect();
+    if !suggestions.is_empty() {
+        message = format!("{} Did you mean: {}?", message, suggestions.join(", "));
+    }
+    Err(Error::NotFound(message))
+}
+
+/// Pick the candidate with a name equal to `input`, ignoring case and
+/// surrounding spaces.
+pub fn pick_exact<'a>(
+    kind: &str,
+    input: &str,
+    candidates: &'a [Candidate],
+) -> Result<&'a Candidate, Error> {
+    let wanted = input.trim().to_lowercase();
+    if wanted.is_empty() {
+        return Err(blank(kind));
+    }
+    let exact: Vec<&Candidate> = candidates
+        .iter()
+        .filter(|c| c.names.iter().any(|n| n.trim().to_lowercase() == wanted))
+        .collect();
+    match unique(&exact) {
+        Some(0) => Err(Error::NotFound(format!(
+            "{} '{}' not found. Give its exact name or UUID.",
+            kind, input
+        ))),
+        Some(_) => Ok(exact[0]),
+        None => Err(ambiguous(kind, input, &exact)),
+    }
+}
+
+/// `Some(count)` when the matches all share one id (`Some(0)` when there are
+/// none), `None` when they point at different entities.
+fn unique(matches: &[&Candidate]) -> Option<usize> {
+    match matches.first() {
+        None => Some(0),
+        Some(first) => matches
+            .iter()
+            .all(|c| c.id == first.id)
+            .then_some(matches.len()),
+    }
+}
+
+fn blank(kind: &str) -> Error {
+    Error::InvalidInput(format!("{} name is empty.", kind))
+}
+
+fn ambiguous(kind: &str, input: &str, matches: &[&Candidate]) -> Error {
+    let mut candidates: Vec<String> = Vec::new();
+    for c in matches {
+        if !candidates.contains(&c.label) {
+            candidates.push(c.label.clone());
+        }
+    }
+    candidates.truncate(MAX_SUGGESTIONS);
+    Error::Ambiguous {
+        message: format!("{} '{}' is ambiguous.", kind, input),
+        candidates,
+    }
+}
+
+/// Distance from `wanted` to the closest of a candidate's names.
+fn distance(wanted: &str, candidate: &Candidate) -> usize {
+    candidate
+        .names
+        .iter()
+        .map(|n| edit_distance(wanted, &normalize(n)))
+        .min()
+        .unwrap_or(usize::MAX)
+}
+
+/// Lowercase, with runs of anything but letters, digits, `@` and `.`
+/// collapsed to one space.
+fn normalize(s: &str) -> String {
+    s.to_lowercase()
+        .split(|c: char| !(c.is_alphanumeric() || c == '@' || c == '.'))
+        .filter(|w| !w.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Case-insensitive Levenshtein distance.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.to_lowercase().chars().collect();
+    let b: Vec<char> = b.to_lowercase().chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn states() -> Vec<Candidate> {
+        ["Todo", "In Progress", "In Review", "Done"]
+            .iter()
+            .enumerate()
+            .map(|(i, name)| Candidate::new(i.to_string(), *name).name(*name))
+            .collect()
+    }
+
+    fn picked(input: &str) -> Result<String, Error> {
+        pick("State", input, &states()).map(|c| c.label.clone())
+    }
+
+    fn candidates<T>(result: Result<T, Error>) -> Vec<String> {
+        match result {
+            Err(Error::Ambiguous { candidates, .. }) => candidates,
+            Err(other) => panic!("expected an ambiguity, got {other:?}"),
+            Ok(_) => panic!("expected an ambiguity, got a match"),
+        }
+    }
+
+    #[test]
+    fn exact_names_ignore_case_and_punctuation() {
+        assert_eq!(picked("in_progress").unwrap(), "In Progress");
+        assert_eq!(picked("  DONE ").unwrap(), "Done");
+    }
+
+    #[test]
+    fn prefixes_beat_substrings() {
+        // "do" starts "Done" but is also inside "Todo".
+        assert_eq!(picked("do").unwrap(), "Done");
+        assert_eq!(picked("prog").unwrap(), "In Progress");
+        assert_eq!(picked("odo").unwrap(), "Todo");
+    }
+
+    #[test]
+    fn equally_good_matches_are_ambiguous() {
+        assert_eq!(candidates(picked("in")), ["In Progress", "In Review"]);
+        // The same entity under several names is not ambiguous.
+        let user = [Candidate::new("u1", "Alice Smith <alice@acme.com>")
+            .name("Alice Smith")
+            .name("alice@acme.com")];
+        assert_eq!(pick("User", "alice", &user).unwrap().id, "u1");
+    }
+
+    #[test]
+    fn typos_resolve_to_the_closest_name() {
+        assert_eq!(picked("Donne").unwrap(), "Done");
+        assert_eq!(picked("In Reveiw").unwrap(), "In Review");
+        // Two names equally close is still ambiguous.
+        let two = [
+            Candidate::new("a", "Alpha").name("Alpha"),
+            Candidate::new("b", "Alphb").name("Alphb"),
+        ];
+        assert_eq!(candidates(pick("Team", "Alphc", &two)), ["Alpha", "Alphb"]);
+    }
+
+    #[test]
+    fn misses_suggest_the_nearest_names() {
+        match picked("Doing").unwrap_err() {
+            Error::NotFound(message) => {
+                assert!(message.starts_with("State 'Doing' not found."), "{message}");
+                assert!(message.contains("Did you mean: Done"), "{message}");
+            }
+            other => panic!("expected not found, got {other:?}"),
+        }
+        assert!(
+            matches!(picked("Blocked waiting"), Err(Error::NotFound(m)) if !m.contains("Did you mean"))
+        );
+    }
+
+    #[test]
+    fn blank_names_are_rejected() {
+        let one = [Candidate::new("a", "Alpha").name("Alpha")];
+        for input in ["", "   ", "-_-"] {
+            assert!(
+                matches!(pick("Team", input, &one), Err(Error::InvalidInput(_))),
+                "{input:?}"
+            );
+            assert!(matches!(
+                pick("State", input, &states()),
+                Err(Error::InvalidInput(_))
+            ));
+        }
+        assert!(matches!(
+            pick_exact("View", " ", &one),
+            Err(Error::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn exact_picks_need_the_whole_name() {
+        let states = states();
+        assert_eq!(
+            pick_exact("State", " in review ", &states).unwrap().label,
+            "In Review"
+        );
+        for partial in ["in", "Reveiw", "in_review"] {
+            assert!(matches!(
+                pick_exact("State", partial, &states),
+                Err(Error::NotFound(_))
+            ));
+        }
+        let twins = [
+            Candidate::new("a", "Roadmap (a)").name("Roadmap"),
+            Candidate::new("b", "Roadmap (b)").name("roadmap"),
+        ];
+        assert_eq!(
+            candidates(Match::Exact.pick("View", "ROADMAP", &twins)),
+            ["Roadmap (a)", "Roadmap (b)"]
+        );
+    }
+
+    #[test]
+    fn edit_distance_counts_single_character_edits() {
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("Done", "dOnE"), 0);
+    }
+}
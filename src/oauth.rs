@@ -0,0 +1,329 @@
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use reqwest::{Client, Url};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tokio::sync::{Mutex, mpsc};
+
+use crate::auth;
+use crate::config::{self, Profile, TokenStore};
+use crate::error::Error;
+
+const AUTHORIZE_URL: &str = "https://linear.app/oauth/authorize";
+const TOKEN_URL: &str = "https://api.linear.app/oauth/token";
+const DEFAULT_REDIRECT_PORT: u16 = 8974;
+const CALLBACK_PATH: &str = "/callback";
+/// How long `--login` waits for the browser to come back.
+const LOGIN_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Tokens persisted by `--login`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tokens {
+    pub access_token: String,
+    pub refresh_token: Option<String>,
+    /// Unix time (seconds) the access token expires at, if known.
+    pub expires_at: Option<u64>,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    refresh_token: Option<String>,
+    expires_in: Option<u64>,
+}
+
+impl From<TokenResponse> for Tokens {
+    fn from(resp: TokenResponse) -> Self {
+        Self {
+            access_token: resp.access_token,
+            refresh_token: resp.refresh_token,
+            expires_at: resp.expires_in.map(|secs| unix_now() + secs),
+        }
+    }
+}
+
+/// OAuth app settings and token location for one profile.
+#[derive(Debug, Clone)]
+pub struct OAuthApp {
+    client_id: String,
+    client_secret: Option<String>,
+    scopes: String,
+    redirect_port: u16,
+    store: Store,
+}
+
+#[derive(Debug, Clone)]
+enum Store {
+    File(PathBuf),
+    /// macOS Keychain or Secret Service item with this service name.
+    Keychain(String),
+}
+
+impl OAuthApp {
+    /// The profile's OAuth app, or `None` if it has no `oauth_client_id`.
+    /// Fails when tokens go in a file but there is no config directory to
+    /// put it in.
+    pub fn from_profile(profile: &Profile) -> Result<Option<Self>, String> {
+        let Some(client_id) = profile.oauth_client_id.clone() else {
+            return Ok(None);
+        };
+        let scopes = if profile.oauth_scopes.is_empty() {
+            "read,write".to_string()
+        } else {
+            profile.oauth_scopes.join(",")
+        };
+        let store = match profile.token_store {
+            TokenStore::File => Store::File(
+                config::config_dir()
+                    .ok_or("no config directory for the OAuth token file")?
+                    .join(format!("oauth-{}.json", profile.name)),
+            ),
+            TokenStore::Keychain => Store::Keychain(format!("linear-mcp-oauth-{}", profile.name)),
+        };
+        Ok(Some(Self {
+            client_id,
+            client_secret: profile.oauth_client_secret.clone(),
+            scopes,
+            redirect_port: profile.oauth_redirect_port.unwrap_or(DEFAULT_REDIRECT_PORT),
+            store,
+        }))
+    }
+
+    pub fn store_description(&self) -> String {
+        match self.store {
+            Store::File(ref path) => format!("OAuth token file {}", path.display()),
+            Store::Keychain(ref service) => format!("OAuth keychain item '{}'", service),
+        }
+    }
+
+    /// Read previously stored tokens.
+    pub fn load_tokens(&self) -> Result<Tokens, String> {
+        let json = match self.store {
+            Store::File(ref path) => auth::read_secret_file(path)?,
+            Store::Keychain(ref service) => auth::keychain_or_secret_service_get(service)?,
+        };
+        serde_json::from_str(&json).map_err(|e| format!("unreadable tokens ({}); run --login", e))
+    }
+
+    fn save_tokens(&self, tokens: &Tokens) -> Result<(), Error> {
+        let json = serde_json::to_string(tokens).map_err(|e| Error::OAuth(e.to_string()))?;
+        match self.store {
+            Store::File(ref path) => auth::write_secret_file(path, &json),
+            Store::Keychain(ref service) => auth::keychain_or_secret_service_set(service, &json),
+        }
+        .map_err(|e| Error::OAuth(format!("could not store tokens: {}", e)))
+    }
+
+    fn redirect_uri(&self) -> String {
+        format!("http://localhost:{}{}", self.redirect_port, CALLBACK_PATH)
+    }
+
+    async fn request_tokens(&self, http: &Client, form: &[(&str, &str)]) -> Result<Tokens, Error> {
+        let mut form = form.to_vec();
+        form.push(("client_id", &self.client_id));
+        if let Some(ref secret) = self.client_secret {
+            form.push(("client_secret", secret));
+        }
+        let resp = http.post(TOKEN_URL).form(&form).send().await?;
+        let status = resp.status();
+        if !status.is_success() {
+            let text = resp.text().await.unwrap_or_default();
+            return Err(Error::OAuth(format!(
+                "token endpoint returned HTTP {status}: {text}"
+            )));
+        }
+        let tokens: TokenResponse = resp.json().await?;
+        Ok(tokens.into())
+    }
+}
+
+/// Run the authorization-code flow with PKCE and store the resulting tokens.
+///
+/// The user approves access in the browser; Linear redirects to a one-shot
+/// listener on `localhost` that receives the authorization code. The code
+/// is exchanged for tokens with `http`.
+pub async fn login(app: &OAuthApp, http: &Client) -> Result<(), Error> {
+    let verifier = URL_SAFE_NO_PAD.encode(rand::random::<[u8; 32]>());
+    let challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(verifier.as_bytes()));
+    let state = URL_SAFE_NO_PAD.encode(rand::random::<[u8; 16]>());
+    let redirect_uri = app.redirect_uri();
+
+    let mut url = Url::parse(AUTHORIZE_URL).expect("valid authorize URL");
+    url.query_pairs_mut()
+        .append_pair("client_id", &app.client_id)
+        .append_pair("redirect_uri", &redirect_uri)
+        .append_pair("response_type", "code")
+        .append_pair("scope", &app.scopes)
+        .append_pair("state", &state)
+        .append_pair("code_challenge", &challenge)
+        .append_pair("code_challenge_method", "S256")
+        .append_pair("prompt", "consent");
+
+    let listener = tokio::net::TcpListener::bind(("127.0.0.1", app.redirect_port))
+        .await
+        .map_err(|e| {
+            Error::OAuth(format!(
+                "cannot listen on port {}: {}",
+                app.redirect_port, e
+            ))
+        })?;
+
+    eprintln!("Open this URL to authorize linear-mcp:\n\n  {}\n", url);
+    open_browser(url.as_str());
+
+    let code = tokio::time::timeout(LOGIN_TIMEOUT, wait_for_code(listener, state))
+        .await
+        .map_err(|_| Error::OAuth("timed out waiting for the browser".into()))??;
+
+    let tokens = app
+        .request_tokens(
+            http,
+            &[
+                ("grant_type", "authorization_code"),
+                ("code", &code),
+                ("redirect_uri", &redirect_uri),
+                ("code_verifier", &verifier),
+            ],
+        )
+        .await?;
+    app.save_tokens(&tokens)?;
+    eprintln!("Logged in. Tokens saved to {}.", app.store_description());
+    Ok(())
+}
+
+/// Serve the redirect URI until Linear calls it, returning the code.
+async fn wait_for_code(listener: tokio::net::TcpListener, state: String) -> Result<String, Error> {
+    let (tx, mut rx) = mpsc::channel::<Result<String, Error>>(1);
+    let handler = move |uri: axum::http::Uri| {
+        let tx = tx.clone();
+        let state = state.clone();
+        async move {
+            let result = parse_callback(&uri, &state);
+            let page = match result {
+                Ok(_) => "Linear login complete. You can close this tab.".to_string(),
+                Err(ref e) => format!("Linear login failed: {}", e),
+            };
+            let _ = tx.send(result).await;
+            page
+        }
+    };
+    let router = axum::Router::new().route(CALLBACK_PATH, axum::routing::get(handler));
+
+    let (done_tx, done_rx) = tokio::sync::oneshot::channel::<()>();
+    let server = tokio::spawn(async move {
+        axum::serve(listener, router)
+            .with_graceful_shutdown(async {
+                let _ = done_rx.await;
+            })
+            .await
+    });
+
+    let result = rx
+        .recv()
+        .await
+        .unwrap_or_else(|| Err(Error::OAuth("callback listener stopped".into())));
+    let _ = done_tx.send(());
+    let _ = server.await;
+    result
+}
+
+fn parse_callback(uri: &axum::http::Uri, expected_state: &str) -> Result<String, Error> {
+    let url = Url::parse(&format!("http://localhost{}", uri))
+        .map_err(|e| Error::OAuth(format!("bad callback URL: {}", e)))?;
+    let param = |name: &str| {
+        url.query_pairs()
+            .find(|(k, _)| k == name)
+            .map(|(_, v)| v.into_owned())
+    };
+    if let Some(error) = param("error") {
+        let description = param("error_description").unwrap_or_default();
+        return Err(Error::OAuth(format!(
+            "authorization denied: {} {}",
+            error, description
+        )));
+    }
+    if param("state").as_deref() != Some(expected_state) {
+        return Err(Error::OAuth("state mismatch in callback".into()));
+    }
+    param("code").ok_or_else(|| Error::OAuth("callback had no code".into()))
+}
+
+fn open_browser(url: &str) {
+    let program = if cfg!(target_os = "macos") {
+        "open"
+    } else {
+        "xdg-open"
+    };
+    let _ = std::process::Command::new(program)
+        .arg(url)
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn();
+}
+
+/// Stored OAuth tokens in use by a `LinearClient`, refreshed on 401.
+#[derive(Debug)]
+pub struct OAuthSession {
+    app: OAuthApp,
+    tokens: Mutex<Tokens>,
+}
+
+impl OAuthSession {
+    pub fn new(app: OAuthApp, tokens: Tokens) -> Self {
+        Self {
+            app,
+            tokens: Mutex::new(tokens),
+        }
+    }
+
+    /// Value for the `Authorization` header.
+    pub async fn authorization(&self) -> String {
+        format!("Bearer {}", self.tokens.lock().await.access_token)
+    }
+
+    /// Exchange the refresh token for a new access token, unless another
+    /// request already did so since `stale` was sent. Returns the new
+    /// `Authorization` header value.
+    pub async fn refresh(&self, http: &Client, stale: &str) -> Result<String, Error> {
+        let mut tokens = self.tokens.lock().await;
+        let current = format!("Bearer {}", tokens.access_token);
+        if current != stale {
+            return Ok(current);
+        }
+        let Some(refresh_token) = tokens.refresh_token.clone() else {
+            return Err(Error::OAuth(
+                "access token expired and no refresh token is stored; run --login".into(),
+            ));
+        };
+        tracing::info!("Refreshing OAuth access token");
+        let mut fresh = self
+            .app
+            .request_tokens(
+                http,
+                &[
+                    ("grant_type", "refresh_token"),
+                    ("refresh_token", &refresh_token),
+                ],
+            )
+            .await?;
+        // Linear may not rotate the refresh token; keep the old one then.
+        if fresh.refresh_token.is_none() {
+            fresh.refresh_token = Some(refresh_token);
+        }
+        if let Err(e) = self.app.save_tokens(&fresh) {
+            tracing::warn!("{}", e);
+        }
+        *tokens = fresh;
+        Ok(format!("Bearer {}", tokens.access_token))
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
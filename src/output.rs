@@ -0,0 +1,48 @@
+//! Tool results as text for chat clients plus the same data as JSON
+//! (`structuredContent`) for scripts, described by each tool's `outputSchema`.
+
+use std::sync::Arc;
+
+use rmcp::model::{CallToolResult, Content, JsonObject};
+use schemars::JsonSchema;
+use serde::Serialize;
+
+/// What a tool handler produces: the rendered text and the data behind it.
+#[derive(Debug)]
+pub struct Output<T> {
+    pub text: String,
+    pub data: T,
+}
+
+impl<T: Serialize> Output<T> {
+    pub fn new(text: impl Into<String>, data: T) -> Self {
+        Self {
+            text: text.into(),
+            data,
+        }
+    }
+
+    pub fn into_result(self) -> CallToolResult {
+        let structured = serde_json::to_value(&self.data).unwrap_or_else(|e| {
+            tracing::warn!("Cannot serialize tool output: {}", e);
+            serde_json::Value::Null
+        });
+        CallToolResult {
+            content: vec![Content::text(self.text)],
+            structured_content: Some(structured),
+            is_error: Some(false),
+            meta: None,
+        }
+    }
+}
+
+/// The `outputSchema` of a tool whose handler returns `Output<T>`.
+pub fn schema<T: JsonSchema + 'static>() -> Arc<JsonObject> {
+    rmcp::handler::server::tool::schema_for_output::<T>().unwrap_or_else(|e| {
+        panic!(
+            "Invalid output schema for {}: {}",
+            std::any::type_name::<T>(),
+            e
+        )
+    })
+}
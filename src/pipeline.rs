@@ -0,0 +1,131 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Instant;
+
+use serde::Serialize;
+
+use crate::client::LinearClient;
+use crate::error::Error;
+
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+static NEXT_REQUEST_ID: AtomicU64 = AtomicU64::new(1);
+
+/// A GraphQL operation on its way to Linear. Serializes to the request body.
+#[derive(Debug, Clone, Serialize)]
+pub struct Request {
+    /// Process-unique id, also recorded on the request's tracing span.
+    #[serde(skip)]
+    pub id: u64,
+    pub query: &'static str,
+    pub variables: serde_json::Value,
+}
+
+impl Request {
+    pub fn new(query: &'static str, variables: serde_json::Value) -> Self {
+        Self {
+            id: NEXT_REQUEST_ID.fetch_add(1, Ordering::Relaxed),
+            query,
+            variables,
+        }
+    }
+
+    pub fn is_mutation(&self) -> bool {
+        self.query.trim_start().starts_with("mutation")
+    }
+
+    /// The operation name (`UpdateIssue` in `mutation UpdateIssue(...)`),
+    /// or `anonymous`.
+    pub fn operation_name(&self) -> &'static str {
+        let mut words = self.query.split_whitespace();
+        match words.next() {
+            Some("query" | "mutation") => {}
+            _ => return "anonymous",
+        }
+        let name = words
+            .next()
+            .unwrap_or_default()
+            .split(['(', '{'])
+            .next()
+            .unwrap_or_default();
+        if name.is_empty() { "anonymous" } else { name }
+    }
+}
+
+/// A step in the request pipeline. It may inspect or rewrite the outcome
+/// of the rest of the pipeline (`next`), call it several times (retries),
+/// or answer without calling it at all (mocks).
+///
+/// The outcome is the raw body of a successful response; HTTP and
+/// rate-limit failures arrive as typed errors.
+pub trait Middleware: Send + Sync {
+    fn handle<'a>(
+        &'a self,
+        request: &'a Request,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<String, Error>>;
+}
+
+/// The remainder of the pipeline after the current middleware.
+#[derive(Clone, Copy)]
+pub struct Next<'a> {
+    client: &'a LinearClient,
+    remaining: &'a [Arc<dyn Middleware>],
+}
+
+impl<'a> Next<'a> {
+    pub fn new(client: &'a LinearClient, middleware: &'a [Arc<dyn Middleware>]) -> Self {
+        Self {
+            client,
+            remaining: middleware,
+        }
+    }
+
+    /// The client the request is sent with.
+    pub fn client(&self) -> &'a LinearClient {
+        self.client
+    }
+
+    pub fn run(self, request: &'a Request) -> BoxFuture<'a, Result<String, Error>> {
+        match self.remaining.split_first() {
+            Some((first, rest)) => first.handle(request, Next::new(self.client, rest)),
+            None => Box::pin(self.client.transport(request)),
+        }
+    }
+}
+
+/// Logs each request's operation, duration, and outcome.
+pub struct Logging;
+
+impl Middleware for Logging {
+    fn handle<'a>(
+        &'a self,
+        request: &'a Request,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<String, Error>> {
+        Box::pin(async move {
+            tracing::trace!(variables = %request.variables, "Sending {}", request.operation_name());
+            let start = Instant::now();
+            let result = next.run(request).await;
+            let elapsed_ms = start.elapsed().as_millis() as u64;
+            match result {
+                Ok(ref body) => tracing::debug!(
+                    elapsed_ms,
+                    bytes = body.len(),
+                    "{} completed",
+                    request.operation_name()
+                ),
+                Err(ref e) => tracing::debug!(
+                    elapsed_ms,
+                    code = e.code(),
+                    "{} failed: {}",
+                    request.operation_name(),
+                    e
+                ),
+            }
+            result
+        })
+    }
+}
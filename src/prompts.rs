@@ -0,0 +1,178 @@
+//! MCP prompts for common Linear workflows. Each one gathers live data
+//! through the tool handlers and asks the model to work from it.
+
+use rmcp::model::{JsonObject, Prompt, PromptArgument};
+
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinearPrompt {
+    TriageInbox,
+    ProjectUpdate,
+    SprintPlanning,
+    BreakDownIssue,
+    ReleaseNotes,
+}
+
+/// One prompt argument: name, description, and whether it is required.
+type ArgumentSpec = (&'static str, &'static str, bool);
+
+const TEAM: ArgumentSpec = (
+    "team",
+    "Team key (e.g. ENG). Defaults to the profile's default_team",
+    false,
+);
+
+impl LinearPrompt {
+    pub const ALL: [LinearPrompt; 5] = [
+        LinearPrompt::TriageInbox,
+        LinearPrompt::ProjectUpdate,
+        LinearPrompt::SprintPlanning,
+        LinearPrompt::BreakDownIssue,
+        LinearPrompt::ReleaseNotes,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            LinearPrompt::TriageInbox => "triage_inbox",
+            LinearPrompt::ProjectUpdate => "project_update",
+            LinearPrompt::SprintPlanning => "sprint_planning",
+            LinearPrompt::BreakDownIssue => "break_down_issue",
+            LinearPrompt::ReleaseNotes => "release_notes",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|p| p.name() == name)
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            LinearPrompt::TriageInbox => {
+                "Triage a team's inbox: suggest a state, priority, assignee and labels for each issue."
+            }
+            LinearPrompt::ProjectUpdate => {
+                "Draft a project update from the project's progress, milestones and recent activity."
+            }
+            LinearPrompt::SprintPlanning => {
+                "Plan a team's next cycle from unfinished work and the prioritized backlog."
+            }
+            LinearPrompt::BreakDownIssue => "Break an issue down into sub-issues.",
+            LinearPrompt::ReleaseNotes => {
+                "Write release notes from the issues a team completed in a cycle or since a date."
+            }
+        }
+    }
+
+    pub fn arguments(self) -> &'static [ArgumentSpec] {
+        match self {
+            LinearPrompt::TriageInbox => &[TEAM],
+            LinearPrompt::ProjectUpdate => &[("project", "Project name or UUID", true)],
+            LinearPrompt::SprintPlanning => &[
+                TEAM,
+                (
+                    "cycle",
+                    "Cycle to plan: next (the default), current, a number, or a name",
+                    false,
+                ),
+            ],
+            LinearPrompt::BreakDownIssue => &[("issue", "Issue identifier (e.g. ENG-123)", true)],
+            LinearPrompt::ReleaseNotes => &[
+                TEAM,
+                (
+                    "cycle",
+                    "Cycle whose completed issues to cover: current (the default), previous, a number, or a name",
+                    false,
+                ),
+                (
+                    "since",
+                    "Cover issues completed since this date instead (e.g. 2026-03-01, -2w, start of month)",
+                    false,
+                ),
+            ],
+        }
+    }
+
+    /// What the model is asked to do with the gathered data.
+    pub fn instructions(self) -> &'static str {
+        match self {
+            LinearPrompt::TriageInbox => {
+                "Triage the issues below. For each one, suggest a workflow state, a priority, \
+                 an assignee and labels, using only the team's states and labels listed here, \
+                 and say in one line why. Flag likely duplicates and issues that need more \
+                 information instead of guessing. Once I confirm, apply the decisions with \
+                 `triage_issue`."
+            }
+            LinearPrompt::ProjectUpdate => {
+                "Write a project update for the project below: what was completed, what is in \
+                 progress, and any risks or blockers, in a few short paragraphs or bullets. \
+                 Suggest a health (onTrack, atRisk or offTrack) and explain it. Keep the tone \
+                 of the previous updates. Once I approve it, post it with \
+                 `create_project_update`."
+            }
+            LinearPrompt::SprintPlanning => {
+                "Plan the cycle below. Start from the unfinished issues of the current cycle, \
+                 then pick from the backlog by priority, keeping the total estimate in line \
+                 with what the team usually completes. List the proposed issues with their \
+                 estimates and owners, and call out anything unestimated or blocked. Once I \
+                 agree, add them with `add_issue_to_cycle`."
+            }
+            LinearPrompt::BreakDownIssue => {
+                "Break the issue below into sub-issues that can each be done and reviewed on \
+                 their own, usually three to eight. Give each a title, a short description \
+                 with acceptance criteria, and an estimate, and note the order they depend \
+                 on. Once I approve, create them with `create_issue`, setting `parent` to \
+                 this issue."
+            }
+            LinearPrompt::ReleaseNotes => {
+                "Write release notes from the completed issues below, for users rather than \
+                 engineers. Group them into new features, improvements and fixes (labels \
+                 help), leave out purely internal work, and mention issue identifiers in \
+                 parentheses."
+            }
+        }
+    }
+
+    pub fn to_prompt(self) -> Prompt {
+        let arguments = self
+            .arguments()
+            .iter()
+            .map(|(name, description, required)| PromptArgument {
+                name: name.to_string(),
+                title: None,
+                description: Some(description.to_string()),
+                required: Some(*required),
+            })
+            .collect();
+        Prompt::new(self.name(), Some(self.description()), Some(arguments))
+    }
+}
+
+/// The arguments of a `prompts/get` request.
+pub struct PromptArgs(pub JsonObject);
+
+impl PromptArgs {
+    /// A non-empty string argument.
+    pub fn get(&self, name: &str) -> Option<String> {
+        self.0
+            .get(name)
+            .and_then(|v| v.as_str())
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .map(String::from)
+    }
+
+    pub fn require(&self, name: &str) -> Result<String, Error> {
+        self.get(name)
+            .ok_or_else(|| Error::InvalidInput(format!("'{}' is required.", name)))
+    }
+}
+
+/// The instructions followed by each `(heading, content)` section.
+pub fn render(prompt: LinearPrompt, sections: &[(String, String)]) -> String {
+    let mut text = prompt.instructions().to_string();
+    for (heading, content) in sections {
+        text.push_str(&format!("\n\n## {}\n\n{}", heading, content.trim_end()));
+    }
+    text
+}
@@ -0,0 +1,95 @@
+use std::path::PathBuf;
+
+use serde_json::Value;
+
+use crate::error::Error;
+use crate::pipeline::{BoxFuture, Middleware, Next, Request};
+
+/// Placeholder for scrubbed secrets.
+const REDACTED: &str = "[redacted]";
+
+/// Middleware that saves each successful response to
+/// `<dir>/<OperationName>.json`, the fixture layout the offline tests'
+/// mock server serves. Secrets and email addresses are scrubbed first.
+/// A later response for the same operation overwrites the earlier one.
+pub struct Recorder {
+    dir: PathBuf,
+}
+
+impl Recorder {
+    pub fn new(dir: PathBuf) -> Result<Self, Error> {
+        std::fs::create_dir_all(&dir).map_err(|e| {
+            Error::Config(format!(
+                "Cannot create recording directory {}: {}",
+                dir.display(),
+                e
+            ))
+        })?;
+        Ok(Self { dir })
+    }
+
+    fn save(&self, request: &Request, body: &str) {
+        let Ok(mut value) = serde_json::from_str::<Value>(body) else {
+            return;
+        };
+        scrub(&mut value);
+        let path = self.dir.join(format!("{}.json", request.operation_name()));
+        let json = serde_json::to_string_pretty(&value).unwrap_or_default() + "\n";
+        match std::fs::write(&path, json) {
+            Ok(()) => tracing::debug!("Recorded {}", path.display()),
+            Err(e) => tracing::warn!("Cannot write {}: {}", path.display(), e),
+        }
+    }
+}
+
+impl Middleware for Recorder {
+    fn handle<'a>(
+        &'a self,
+        request: &'a Request,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<String, Error>> {
+        Box::pin(async move {
+            let body = next.run(request).await?;
+            self.save(request, &body);
+            Ok(body)
+        })
+    }
+}
+
+/// Replace secrets in a response: values of token/secret/password/key
+/// fields, strings that look like Linear API or OAuth tokens, and email
+/// addresses.
+pub fn scrub(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, v) in map.iter_mut() {
+                let key = key.to_ascii_lowercase();
+                if v.is_string() && is_secret_field(&key) {
+                    *v = Value::String(REDACTED.into());
+                } else if v.is_string() && key.contains("email") {
+                    *v = Value::String("user@example.com".into());
+                } else {
+                    scrub(v);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(scrub),
+        Value::String(s) if s.starts_with("lin_api_") || s.starts_with("lin_oauth_") => {
+            *s = REDACTED.into();
+        }
+        _ => {}
+    }
+}
+
+fn is_secret_field(key: &str) -> bool {
+    [
+        "token",
+        "secret",
+        "password",
+        "apikey",
+        "signingkey",
+        "privatekey",
+    ]
+    .iter()
+    .any(|k| key.contains(k))
+}
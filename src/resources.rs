@@ -0,0 +1,126 @@
+//! MCP resources: Linear entities addressed by `linear://` URIs, so clients
+//! can attach them to a conversation without a tool call.
+
+use rmcp::model::{AnnotateAble, RawResourceTemplate, ResourceTemplate};
+
+/// URI templates of the readable resources, with their names and descriptions.
+const TEMPLATES: &[(&str, &str, &str)] = &[
+    (
+        "linear://issue/{identifier}",
+        "issue",
+        "An issue by identifier (e.g. ENG-123) or UUID, with its description, comments and relations.",
+    ),
+    (
+        "linear://project/{name}",
+        "project",
+        "A project by name or UUID, with its status, milestones and progress.",
+    ),
+    (
+        "linear://document/{id}",
+        "document",
+        "A document by title or UUID, with its content.",
+    ),
+    (
+        "linear://cycle/{team}/current",
+        "current-cycle",
+        "A team's current cycle (also /next and /previous), with its issues and progress.",
+    ),
+];
+
+/// A parsed `linear://` resource URI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinearResource {
+    Issue(String),
+    Project(String),
+    Document(String),
+    /// A team's `current`, `next` or `previous` cycle.
+    Cycle {
+        team: String,
+        which: String,
+    },
+}
+
+impl LinearResource {
+    /// Parse a resource URI; path segments may be percent-encoded
+    /// (`linear://project/Mobile%20App`).
+    pub fn parse(uri: &str) -> Option<Self> {
+        let path = uri.strip_prefix("linear://")?;
+        let segments: Vec<String> = path
+            .trim_end_matches('/')
+            .split('/')
+            .map(percent_decode)
+            .collect::<Option<_>>()?;
+        if segments.iter().any(|s| s.trim().is_empty()) {
+            return None;
+        }
+        let [kind, rest @ ..] = segments.as_slice() else {
+            return None;
+        };
+        Some(match (kind.as_str(), rest) {
+            ("issue", [id]) => Self::Issue(id.clone()),
+            ("project", [name]) => Self::Project(name.clone()),
+            ("document", [id]) => Self::Document(id.clone()),
+            ("cycle", [team, which])
+                if matches!(which.as_str(), "current" | "next" | "previous") =>
+            {
+                Self::Cycle {
+                    team: team.clone(),
+                    which: which.clone(),
+                }
+            }
+            _ => return None,
+        })
+    }
+
+    /// The URI of a team's current cycle.
+    pub fn current_cycle_uri(team: &str) -> String {
+        format!("linear://cycle/{}/current", percent_encode(team))
+    }
+}
+
+pub fn templates() -> Vec<ResourceTemplate> {
+    TEMPLATES
+        .iter()
+        .map(|(uri_template, name, description)| {
+            RawResourceTemplate {
+                uri_template: uri_template.to_string(),
+                name: name.to_string(),
+                title: None,
+                description: Some(description.to_string()),
+                mime_type: Some("text/markdown".into()),
+                icons: None,
+            }
+            .no_annotation()
+        })
+        .collect()
+}
+
+/// Decode `%XX` escapes; `None` for malformed ones or invalid UTF-8.
+fn percent_decode(s: &str) -> Option<String> {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = std::str::from_utf8(bytes.get(i + 1..i + 3)?).ok()?;
+            out.push(u8::from_str_radix(hex, 16).ok()?);
+            i += 3;
+        } else {
+            out.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8(out).ok()
+}
+
+fn percent_encode(s: &str) -> String {
+    s.bytes()
+        .map(|b| {
+            if b.is_ascii_alphanumeric() || b"-_.~".contains(&b) {
+                (b as char).to_string()
+            } else {
+                format!("%{:02X}", b)
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,221 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use reqwest::header::HeaderMap;
+
+use crate::error::Error;
+use crate::graphql::queries;
+use crate::pipeline::{BoxFuture, Middleware, Next, Request};
+
+/// When and how often `LinearClient` retries failed requests.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt (0 disables retrying).
+    pub max_retries: u32,
+    /// Backoff before the first retry; doubled for each further one.
+    pub base_delay: Duration,
+    /// Longest single wait, including waits for a rate-limit reset. A reset
+    /// further away than this fails the request instead of stalling it.
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Exponential backoff with jitter for retry number `attempt` (0-based):
+    /// a random wait between half and all of `base_delay * 2^attempt`.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exp = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_delay);
+        let half = exp / 2;
+        half + half.mul_f64(rand::random::<f64>())
+    }
+}
+
+/// Why a failed attempt may be retried.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetryReason {
+    /// HTTP 429 or a `RATELIMITED` GraphQL error. Linear rejects these
+    /// before executing anything, so even mutations can be resent.
+    RateLimited,
+    /// 5xx, timeout, or dropped connection. The request may have run, so
+    /// only idempotent operations are resent.
+    Transient,
+}
+
+/// Middleware that resends failed requests according to a `RetryPolicy`.
+///
+/// Rate-limited requests are retried after the reset the headers announce
+/// (or with backoff); 5xx replies, timeouts, and dropped connections are
+/// retried with backoff if the operation is idempotent.
+pub struct Retry {
+    policy: RetryPolicy,
+}
+
+impl Retry {
+    pub fn new(policy: RetryPolicy) -> Self {
+        Self { policy }
+    }
+
+    async fn run<'a>(&'a self, request: &'a Request, next: Next<'a>) -> Result<String, Error> {
+        let mut attempt = 0;
+        loop {
+            let err = match next.run(request).await {
+                Ok(body) => return Ok(body),
+                Err(err) => err,
+            };
+            let reason = match err {
+                Error::RateLimited { .. } => RetryReason::RateLimited,
+                Error::Server(_) => RetryReason::Transient,
+                Error::Http(ref e) if e.is_timeout() || e.is_connect() || e.is_request() => {
+                    RetryReason::Transient
+                }
+                _ => return Err(err),
+            };
+
+            if attempt >= self.policy.max_retries
+                || (reason == RetryReason::Transient && !is_idempotent(request))
+            {
+                return Err(err);
+            }
+            let delay = match reason {
+                RetryReason::RateLimited => {
+                    match next.client().rate_limit_budget().wait_for_reset() {
+                        Some(wait) if wait > self.policy.max_delay => return Err(err),
+                        Some(wait) => wait,
+                        None => self.policy.backoff(attempt),
+                    }
+                }
+                RetryReason::Transient => self.policy.backoff(attempt),
+            };
+            attempt += 1;
+            tracing::warn!(
+                "Linear request failed ({}); retry {}/{} in {:?}",
+                err,
+                attempt,
+                self.policy.max_retries,
+                delay
+            );
+            tokio::time::sleep(delay).await;
+        }
+    }
+}
+
+impl Middleware for Retry {
+    fn handle<'a>(
+        &'a self,
+        request: &'a Request,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<String, Error>> {
+        Box::pin(self.run(request, next))
+    }
+}
+
+/// Whether `request` can be resent after a transient failure: every query,
+/// plus the mutations listed in `queries::RETRY_SAFE_MUTATIONS`.
+pub fn is_idempotent(request: &Request) -> bool {
+    !request.is_mutation() || queries::RETRY_SAFE_MUTATIONS.contains(&request.operation_name())
+}
+
+/// Whether a response body carries a `RATELIMITED` GraphQL error.
+pub fn is_rate_limited_body(body: &str) -> bool {
+    // Cheap pre-check; rate-limit errors are rare.
+    if !body.contains("RATELIMITED") {
+        return false;
+    }
+    serde_json::from_str::<serde_json::Value>(body).is_ok_and(|v| {
+        v["errors"].as_array().is_some_and(|errors| {
+            errors
+                .iter()
+                .any(|e| e["extensions"]["code"] == "RATELIMITED")
+        })
+    })
+}
+
+/// Rate-limit budget reported in Linear's `X-RateLimit-*` response headers.
+#[derive(Debug, Clone, Default)]
+pub struct RateLimitBudget {
+    pub requests_limit: Option<u64>,
+    pub requests_remaining: Option<u64>,
+    /// UTC epoch milliseconds.
+    pub requests_reset: Option<u64>,
+    pub complexity_limit: Option<u64>,
+    pub complexity_remaining: Option<u64>,
+    /// UTC epoch milliseconds.
+    pub complexity_reset: Option<u64>,
+    /// Complexity of the last request (`X-Complexity`).
+    pub last_complexity: Option<u64>,
+    /// `Retry-After` of the last rate-limited response, in seconds.
+    pub retry_after: Option<u64>,
+}
+
+impl RateLimitBudget {
+    /// Update from a response's headers. Absent headers keep their old value.
+    pub fn record(&mut self, headers: &HeaderMap) {
+        let get = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.trim().parse::<u64>().ok())
+        };
+        let fields = [
+            ("x-ratelimit-requests-limit", &mut self.requests_limit),
+            (
+                "x-ratelimit-requests-remaining",
+                &mut self.requests_remaining,
+            ),
+            ("x-ratelimit-requests-reset", &mut self.requests_reset),
+            ("x-ratelimit-complexity-limit", &mut self.complexity_limit),
+            (
+                "x-ratelimit-complexity-remaining",
+                &mut self.complexity_remaining,
+            ),
+            ("x-ratelimit-complexity-reset", &mut self.complexity_reset),
+            ("x-complexity", &mut self.last_complexity),
+        ];
+        for (name, field) in fields {
+            if let Some(value) = get(name) {
+                *field = Some(value);
+            }
+        }
+        self.retry_after = get("retry-after");
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.requests_limit.is_none() && self.complexity_limit.is_none()
+    }
+
+    /// How long until the exhausted budget resets, if the headers say so.
+    pub fn wait_for_reset(&self) -> Option<Duration> {
+        if let Some(secs) = self.retry_after {
+            return Some(Duration::from_secs(secs));
+        }
+        let exhausted = |remaining: Option<u64>, reset: Option<u64>| match (remaining, reset) {
+            (Some(0), Some(reset)) => Some(reset),
+            _ => None,
+        };
+        let reset_ms = exhausted(self.requests_remaining, self.requests_reset)
+            .into_iter()
+            .chain(exhausted(self.complexity_remaining, self.complexity_reset))
+            .max()?;
+        Some(Duration::from_millis(
+            reset_ms.saturating_sub(unix_millis()),
+        ))
+    }
+}
+
+fn unix_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
@@ -1,42 +1,156 @@
-use std::collections::BTreeMap;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, OnceLock, Weak};
+use std::time::Duration;
 
 use rmcp::{
-    handler::server::{tool::ToolRouter, wrapper::Parameters},
+    ErrorData as McpError, Peer, RoleServer, ServerHandler,
+    handler::server::{
+        tool::{ToolCallContext, ToolRouter},
+        wrapper::Parameters,
+    },
     model::*,
-    tool, tool_handler, tool_router,
-    ErrorData as McpError, ServerHandler,
+    service::RequestContext,
+    tool, tool_router,
 };
 
-use crate::cache::EntityCache;
+use crate::cache::EntityKind;
 use crate::client::LinearClient;
+use crate::completion::{self, Catalog};
+use crate::config::OutputFormat;
+use crate::dates::{self, Bound, CycleRef, DateExpr, Dates};
 use crate::error::Error;
 use crate::format;
 use crate::graphql::{filters, queries, response};
+use crate::identifier::{Identifier, is_uuid};
+use crate::issue_query::{self, DateRange};
+use crate::matching::{self, Candidate, Match, edit_distance};
+use crate::output::{self, Output};
+use crate::prompts::{self, LinearPrompt, PromptArgs};
+use crate::resources::{self, LinearResource};
+use crate::subscriptions::{self, Subscriptions};
+use crate::tenant::{self, Tenant, TenantRegistry};
+use crate::tool_filter::{self, ToolFilter};
 use crate::tools::*;
 use crate::types;
 
 #[derive(Clone)]
 pub struct LinearMcp {
     client: LinearClient,
-    issue_id_cache: EntityCache<String>,
-    tool_router: ToolRouter<Self>,
+    tool_router: Arc<ToolRouter<Self>>,
+    /// Per-caller credentials for shared (HTTP) deployments. When unset,
+    /// every request uses `client`.
+    tenants: Option<TenantRegistry>,
+    /// On a server bound to a caller by `for_request`, the caller's tenant.
+    tenant_key: Option<tenant::TokenKey>,
+    /// Token supplied in this session's `initialize` metadata, if any.
+    session_token: Arc<OnceLock<String>>,
+    /// Team key used by team-scoped tools when the caller omits `team`.
+    default_team: Option<String>,
+    output_format: OutputFormat,
+    /// Interprets relative date arguments in the profile's timezone.
+    dates: Dates,
+    /// Resources this session subscribed to.
+    subscriptions: Arc<Subscriptions>,
+    poll_interval: Duration,
 }
 
 // ---- Tool registration ----
 
 #[tool_router]
 impl LinearMcp {
+    /// Create the server. A read-only client only gets the tools annotated
+    /// `read_only_hint = true`.
     pub fn new(client: LinearClient) -> Self {
+        let mut tool_router = Self::tool_router();
+        if client.is_read_only() {
+            tool_router.map.retain(|_, route| {
+                route
+                    .attr
+                    .annotations
+                    .as_ref()
+                    .and_then(|a| a.read_only_hint)
+                    .unwrap_or(false)
+            });
+        }
         Self {
             client,
-            issue_id_cache: EntityCache::new(),
-            tool_router: Self::tool_router(),
+            tool_router: Arc::new(tool_router),
+            tenants: None,
+            tenant_key: None,
+            session_token: Arc::default(),
+            default_team: None,
+            output_format: OutputFormat::default(),
+            dates: Dates::default(),
+            subscriptions: Arc::default(),
+            poll_interval: subscriptions::DEFAULT_POLL_INTERVAL,
+        }
+    }
+
+    pub fn with_default_team(mut self, team: Option<String>) -> Self {
+        self.default_team = team;
+        self
+    }
+
+    pub fn with_output_format(mut self, format: OutputFormat) -> Self {
+        self.output_format = format;
+        self
+    }
+
+    pub fn with_dates(mut self, dates: Dates) -> Self {
+        self.dates = dates;
+        self
+    }
+
+    /// How often subscribed resources are checked for changes.
+    pub fn with_poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Only expose the tools `filter` allows.
+    pub fn with_tool_filter(mut self, filter: &ToolFilter) -> Self {
+        if filter.is_empty() {
+            return self;
+        }
+        let router = Arc::make_mut(&mut self.tool_router);
+        let names: Vec<&str> = router.map.keys().map(|k| k.as_ref()).collect();
+        for pattern in filter.unmatched(&names) {
+            tracing::warn!(
+                "Tool pattern '{}' matches no available tool (profiles: {})",
+                pattern,
+                tool_filter::profile_names().collect::<Vec<_>>().join(", ")
+            );
+        }
+        router.map.retain(|name, _| filter.allows(name));
+        tracing::info!("Exposing {} tools", router.map.len());
+        self
+    }
+
+    /// Let callers supply their own Linear token per request or session.
+    /// Their clients inherit this server's client settings.
+    pub fn with_tenants(mut self, require_session_key: bool) -> Self {
+        self.tenants = Some(TenantRegistry::new(
+            self.client.clone(),
+            require_session_key,
+        ));
+        self
+    }
+
+    /// A copy of this server for a new MCP session, sharing clients and
+    /// caches but with no session token or subscriptions yet.
+    pub fn new_session(&self) -> Self {
+        Self {
+            session_token: Arc::default(),
+            subscriptions: Arc::default(),
+            ..self.clone()
         }
     }
 
     #[tool(
         name = "list_issues",
-        description = "List Linear issues with flexible filtering. Filter by team, assignee, status, project, label, and priority.",
+        description = "List Linear issues with flexible filtering. Filter by team, assignee, status, project, label, cycle, milestone, parent, priority, and more.",
+        output_schema = output::schema::<types::Connection<types::Issue>>(),
         annotations(read_only_hint = true)
     )]
     async fn list_issues(
@@ -44,7 +158,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_issues::ListIssuesParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_issues(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -52,6 +166,7 @@ impl LinearMcp {
     #[tool(
         name = "search_issues",
         description = "Full-text search across Linear issues. Searches titles, descriptions, and comments.",
+        output_schema = output::schema::<types::Connection<types::Issue>>(),
         annotations(read_only_hint = true)
     )]
     async fn search_issues(
@@ -59,7 +174,7 @@ impl LinearMcp {
         Parameters(params): Parameters<search_issues::SearchIssuesParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_search_issues(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -67,6 +182,7 @@ impl LinearMcp {
     #[tool(
         name = "get_issue",
         description = "Get full details of a single Linear issue by identifier (e.g. 'ENG-123'). Includes description, comments, labels, and relations.",
+        output_schema = output::schema::<types::Issue>(),
         annotations(read_only_hint = true)
     )]
     async fn get_issue(
@@ -74,7 +190,7 @@ impl LinearMcp {
         Parameters(params): Parameters<get_issue::GetIssueParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_issue(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -82,6 +198,7 @@ impl LinearMcp {
     #[tool(
         name = "list_teams",
         description = "List all teams in the Linear workspace with their keys and names. Optionally include member counts.",
+        output_schema = output::schema::<types::NodeList<response::TeamWithMembers>>(),
         annotations(read_only_hint = true)
     )]
     async fn list_teams(
@@ -89,7 +206,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_teams::ListTeamsParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_teams(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -97,6 +214,7 @@ impl LinearMcp {
     #[tool(
         name = "list_projects",
         description = "List all projects in the Linear workspace with status and progress.",
+        output_schema = output::schema::<types::NodeList<types::Project>>(),
         annotations(read_only_hint = true)
     )]
     async fn list_projects(
@@ -104,7 +222,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_projects::ListProjectsParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_projects(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -112,6 +230,7 @@ impl LinearMcp {
     #[tool(
         name = "list_users",
         description = "List all members of the Linear workspace.",
+        output_schema = output::schema::<types::NodeList<types::User>>(),
         annotations(read_only_hint = true)
     )]
     async fn list_users(
@@ -119,7 +238,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_users::ListUsersParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_users(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -127,6 +246,7 @@ impl LinearMcp {
     #[tool(
         name = "list_states",
         description = "List workflow states (statuses) available in the workspace. Optionally filter by team.",
+        output_schema = output::schema::<types::NodeList<response::WorkflowStateWithTeam>>(),
         annotations(read_only_hint = true)
     )]
     async fn list_states(
@@ -134,7 +254,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_states::ListStatesParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_states(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -142,6 +262,7 @@ impl LinearMcp {
     #[tool(
         name = "my_issues",
         description = "Get issues assigned to the authenticated user, grouped by status. A quick overview of your current workload.",
+        output_schema = output::schema::<types::Connection<types::Issue>>(),
         annotations(read_only_hint = true)
     )]
     async fn my_issues(
@@ -149,7 +270,7 @@ impl LinearMcp {
         Parameters(params): Parameters<my_issues::MyIssuesParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_my_issues(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -157,6 +278,7 @@ impl LinearMcp {
     #[tool(
         name = "create_issue",
         description = "Create a new Linear issue. Accepts human-friendly inputs (team key, assignee email, state name) and resolves them automatically.",
+        output_schema = output::schema::<types::Issue>(),
         annotations(destructive_hint = false)
     )]
     async fn create_issue(
@@ -164,7 +286,7 @@ impl LinearMcp {
         Parameters(params): Parameters<create_issue::CreateIssueParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_create_issue(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -172,6 +294,7 @@ impl LinearMcp {
     #[tool(
         name = "update_issue",
         description = "Update an existing Linear issue. Accepts human-friendly inputs (state name, assignee email) and resolves them automatically.",
+        output_schema = output::schema::<types::Issue>(),
         annotations(destructive_hint = false)
     )]
     async fn update_issue(
@@ -179,7 +302,7 @@ impl LinearMcp {
         Parameters(params): Parameters<update_issue::UpdateIssueParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_update_issue(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -187,6 +310,7 @@ impl LinearMcp {
     #[tool(
         name = "add_comment",
         description = "Add a comment to a Linear issue. Supports markdown and threaded replies via parentId.",
+        output_schema = output::schema::<types::CommentMutationResult>(),
         annotations(destructive_hint = false)
     )]
     async fn add_comment(
@@ -194,7 +318,7 @@ impl LinearMcp {
         Parameters(params): Parameters<add_comment::AddCommentParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_add_comment(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -202,6 +326,7 @@ impl LinearMcp {
     #[tool(
         name = "update_comment",
         description = "Update an existing comment on a Linear issue.",
+        output_schema = output::schema::<types::CommentMutationResult>(),
         annotations(destructive_hint = false)
     )]
     async fn update_comment(
@@ -209,7 +334,7 @@ impl LinearMcp {
         Parameters(params): Parameters<update_comment::UpdateCommentParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_update_comment(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -217,6 +342,7 @@ impl LinearMcp {
     #[tool(
         name = "delete_comment",
         description = "Delete a comment from a Linear issue.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = true)
     )]
     async fn delete_comment(
@@ -224,7 +350,7 @@ impl LinearMcp {
         Parameters(params): Parameters<delete_comment::DeleteCommentParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_delete_comment(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -232,6 +358,7 @@ impl LinearMcp {
     #[tool(
         name = "list_cycles",
         description = "List cycles for a team. Returns cycle names, dates, and progress.",
+        output_schema = output::schema::<response::TeamCycles>(),
         annotations(read_only_hint = true)
     )]
     async fn list_cycles(
@@ -239,7 +366,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_cycles::ListCyclesParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_cycles(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -247,6 +374,7 @@ impl LinearMcp {
     #[tool(
         name = "get_cycle",
         description = "Get full details of a single cycle by UUID.",
+        output_schema = output::schema::<types::Cycle>(),
         annotations(read_only_hint = true)
     )]
     async fn get_cycle(
@@ -254,7 +382,7 @@ impl LinearMcp {
         Parameters(params): Parameters<get_cycle::GetCycleParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_cycle(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -262,6 +390,7 @@ impl LinearMcp {
     #[tool(
         name = "add_issue_to_cycle",
         description = "Add an issue to a cycle by setting its cycleId.",
+        output_schema = output::schema::<types::Issue>(),
         annotations(destructive_hint = false)
     )]
     async fn add_issue_to_cycle(
@@ -269,7 +398,7 @@ impl LinearMcp {
         Parameters(params): Parameters<add_issue_to_cycle::AddIssueToCycleParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_add_issue_to_cycle(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -277,6 +406,7 @@ impl LinearMcp {
     #[tool(
         name = "remove_issue_from_cycle",
         description = "Remove an issue from its current cycle.",
+        output_schema = output::schema::<types::Issue>(),
         annotations(destructive_hint = true)
     )]
     async fn remove_issue_from_cycle(
@@ -284,7 +414,7 @@ impl LinearMcp {
         Parameters(params): Parameters<remove_issue_from_cycle::RemoveIssueFromCycleParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_remove_issue_from_cycle(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -292,6 +422,7 @@ impl LinearMcp {
     #[tool(
         name = "list_labels",
         description = "List issue labels in the workspace. Optionally filter by team.",
+        output_schema = output::schema::<types::NodeList<types::Label>>(),
         annotations(read_only_hint = true)
     )]
     async fn list_labels(
@@ -299,7 +430,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_labels::ListLabelsParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_labels(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -307,6 +438,7 @@ impl LinearMcp {
     #[tool(
         name = "create_label",
         description = "Create a new issue label. Optionally scope to a team and set a color.",
+        output_schema = output::schema::<types::Label>(),
         annotations(destructive_hint = false)
     )]
     async fn create_label(
@@ -314,7 +446,7 @@ impl LinearMcp {
         Parameters(params): Parameters<create_label::CreateLabelParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_create_label(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -322,6 +454,7 @@ impl LinearMcp {
     #[tool(
         name = "create_issue_relation",
         description = "Create a relation between two issues (blocks, blocked_by, related, duplicate).",
+        output_schema = output::schema::<types::IssueRelation>(),
         annotations(destructive_hint = false)
     )]
     async fn create_issue_relation(
@@ -329,7 +462,7 @@ impl LinearMcp {
         Parameters(params): Parameters<create_issue_relation::CreateIssueRelationParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_create_issue_relation(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -337,6 +470,7 @@ impl LinearMcp {
     #[tool(
         name = "delete_issue_relation",
         description = "Delete an issue relation by its UUID.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = true)
     )]
     async fn delete_issue_relation(
@@ -344,7 +478,7 @@ impl LinearMcp {
         Parameters(params): Parameters<delete_issue_relation::DeleteIssueRelationParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_delete_issue_relation(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -352,6 +486,7 @@ impl LinearMcp {
     #[tool(
         name = "archive_issue",
         description = "Archive a Linear issue.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = true)
     )]
     async fn archive_issue(
@@ -359,7 +494,7 @@ impl LinearMcp {
         Parameters(params): Parameters<archive_issue::ArchiveIssueParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_archive_issue(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -369,6 +504,7 @@ impl LinearMcp {
     #[tool(
         name = "list_documents",
         description = "List all documents in the workspace with project and creator info.",
+        output_schema = output::schema::<types::NodeList<types::Document>>(),
         annotations(read_only_hint = true)
     )]
     async fn list_documents(
@@ -376,7 +512,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_documents::ListDocumentsParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_documents(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -384,6 +520,7 @@ impl LinearMcp {
     #[tool(
         name = "get_document",
         description = "Get full details of a document including its content.",
+        output_schema = output::schema::<types::Document>(),
         annotations(read_only_hint = true)
     )]
     async fn get_document(
@@ -391,7 +528,7 @@ impl LinearMcp {
         Parameters(params): Parameters<get_document::GetDocumentParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_document(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -399,6 +536,7 @@ impl LinearMcp {
     #[tool(
         name = "create_document",
         description = "Create a new document. Optionally associate it with a project.",
+        output_schema = output::schema::<types::Document>(),
         annotations(destructive_hint = false)
     )]
     async fn create_document(
@@ -406,7 +544,7 @@ impl LinearMcp {
         Parameters(params): Parameters<create_document::CreateDocumentParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_create_document(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -416,6 +554,7 @@ impl LinearMcp {
     #[tool(
         name = "get_project",
         description = "Get full details of a project including teams, members, lead, and dates.",
+        output_schema = output::schema::<types::ProjectDetail>(),
         annotations(read_only_hint = true)
     )]
     async fn get_project(
@@ -423,7 +562,7 @@ impl LinearMcp {
         Parameters(params): Parameters<get_project::GetProjectParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_project(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -431,6 +570,7 @@ impl LinearMcp {
     #[tool(
         name = "create_project",
         description = "Create a new project with team associations, lead, and dates.",
+        output_schema = output::schema::<types::ProjectDetail>(),
         annotations(destructive_hint = false)
     )]
     async fn create_project(
@@ -438,7 +578,7 @@ impl LinearMcp {
         Parameters(params): Parameters<create_project::CreateProjectParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_create_project(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -446,6 +586,7 @@ impl LinearMcp {
     #[tool(
         name = "update_project",
         description = "Update an existing project's name, description, state, lead, or dates.",
+        output_schema = output::schema::<types::ProjectDetail>(),
         annotations(destructive_hint = false)
     )]
     async fn update_project(
@@ -453,7 +594,7 @@ impl LinearMcp {
         Parameters(params): Parameters<update_project::UpdateProjectParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_update_project(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -463,6 +604,7 @@ impl LinearMcp {
     #[tool(
         name = "list_project_updates",
         description = "List status updates for a project.",
+        output_schema = output::schema::<response::ProjectWithUpdates>(),
         annotations(read_only_hint = true)
     )]
     async fn list_project_updates(
@@ -470,7 +612,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_project_updates::ListProjectUpdatesParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_project_updates(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -478,6 +620,7 @@ impl LinearMcp {
     #[tool(
         name = "create_project_update",
         description = "Post a status update to a project with optional health indicator.",
+        output_schema = output::schema::<types::ProjectUpdate>(),
         annotations(destructive_hint = false)
     )]
     async fn create_project_update(
@@ -485,7 +628,7 @@ impl LinearMcp {
         Parameters(params): Parameters<create_project_update::CreateProjectUpdateParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_create_project_update(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -495,6 +638,7 @@ impl LinearMcp {
     #[tool(
         name = "list_project_milestones",
         description = "List milestones for a project.",
+        output_schema = output::schema::<response::ProjectWithMilestones>(),
         annotations(read_only_hint = true)
     )]
     async fn list_project_milestones(
@@ -502,7 +646,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_project_milestones::ListProjectMilestonesParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_project_milestones(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -510,6 +654,7 @@ impl LinearMcp {
     #[tool(
         name = "create_project_milestone",
         description = "Create a milestone for a project with name, description, and target date.",
+        output_schema = output::schema::<types::ProjectMilestone>(),
         annotations(destructive_hint = false)
     )]
     async fn create_project_milestone(
@@ -517,7 +662,7 @@ impl LinearMcp {
         Parameters(params): Parameters<create_project_milestone::CreateProjectMilestoneParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_create_project_milestone(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -527,6 +672,7 @@ impl LinearMcp {
     #[tool(
         name = "list_initiatives",
         description = "List all initiatives in the workspace.",
+        output_schema = output::schema::<types::NodeList<types::Initiative>>(),
         annotations(read_only_hint = true)
     )]
     async fn list_initiatives(
@@ -534,7 +680,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_initiatives::ListInitiativesParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_initiatives(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -544,6 +690,7 @@ impl LinearMcp {
     #[tool(
         name = "list_notifications",
         description = "List inbox notifications for the authenticated user.",
+        output_schema = output::schema::<types::NodeList<types::Notification>>(),
         annotations(read_only_hint = true)
     )]
     async fn list_notifications(
@@ -551,7 +698,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_notifications::ListNotificationsParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_notifications(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -559,6 +706,7 @@ impl LinearMcp {
     #[tool(
         name = "mark_notification_read",
         description = "Mark a notification as read.",
+        output_schema = output::schema::<types::NotificationMutationResult>(),
         annotations(destructive_hint = false)
     )]
     async fn mark_notification_read(
@@ -566,7 +714,7 @@ impl LinearMcp {
         Parameters(params): Parameters<mark_notification_read::MarkNotificationReadParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_mark_notification_read(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -576,6 +724,7 @@ impl LinearMcp {
     #[tool(
         name = "list_views",
         description = "List custom saved views in the workspace.",
+        output_schema = output::schema::<types::NodeList<types::CustomView>>(),
         annotations(read_only_hint = true)
     )]
     async fn list_views(
@@ -583,7 +732,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_views::ListViewsParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_views(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -593,6 +742,7 @@ impl LinearMcp {
     #[tool(
         name = "list_attachments",
         description = "List attachments on an issue.",
+        output_schema = output::schema::<response::IssueWithAttachments>(),
         annotations(read_only_hint = true)
     )]
     async fn list_attachments(
@@ -600,7 +750,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_attachments::ListAttachmentsParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_attachments(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -608,6 +758,7 @@ impl LinearMcp {
     #[tool(
         name = "add_attachment",
         description = "Add a URL attachment to an issue.",
+        output_schema = output::schema::<types::Attachment>(),
         annotations(destructive_hint = false)
     )]
     async fn add_attachment(
@@ -615,7 +766,7 @@ impl LinearMcp {
         Parameters(params): Parameters<add_attachment::AddAttachmentParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_add_attachment(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -625,6 +776,7 @@ impl LinearMcp {
     #[tool(
         name = "add_reaction",
         description = "Add an emoji reaction to a comment.",
+        output_schema = output::schema::<types::Reaction>(),
         annotations(destructive_hint = false)
     )]
     async fn add_reaction(
@@ -632,7 +784,7 @@ impl LinearMcp {
         Parameters(params): Parameters<add_reaction::AddReactionParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_add_reaction(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -640,6 +792,7 @@ impl LinearMcp {
     #[tool(
         name = "remove_reaction",
         description = "Remove an emoji reaction by its UUID.",
+        output_schema = output::schema::<types::DeleteMutationResult>(),
         annotations(destructive_hint = true)
     )]
     async fn remove_reaction(
@@ -647,7 +800,7 @@ impl LinearMcp {
         Parameters(params): Parameters<remove_reaction::RemoveReactionParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_remove_reaction(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -657,6 +810,7 @@ impl LinearMcp {
     #[tool(
         name = "list_favorites",
         description = "List the authenticated user's favorites.",
+        output_schema = output::schema::<types::NodeList<types::Favorite>>(),
         annotations(read_only_hint = true)
     )]
     async fn list_favorites(
@@ -664,7 +818,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_favorites::ListFavoritesParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_favorites(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -672,6 +826,7 @@ impl LinearMcp {
     #[tool(
         name = "add_favorite",
         description = "Favorite an issue or project.",
+        output_schema = output::schema::<types::Favorite>(),
         annotations(destructive_hint = false)
     )]
     async fn add_favorite(
@@ -679,7 +834,7 @@ impl LinearMcp {
         Parameters(params): Parameters<add_favorite::AddFavoriteParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_add_favorite(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -687,6 +842,7 @@ impl LinearMcp {
     #[tool(
         name = "remove_favorite",
         description = "Remove a favorite by its UUID.",
+        output_schema = output::schema::<types::DeleteMutationResult>(),
         annotations(destructive_hint = true)
     )]
     async fn remove_favorite(
@@ -694,7 +850,7 @@ impl LinearMcp {
         Parameters(params): Parameters<remove_favorite::RemoveFavoriteParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_remove_favorite(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -704,6 +860,7 @@ impl LinearMcp {
     #[tool(
         name = "list_templates",
         description = "List available issue templates in the workspace.",
+        output_schema = output::schema::<types::NodeList<types::Template>>(),
         annotations(read_only_hint = true)
     )]
     async fn list_templates(
@@ -711,7 +868,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_templates::ListTemplatesParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_templates(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -721,6 +878,7 @@ impl LinearMcp {
     #[tool(
         name = "get_issue_history",
         description = "Get the audit trail of changes for an issue.",
+        output_schema = output::schema::<response::IssueWithHistory>(),
         annotations(read_only_hint = true)
     )]
     async fn get_issue_history(
@@ -728,7 +886,7 @@ impl LinearMcp {
         Parameters(params): Parameters<get_issue_history::GetIssueHistoryParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_issue_history(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -738,6 +896,7 @@ impl LinearMcp {
     #[tool(
         name = "list_webhooks",
         description = "List active webhooks in the workspace.",
+        output_schema = output::schema::<types::NodeList<types::Webhook>>(),
         annotations(read_only_hint = true)
     )]
     async fn list_webhooks(
@@ -745,7 +904,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_webhooks::ListWebhooksParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_webhooks(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -753,6 +912,7 @@ impl LinearMcp {
     #[tool(
         name = "create_webhook",
         description = "Create a webhook endpoint for receiving Linear events.",
+        output_schema = output::schema::<types::Webhook>(),
         annotations(destructive_hint = false, open_world_hint = true)
     )]
     async fn create_webhook(
@@ -760,7 +920,7 @@ impl LinearMcp {
         Parameters(params): Parameters<create_webhook::CreateWebhookParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_create_webhook(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -768,6 +928,7 @@ impl LinearMcp {
     #[tool(
         name = "delete_webhook",
         description = "Delete a webhook by its UUID.",
+        output_schema = output::schema::<types::DeleteMutationResult>(),
         annotations(destructive_hint = true)
     )]
     async fn delete_webhook(
@@ -775,7 +936,7 @@ impl LinearMcp {
         Parameters(params): Parameters<delete_webhook::DeleteWebhookParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_delete_webhook(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -785,6 +946,7 @@ impl LinearMcp {
     #[tool(
         name = "list_integrations",
         description = "List active integrations in the workspace.",
+        output_schema = output::schema::<types::NodeList<types::Integration>>(),
         annotations(read_only_hint = true)
     )]
     async fn list_integrations(
@@ -792,7 +954,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_integrations::ListIntegrationsParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_integrations(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -802,6 +964,7 @@ impl LinearMcp {
     #[tool(
         name = "query_audit_log",
         description = "Query audit log entries for the workspace.",
+        output_schema = output::schema::<types::NodeList<types::AuditEntry>>(),
         annotations(read_only_hint = true)
     )]
     async fn query_audit_log(
@@ -809,7 +972,7 @@ impl LinearMcp {
         Parameters(params): Parameters<query_audit_log::QueryAuditLogParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_query_audit_log(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -819,6 +982,7 @@ impl LinearMcp {
     #[tool(
         name = "create_team",
         description = "Create a new team in the workspace.",
+        output_schema = output::schema::<types::TeamDetail>(),
         annotations(destructive_hint = false)
     )]
     async fn create_team(
@@ -826,7 +990,7 @@ impl LinearMcp {
         Parameters(params): Parameters<create_team::CreateTeamParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_create_team(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -834,6 +998,7 @@ impl LinearMcp {
     #[tool(
         name = "update_team",
         description = "Update an existing team's name, description, or timezone.",
+        output_schema = output::schema::<types::TeamDetail>(),
         annotations(destructive_hint = false)
     )]
     async fn update_team(
@@ -841,7 +1006,7 @@ impl LinearMcp {
         Parameters(params): Parameters<update_team::UpdateTeamParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_update_team(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -851,6 +1016,7 @@ impl LinearMcp {
     #[tool(
         name = "archive_project",
         description = "Archive a project by name or UUID.",
+        output_schema = output::schema::<types::DeleteMutationResult>(),
         annotations(destructive_hint = true)
     )]
     async fn archive_project(
@@ -858,7 +1024,7 @@ impl LinearMcp {
         Parameters(params): Parameters<archive_project::ArchiveProjectParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_archive_project(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -866,6 +1032,7 @@ impl LinearMcp {
     #[tool(
         name = "update_document",
         description = "Update an existing document's title or content.",
+        output_schema = output::schema::<types::Document>(),
         annotations(destructive_hint = false)
     )]
     async fn update_document(
@@ -873,7 +1040,7 @@ impl LinearMcp {
         Parameters(params): Parameters<update_document::UpdateDocumentParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_update_document(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -881,6 +1048,7 @@ impl LinearMcp {
     #[tool(
         name = "create_cycle",
         description = "Create a new cycle for a team with start and end dates.",
+        output_schema = output::schema::<types::CycleDetail>(),
         annotations(destructive_hint = false)
     )]
     async fn create_cycle(
@@ -888,7 +1056,7 @@ impl LinearMcp {
         Parameters(params): Parameters<create_cycle::CreateCycleParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_create_cycle(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -896,6 +1064,7 @@ impl LinearMcp {
     #[tool(
         name = "update_label",
         description = "Update a label's name or color.",
+        output_schema = output::schema::<types::Label>(),
         annotations(destructive_hint = false)
     )]
     async fn update_label(
@@ -903,7 +1072,7 @@ impl LinearMcp {
         Parameters(params): Parameters<update_label::UpdateLabelParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_update_label(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -911,6 +1080,7 @@ impl LinearMcp {
     #[tool(
         name = "archive_label",
         description = "Archive (delete) a label by its UUID.",
+        output_schema = output::schema::<types::DeleteMutationResult>(),
         annotations(destructive_hint = true)
     )]
     async fn archive_label(
@@ -918,7 +1088,7 @@ impl LinearMcp {
         Parameters(params): Parameters<archive_label::ArchiveLabelParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_archive_label(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -926,6 +1096,7 @@ impl LinearMcp {
     #[tool(
         name = "unarchive_issue",
         description = "Restore an archived issue.",
+        output_schema = output::schema::<types::DeleteMutationResult>(),
         annotations(destructive_hint = false)
     )]
     async fn unarchive_issue(
@@ -933,7 +1104,7 @@ impl LinearMcp {
         Parameters(params): Parameters<unarchive_issue::UnarchiveIssueParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_unarchive_issue(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -943,6 +1114,7 @@ impl LinearMcp {
     #[tool(
         name = "bulk_update_issues",
         description = "Batch update multiple issues at once. All specified issues get the same update. Max 50 issues per call.",
+        output_schema = output::schema::<types::BatchUpdateResult>(),
         annotations(destructive_hint = true)
     )]
     async fn bulk_update_issues(
@@ -950,7 +1122,7 @@ impl LinearMcp {
         Parameters(params): Parameters<bulk_update_issues::BulkUpdateIssuesParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_bulk_update_issues(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -958,6 +1130,7 @@ impl LinearMcp {
     #[tool(
         name = "search_documents",
         description = "Full-text search across all documents in the workspace.",
+        output_schema = output::schema::<types::DocumentSearchConnection>(),
         annotations(read_only_hint = true)
     )]
     async fn search_documents(
@@ -965,7 +1138,7 @@ impl LinearMcp {
         Parameters(params): Parameters<search_documents::SearchDocumentsParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_search_documents(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -973,6 +1146,7 @@ impl LinearMcp {
     #[tool(
         name = "create_initiative",
         description = "Create a new initiative for tracking high-level goals.",
+        output_schema = output::schema::<types::InitiativeDetail>(),
         annotations(destructive_hint = false)
     )]
     async fn create_initiative(
@@ -980,7 +1154,7 @@ impl LinearMcp {
         Parameters(params): Parameters<create_initiative::CreateInitiativeParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_create_initiative(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -988,6 +1162,7 @@ impl LinearMcp {
     #[tool(
         name = "update_initiative",
         description = "Update an existing initiative's name, description, status, owner, or target date.",
+        output_schema = output::schema::<types::InitiativeDetail>(),
         annotations(destructive_hint = false)
     )]
     async fn update_initiative(
@@ -995,7 +1170,7 @@ impl LinearMcp {
         Parameters(params): Parameters<update_initiative::UpdateInitiativeParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_update_initiative(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1003,6 +1178,7 @@ impl LinearMcp {
     #[tool(
         name = "delete_initiative",
         description = "Permanently delete an initiative.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = true)
     )]
     async fn delete_initiative(
@@ -1010,7 +1186,7 @@ impl LinearMcp {
         Parameters(params): Parameters<delete_initiative::DeleteInitiativeParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_delete_initiative(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1018,6 +1194,7 @@ impl LinearMcp {
     #[tool(
         name = "get_view_issues",
         description = "Get issues matching a custom view's saved filters.",
+        output_schema = output::schema::<types::CustomViewWithIssues>(),
         annotations(read_only_hint = true)
     )]
     async fn get_view_issues(
@@ -1025,7 +1202,7 @@ impl LinearMcp {
         Parameters(params): Parameters<get_view_issues::GetViewIssuesParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_view_issues(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1033,6 +1210,7 @@ impl LinearMcp {
     #[tool(
         name = "list_triage_issues",
         description = "List issues in the triage state for a team. Triage must be enabled for the team.",
+        output_schema = output::schema::<types::Connection<types::Issue>>(),
         annotations(read_only_hint = true)
     )]
     async fn list_triage_issues(
@@ -1040,7 +1218,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_triage_issues::ListTriageIssuesParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_triage_issues(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1048,6 +1226,7 @@ impl LinearMcp {
     #[tool(
         name = "triage_issue",
         description = "Move an issue out of triage by changing its state. Optionally set assignee and priority.",
+        output_schema = output::schema::<types::Issue>(),
         annotations(destructive_hint = false)
     )]
     async fn triage_issue(
@@ -1055,7 +1234,7 @@ impl LinearMcp {
         Parameters(params): Parameters<triage_issue::TriageIssueParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_triage_issue(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1063,6 +1242,7 @@ impl LinearMcp {
     #[tool(
         name = "create_issue_from_template",
         description = "Create a new issue using a template. Use list_templates to find template IDs. Any provided fields override template defaults.",
+        output_schema = output::schema::<types::Issue>(),
         annotations(destructive_hint = false)
     )]
     async fn create_issue_from_template(
@@ -1070,7 +1250,7 @@ impl LinearMcp {
         Parameters(params): Parameters<create_issue_from_template::CreateIssueFromTemplateParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_create_issue_from_template(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1080,6 +1260,7 @@ impl LinearMcp {
     #[tool(
         name = "delete_document",
         description = "Permanently delete a document.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = true)
     )]
     async fn delete_document(
@@ -1087,7 +1268,7 @@ impl LinearMcp {
         Parameters(params): Parameters<delete_document::DeleteDocumentParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_delete_document(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1095,6 +1276,7 @@ impl LinearMcp {
     #[tool(
         name = "delete_project_milestone",
         description = "Delete a project milestone.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = true)
     )]
     async fn delete_project_milestone(
@@ -1102,7 +1284,7 @@ impl LinearMcp {
         Parameters(params): Parameters<delete_project_milestone::DeleteProjectMilestoneParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_delete_project_milestone(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1110,6 +1292,7 @@ impl LinearMcp {
     #[tool(
         name = "delete_project_update",
         description = "Delete a project status update.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = true)
     )]
     async fn delete_project_update(
@@ -1117,7 +1300,7 @@ impl LinearMcp {
         Parameters(params): Parameters<delete_project_update::DeleteProjectUpdateParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_delete_project_update(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1125,6 +1308,7 @@ impl LinearMcp {
     #[tool(
         name = "delete_attachment",
         description = "Delete an attachment.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = true)
     )]
     async fn delete_attachment(
@@ -1132,7 +1316,7 @@ impl LinearMcp {
         Parameters(params): Parameters<delete_attachment::DeleteAttachmentParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_delete_attachment(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1140,6 +1324,7 @@ impl LinearMcp {
     #[tool(
         name = "delete_issue",
         description = "PERMANENTLY delete an issue. This cannot be undone. Use archive_issue for reversible removal.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = true)
     )]
     async fn delete_issue(
@@ -1147,7 +1332,7 @@ impl LinearMcp {
         Parameters(params): Parameters<delete_issue::DeleteIssueParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_delete_issue(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1155,6 +1340,7 @@ impl LinearMcp {
     #[tool(
         name = "delete_view",
         description = "Delete a custom view.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = true)
     )]
     async fn delete_view(
@@ -1162,7 +1348,7 @@ impl LinearMcp {
         Parameters(params): Parameters<delete_view::DeleteViewParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_delete_view(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1170,6 +1356,7 @@ impl LinearMcp {
     #[tool(
         name = "archive_cycle",
         description = "Archive a cycle.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = true)
     )]
     async fn archive_cycle(
@@ -1177,7 +1364,7 @@ impl LinearMcp {
         Parameters(params): Parameters<archive_cycle::ArchiveCycleParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_archive_cycle(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1187,6 +1374,7 @@ impl LinearMcp {
     #[tool(
         name = "update_cycle",
         description = "Update an existing cycle's name, description, or dates.",
+        output_schema = output::schema::<types::CycleDetail>(),
         annotations(destructive_hint = false)
     )]
     async fn update_cycle(
@@ -1194,7 +1382,7 @@ impl LinearMcp {
         Parameters(params): Parameters<update_cycle::UpdateCycleParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_update_cycle(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1202,6 +1390,7 @@ impl LinearMcp {
     #[tool(
         name = "update_project_milestone",
         description = "Update a project milestone's name, description, or target date.",
+        output_schema = output::schema::<types::ProjectMilestone>(),
         annotations(destructive_hint = false)
     )]
     async fn update_project_milestone(
@@ -1209,7 +1398,7 @@ impl LinearMcp {
         Parameters(params): Parameters<update_project_milestone::UpdateProjectMilestoneParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_update_project_milestone(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1217,6 +1406,7 @@ impl LinearMcp {
     #[tool(
         name = "update_project_update",
         description = "Update a project status update's body or health.",
+        output_schema = output::schema::<types::ProjectUpdate>(),
         annotations(destructive_hint = false)
     )]
     async fn update_project_update(
@@ -1224,7 +1414,7 @@ impl LinearMcp {
         Parameters(params): Parameters<update_project_update::UpdateProjectUpdateParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_update_project_update(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1232,6 +1422,7 @@ impl LinearMcp {
     #[tool(
         name = "update_webhook",
         description = "Update an existing webhook's URL, label, enabled status, or resource types.",
+        output_schema = output::schema::<types::Webhook>(),
         annotations(destructive_hint = false)
     )]
     async fn update_webhook(
@@ -1239,7 +1430,7 @@ impl LinearMcp {
         Parameters(params): Parameters<update_webhook::UpdateWebhookParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_update_webhook(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1247,6 +1438,7 @@ impl LinearMcp {
     #[tool(
         name = "update_attachment",
         description = "Update an attachment's title or subtitle.",
+        output_schema = output::schema::<types::Attachment>(),
         annotations(destructive_hint = false)
     )]
     async fn update_attachment(
@@ -1254,7 +1446,7 @@ impl LinearMcp {
         Parameters(params): Parameters<update_attachment::UpdateAttachmentParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_update_attachment(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1262,6 +1454,7 @@ impl LinearMcp {
     #[tool(
         name = "update_view",
         description = "Update a custom view's name, description, color, icon, or sharing.",
+        output_schema = output::schema::<types::CustomView>(),
         annotations(destructive_hint = false)
     )]
     async fn update_view(
@@ -1269,7 +1462,7 @@ impl LinearMcp {
         Parameters(params): Parameters<update_view::UpdateViewParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_update_view(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1279,6 +1472,7 @@ impl LinearMcp {
     #[tool(
         name = "list_comments",
         description = "List comments on an issue, including reply threads and resolution status.",
+        output_schema = output::schema::<response::IssueWithComments>(),
         annotations(read_only_hint = true)
     )]
     async fn list_comments(
@@ -1286,7 +1480,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_comments::ListCommentsParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_comments(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1294,6 +1488,7 @@ impl LinearMcp {
     #[tool(
         name = "resolve_comment",
         description = "Mark a comment thread as resolved.",
+        output_schema = output::schema::<types::Comment>(),
         annotations(destructive_hint = false)
     )]
     async fn resolve_comment(
@@ -1301,7 +1496,7 @@ impl LinearMcp {
         Parameters(params): Parameters<resolve_comment::ResolveCommentParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_resolve_comment(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1309,6 +1504,7 @@ impl LinearMcp {
     #[tool(
         name = "unresolve_comment",
         description = "Reopen a resolved comment thread.",
+        output_schema = output::schema::<types::Comment>(),
         annotations(destructive_hint = false)
     )]
     async fn unresolve_comment(
@@ -1316,7 +1512,7 @@ impl LinearMcp {
         Parameters(params): Parameters<unresolve_comment::UnresolveCommentParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_unresolve_comment(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1326,6 +1522,7 @@ impl LinearMcp {
     #[tool(
         name = "subscribe_to_issue",
         description = "Subscribe to an issue to receive notifications about it.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = false)
     )]
     async fn subscribe_to_issue(
@@ -1333,7 +1530,7 @@ impl LinearMcp {
         Parameters(params): Parameters<subscribe_to_issue::SubscribeToIssueParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_subscribe_to_issue(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1341,6 +1538,7 @@ impl LinearMcp {
     #[tool(
         name = "unsubscribe_from_issue",
         description = "Unsubscribe from an issue to stop receiving notifications.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = false)
     )]
     async fn unsubscribe_from_issue(
@@ -1348,7 +1546,7 @@ impl LinearMcp {
         Parameters(params): Parameters<unsubscribe_from_issue::UnsubscribeFromIssueParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_unsubscribe_from_issue(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1358,6 +1556,7 @@ impl LinearMcp {
     #[tool(
         name = "create_view",
         description = "Create a new custom view with optional team scope and sharing.",
+        output_schema = output::schema::<types::CustomView>(),
         annotations(destructive_hint = false)
     )]
     async fn create_view(
@@ -1365,7 +1564,7 @@ impl LinearMcp {
         Parameters(params): Parameters<create_view::CreateViewParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_create_view(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1375,6 +1574,7 @@ impl LinearMcp {
     #[tool(
         name = "search_projects",
         description = "Search for projects by name or description.",
+        output_schema = output::schema::<types::ProjectSearchConnection>(),
         annotations(read_only_hint = true)
     )]
     async fn search_projects(
@@ -1382,7 +1582,7 @@ impl LinearMcp {
         Parameters(params): Parameters<search_projects::SearchProjectsParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_search_projects(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1390,6 +1590,7 @@ impl LinearMcp {
     #[tool(
         name = "issue_vcs_branch_search",
         description = "Find the Linear issue associated with a git branch name.",
+        output_schema = output::schema::<types::NodeList<types::Issue>>(),
         annotations(read_only_hint = true)
     )]
     async fn issue_vcs_branch_search(
@@ -1397,7 +1598,7 @@ impl LinearMcp {
         Parameters(params): Parameters<issue_vcs_branch_search::IssueVcsBranchSearchParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_issue_vcs_branch_search(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1407,6 +1608,7 @@ impl LinearMcp {
     #[tool(
         name = "create_agent_session",
         description = "Create a new agent session on an issue or comment for AI-assisted work tracking.",
+        output_schema = output::schema::<types::AgentSession>(),
         annotations(destructive_hint = false)
     )]
     async fn create_agent_session(
@@ -1414,7 +1616,7 @@ impl LinearMcp {
         Parameters(params): Parameters<create_agent_session::CreateAgentSessionParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_create_agent_session(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1422,6 +1624,7 @@ impl LinearMcp {
     #[tool(
         name = "update_agent_session",
         description = "Update an agent session's plan or external link.",
+        output_schema = output::schema::<types::AgentSession>(),
         annotations(destructive_hint = false)
     )]
     async fn update_agent_session(
@@ -1429,7 +1632,7 @@ impl LinearMcp {
         Parameters(params): Parameters<update_agent_session::UpdateAgentSessionParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_update_agent_session(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1437,6 +1640,7 @@ impl LinearMcp {
     #[tool(
         name = "create_agent_activity",
         description = "Log an activity (thinking, tool call, code change, error) within an agent session.",
+        output_schema = output::schema::<types::AgentActivityMutationResult>(),
         annotations(destructive_hint = false)
     )]
     async fn create_agent_activity(
@@ -1444,7 +1648,7 @@ impl LinearMcp {
         Parameters(params): Parameters<create_agent_activity::CreateAgentActivityParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_create_agent_activity(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1452,6 +1656,7 @@ impl LinearMcp {
     #[tool(
         name = "list_agent_sessions",
         description = "List agent sessions in the workspace.",
+        output_schema = output::schema::<types::Connection<types::AgentSession>>(),
         annotations(read_only_hint = true)
     )]
     async fn list_agent_sessions(
@@ -1459,7 +1664,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_agent_sessions::ListAgentSessionsParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_agent_sessions(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1467,6 +1672,7 @@ impl LinearMcp {
     #[tool(
         name = "get_agent_session",
         description = "Get full details of an agent session including activities.",
+        output_schema = output::schema::<types::AgentSession>(),
         annotations(read_only_hint = true)
     )]
     async fn get_agent_session(
@@ -1474,7 +1680,7 @@ impl LinearMcp {
         Parameters(params): Parameters<get_agent_session::GetAgentSessionParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_agent_session(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1484,6 +1690,7 @@ impl LinearMcp {
     #[tool(
         name = "list_customers",
         description = "List customers in the workspace.",
+        output_schema = output::schema::<types::Connection<types::Customer>>(),
         annotations(read_only_hint = true)
     )]
     async fn list_customers(
@@ -1491,7 +1698,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_customers::ListCustomersParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_customers(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1499,6 +1706,7 @@ impl LinearMcp {
     #[tool(
         name = "get_customer",
         description = "Get full details of a customer.",
+        output_schema = output::schema::<types::Customer>(),
         annotations(read_only_hint = true)
     )]
     async fn get_customer(
@@ -1506,7 +1714,7 @@ impl LinearMcp {
         Parameters(params): Parameters<get_customer::GetCustomerParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_customer(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1514,6 +1722,7 @@ impl LinearMcp {
     #[tool(
         name = "create_customer",
         description = "Create a new customer with name, domains, owner, revenue, and size.",
+        output_schema = output::schema::<types::Customer>(),
         annotations(destructive_hint = false)
     )]
     async fn create_customer(
@@ -1521,7 +1730,7 @@ impl LinearMcp {
         Parameters(params): Parameters<create_customer::CreateCustomerParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_create_customer(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1529,6 +1738,7 @@ impl LinearMcp {
     #[tool(
         name = "update_customer",
         description = "Update a customer's name, domains, owner, revenue, or size.",
+        output_schema = output::schema::<types::Customer>(),
         annotations(destructive_hint = false)
     )]
     async fn update_customer(
@@ -1536,7 +1746,7 @@ impl LinearMcp {
         Parameters(params): Parameters<update_customer::UpdateCustomerParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_update_customer(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1544,6 +1754,7 @@ impl LinearMcp {
     #[tool(
         name = "delete_customer",
         description = "Delete a customer.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = true)
     )]
     async fn delete_customer(
@@ -1551,7 +1762,7 @@ impl LinearMcp {
         Parameters(params): Parameters<delete_customer::DeleteCustomerParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_delete_customer(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1559,6 +1770,7 @@ impl LinearMcp {
     #[tool(
         name = "list_customer_needs",
         description = "List customer needs in the workspace.",
+        output_schema = output::schema::<types::Connection<types::CustomerNeed>>(),
         annotations(read_only_hint = true)
     )]
     async fn list_customer_needs(
@@ -1566,7 +1778,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_customer_needs::ListCustomerNeedsParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_customer_needs(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1574,6 +1786,7 @@ impl LinearMcp {
     #[tool(
         name = "create_customer_need",
         description = "Create a customer need linking a customer to an issue.",
+        output_schema = output::schema::<types::CustomerNeed>(),
         annotations(destructive_hint = false)
     )]
     async fn create_customer_need(
@@ -1581,7 +1794,7 @@ impl LinearMcp {
         Parameters(params): Parameters<create_customer_need::CreateCustomerNeedParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_create_customer_need(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1589,6 +1802,7 @@ impl LinearMcp {
     #[tool(
         name = "update_customer_need",
         description = "Update a customer need.",
+        output_schema = output::schema::<types::CustomerNeed>(),
         annotations(destructive_hint = false)
     )]
     async fn update_customer_need(
@@ -1596,7 +1810,7 @@ impl LinearMcp {
         Parameters(params): Parameters<update_customer_need::UpdateCustomerNeedParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_update_customer_need(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1606,6 +1820,7 @@ impl LinearMcp {
     #[tool(
         name = "list_initiative_updates",
         description = "List status updates for an initiative.",
+        output_schema = output::schema::<response::InitiativeWithUpdates>(),
         annotations(read_only_hint = true)
     )]
     async fn list_initiative_updates(
@@ -1613,7 +1828,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_initiative_updates::ListInitiativeUpdatesParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_initiative_updates(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1621,6 +1836,7 @@ impl LinearMcp {
     #[tool(
         name = "create_initiative_update",
         description = "Post a status update to an initiative with optional health indicator.",
+        output_schema = output::schema::<types::InitiativeStatusUpdate>(),
         annotations(destructive_hint = false)
     )]
     async fn create_initiative_update(
@@ -1628,7 +1844,7 @@ impl LinearMcp {
         Parameters(params): Parameters<create_initiative_update::CreateInitiativeUpdateParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_create_initiative_update(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1636,6 +1852,7 @@ impl LinearMcp {
     #[tool(
         name = "add_project_to_initiative",
         description = "Link a project to an initiative.",
+        output_schema = output::schema::<types::InitiativeToProject>(),
         annotations(destructive_hint = false)
     )]
     async fn add_project_to_initiative(
@@ -1643,7 +1860,7 @@ impl LinearMcp {
         Parameters(params): Parameters<add_project_to_initiative::AddProjectToInitiativeParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_add_project_to_initiative(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1651,14 +1868,17 @@ impl LinearMcp {
     #[tool(
         name = "remove_project_from_initiative",
         description = "Remove a project-to-initiative link.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = true)
     )]
     async fn remove_project_from_initiative(
         &self,
-        Parameters(params): Parameters<remove_project_from_initiative::RemoveProjectFromInitiativeParams>,
+        Parameters(params): Parameters<
+            remove_project_from_initiative::RemoveProjectFromInitiativeParams,
+        >,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_remove_project_from_initiative(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1668,6 +1888,7 @@ impl LinearMcp {
     #[tool(
         name = "create_project_relation",
         description = "Create a relation between two projects (blocks, dependsOn, related).",
+        output_schema = output::schema::<types::ProjectRelation>(),
         annotations(destructive_hint = false)
     )]
     async fn create_project_relation(
@@ -1675,7 +1896,7 @@ impl LinearMcp {
         Parameters(params): Parameters<create_project_relation::CreateProjectRelationParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_create_project_relation(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1683,6 +1904,7 @@ impl LinearMcp {
     #[tool(
         name = "delete_project_relation",
         description = "Delete a project relation.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = true)
     )]
     async fn delete_project_relation(
@@ -1690,7 +1912,7 @@ impl LinearMcp {
         Parameters(params): Parameters<delete_project_relation::DeleteProjectRelationParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_delete_project_relation(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1698,6 +1920,7 @@ impl LinearMcp {
     #[tool(
         name = "list_project_relations",
         description = "List relations for a project.",
+        output_schema = output::schema::<response::ProjectWithRelations>(),
         annotations(read_only_hint = true)
     )]
     async fn list_project_relations(
@@ -1705,7 +1928,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_project_relations::ListProjectRelationsParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_project_relations(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1715,6 +1938,7 @@ impl LinearMcp {
     #[tool(
         name = "list_releases",
         description = "List releases in the workspace (alpha feature).",
+        output_schema = output::schema::<types::Connection<types::Release>>(),
         annotations(read_only_hint = true)
     )]
     async fn list_releases(
@@ -1722,7 +1946,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_releases::ListReleasesParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_releases(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1730,6 +1954,7 @@ impl LinearMcp {
     #[tool(
         name = "create_release",
         description = "Create a new release in a pipeline (alpha feature).",
+        output_schema = output::schema::<types::Release>(),
         annotations(destructive_hint = false)
     )]
     async fn create_release(
@@ -1737,7 +1962,7 @@ impl LinearMcp {
         Parameters(params): Parameters<create_release::CreateReleaseParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_create_release(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1745,6 +1970,7 @@ impl LinearMcp {
     #[tool(
         name = "update_release",
         description = "Update a release's name, description, version, commit SHA, or stage (alpha feature).",
+        output_schema = output::schema::<types::Release>(),
         annotations(destructive_hint = false)
     )]
     async fn update_release(
@@ -1752,7 +1978,7 @@ impl LinearMcp {
         Parameters(params): Parameters<update_release::UpdateReleaseParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_update_release(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1762,6 +1988,7 @@ impl LinearMcp {
     #[tool(
         name = "get_workflow_state",
         description = "Get a workflow state by UUID.",
+        output_schema = output::schema::<types::WorkflowState>(),
         annotations(read_only_hint = true)
     )]
     async fn get_workflow_state(
@@ -1769,7 +1996,7 @@ impl LinearMcp {
         Parameters(params): Parameters<get_workflow_state::GetWorkflowStateParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_workflow_state(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1777,6 +2004,7 @@ impl LinearMcp {
     #[tool(
         name = "create_workflow_state",
         description = "Create a new workflow state for a team.",
+        output_schema = output::schema::<types::WorkflowState>(),
         annotations(destructive_hint = false)
     )]
     async fn create_workflow_state(
@@ -1784,7 +2012,7 @@ impl LinearMcp {
         Parameters(params): Parameters<create_workflow_state::CreateWorkflowStateParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_create_workflow_state(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1792,6 +2020,7 @@ impl LinearMcp {
     #[tool(
         name = "update_workflow_state",
         description = "Update a workflow state's name, color, description, or position.",
+        output_schema = output::schema::<types::WorkflowState>(),
         annotations(destructive_hint = false)
     )]
     async fn update_workflow_state(
@@ -1799,7 +2028,7 @@ impl LinearMcp {
         Parameters(params): Parameters<update_workflow_state::UpdateWorkflowStateParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_update_workflow_state(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1807,6 +2036,7 @@ impl LinearMcp {
     #[tool(
         name = "archive_workflow_state",
         description = "Archive a workflow state.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = true)
     )]
     async fn archive_workflow_state(
@@ -1814,7 +2044,7 @@ impl LinearMcp {
         Parameters(params): Parameters<archive_workflow_state::ArchiveWorkflowStateParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_archive_workflow_state(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1824,6 +2054,7 @@ impl LinearMcp {
     #[tool(
         name = "add_issue_label",
         description = "Add a label to an issue.",
+        output_schema = output::schema::<types::MutationResult>(),
         annotations(destructive_hint = false)
     )]
     async fn add_issue_label(
@@ -1831,7 +2062,7 @@ impl LinearMcp {
         Parameters(params): Parameters<add_issue_label::AddIssueLabelParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_add_issue_label(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1839,6 +2070,7 @@ impl LinearMcp {
     #[tool(
         name = "remove_issue_label",
         description = "Remove a label from an issue.",
+        output_schema = output::schema::<types::MutationResult>(),
         annotations(destructive_hint = true)
     )]
     async fn remove_issue_label(
@@ -1846,7 +2078,7 @@ impl LinearMcp {
         Parameters(params): Parameters<remove_issue_label::RemoveIssueLabelParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_remove_issue_label(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1854,6 +2086,7 @@ impl LinearMcp {
     #[tool(
         name = "batch_create_issues",
         description = "Batch-create multiple issues at once from a JSON array.",
+        output_schema = output::schema::<types::BatchCreateResult>(),
         annotations(destructive_hint = false)
     )]
     async fn batch_create_issues(
@@ -1861,7 +2094,7 @@ impl LinearMcp {
         Parameters(params): Parameters<batch_create_issues::BatchCreateIssuesParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_batch_create_issues(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1869,6 +2102,7 @@ impl LinearMcp {
     #[tool(
         name = "update_issue_relation",
         description = "Update an issue relation's type.",
+        output_schema = output::schema::<types::IssueRelationMutationResult>(),
         annotations(destructive_hint = false)
     )]
     async fn update_issue_relation(
@@ -1876,7 +2110,7 @@ impl LinearMcp {
         Parameters(params): Parameters<update_issue_relation::UpdateIssueRelationParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_update_issue_relation(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1884,6 +2118,7 @@ impl LinearMcp {
     #[tool(
         name = "get_issue_priority_values",
         description = "Get the priority scale configuration (labels and values).",
+        output_schema = output::schema::<types::NodeList<types::IssuePriorityValue>>(),
         annotations(read_only_hint = true)
     )]
     async fn get_issue_priority_values(
@@ -1891,7 +2126,7 @@ impl LinearMcp {
         Parameters(params): Parameters<get_issue_priority_values::GetIssuePriorityValuesParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_issue_priority_values(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1901,6 +2136,7 @@ impl LinearMcp {
     #[tool(
         name = "delete_project",
         description = "Permanently delete a project. Warning: this is irreversible.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = true)
     )]
     async fn delete_project(
@@ -1908,7 +2144,7 @@ impl LinearMcp {
         Parameters(params): Parameters<delete_project::DeleteProjectParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_delete_project(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1916,6 +2152,7 @@ impl LinearMcp {
     #[tool(
         name = "unarchive_project",
         description = "Unarchive a previously archived project.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = false)
     )]
     async fn unarchive_project(
@@ -1923,7 +2160,7 @@ impl LinearMcp {
         Parameters(params): Parameters<unarchive_project::UnarchiveProjectParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_unarchive_project(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1931,6 +2168,7 @@ impl LinearMcp {
     #[tool(
         name = "update_project_relation",
         description = "Update a project relation's anchor types.",
+        output_schema = output::schema::<types::ProjectRelationMutationResult>(),
         annotations(destructive_hint = false)
     )]
     async fn update_project_relation(
@@ -1938,7 +2176,7 @@ impl LinearMcp {
         Parameters(params): Parameters<update_project_relation::UpdateProjectRelationParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_update_project_relation(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1946,6 +2184,7 @@ impl LinearMcp {
     #[tool(
         name = "get_project_milestone",
         description = "Get a project milestone by UUID.",
+        output_schema = output::schema::<types::ProjectMilestone>(),
         annotations(read_only_hint = true)
     )]
     async fn get_project_milestone(
@@ -1953,7 +2192,7 @@ impl LinearMcp {
         Parameters(params): Parameters<get_project_milestone::GetProjectMilestoneParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_project_milestone(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1963,6 +2202,7 @@ impl LinearMcp {
     #[tool(
         name = "delete_team",
         description = "Permanently delete a team. Warning: this is irreversible.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = true)
     )]
     async fn delete_team(
@@ -1970,7 +2210,7 @@ impl LinearMcp {
         Parameters(params): Parameters<delete_team::DeleteTeamParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_delete_team(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1978,6 +2218,7 @@ impl LinearMcp {
     #[tool(
         name = "unarchive_team",
         description = "Unarchive a previously archived team.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = false)
     )]
     async fn unarchive_team(
@@ -1985,7 +2226,7 @@ impl LinearMcp {
         Parameters(params): Parameters<unarchive_team::UnarchiveTeamParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_unarchive_team(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -1993,6 +2234,7 @@ impl LinearMcp {
     #[tool(
         name = "get_team",
         description = "Get detailed info about a team by key or UUID.",
+        output_schema = output::schema::<types::Team>(),
         annotations(read_only_hint = true)
     )]
     async fn get_team(
@@ -2000,7 +2242,7 @@ impl LinearMcp {
         Parameters(params): Parameters<get_team::GetTeamParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_team(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2010,6 +2252,7 @@ impl LinearMcp {
     #[tool(
         name = "unarchive_document",
         description = "Unarchive a previously archived document.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = false)
     )]
     async fn unarchive_document(
@@ -2017,7 +2260,7 @@ impl LinearMcp {
         Parameters(params): Parameters<unarchive_document::UnarchiveDocumentParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_unarchive_document(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2025,14 +2268,17 @@ impl LinearMcp {
     #[tool(
         name = "get_document_content_history",
         description = "Get the content version history for a document.",
+        output_schema = output::schema::<response::DocumentContentHistoryPayload>(),
         annotations(read_only_hint = true)
     )]
     async fn get_document_content_history(
         &self,
-        Parameters(params): Parameters<get_document_content_history::GetDocumentContentHistoryParams>,
+        Parameters(params): Parameters<
+            get_document_content_history::GetDocumentContentHistoryParams,
+        >,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_document_content_history(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2042,6 +2288,7 @@ impl LinearMcp {
     #[tool(
         name = "get_viewer",
         description = "Get the currently authenticated user's info.",
+        output_schema = output::schema::<types::User>(),
         annotations(read_only_hint = true)
     )]
     async fn get_viewer_tool(
@@ -2049,7 +2296,7 @@ impl LinearMcp {
         Parameters(_params): Parameters<get_viewer::GetViewerParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_viewer_tool().await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2057,6 +2304,7 @@ impl LinearMcp {
     #[tool(
         name = "get_user",
         description = "Get a user by email or UUID.",
+        output_schema = output::schema::<types::User>(),
         annotations(read_only_hint = true)
     )]
     async fn get_user(
@@ -2064,7 +2312,7 @@ impl LinearMcp {
         Parameters(params): Parameters<get_user::GetUserParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_user(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2072,6 +2320,7 @@ impl LinearMcp {
     #[tool(
         name = "update_user",
         description = "Update a user's display name, description, or status.",
+        output_schema = output::schema::<types::User>(),
         annotations(destructive_hint = false)
     )]
     async fn update_user(
@@ -2079,7 +2328,7 @@ impl LinearMcp {
         Parameters(params): Parameters<update_user::UpdateUserParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_update_user(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2087,6 +2336,7 @@ impl LinearMcp {
     #[tool(
         name = "get_attachment",
         description = "Get an attachment by UUID.",
+        output_schema = output::schema::<types::Attachment>(),
         annotations(read_only_hint = true)
     )]
     async fn get_attachment(
@@ -2094,7 +2344,7 @@ impl LinearMcp {
         Parameters(params): Parameters<get_attachment::GetAttachmentParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_attachment(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2102,6 +2352,7 @@ impl LinearMcp {
     #[tool(
         name = "get_comment",
         description = "Get a comment by UUID.",
+        output_schema = output::schema::<types::Comment>(),
         annotations(read_only_hint = true)
     )]
     async fn get_comment(
@@ -2109,7 +2360,7 @@ impl LinearMcp {
         Parameters(params): Parameters<get_comment::GetCommentParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_comment(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2117,6 +2368,7 @@ impl LinearMcp {
     #[tool(
         name = "get_favorite",
         description = "Get a favorite by UUID.",
+        output_schema = output::schema::<types::Favorite>(),
         annotations(read_only_hint = true)
     )]
     async fn get_favorite(
@@ -2124,7 +2376,7 @@ impl LinearMcp {
         Parameters(params): Parameters<get_favorite::GetFavoriteParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_favorite(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2132,6 +2384,7 @@ impl LinearMcp {
     #[tool(
         name = "update_favorite",
         description = "Update a favorite's sort order, parent, or folder name.",
+        output_schema = output::schema::<types::Favorite>(),
         annotations(destructive_hint = false)
     )]
     async fn update_favorite(
@@ -2139,7 +2392,7 @@ impl LinearMcp {
         Parameters(params): Parameters<update_favorite::UpdateFavoriteParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_update_favorite(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2147,6 +2400,7 @@ impl LinearMcp {
     #[tool(
         name = "get_notification",
         description = "Get a notification by UUID.",
+        output_schema = output::schema::<types::Notification>(),
         annotations(read_only_hint = true)
     )]
     async fn get_notification(
@@ -2154,7 +2408,7 @@ impl LinearMcp {
         Parameters(params): Parameters<get_notification::GetNotificationParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_notification(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2164,6 +2418,7 @@ impl LinearMcp {
     #[tool(
         name = "list_customer_statuses",
         description = "List all customer statuses.",
+        output_schema = output::schema::<types::NodeList<types::CustomerStatusFull>>(),
         annotations(read_only_hint = true)
     )]
     async fn list_customer_statuses(
@@ -2171,7 +2426,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_customer_statuses::ListCustomerStatusesParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_customer_statuses(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2179,6 +2434,7 @@ impl LinearMcp {
     #[tool(
         name = "get_customer_status",
         description = "Get a customer status by UUID.",
+        output_schema = output::schema::<types::CustomerStatusFull>(),
         annotations(read_only_hint = true)
     )]
     async fn get_customer_status(
@@ -2186,7 +2442,7 @@ impl LinearMcp {
         Parameters(params): Parameters<get_customer_status::GetCustomerStatusParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_customer_status(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2194,6 +2450,7 @@ impl LinearMcp {
     #[tool(
         name = "create_customer_status",
         description = "Create a new customer status.",
+        output_schema = output::schema::<types::CustomerStatusFull>(),
         annotations(destructive_hint = false)
     )]
     async fn create_customer_status(
@@ -2201,7 +2458,7 @@ impl LinearMcp {
         Parameters(params): Parameters<create_customer_status::CreateCustomerStatusParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_create_customer_status(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2209,6 +2466,7 @@ impl LinearMcp {
     #[tool(
         name = "update_customer_status",
         description = "Update a customer status.",
+        output_schema = output::schema::<types::CustomerStatusFull>(),
         annotations(destructive_hint = false)
     )]
     async fn update_customer_status(
@@ -2216,7 +2474,7 @@ impl LinearMcp {
         Parameters(params): Parameters<update_customer_status::UpdateCustomerStatusParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_update_customer_status(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2224,6 +2482,7 @@ impl LinearMcp {
     #[tool(
         name = "delete_customer_status",
         description = "Delete a customer status.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = true)
     )]
     async fn delete_customer_status(
@@ -2231,7 +2490,7 @@ impl LinearMcp {
         Parameters(params): Parameters<delete_customer_status::DeleteCustomerStatusParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_delete_customer_status(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2241,6 +2500,7 @@ impl LinearMcp {
     #[tool(
         name = "list_customer_tiers",
         description = "List all customer tiers.",
+        output_schema = output::schema::<types::NodeList<types::CustomerTierFull>>(),
         annotations(read_only_hint = true)
     )]
     async fn list_customer_tiers(
@@ -2248,7 +2508,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_customer_tiers::ListCustomerTiersParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_customer_tiers(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2256,6 +2516,7 @@ impl LinearMcp {
     #[tool(
         name = "get_customer_tier",
         description = "Get a customer tier by UUID.",
+        output_schema = output::schema::<types::CustomerTierFull>(),
         annotations(read_only_hint = true)
     )]
     async fn get_customer_tier(
@@ -2263,7 +2524,7 @@ impl LinearMcp {
         Parameters(params): Parameters<get_customer_tier::GetCustomerTierParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_customer_tier(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2271,6 +2532,7 @@ impl LinearMcp {
     #[tool(
         name = "create_customer_tier",
         description = "Create a new customer tier.",
+        output_schema = output::schema::<types::CustomerTierFull>(),
         annotations(destructive_hint = false)
     )]
     async fn create_customer_tier(
@@ -2278,7 +2540,7 @@ impl LinearMcp {
         Parameters(params): Parameters<create_customer_tier::CreateCustomerTierParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_create_customer_tier(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2286,6 +2548,7 @@ impl LinearMcp {
     #[tool(
         name = "update_customer_tier",
         description = "Update a customer tier.",
+        output_schema = output::schema::<types::CustomerTierFull>(),
         annotations(destructive_hint = false)
     )]
     async fn update_customer_tier(
@@ -2293,7 +2556,7 @@ impl LinearMcp {
         Parameters(params): Parameters<update_customer_tier::UpdateCustomerTierParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_update_customer_tier(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2301,6 +2564,7 @@ impl LinearMcp {
     #[tool(
         name = "delete_customer_tier",
         description = "Delete a customer tier.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = true)
     )]
     async fn delete_customer_tier(
@@ -2308,7 +2572,7 @@ impl LinearMcp {
         Parameters(params): Parameters<delete_customer_tier::DeleteCustomerTierParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_delete_customer_tier(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2318,6 +2582,7 @@ impl LinearMcp {
     #[tool(
         name = "merge_customers",
         description = "Merge two customers (source into target).",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = false)
     )]
     async fn merge_customers(
@@ -2325,7 +2590,7 @@ impl LinearMcp {
         Parameters(params): Parameters<merge_customers::MergeCustomersParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_merge_customers(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2333,6 +2598,7 @@ impl LinearMcp {
     #[tool(
         name = "get_customer_need",
         description = "Get a customer need by UUID.",
+        output_schema = output::schema::<types::CustomerNeed>(),
         annotations(read_only_hint = true)
     )]
     async fn get_customer_need(
@@ -2340,7 +2606,7 @@ impl LinearMcp {
         Parameters(params): Parameters<get_customer_need::GetCustomerNeedParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_customer_need(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2348,6 +2614,7 @@ impl LinearMcp {
     #[tool(
         name = "archive_customer_need",
         description = "Archive a customer need.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = true)
     )]
     async fn archive_customer_need(
@@ -2355,7 +2622,7 @@ impl LinearMcp {
         Parameters(params): Parameters<archive_customer_need::ArchiveCustomerNeedParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_archive_customer_need(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2363,6 +2630,7 @@ impl LinearMcp {
     #[tool(
         name = "unarchive_customer_need",
         description = "Unarchive a customer need.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = false)
     )]
     async fn unarchive_customer_need(
@@ -2370,7 +2638,7 @@ impl LinearMcp {
         Parameters(params): Parameters<unarchive_customer_need::UnarchiveCustomerNeedParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_unarchive_customer_need(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2378,6 +2646,7 @@ impl LinearMcp {
     #[tool(
         name = "delete_customer_need",
         description = "Delete a customer need.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = true)
     )]
     async fn delete_customer_need(
@@ -2385,7 +2654,7 @@ impl LinearMcp {
         Parameters(params): Parameters<delete_customer_need::DeleteCustomerNeedParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_delete_customer_need(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2395,6 +2664,7 @@ impl LinearMcp {
     #[tool(
         name = "archive_initiative",
         description = "Archive an initiative.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = true)
     )]
     async fn archive_initiative(
@@ -2402,7 +2672,7 @@ impl LinearMcp {
         Parameters(params): Parameters<archive_initiative::ArchiveInitiativeParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_archive_initiative(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2410,6 +2680,7 @@ impl LinearMcp {
     #[tool(
         name = "unarchive_initiative",
         description = "Unarchive an initiative.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = false)
     )]
     async fn unarchive_initiative(
@@ -2417,7 +2688,7 @@ impl LinearMcp {
         Parameters(params): Parameters<unarchive_initiative::UnarchiveInitiativeParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_unarchive_initiative(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2425,14 +2696,17 @@ impl LinearMcp {
     #[tool(
         name = "update_initiative_to_project",
         description = "Update an initiative-to-project link's sort order.",
+        output_schema = output::schema::<types::InitiativeToProjectMutationResult>(),
         annotations(destructive_hint = false)
     )]
     async fn update_initiative_to_project(
         &self,
-        Parameters(params): Parameters<update_initiative_to_project::UpdateInitiativeToProjectParams>,
+        Parameters(params): Parameters<
+            update_initiative_to_project::UpdateInitiativeToProjectParams,
+        >,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_update_initiative_to_project(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2440,6 +2714,7 @@ impl LinearMcp {
     #[tool(
         name = "archive_initiative_update",
         description = "Archive an initiative update.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = true)
     )]
     async fn archive_initiative_update(
@@ -2447,7 +2722,7 @@ impl LinearMcp {
         Parameters(params): Parameters<archive_initiative_update::ArchiveInitiativeUpdateParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_archive_initiative_update(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2455,14 +2730,17 @@ impl LinearMcp {
     #[tool(
         name = "unarchive_initiative_update",
         description = "Unarchive an initiative update.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = false)
     )]
     async fn unarchive_initiative_update(
         &self,
-        Parameters(params): Parameters<unarchive_initiative_update::UnarchiveInitiativeUpdateParams>,
+        Parameters(params): Parameters<
+            unarchive_initiative_update::UnarchiveInitiativeUpdateParams,
+        >,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_unarchive_initiative_update(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2472,6 +2750,7 @@ impl LinearMcp {
     #[tool(
         name = "get_release",
         description = "Get a release by UUID.",
+        output_schema = output::schema::<types::Release>(),
         annotations(read_only_hint = true)
     )]
     async fn get_release(
@@ -2479,7 +2758,7 @@ impl LinearMcp {
         Parameters(params): Parameters<get_release::GetReleaseParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_release(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2487,6 +2766,7 @@ impl LinearMcp {
     #[tool(
         name = "archive_release",
         description = "Archive a release.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = true)
     )]
     async fn archive_release(
@@ -2494,7 +2774,7 @@ impl LinearMcp {
         Parameters(params): Parameters<archive_release::ArchiveReleaseParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_archive_release(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2502,6 +2782,7 @@ impl LinearMcp {
     #[tool(
         name = "delete_release",
         description = "Permanently delete a release.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = true)
     )]
     async fn delete_release(
@@ -2509,7 +2790,7 @@ impl LinearMcp {
         Parameters(params): Parameters<delete_release::DeleteReleaseParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_delete_release(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2517,6 +2798,7 @@ impl LinearMcp {
     #[tool(
         name = "unarchive_release",
         description = "Unarchive a release.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = false)
     )]
     async fn unarchive_release(
@@ -2524,7 +2806,7 @@ impl LinearMcp {
         Parameters(params): Parameters<unarchive_release::UnarchiveReleaseParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_unarchive_release(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2532,6 +2814,7 @@ impl LinearMcp {
     #[tool(
         name = "search_releases",
         description = "Search releases by name or version.",
+        output_schema = output::schema::<types::ReleaseSearchConnection>(),
         annotations(read_only_hint = true)
     )]
     async fn search_releases(
@@ -2539,7 +2822,7 @@ impl LinearMcp {
         Parameters(params): Parameters<search_releases::SearchReleasesParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_search_releases(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2549,6 +2832,7 @@ impl LinearMcp {
     #[tool(
         name = "list_release_pipelines",
         description = "List all release pipelines.",
+        output_schema = output::schema::<types::NodeList<types::ReleasePipelineFull>>(),
         annotations(read_only_hint = true)
     )]
     async fn list_release_pipelines(
@@ -2556,7 +2840,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_release_pipelines::ListReleasePipelinesParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_release_pipelines(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2564,6 +2848,7 @@ impl LinearMcp {
     #[tool(
         name = "get_release_pipeline",
         description = "Get a release pipeline by UUID.",
+        output_schema = output::schema::<types::ReleasePipelineFull>(),
         annotations(read_only_hint = true)
     )]
     async fn get_release_pipeline(
@@ -2571,7 +2856,7 @@ impl LinearMcp {
         Parameters(params): Parameters<get_release_pipeline::GetReleasePipelineParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_release_pipeline(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2579,6 +2864,7 @@ impl LinearMcp {
     #[tool(
         name = "create_release_pipeline",
         description = "Create a new release pipeline.",
+        output_schema = output::schema::<types::ReleasePipelineFull>(),
         annotations(destructive_hint = false)
     )]
     async fn create_release_pipeline(
@@ -2586,7 +2872,7 @@ impl LinearMcp {
         Parameters(params): Parameters<create_release_pipeline::CreateReleasePipelineParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_create_release_pipeline(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2594,6 +2880,7 @@ impl LinearMcp {
     #[tool(
         name = "update_release_pipeline",
         description = "Update a release pipeline.",
+        output_schema = output::schema::<types::ReleasePipelineFull>(),
         annotations(destructive_hint = false)
     )]
     async fn update_release_pipeline(
@@ -2601,7 +2888,7 @@ impl LinearMcp {
         Parameters(params): Parameters<update_release_pipeline::UpdateReleasePipelineParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_update_release_pipeline(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2609,6 +2896,7 @@ impl LinearMcp {
     #[tool(
         name = "delete_release_pipeline",
         description = "Delete a release pipeline.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = true)
     )]
     async fn delete_release_pipeline(
@@ -2616,7 +2904,7 @@ impl LinearMcp {
         Parameters(params): Parameters<delete_release_pipeline::DeleteReleasePipelineParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_delete_release_pipeline(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2626,6 +2914,7 @@ impl LinearMcp {
     #[tool(
         name = "list_release_stages",
         description = "List all release stages.",
+        output_schema = output::schema::<types::NodeList<types::ReleaseStageFull>>(),
         annotations(read_only_hint = true)
     )]
     async fn list_release_stages(
@@ -2633,7 +2922,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_release_stages::ListReleaseStagesParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_release_stages(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2641,6 +2930,7 @@ impl LinearMcp {
     #[tool(
         name = "get_release_stage",
         description = "Get a release stage by UUID.",
+        output_schema = output::schema::<types::ReleaseStageFull>(),
         annotations(read_only_hint = true)
     )]
     async fn get_release_stage(
@@ -2648,7 +2938,7 @@ impl LinearMcp {
         Parameters(params): Parameters<get_release_stage::GetReleaseStageParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_release_stage(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2656,6 +2946,7 @@ impl LinearMcp {
     #[tool(
         name = "create_release_stage",
         description = "Create a new release stage in a pipeline.",
+        output_schema = output::schema::<types::ReleaseStageFull>(),
         annotations(destructive_hint = false)
     )]
     async fn create_release_stage(
@@ -2663,7 +2954,7 @@ impl LinearMcp {
         Parameters(params): Parameters<create_release_stage::CreateReleaseStageParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_create_release_stage(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2671,6 +2962,7 @@ impl LinearMcp {
     #[tool(
         name = "update_release_stage",
         description = "Update a release stage.",
+        output_schema = output::schema::<types::ReleaseStageFull>(),
         annotations(destructive_hint = false)
     )]
     async fn update_release_stage(
@@ -2678,7 +2970,7 @@ impl LinearMcp {
         Parameters(params): Parameters<update_release_stage::UpdateReleaseStageParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_update_release_stage(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2688,6 +2980,7 @@ impl LinearMcp {
     #[tool(
         name = "list_issue_to_releases",
         description = "List issue-to-release links.",
+        output_schema = output::schema::<types::NodeList<types::IssueToRelease>>(),
         annotations(read_only_hint = true)
     )]
     async fn list_issue_to_releases(
@@ -2695,7 +2988,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_issue_to_releases::ListIssueToReleasesParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_issue_to_releases(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2703,6 +2996,7 @@ impl LinearMcp {
     #[tool(
         name = "get_issue_to_release",
         description = "Get an issue-to-release link by UUID.",
+        output_schema = output::schema::<types::IssueToRelease>(),
         annotations(read_only_hint = true)
     )]
     async fn get_issue_to_release(
@@ -2710,7 +3004,7 @@ impl LinearMcp {
         Parameters(params): Parameters<get_issue_to_release::GetIssueToReleaseParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_issue_to_release(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2718,6 +3012,7 @@ impl LinearMcp {
     #[tool(
         name = "add_issue_to_release",
         description = "Add an issue to a release.",
+        output_schema = output::schema::<types::IssueToReleaseMutationResult>(),
         annotations(destructive_hint = false)
     )]
     async fn add_issue_to_release(
@@ -2725,7 +3020,7 @@ impl LinearMcp {
         Parameters(params): Parameters<add_issue_to_release::AddIssueToReleaseParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_add_issue_to_release(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2733,6 +3028,7 @@ impl LinearMcp {
     #[tool(
         name = "remove_issue_from_release",
         description = "Remove an issue from a release.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = true)
     )]
     async fn remove_issue_from_release(
@@ -2740,7 +3036,7 @@ impl LinearMcp {
         Parameters(params): Parameters<remove_issue_from_release::RemoveIssueFromReleaseParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_remove_issue_from_release(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2750,6 +3046,7 @@ impl LinearMcp {
     #[tool(
         name = "list_project_statuses",
         description = "List all project statuses.",
+        output_schema = output::schema::<types::NodeList<types::ProjectStatusFull>>(),
         annotations(read_only_hint = true)
     )]
     async fn list_project_statuses(
@@ -2757,7 +3054,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_project_statuses::ListProjectStatusesParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_project_statuses(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2765,6 +3062,7 @@ impl LinearMcp {
     #[tool(
         name = "get_project_status",
         description = "Get a project status by UUID.",
+        output_schema = output::schema::<types::ProjectStatusFull>(),
         annotations(read_only_hint = true)
     )]
     async fn get_project_status(
@@ -2772,7 +3070,7 @@ impl LinearMcp {
         Parameters(params): Parameters<get_project_status::GetProjectStatusParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_project_status(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2780,6 +3078,7 @@ impl LinearMcp {
     #[tool(
         name = "create_project_status",
         description = "Create a new project status.",
+        output_schema = output::schema::<types::ProjectStatusFull>(),
         annotations(destructive_hint = false)
     )]
     async fn create_project_status(
@@ -2787,7 +3086,7 @@ impl LinearMcp {
         Parameters(params): Parameters<create_project_status::CreateProjectStatusParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_create_project_status(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2795,6 +3094,7 @@ impl LinearMcp {
     #[tool(
         name = "update_project_status",
         description = "Update a project status.",
+        output_schema = output::schema::<types::ProjectStatusFull>(),
         annotations(destructive_hint = false)
     )]
     async fn update_project_status(
@@ -2802,7 +3102,7 @@ impl LinearMcp {
         Parameters(params): Parameters<update_project_status::UpdateProjectStatusParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_update_project_status(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2810,6 +3110,7 @@ impl LinearMcp {
     #[tool(
         name = "archive_project_status",
         description = "Archive a project status.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = true)
     )]
     async fn archive_project_status(
@@ -2817,7 +3118,7 @@ impl LinearMcp {
         Parameters(params): Parameters<archive_project_status::ArchiveProjectStatusParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_archive_project_status(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2825,6 +3126,7 @@ impl LinearMcp {
     #[tool(
         name = "unarchive_project_status",
         description = "Unarchive a project status.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = false)
     )]
     async fn unarchive_project_status(
@@ -2832,7 +3134,7 @@ impl LinearMcp {
         Parameters(params): Parameters<unarchive_project_status::UnarchiveProjectStatusParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_unarchive_project_status(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2842,6 +3144,7 @@ impl LinearMcp {
     #[tool(
         name = "list_project_labels",
         description = "List all project labels.",
+        output_schema = output::schema::<types::NodeList<types::ProjectLabel>>(),
         annotations(read_only_hint = true)
     )]
     async fn list_project_labels(
@@ -2849,7 +3152,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_project_labels::ListProjectLabelsParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_project_labels(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2857,6 +3160,7 @@ impl LinearMcp {
     #[tool(
         name = "get_project_label",
         description = "Get a project label by UUID.",
+        output_schema = output::schema::<types::ProjectLabel>(),
         annotations(read_only_hint = true)
     )]
     async fn get_project_label(
@@ -2864,7 +3168,7 @@ impl LinearMcp {
         Parameters(params): Parameters<get_project_label::GetProjectLabelParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_project_label(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2872,6 +3176,7 @@ impl LinearMcp {
     #[tool(
         name = "create_project_label",
         description = "Create a new project label.",
+        output_schema = output::schema::<types::ProjectLabel>(),
         annotations(destructive_hint = false)
     )]
     async fn create_project_label(
@@ -2879,7 +3184,7 @@ impl LinearMcp {
         Parameters(params): Parameters<create_project_label::CreateProjectLabelParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_create_project_label(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2887,6 +3192,7 @@ impl LinearMcp {
     #[tool(
         name = "update_project_label",
         description = "Update a project label.",
+        output_schema = output::schema::<types::ProjectLabel>(),
         annotations(destructive_hint = false)
     )]
     async fn update_project_label(
@@ -2894,7 +3200,7 @@ impl LinearMcp {
         Parameters(params): Parameters<update_project_label::UpdateProjectLabelParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_update_project_label(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2902,6 +3208,7 @@ impl LinearMcp {
     #[tool(
         name = "delete_project_label",
         description = "Delete a project label.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = true)
     )]
     async fn delete_project_label(
@@ -2909,7 +3216,7 @@ impl LinearMcp {
         Parameters(params): Parameters<delete_project_label::DeleteProjectLabelParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_delete_project_label(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2919,6 +3226,7 @@ impl LinearMcp {
     #[tool(
         name = "list_team_memberships",
         description = "List team memberships.",
+        output_schema = output::schema::<types::NodeList<types::TeamMembership>>(),
         annotations(read_only_hint = true)
     )]
     async fn list_team_memberships(
@@ -2926,7 +3234,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_team_memberships::ListTeamMembershipsParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_team_memberships(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2934,6 +3242,7 @@ impl LinearMcp {
     #[tool(
         name = "get_team_membership",
         description = "Get a team membership by UUID.",
+        output_schema = output::schema::<types::TeamMembership>(),
         annotations(read_only_hint = true)
     )]
     async fn get_team_membership(
@@ -2941,7 +3250,7 @@ impl LinearMcp {
         Parameters(params): Parameters<get_team_membership::GetTeamMembershipParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_team_membership(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2949,6 +3258,7 @@ impl LinearMcp {
     #[tool(
         name = "create_team_membership",
         description = "Add a user to a team.",
+        output_schema = output::schema::<types::TeamMembership>(),
         annotations(destructive_hint = false)
     )]
     async fn create_team_membership(
@@ -2956,7 +3266,7 @@ impl LinearMcp {
         Parameters(params): Parameters<create_team_membership::CreateTeamMembershipParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_create_team_membership(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2964,6 +3274,7 @@ impl LinearMcp {
     #[tool(
         name = "update_team_membership",
         description = "Update a team membership (e.g. toggle owner).",
+        output_schema = output::schema::<types::TeamMembership>(),
         annotations(destructive_hint = false)
     )]
     async fn update_team_membership(
@@ -2971,7 +3282,7 @@ impl LinearMcp {
         Parameters(params): Parameters<update_team_membership::UpdateTeamMembershipParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_update_team_membership(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2979,6 +3290,7 @@ impl LinearMcp {
     #[tool(
         name = "delete_team_membership",
         description = "Remove a user from a team.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = true)
     )]
     async fn delete_team_membership(
@@ -2986,7 +3298,7 @@ impl LinearMcp {
         Parameters(params): Parameters<delete_team_membership::DeleteTeamMembershipParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_delete_team_membership(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -2996,14 +3308,17 @@ impl LinearMcp {
     #[tool(
         name = "list_notification_subscriptions",
         description = "List notification subscriptions.",
+        output_schema = output::schema::<types::NodeList<types::NotificationSubscription>>(),
         annotations(read_only_hint = true)
     )]
     async fn list_notification_subscriptions(
         &self,
-        Parameters(params): Parameters<list_notification_subscriptions::ListNotificationSubscriptionsParams>,
+        Parameters(params): Parameters<
+            list_notification_subscriptions::ListNotificationSubscriptionsParams,
+        >,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_notification_subscriptions(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3011,14 +3326,17 @@ impl LinearMcp {
     #[tool(
         name = "get_notification_subscription",
         description = "Get a notification subscription by UUID.",
+        output_schema = output::schema::<types::NotificationSubscription>(),
         annotations(read_only_hint = true)
     )]
     async fn get_notification_subscription(
         &self,
-        Parameters(params): Parameters<get_notification_subscription::GetNotificationSubscriptionParams>,
+        Parameters(params): Parameters<
+            get_notification_subscription::GetNotificationSubscriptionParams,
+        >,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_notification_subscription(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3026,14 +3344,17 @@ impl LinearMcp {
     #[tool(
         name = "create_notification_subscription",
         description = "Create a notification subscription for a team, project, or label.",
+        output_schema = output::schema::<types::NotificationSubscription>(),
         annotations(destructive_hint = false)
     )]
     async fn create_notification_subscription(
         &self,
-        Parameters(params): Parameters<create_notification_subscription::CreateNotificationSubscriptionParams>,
+        Parameters(params): Parameters<
+            create_notification_subscription::CreateNotificationSubscriptionParams,
+        >,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_create_notification_subscription(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3041,14 +3362,17 @@ impl LinearMcp {
     #[tool(
         name = "update_notification_subscription",
         description = "Update a notification subscription.",
+        output_schema = output::schema::<types::NotificationSubscription>(),
         annotations(destructive_hint = false)
     )]
     async fn update_notification_subscription(
         &self,
-        Parameters(params): Parameters<update_notification_subscription::UpdateNotificationSubscriptionParams>,
+        Parameters(params): Parameters<
+            update_notification_subscription::UpdateNotificationSubscriptionParams,
+        >,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_update_notification_subscription(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3056,14 +3380,17 @@ impl LinearMcp {
     #[tool(
         name = "get_notifications_unread_count",
         description = "Get the count of unread notifications.",
+        output_schema = output::schema::<response::NotificationsUnreadCountData>(),
         annotations(read_only_hint = true)
     )]
     async fn get_notifications_unread_count(
         &self,
-        Parameters(_params): Parameters<get_notifications_unread_count::GetNotificationsUnreadCountParams>,
+        Parameters(_params): Parameters<
+            get_notifications_unread_count::GetNotificationsUnreadCountParams,
+        >,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_notifications_unread_count().await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3073,6 +3400,7 @@ impl LinearMcp {
     #[tool(
         name = "get_template",
         description = "Get a template by UUID.",
+        output_schema = output::schema::<types::Template>(),
         annotations(read_only_hint = true)
     )]
     async fn get_template(
@@ -3080,7 +3408,7 @@ impl LinearMcp {
         Parameters(params): Parameters<get_template::GetTemplateParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_template(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3088,6 +3416,7 @@ impl LinearMcp {
     #[tool(
         name = "create_template",
         description = "Create a new template (issue, project, or document).",
+        output_schema = output::schema::<types::Template>(),
         annotations(destructive_hint = false)
     )]
     async fn create_template(
@@ -3095,7 +3424,7 @@ impl LinearMcp {
         Parameters(params): Parameters<create_template::CreateTemplateParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_create_template(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3103,6 +3432,7 @@ impl LinearMcp {
     #[tool(
         name = "update_template",
         description = "Update an existing template.",
+        output_schema = output::schema::<types::Template>(),
         annotations(destructive_hint = false)
     )]
     async fn update_template(
@@ -3110,7 +3440,7 @@ impl LinearMcp {
         Parameters(params): Parameters<update_template::UpdateTemplateParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_update_template(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3118,6 +3448,7 @@ impl LinearMcp {
     #[tool(
         name = "delete_template",
         description = "Delete a template.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = true)
     )]
     async fn delete_template(
@@ -3125,7 +3456,7 @@ impl LinearMcp {
         Parameters(params): Parameters<delete_template::DeleteTemplateParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_delete_template(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3135,6 +3466,7 @@ impl LinearMcp {
     #[tool(
         name = "get_entity_external_link",
         description = "Get an entity external link by UUID.",
+        output_schema = output::schema::<types::EntityExternalLink>(),
         annotations(read_only_hint = true)
     )]
     async fn get_entity_external_link(
@@ -3142,7 +3474,7 @@ impl LinearMcp {
         Parameters(params): Parameters<get_entity_external_link::GetEntityExternalLinkParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_entity_external_link(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3150,6 +3482,7 @@ impl LinearMcp {
     #[tool(
         name = "create_entity_external_link",
         description = "Create an external link on an initiative, project, or team.",
+        output_schema = output::schema::<types::EntityExternalLink>(),
         annotations(destructive_hint = false)
     )]
     async fn create_entity_external_link(
@@ -3157,7 +3490,7 @@ impl LinearMcp {
         Parameters(params): Parameters<create_entity_external_link::CreateEntityExternalLinkParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_create_entity_external_link(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3165,6 +3498,7 @@ impl LinearMcp {
     #[tool(
         name = "update_entity_external_link",
         description = "Update an entity external link.",
+        output_schema = output::schema::<types::EntityExternalLink>(),
         annotations(destructive_hint = false)
     )]
     async fn update_entity_external_link(
@@ -3172,7 +3506,7 @@ impl LinearMcp {
         Parameters(params): Parameters<update_entity_external_link::UpdateEntityExternalLinkParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_update_entity_external_link(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3180,6 +3514,7 @@ impl LinearMcp {
     #[tool(
         name = "delete_entity_external_link",
         description = "Delete an entity external link.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = true)
     )]
     async fn delete_entity_external_link(
@@ -3187,7 +3522,7 @@ impl LinearMcp {
         Parameters(params): Parameters<delete_entity_external_link::DeleteEntityExternalLinkParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_delete_entity_external_link(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3197,6 +3532,7 @@ impl LinearMcp {
     #[tool(
         name = "list_emojis",
         description = "List custom emojis.",
+        output_schema = output::schema::<types::NodeList<types::Emoji>>(),
         annotations(read_only_hint = true)
     )]
     async fn list_emojis(
@@ -3204,7 +3540,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_emojis::ListEmojisParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_emojis(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3212,6 +3548,7 @@ impl LinearMcp {
     #[tool(
         name = "get_emoji",
         description = "Get a custom emoji by UUID.",
+        output_schema = output::schema::<types::Emoji>(),
         annotations(read_only_hint = true)
     )]
     async fn get_emoji(
@@ -3219,7 +3556,7 @@ impl LinearMcp {
         Parameters(params): Parameters<get_emoji::GetEmojiParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_emoji(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3227,6 +3564,7 @@ impl LinearMcp {
     #[tool(
         name = "create_emoji",
         description = "Create a custom emoji.",
+        output_schema = output::schema::<types::Emoji>(),
         annotations(destructive_hint = false)
     )]
     async fn create_emoji(
@@ -3234,7 +3572,7 @@ impl LinearMcp {
         Parameters(params): Parameters<create_emoji::CreateEmojiParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_create_emoji(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3242,6 +3580,7 @@ impl LinearMcp {
     #[tool(
         name = "delete_emoji",
         description = "Delete a custom emoji.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = true)
     )]
     async fn delete_emoji(
@@ -3249,7 +3588,7 @@ impl LinearMcp {
         Parameters(params): Parameters<delete_emoji::DeleteEmojiParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_delete_emoji(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3259,6 +3598,7 @@ impl LinearMcp {
     #[tool(
         name = "list_initiative_relations",
         description = "List initiative relations.",
+        output_schema = output::schema::<types::NodeList<types::InitiativeRelation>>(),
         annotations(read_only_hint = true)
     )]
     async fn list_initiative_relations(
@@ -3266,7 +3606,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_initiative_relations::ListInitiativeRelationsParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_initiative_relations(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3274,6 +3614,7 @@ impl LinearMcp {
     #[tool(
         name = "get_initiative_relation",
         description = "Get an initiative relation by UUID.",
+        output_schema = output::schema::<types::InitiativeRelation>(),
         annotations(read_only_hint = true)
     )]
     async fn get_initiative_relation(
@@ -3281,7 +3622,7 @@ impl LinearMcp {
         Parameters(params): Parameters<get_initiative_relation::GetInitiativeRelationParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_initiative_relation(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3289,6 +3630,7 @@ impl LinearMcp {
     #[tool(
         name = "create_initiative_relation",
         description = "Create a relation between two initiatives.",
+        output_schema = output::schema::<types::InitiativeRelation>(),
         annotations(destructive_hint = false)
     )]
     async fn create_initiative_relation(
@@ -3296,7 +3638,7 @@ impl LinearMcp {
         Parameters(params): Parameters<create_initiative_relation::CreateInitiativeRelationParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_create_initiative_relation(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3304,6 +3646,7 @@ impl LinearMcp {
     #[tool(
         name = "update_initiative_relation",
         description = "Update an initiative relation's sort order.",
+        output_schema = output::schema::<types::InitiativeRelation>(),
         annotations(destructive_hint = false)
     )]
     async fn update_initiative_relation(
@@ -3311,7 +3654,7 @@ impl LinearMcp {
         Parameters(params): Parameters<update_initiative_relation::UpdateInitiativeRelationParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_update_initiative_relation(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3319,6 +3662,7 @@ impl LinearMcp {
     #[tool(
         name = "delete_initiative_relation",
         description = "Delete an initiative relation.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = true)
     )]
     async fn delete_initiative_relation(
@@ -3326,7 +3670,7 @@ impl LinearMcp {
         Parameters(params): Parameters<delete_initiative_relation::DeleteInitiativeRelationParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_delete_initiative_relation(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3336,6 +3680,7 @@ impl LinearMcp {
     #[tool(
         name = "list_time_schedules",
         description = "List time schedules.",
+        output_schema = output::schema::<types::NodeList<types::TimeSchedule>>(),
         annotations(read_only_hint = true)
     )]
     async fn list_time_schedules(
@@ -3343,7 +3688,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_time_schedules::ListTimeSchedulesParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_time_schedules(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3351,6 +3696,7 @@ impl LinearMcp {
     #[tool(
         name = "get_time_schedule",
         description = "Get a time schedule by UUID.",
+        output_schema = output::schema::<types::TimeSchedule>(),
         annotations(read_only_hint = true)
     )]
     async fn get_time_schedule(
@@ -3358,7 +3704,7 @@ impl LinearMcp {
         Parameters(params): Parameters<get_time_schedule::GetTimeScheduleParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_time_schedule(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3366,6 +3712,7 @@ impl LinearMcp {
     #[tool(
         name = "create_time_schedule",
         description = "Create a new time schedule.",
+        output_schema = output::schema::<types::TimeSchedule>(),
         annotations(destructive_hint = false)
     )]
     async fn create_time_schedule(
@@ -3373,7 +3720,7 @@ impl LinearMcp {
         Parameters(params): Parameters<create_time_schedule::CreateTimeScheduleParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_create_time_schedule(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3381,6 +3728,7 @@ impl LinearMcp {
     #[tool(
         name = "update_time_schedule",
         description = "Update a time schedule.",
+        output_schema = output::schema::<types::TimeSchedule>(),
         annotations(destructive_hint = false)
     )]
     async fn update_time_schedule(
@@ -3388,7 +3736,7 @@ impl LinearMcp {
         Parameters(params): Parameters<update_time_schedule::UpdateTimeScheduleParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_update_time_schedule(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3396,6 +3744,7 @@ impl LinearMcp {
     #[tool(
         name = "delete_time_schedule",
         description = "Delete a time schedule.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = true)
     )]
     async fn delete_time_schedule(
@@ -3403,7 +3752,7 @@ impl LinearMcp {
         Parameters(params): Parameters<delete_time_schedule::DeleteTimeScheduleParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_delete_time_schedule(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3413,14 +3762,17 @@ impl LinearMcp {
     #[tool(
         name = "list_triage_responsibilities",
         description = "List triage responsibilities.",
+        output_schema = output::schema::<types::NodeList<types::TriageResponsibility>>(),
         annotations(read_only_hint = true)
     )]
     async fn list_triage_responsibilities(
         &self,
-        Parameters(params): Parameters<list_triage_responsibilities::ListTriageResponsibilitiesParams>,
+        Parameters(params): Parameters<
+            list_triage_responsibilities::ListTriageResponsibilitiesParams,
+        >,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_triage_responsibilities(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3428,6 +3780,7 @@ impl LinearMcp {
     #[tool(
         name = "get_triage_responsibility",
         description = "Get a triage responsibility by UUID.",
+        output_schema = output::schema::<types::TriageResponsibility>(),
         annotations(read_only_hint = true)
     )]
     async fn get_triage_responsibility(
@@ -3435,7 +3788,7 @@ impl LinearMcp {
         Parameters(params): Parameters<get_triage_responsibility::GetTriageResponsibilityParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_triage_responsibility(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3443,14 +3796,17 @@ impl LinearMcp {
     #[tool(
         name = "create_triage_responsibility",
         description = "Create a triage responsibility for a team.",
+        output_schema = output::schema::<types::TriageResponsibility>(),
         annotations(destructive_hint = false)
     )]
     async fn create_triage_responsibility(
         &self,
-        Parameters(params): Parameters<create_triage_responsibility::CreateTriageResponsibilityParams>,
+        Parameters(params): Parameters<
+            create_triage_responsibility::CreateTriageResponsibilityParams,
+        >,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_create_triage_responsibility(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3458,14 +3814,17 @@ impl LinearMcp {
     #[tool(
         name = "update_triage_responsibility",
         description = "Update a triage responsibility.",
+        output_schema = output::schema::<types::TriageResponsibility>(),
         annotations(destructive_hint = false)
     )]
     async fn update_triage_responsibility(
         &self,
-        Parameters(params): Parameters<update_triage_responsibility::UpdateTriageResponsibilityParams>,
+        Parameters(params): Parameters<
+            update_triage_responsibility::UpdateTriageResponsibilityParams,
+        >,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_update_triage_responsibility(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3473,14 +3832,17 @@ impl LinearMcp {
     #[tool(
         name = "delete_triage_responsibility",
         description = "Delete a triage responsibility.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = true)
     )]
     async fn delete_triage_responsibility(
         &self,
-        Parameters(params): Parameters<delete_triage_responsibility::DeleteTriageResponsibilityParams>,
+        Parameters(params): Parameters<
+            delete_triage_responsibility::DeleteTriageResponsibilityParams,
+        >,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_delete_triage_responsibility(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3490,6 +3852,7 @@ impl LinearMcp {
     #[tool(
         name = "create_git_automation_state",
         description = "Create a git automation state mapping for a team.",
+        output_schema = output::schema::<types::GitAutomationState>(),
         annotations(destructive_hint = false)
     )]
     async fn create_git_automation_state(
@@ -3497,7 +3860,7 @@ impl LinearMcp {
         Parameters(params): Parameters<create_git_automation_state::CreateGitAutomationStateParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_create_git_automation_state(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3505,6 +3868,7 @@ impl LinearMcp {
     #[tool(
         name = "update_git_automation_state",
         description = "Update a git automation state mapping.",
+        output_schema = output::schema::<types::GitAutomationState>(),
         annotations(destructive_hint = false)
     )]
     async fn update_git_automation_state(
@@ -3512,7 +3876,7 @@ impl LinearMcp {
         Parameters(params): Parameters<update_git_automation_state::UpdateGitAutomationStateParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_update_git_automation_state(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3520,6 +3884,7 @@ impl LinearMcp {
     #[tool(
         name = "delete_git_automation_state",
         description = "Delete a git automation state mapping.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = true)
     )]
     async fn delete_git_automation_state(
@@ -3527,7 +3892,7 @@ impl LinearMcp {
         Parameters(params): Parameters<delete_git_automation_state::DeleteGitAutomationStateParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_delete_git_automation_state(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3535,14 +3900,20 @@ impl LinearMcp {
     #[tool(
         name = "create_git_automation_target_branch",
         description = "Create a git automation target branch pattern for a team.",
+        output_schema = output::schema::<types::GitAutomationTargetBranch>(),
         annotations(destructive_hint = false)
     )]
     async fn create_git_automation_target_branch(
         &self,
-        Parameters(params): Parameters<create_git_automation_target_branch::CreateGitAutomationTargetBranchParams>,
+        Parameters(params): Parameters<
+            create_git_automation_target_branch::CreateGitAutomationTargetBranchParams,
+        >,
     ) -> Result<CallToolResult, McpError> {
-        match self.handle_create_git_automation_target_branch(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+        match self
+            .handle_create_git_automation_target_branch(params)
+            .await
+        {
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3550,14 +3921,20 @@ impl LinearMcp {
     #[tool(
         name = "update_git_automation_target_branch",
         description = "Update a git automation target branch pattern.",
+        output_schema = output::schema::<types::GitAutomationTargetBranch>(),
         annotations(destructive_hint = false)
     )]
     async fn update_git_automation_target_branch(
         &self,
-        Parameters(params): Parameters<update_git_automation_target_branch::UpdateGitAutomationTargetBranchParams>,
+        Parameters(params): Parameters<
+            update_git_automation_target_branch::UpdateGitAutomationTargetBranchParams,
+        >,
     ) -> Result<CallToolResult, McpError> {
-        match self.handle_update_git_automation_target_branch(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+        match self
+            .handle_update_git_automation_target_branch(params)
+            .await
+        {
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3565,14 +3942,20 @@ impl LinearMcp {
     #[tool(
         name = "delete_git_automation_target_branch",
         description = "Delete a git automation target branch pattern.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = true)
     )]
     async fn delete_git_automation_target_branch(
         &self,
-        Parameters(params): Parameters<delete_git_automation_target_branch::DeleteGitAutomationTargetBranchParams>,
+        Parameters(params): Parameters<
+            delete_git_automation_target_branch::DeleteGitAutomationTargetBranchParams,
+        >,
     ) -> Result<CallToolResult, McpError> {
-        match self.handle_delete_git_automation_target_branch(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+        match self
+            .handle_delete_git_automation_target_branch(params)
+            .await
+        {
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3582,6 +3965,7 @@ impl LinearMcp {
     #[tool(
         name = "get_email_intake_address",
         description = "Get an email intake address by UUID.",
+        output_schema = output::schema::<types::EmailIntakeAddress>(),
         annotations(read_only_hint = true)
     )]
     async fn get_email_intake_address(
@@ -3589,7 +3973,7 @@ impl LinearMcp {
         Parameters(params): Parameters<get_email_intake_address::GetEmailIntakeAddressParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_email_intake_address(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3597,6 +3981,7 @@ impl LinearMcp {
     #[tool(
         name = "create_email_intake_address",
         description = "Create an email intake address.",
+        output_schema = output::schema::<types::EmailIntakeAddress>(),
         annotations(destructive_hint = false)
     )]
     async fn create_email_intake_address(
@@ -3604,7 +3989,7 @@ impl LinearMcp {
         Parameters(params): Parameters<create_email_intake_address::CreateEmailIntakeAddressParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_create_email_intake_address(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3612,6 +3997,7 @@ impl LinearMcp {
     #[tool(
         name = "update_email_intake_address",
         description = "Update an email intake address.",
+        output_schema = output::schema::<types::EmailIntakeAddress>(),
         annotations(destructive_hint = false)
     )]
     async fn update_email_intake_address(
@@ -3619,7 +4005,7 @@ impl LinearMcp {
         Parameters(params): Parameters<update_email_intake_address::UpdateEmailIntakeAddressParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_update_email_intake_address(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3627,6 +4013,7 @@ impl LinearMcp {
     #[tool(
         name = "delete_email_intake_address",
         description = "Delete an email intake address.",
+        output_schema = output::schema::<types::SuccessResult>(),
         annotations(destructive_hint = true)
     )]
     async fn delete_email_intake_address(
@@ -3634,7 +4021,7 @@ impl LinearMcp {
         Parameters(params): Parameters<delete_email_intake_address::DeleteEmailIntakeAddressParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_delete_email_intake_address(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3644,6 +4031,7 @@ impl LinearMcp {
     #[tool(
         name = "list_archived_teams",
         description = "List archived teams.",
+        output_schema = output::schema::<types::NodeList<types::TeamDetail>>(),
         annotations(read_only_hint = true)
     )]
     async fn list_archived_teams(
@@ -3651,7 +4039,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_archived_teams::ListArchivedTeamsParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_archived_teams(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3659,6 +4047,7 @@ impl LinearMcp {
     #[tool(
         name = "get_rate_limit_status",
         description = "Get current API rate limit status.",
+        output_schema = output::schema::<types::RateLimitStatus>(),
         annotations(read_only_hint = true)
     )]
     async fn get_rate_limit_status(
@@ -3666,7 +4055,7 @@ impl LinearMcp {
         Parameters(_params): Parameters<get_rate_limit_status::GetRateLimitStatusParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_rate_limit_status().await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3674,6 +4063,7 @@ impl LinearMcp {
     #[tool(
         name = "get_organization",
         description = "Get the current organization's info.",
+        output_schema = output::schema::<types::Organization>(),
         annotations(read_only_hint = true)
     )]
     async fn get_organization(
@@ -3681,7 +4071,7 @@ impl LinearMcp {
         Parameters(_params): Parameters<get_organization::GetOrganizationParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_organization().await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3689,6 +4079,7 @@ impl LinearMcp {
     #[tool(
         name = "get_application_info",
         description = "Get the current API application's info.",
+        output_schema = output::schema::<types::ApplicationInfo>(),
         annotations(read_only_hint = true)
     )]
     async fn get_application_info(
@@ -3696,7 +4087,7 @@ impl LinearMcp {
         Parameters(params): Parameters<get_application_info::GetApplicationInfoParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_application_info(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3704,6 +4095,7 @@ impl LinearMcp {
     #[tool(
         name = "semantic_search",
         description = "Semantic search across Linear entities.",
+        output_schema = output::schema::<response::SemanticSearchPayload>(),
         annotations(read_only_hint = true)
     )]
     async fn semantic_search(
@@ -3711,7 +4103,7 @@ impl LinearMcp {
         Parameters(params): Parameters<semantic_search::SemanticSearchParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_semantic_search(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3719,6 +4111,7 @@ impl LinearMcp {
     #[tool(
         name = "attach_link_url",
         description = "Attach a URL to an issue.",
+        output_schema = output::schema::<types::AttachmentMutationResult>(),
         annotations(destructive_hint = false)
     )]
     async fn attach_link_url(
@@ -3726,7 +4119,7 @@ impl LinearMcp {
         Parameters(params): Parameters<attach_link_url::AttachLinkUrlParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_attach_link_url(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3734,6 +4127,7 @@ impl LinearMcp {
     #[tool(
         name = "get_attachments_for_url",
         description = "Get all attachments matching a URL.",
+        output_schema = output::schema::<types::NodeList<types::Attachment>>(),
         annotations(read_only_hint = true)
     )]
     async fn get_attachments_for_url(
@@ -3741,7 +4135,7 @@ impl LinearMcp {
         Parameters(params): Parameters<get_attachments_for_url::GetAttachmentsForUrlParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_attachments_for_url(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3749,6 +4143,7 @@ impl LinearMcp {
     #[tool(
         name = "get_issue_filter_suggestion",
         description = "Get AI-powered issue filter suggestion from a natural language prompt.",
+        output_schema = output::schema::<response::FilterSuggestionResult>(),
         annotations(read_only_hint = true)
     )]
     async fn get_issue_filter_suggestion(
@@ -3756,7 +4151,7 @@ impl LinearMcp {
         Parameters(params): Parameters<get_issue_filter_suggestion::GetIssueFilterSuggestionParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_issue_filter_suggestion(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3764,14 +4159,17 @@ impl LinearMcp {
     #[tool(
         name = "get_project_filter_suggestion",
         description = "Get AI-powered project filter suggestion from a natural language prompt.",
+        output_schema = output::schema::<response::FilterSuggestionResult>(),
         annotations(read_only_hint = true)
     )]
     async fn get_project_filter_suggestion(
         &self,
-        Parameters(params): Parameters<get_project_filter_suggestion::GetProjectFilterSuggestionParams>,
+        Parameters(params): Parameters<
+            get_project_filter_suggestion::GetProjectFilterSuggestionParams,
+        >,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_project_filter_suggestion(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3779,6 +4177,7 @@ impl LinearMcp {
     #[tool(
         name = "get_custom_view_suggestion",
         description = "Get AI-powered custom view suggestion from a natural language prompt.",
+        output_schema = output::schema::<response::CustomViewSuggestionResult>(),
         annotations(read_only_hint = true)
     )]
     async fn get_custom_view_suggestion(
@@ -3786,7 +4185,7 @@ impl LinearMcp {
         Parameters(params): Parameters<get_custom_view_suggestion::GetCustomViewSuggestionParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_custom_view_suggestion(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3794,14 +4193,17 @@ impl LinearMcp {
     #[tool(
         name = "check_custom_view_has_subscribers",
         description = "Check if a custom view has subscribers.",
+        output_schema = output::schema::<response::CustomViewHasSubscribersPayload>(),
         annotations(read_only_hint = true)
     )]
     async fn check_custom_view_has_subscribers(
         &self,
-        Parameters(params): Parameters<check_custom_view_has_subscribers::CheckCustomViewHasSubscribersParams>,
+        Parameters(params): Parameters<
+            check_custom_view_has_subscribers::CheckCustomViewHasSubscribersParams,
+        >,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_check_custom_view_has_subscribers(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3809,6 +4211,7 @@ impl LinearMcp {
     #[tool(
         name = "search_issue_figma_file_key",
         description = "Search for issues linked to a Figma file key.",
+        output_schema = output::schema::<types::NodeList<types::Issue>>(),
         annotations(read_only_hint = true)
     )]
     async fn search_issue_figma_file_key(
@@ -3816,7 +4219,7 @@ impl LinearMcp {
         Parameters(params): Parameters<search_issue_figma_file_key::SearchIssueFigmaFileKeyParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_search_issue_figma_file_key(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3824,6 +4227,7 @@ impl LinearMcp {
     #[tool(
         name = "update_initiative_update",
         description = "Update an initiative update's body or health.",
+        output_schema = output::schema::<types::InitiativeUpdateMutationResult>(),
         annotations(destructive_hint = false)
     )]
     async fn update_initiative_update(
@@ -3831,7 +4235,7 @@ impl LinearMcp {
         Parameters(params): Parameters<update_initiative_update::UpdateInitiativeUpdateParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_update_initiative_update(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3839,6 +4243,7 @@ impl LinearMcp {
     #[tool(
         name = "list_comments_all",
         description = "List all comments globally (not per-issue).",
+        output_schema = output::schema::<types::NodeList<types::Comment>>(),
         annotations(read_only_hint = true)
     )]
     async fn list_comments_all(
@@ -3846,7 +4251,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_comments_all::ListCommentsAllParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_comments_all(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3854,6 +4259,7 @@ impl LinearMcp {
     #[tool(
         name = "get_issue_label",
         description = "Get an issue label by UUID.",
+        output_schema = output::schema::<types::Label>(),
         annotations(read_only_hint = true)
     )]
     async fn get_issue_label(
@@ -3861,7 +4267,7 @@ impl LinearMcp {
         Parameters(params): Parameters<get_issue_label::GetIssueLabelParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_issue_label(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3869,6 +4275,7 @@ impl LinearMcp {
     #[tool(
         name = "get_issue_relation",
         description = "Get an issue relation by UUID.",
+        output_schema = output::schema::<types::IssueRelation>(),
         annotations(read_only_hint = true)
     )]
     async fn get_issue_relation(
@@ -3876,7 +4283,7 @@ impl LinearMcp {
         Parameters(params): Parameters<get_issue_relation::GetIssueRelationParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_get_issue_relation(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3884,6 +4291,7 @@ impl LinearMcp {
     #[tool(
         name = "list_issue_relations",
         description = "List all issue relations.",
+        output_schema = output::schema::<types::NodeList<types::IssueRelation>>(),
         annotations(read_only_hint = true)
     )]
     async fn list_issue_relations(
@@ -3891,7 +4299,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_issue_relations::ListIssueRelationsParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_issue_relations(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3899,6 +4307,7 @@ impl LinearMcp {
     #[tool(
         name = "list_external_users",
         description = "List external users.",
+        output_schema = output::schema::<types::NodeList<types::ExternalUser>>(),
         annotations(read_only_hint = true)
     )]
     async fn list_external_users(
@@ -3906,7 +4315,7 @@ impl LinearMcp {
         Parameters(params): Parameters<list_external_users::ListExternalUsersParams>,
     ) -> Result<CallToolResult, McpError> {
         match self.handle_list_external_users(params).await {
-            Ok(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            Ok(output) => Ok(output.into_result()),
             Err(e) => Ok(error_result(&e)),
         }
     }
@@ -3914,12 +4323,17 @@ impl LinearMcp {
 
 // ---- ServerHandler ----
 
-#[tool_handler]
 impl ServerHandler for LinearMcp {
     fn get_info(&self) -> ServerInfo {
         ServerInfo {
-            protocol_version: ProtocolVersion::V_2024_11_05,
-            capabilities: ServerCapabilities::builder().enable_tools().build(),
+            protocol_version: ProtocolVersion::V_2025_06_18,
+            capabilities: ServerCapabilities::builder()
+                .enable_tools()
+                .enable_completions()
+                .enable_prompts()
+                .enable_resources()
+                .enable_resources_subscribe()
+                .build(),
             server_info: Implementation {
                 name: "linear-mcp".into(),
                 version: env!("CARGO_PKG_VERSION").into(),
@@ -3928,305 +4342,1511 @@ impl ServerHandler for LinearMcp {
                 icons: None,
                 website_url: None,
             },
-            instructions: Some(
-                "Linear MCP server. Interact with Linear issues, teams, projects, and more."
-                    .into(),
-            ),
+            instructions: Some(if self.client.is_read_only() {
+                "Linear MCP server (read-only). Look up Linear issues, teams, projects, and more; \
+                 tools that change data are disabled."
+                    .into()
+            } else {
+                "Linear MCP server. Interact with Linear issues, teams, projects, and more.".into()
+            }),
         }
     }
-}
-
-// ---- Business logic handlers ----
 
-impl LinearMcp {
-    // ---- Shared helpers ----
+    async fn initialize(
+        &self,
+        request: InitializeRequestParams,
+        context: RequestContext<RoleServer>,
+    ) -> Result<InitializeResult, McpError> {
+        if self.tenants.is_some()
+            && let Some(token) = tenant::init_token(&context)
+        {
+            let _ = self.session_token.set(token);
+        }
+        if context.peer.peer_info().is_none() {
+            context.peer.set_peer_info(request);
+        }
+        Ok(self.get_info())
+    }
 
-    /// Resolve an issue identifier (e.g. "ENG-123") or UUID to a UUID.
-    async fn resolve_issue_id(&self, id_or_identifier: &str) -> Result<String, Error> {
-        // UUID pattern
-        let is_uuid = id_or_identifier.len() == 36
-            && id_or_identifier
-                .chars()
-                .all(|c| c.is_ascii_hexdigit() || c == '-');
-        if is_uuid {
-            return Ok(id_or_identifier.to_string());
-        }
-
-        let identifier = id_or_identifier.to_string();
-        self.issue_id_cache
-            .get_or_fetch(&identifier, || {
-                let client = self.client.clone();
-                let id = identifier.clone();
-                async move {
-                    let vars = serde_json::json!({ "query": id, "first": 5 });
-                    let data: response::SearchIssuesData =
-                        client.execute_json(queries::SEARCH_ISSUES, vars).await?;
-                    let found = data.search_issues.nodes.iter().find(|n| {
-                        n.identifier.eq_ignore_ascii_case(&id)
-                    });
-                    match found {
-                        Some(issue) => Ok(issue.id.clone()),
-                        None => Err(Error::NotFound(format!("Issue '{}' not found", id))),
-                    }
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParams,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, McpError> {
+        let server = match self.for_request(&context) {
+            Ok(server) => server,
+            Err(e) => return Ok(error_result(&e)),
+        };
+        let tcc = ToolCallContext::new(server.as_ref(), request, context);
+        let mut result = server.tool_router.call(tcc).await?;
+        self.keep_tenant(&server);
+        if self.output_format == OutputFormat::Plain && result.is_error != Some(true) {
+            for content in &mut result.content {
+                if let RawContent::Text(text) = &mut content.raw {
+                    text.text = format::to_plain_text(&text.text);
                 }
-            })
-            .await
+            }
+        }
+        Ok(result)
     }
 
-    /// Resolve a team key (e.g. "ENG") to a team ID.
-    async fn resolve_team_id(&self, team_key: &str) -> Result<String, Error> {
-        let is_uuid = team_key.len() == 36
-            && team_key.chars().all(|c| c.is_ascii_hexdigit() || c == '-');
-        if is_uuid {
-            return Ok(team_key.to_string());
-        }
-        let filter = filters::TeamFilter {
-            key: Some(filters::StringFilter::eq_exact(team_key.to_uppercase())),
-            ..Default::default()
-        };
-        let vars = serde_json::json!({ "filter": filter });
-        let data: response::TeamsData = self
-            .client
-            .execute_json(queries::RESOLVE_TEAM, vars)
-            .await?;
-        data.teams
-            .nodes
-            .first()
-            .map(|t| t.id.clone())
-            .ok_or_else(|| Error::NotFound(format!("Team '{}' not found", team_key)))
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParams>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListToolsResult, McpError> {
+        Ok(ListToolsResult {
+            tools: self.tool_router.list_all(),
+            meta: None,
+            next_cursor: None,
+        })
     }
 
-    /// Resolve a user email to a user ID.
-    async fn resolve_user_id(&self, email: &str) -> Result<String, Error> {
-        let filter = filters::UserFilter {
-            email: filters::StringFilter::eq_ignore_case(email),
-        };
-        let vars = serde_json::json!({ "filter": filter });
-        let data: response::UsersData = self
-            .client
-            .execute_json(queries::RESOLVE_USER, vars)
-            .await?;
-        data.users
-            .nodes
-            .first()
-            .map(|u| u.id.clone())
-            .ok_or_else(|| Error::NotFound(format!("User with email '{}' not found", email)))
+    fn get_tool(&self, name: &str) -> Option<Tool> {
+        self.tool_router.get(name).cloned()
     }
 
-    /// Resolve a workflow state name + team key to a state ID.
-    async fn resolve_state_id(&self, state_name: &str, team_key: &str) -> Result<String, Error> {
-        let filter = filters::WorkflowStateFilter {
-            name: Some(filters::StringFilter::eq_ignore_case(state_name)),
-            team: Some(filters::TeamFilter {
-                key: Some(filters::StringFilter::eq_exact(team_key.to_uppercase())),
-                ..Default::default()
-            }),
+    async fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParams>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListPromptsResult, McpError> {
+        let prompts = LinearPrompt::ALL
+            .into_iter()
+            .map(LinearPrompt::to_prompt)
+            .collect();
+        Ok(ListPromptsResult::with_all_items(prompts))
+    }
+
+    async fn get_prompt(
+        &self,
+        request: GetPromptRequestParams,
+        context: RequestContext<RoleServer>,
+    ) -> Result<GetPromptResult, McpError> {
+        let Some(prompt) = LinearPrompt::from_name(&request.name) else {
+            return Err(McpError::invalid_params(
+                format!("unknown prompt '{}'", request.name),
+                None,
+            ));
         };
-        let vars = serde_json::json!({ "filter": filter });
-        let data: response::ResolveStateData = self
-            .client
-            .execute_json(queries::RESOLVE_STATE, vars)
-            .await?;
-        data.workflow_states
-            .nodes
-            .first()
-            .map(|s| s.id.clone())
-            .ok_or_else(|| {
-                Error::NotFound(format!(
-                    "Workflow state '{}' not found for team '{}'",
-                    state_name, team_key
-                ))
+        let args = PromptArgs(request.arguments.unwrap_or_default());
+        let server = self.for_request(&context).map_err(request_error)?;
+        let text = server
+            .handle_get_prompt(prompt, &args)
+            .await
+            .map_err(request_error)?;
+        self.keep_tenant(&server);
+        Ok(GetPromptResult {
+            description: Some(prompt.description().into()),
+            messages: vec![PromptMessage::new_text(PromptMessageRole::User, text)],
+        })
+    }
+
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParams>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListResourcesResult, McpError> {
+        // Only the default team's cycle is known without asking Linear;
+        // everything else is reached through the templates.
+        let resources = self
+            .default_team
+            .iter()
+            .map(|team| {
+                let mut resource = RawResource::new(
+                    LinearResource::current_cycle_uri(team),
+                    format!("{} current cycle", team),
+                );
+                resource.mime_type = Some(self.resource_mime_type().into());
+                resource.no_annotation()
             })
+            .collect();
+        Ok(ListResourcesResult::with_all_items(resources))
     }
 
-    /// Get the authenticated viewer.
-    async fn get_viewer(&self) -> Result<types::Viewer, Error> {
-        let data: response::ViewerData = self
-            .client
-            .execute::<(), _>(queries::VIEWER, None)
-            .await?;
-        Ok(data.viewer)
+    async fn list_resource_templates(
+        &self,
+        _request: Option<PaginatedRequestParams>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListResourceTemplatesResult, McpError> {
+        Ok(ListResourceTemplatesResult::with_all_items(
+            resources::templates(),
+        ))
     }
 
-    // ---- list_issues ----
-
-    async fn handle_list_issues(
+    async fn read_resource(
         &self,
-        params: list_issues::ListIssuesParams,
-    ) -> Result<String, Error> {
-        let mut issue_filters = Vec::new();
-
-        if let Some(ref team) = params.team {
-            issue_filters.push(filters::team_filter(team));
-        }
-        if let Some(ref assignee) = params.assignee {
-            issue_filters.push(filters::assignee_filter(assignee));
-        }
-        if let Some(ref creator) = params.creator {
-            issue_filters.push(filters::creator_filter(creator));
-        }
-        if let Some(ref status) = params.status {
-            issue_filters.push(filters::status_filter(status));
-        }
-        if let Some(ref project) = params.project {
-            issue_filters.push(filters::project_filter(project));
-        }
-        if let Some(ref label) = params.label {
-            issue_filters.push(filters::label_filter(label));
-        }
-        if let Some(ref priority) = params.priority {
-            issue_filters.push(filters::priority_filter(priority.to_number()));
-        }
-        if let Some(estimate) = params.estimate {
-            issue_filters.push(filters::estimate_filter(estimate));
-        }
-        // Note: Linear's IssueFilter only supports `relations: { some: {} }` — it cannot
-        // distinguish between blocking vs blocked-by at the filter level. Both flags filter
-        // to issues that have any relations.
-        if params.has_blocked_by_relation == Some(true) || params.has_blocking_relation == Some(true) {
-            issue_filters.push(filters::has_relation_filter());
+        request: ReadResourceRequestParams,
+        context: RequestContext<RoleServer>,
+    ) -> Result<ReadResourceResult, McpError> {
+        let uri = request.uri;
+        let resource = parse_resource(&uri)?;
+        let server = self.for_request(&context).map_err(resource_error)?;
+        let text = server
+            .handle_read_resource(resource)
+            .await
+            .map_err(resource_error)?;
+        self.keep_tenant(&server);
+        let text = match self.output_format {
+            OutputFormat::Plain => format::to_plain_text(&text),
+            OutputFormat::Markdown => text,
+        };
+        Ok(ReadResourceResult {
+            contents: vec![ResourceContents::TextResourceContents {
+                uri,
+                mime_type: Some(self.resource_mime_type().into()),
+                text,
+                meta: None,
+            }],
+        })
+    }
+
+    async fn subscribe(
+        &self,
+        request: SubscribeRequestParams,
+        context: RequestContext<RoleServer>,
+    ) -> Result<(), McpError> {
+        let resource = parse_resource(&request.uri)?;
+        let server = self.for_request(&context).map_err(resource_error)?;
+        let version = server
+            .resource_version(&resource)
+            .await
+            .map_err(resource_error)?;
+        self.keep_tenant(&server);
+        self.subscriptions.insert(request.uri, version);
+        if self.subscriptions.start_polling() {
+            // The poller must not keep the subscriptions alive, or it would
+            // outlive the session.
+            let poller = Self {
+                subscriptions: Arc::default(),
+                ..server.into_owned()
+            };
+            tokio::spawn(
+                poller
+                    .poll_subscriptions(Arc::downgrade(&self.subscriptions), context.peer.clone()),
+            );
         }
+        Ok(())
+    }
 
-        // Date range filters (#8)
-        if params.due_before.is_some() || params.due_after.is_some() {
-            issue_filters.push(filters::due_date_filter(
-                params.due_before.as_deref(),
-                params.due_after.as_deref(),
-            ));
-        }
-        if params.created_before.is_some() || params.created_after.is_some() {
-            issue_filters.push(filters::created_at_filter(
-                params.created_before.as_deref(),
-                params.created_after.as_deref(),
-            ));
-        }
-        if params.updated_before.is_some() || params.updated_after.is_some() {
-            issue_filters.push(filters::updated_at_filter(
-                params.updated_before.as_deref(),
-                params.updated_after.as_deref(),
-            ));
-        }
-        if params.completed_before.is_some() || params.completed_after.is_some() {
-            issue_filters.push(filters::completed_at_filter(
-                params.completed_before.as_deref(),
-                params.completed_after.as_deref(),
-            ));
-        }
-        if params.canceled_before.is_some() || params.canceled_after.is_some() {
-            issue_filters.push(filters::canceled_at_filter(
-                params.canceled_before.as_deref(),
-                params.canceled_after.as_deref(),
-            ));
-        }
-        if let Some(ref snoozed) = params.snoozed_until_after {
-            issue_filters.push(filters::snoozed_until_at_filter(snoozed));
-        }
+    async fn unsubscribe(
+        &self,
+        request: UnsubscribeRequestParams,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<(), McpError> {
+        self.subscriptions.remove(&request.uri);
+        Ok(())
+    }
 
-        let filter = filters::IssueFilter::combine(issue_filters);
-        let limit = params.limit.unwrap_or(25).min(100);
-        let order_by = params.order_by.as_ref().map(|o| o.as_str()).unwrap_or("updatedAt");
+    async fn complete(
+        &self,
+        request: CompleteRequestParams,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CompleteResult, McpError> {
+        let argument = &request.argument;
+        let catalog = match &request.r#ref {
+            // MCP has no reference to a tool, so a prompt reference naming a
+            // tool completes that tool's arguments.
+            Reference::Prompt(prompt) => {
+                if LinearPrompt::from_name(&prompt.name).is_none()
+                    && self.tool_router.get(&prompt.name).is_none()
+                {
+                    return Err(McpError::invalid_params(
+                        format!("unknown prompt '{}'", prompt.name),
+                        None,
+                    ));
+                }
+                Catalog::for_argument(&prompt.name, &argument.name)
+            }
+            Reference::Resource(resource) => Catalog::for_resource(&resource.uri, &argument.name),
+        };
+        let Some(catalog) = catalog else {
+            return Ok(CompleteResult::default());
+        };
+        let team = request
+            .context
+            .as_ref()
+            .and_then(|c| c.get_argument("team"))
+            .map(|team| team.trim())
+            .filter(|team| !team.is_empty());
+        let server = self.for_request(&context).map_err(request_error)?;
+        let names = server.completion_names(catalog, team).await;
+        self.keep_tenant(&server);
+        let names = match names {
+            Ok(names) => names,
+            // An unknown team in the context has nothing to offer.
+            Err(Error::NotFound(_)) => return Ok(CompleteResult::default()),
+            Err(e) => return Err(request_error(e)),
+        };
+        Ok(CompleteResult {
+            completion: completion::complete(catalog, &names, &argument.value),
+        })
+    }
+}
 
-        let mut vars = serde_json::json!({
-            "first": limit,
-            "orderBy": order_by,
-        });
-        if let Some(ref cursor) = params.cursor {
-            vars["after"] = serde_json::Value::String(cursor.clone());
-        }
-        if let Some(f) = filter {
-            vars["filter"] = serde_json::to_value(f).unwrap();
+impl LinearMcp {
+    /// Bind this server to the caller's credentials for a single request.
+    ///
+    /// Without a tenant registry this is `self`. Otherwise the caller's token
+    /// (request header first, then the session's `initialize` metadata)
+    /// selects a client and caches of its own, falling back to the
+    /// server-wide key unless the registry requires a per-session key.
+    fn for_request(&self, context: &RequestContext<RoleServer>) -> Result<Cow<'_, Self>, Error> {
+        let Some(ref tenants) = self.tenants else {
+            return Ok(Cow::Borrowed(self));
+        };
+        let token = tenant::request_token(context).or_else(|| self.session_token.get().cloned());
+        match token {
+            Some(token) => {
+                let Tenant { key, client } = tenants.get(&token);
+                Ok(Cow::Owned(Self {
+                    client,
+                    tenants: None,
+                    tenant_key: Some(key),
+                    ..self.clone()
+                }))
+            }
+            None if tenants.requires_session_key() => Err(Error::SessionAuth),
+            None => Ok(Cow::Borrowed(self)),
         }
+    }
 
-        let data: response::IssuesData = self
-            .client
-            .execute_json(queries::LIST_ISSUES, vars)
-            .await?;
-
-        let issues = &data.issues.nodes;
-        if issues.is_empty() {
-            return Ok("No issues found matching the filters.".to_string());
+    /// After a request bound by `for_request`, keep the caller's tenant for
+    /// the next one if Linear accepted its token.
+    fn keep_tenant(&self, server: &Self) {
+        if let (Some(tenants), Some(key)) = (&self.tenants, server.tenant_key) {
+            tenants.keep(&Tenant {
+                key,
+                client: server.client.clone(),
+            });
         }
-
-        let lines: Vec<String> = issues.iter().map(format::format_issue_summary).collect();
-        let pagination = format::format_pagination_with_cursor(
-            data.issues.page_info.has_next_page,
-            issues.len(),
-            data.issues.page_info.end_cursor.as_deref(),
-        );
-
-        Ok(format!("{}{}", lines.join("\n"), pagination))
     }
+}
 
-    // ---- search_issues ----
+// ---- Business logic handlers ----
 
-    async fn handle_search_issues(
+impl LinearMcp {
+    // ---- Prompts ----
+
+    /// A prompt's instructions with the live data it works from.
+    async fn handle_get_prompt(
         &self,
-        params: search_issues::SearchIssuesParams,
+        prompt: LinearPrompt,
+        args: &PromptArgs,
     ) -> Result<String, Error> {
-        let mut issue_filters = Vec::new();
-
-        if let Some(ref team) = params.team {
-            issue_filters.push(filters::team_filter(team));
-        }
-        if let Some(ref status) = params.status {
-            issue_filters.push(filters::status_filter(status));
+        let mut sections = Vec::new();
+        match prompt {
+            LinearPrompt::TriageInbox => {
+                let team = self.team_or_default(args.get("team").as_deref())?;
+                let issues = list_triage_issues::ListTriageIssuesParams {
+                    team: Some(team.clone()),
+                    limit: None,
+                };
+                sections.push((
+                    format!("Triage inbox of {}", team),
+                    self.handle_list_triage_issues(issues).await?.text,
+                ));
+                let states = list_states::ListStatesParams {
+                    team: Some(team.clone()),
+                };
+                sections.push((
+                    "Workflow states".into(),
+                    self.handle_list_states(states).await?.text,
+                ));
+                let labels = list_labels::ListLabelsParams {
+                    team: Some(team),
+                    limit: None,
+                };
+                sections.push(("Labels".into(), self.handle_list_labels(labels).await?.text));
+            }
+            LinearPrompt::ProjectUpdate => {
+                let project = args.require("project")?;
+                let detail = get_project::GetProjectParams {
+                    id: project.clone(),
+                };
+                sections.push((
+                    "Project".into(),
+                    self.handle_get_project(detail).await?.text,
+                ));
+                let milestones = list_project_milestones::ListProjectMilestonesParams {
+                    project: project.clone(),
+                };
+                sections.push((
+                    "Milestones".into(),
+                    self.handle_list_project_milestones(milestones).await?.text,
+                ));
+                let updates = list_project_updates::ListProjectUpdatesParams {
+                    project: project.clone(),
+                };
+                sections.push((
+                    "Previous updates".into(),
+                    self.handle_list_project_updates(updates).await?.text,
+                ));
+                let recent = list_issues_args(serde_json::json!({
+                    "project": project,
+                    "updatedAfter": "-7d",
+                    "limit": 50,
+                }));
+                sections.push((
+                    "Issues updated in the last week".into(),
+                    self.handle_list_issues(recent).await?.text,
+                ));
+            }
+            LinearPrompt::SprintPlanning => {
+                let team = self.team_or_default(args.get("team").as_deref())?;
+                let cycle = args.get("cycle").unwrap_or_else(|| "next".into());
+                let planned = get_cycle::GetCycleParams {
+                    id: cycle,
+                    team: Some(team.clone()),
+                };
+                sections.push((
+                    "Cycle to plan".into(),
+                    self.handle_get_cycle(planned).await?.text,
+                ));
+                let unfinished = list_issues_args(serde_json::json!({
+                    "team": team,
+                    "cycle": "current",
+                    "filter": "state.type in (triage, backlog, unstarted, started)",
+                    "limit": 100,
+                }));
+                sections.push((
+                    "Unfinished in the current cycle".into(),
+                    self.handle_list_issues(unfinished).await?.text,
+                ));
+                let backlog = list_issues_args(serde_json::json!({
+                    "team": team,
+                    "cycle": "none",
+                    "filter": "state.type in (backlog, unstarted)",
+                    "orderBy": "priority",
+                    "limit": 50,
+                }));
+                sections.push((
+                    "Backlog".into(),
+                    self.handle_list_issues(backlog).await?.text,
+                ));
+            }
+            LinearPrompt::BreakDownIssue => {
+                let id = args.require("issue")?;
+                let issue = self
+                    .handle_get_issue(get_issue::GetIssueParams { id })
+                    .await?
+                    .text;
+                sections.push(("Issue".into(), issue));
+            }
+            LinearPrompt::ReleaseNotes => {
+                let team = self.team_or_default(args.get("team").as_deref())?;
+                let mut query = serde_json::json!({
+                    "team": team,
+                    "filter": "state.type = completed",
+                    "limit": 100,
+                });
+                let heading = match args.get("since") {
+                    Some(since) => {
+                        self.parse_date("since", &since)?;
+                        let heading = format!("Completed by {} since {}", team, since);
+                        query["completedAfter"] = since.into();
+                        heading
+                    }
+                    None => {
+                        let cycle = args.get("cycle").unwrap_or_else(|| "current".into());
+                        let heading = format!("Completed by {} in the {} cycle", team, cycle);
+                        query["cycle"] = cycle.into();
+                        heading
+                    }
+                };
+                let completed = self.handle_list_issues(list_issues_args(query)).await?.text;
+                sections.push((heading, completed));
+            }
         }
-        if let Some(ref assignee) = params.assignee {
-            issue_filters.push(filters::assignee_filter(assignee));
+        Ok(prompts::render(prompt, &sections))
+    }
+
+    // ---- Resources ----
+
+    /// Render a resource the way the matching `get_*` tool does.
+    async fn handle_read_resource(&self, resource: LinearResource) -> Result<String, Error> {
+        match resource {
+            LinearResource::Issue(id) => self
+                .handle_get_issue(get_issue::GetIssueParams { id })
+                .await
+                .map(|output| output.text),
+            LinearResource::Project(id) => self
+                .handle_get_project(get_project::GetProjectParams { id })
+                .await
+                .map(|output| output.text),
+            LinearResource::Document(id) => self
+                .handle_get_document(get_document::GetDocumentParams { id })
+                .await
+                .map(|output| output.text),
+            LinearResource::Cycle { team, which } => {
+                let params = get_cycle::GetCycleParams {
+                    id: which,
+                    team: Some(team),
+                };
+                self.handle_get_cycle(params)
+                    .await
+                    .map(|output| output.text)
+            }
         }
+    }
 
-        let filter = filters::IssueFilter::combine(issue_filters);
-        let limit = params.limit.unwrap_or(25).min(100);
-
-        let mut vars = serde_json::json!({
-            "query": params.query,
-            "first": limit,
-        });
-        if let Some(ref cursor) = params.cursor {
-            vars["after"] = serde_json::Value::String(cursor.clone());
-        }
-        if let Some(f) = filter {
-            vars["filter"] = serde_json::to_value(f).unwrap();
+    /// What a resource looks like now, as an opaque string that changes
+    /// whenever it does: a new comment on an issue, an issue of a project
+    /// or cycle moving, or the cycle itself rolling over.
+    async fn resource_version(&self, resource: &LinearResource) -> Result<String, Error> {
+        let (query, id) = match resource {
+            LinearResource::Issue(id) => (queries::ISSUE_VERSION, self.resolve_issue_id(id).await?),
+            LinearResource::Project(name) => (
+                queries::PROJECT_VERSION,
+                self.resolve_project_id_or_uuid(name).await?,
+            ),
+            LinearResource::Document(id) => (
+                queries::DOCUMENT_VERSION,
+                self.resolve_document_id(id, Match::Fuzzy).await?,
+            ),
+            LinearResource::Cycle { team, which } => (
+                queries::CYCLE_VERSION,
+                self.resolve_cycle_param(which, Some(team), Match::Fuzzy)
+                    .await?,
+            ),
+        };
+        let data: response::VersionData = self
+            .client
+            .execute(query, serde_json::json!({ "id": id }))
+            .await?;
+        let entity = data.entity;
+        let latest = entity
+            .latest
+            .and_then(|l| l.nodes.into_iter().next())
+            .map(|n| n.updated_at)
+            .unwrap_or_default();
+        Ok(format!("{} {} {}", entity.id, entity.updated_at, latest))
+    }
+
+    /// Check subscribed resources every `poll_interval` until the session
+    /// ends, sending `notifications/resources/updated` for each that changed.
+    async fn poll_subscriptions(self, subscriptions: Weak<Subscriptions>, peer: Peer<RoleServer>) {
+        let mut ticker = tokio::time::interval(self.poll_interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        // The first tick completes at once; versions were just fetched.
+        ticker.tick().await;
+        loop {
+            ticker.tick().await;
+            let Some(subscriptions) = subscriptions.upgrade() else {
+                return;
+            };
+            if peer.is_transport_closed() {
+                return;
+            }
+            for uri in subscriptions.uris() {
+                let Some(resource) = LinearResource::parse(&uri) else {
+                    continue;
+                };
+                let version = match self.resource_version(&resource).await {
+                    Ok(version) => version,
+                    Err(e) => {
+                        tracing::warn!("Checking {} for changes failed: {}", uri, e);
+                        continue;
+                    }
+                };
+                if subscriptions.update(&uri, version) {
+                    let params = ResourceUpdatedNotificationParam { uri };
+                    if peer.notify_resource_updated(params).await.is_err() {
+                        return;
+                    }
+                }
+            }
         }
+    }
 
-        let data: response::SearchIssuesData = self
-            .client
-            .execute_json(queries::SEARCH_ISSUES, vars)
-            .await?;
-
-        let issues = &data.search_issues.nodes;
-        if issues.is_empty() {
-            return Ok(format!(
-                "No issues found matching \"{}\".",
-                params.query
-            ));
-        }
+    // ---- Completion ----
 
-        let lines: Vec<String> = issues.iter().map(format::format_issue_summary).collect();
+    /// The names offered for a `catalog` argument, cached per client.
+    /// Team-scoped catalogs follow `team`, else the default team; with
+    /// neither, states and labels come from every team. A team that is not
+    /// one of the workspace's keys gets no names.
+    async fn completion_names(
+        &self,
+        catalog: Catalog,
+        team: Option<&str>,
+    ) -> Result<Arc<Vec<String>>, Error> {
+        let cache = self.client.cache();
+        let team = match team.or(self.default_team.as_deref()) {
+            Some(team) if catalog.is_team_scoped() => {
+                let keys = cache
+                    .names_or_fetch(Catalog::Team.name(), || {
+                        self.fetch_completion_names(Catalog::Team, None)
+                    })
+                    .await?;
+                if !keys.iter().any(|key| key.eq_ignore_ascii_case(team)) {
+                    return Ok(Arc::default());
+                }
+                Some(team.to_uppercase())
+            }
+            _ => None,
+        };
+        let scope = match team {
+            Some(ref team) => format!("{}:{}", catalog.name(), team),
+            None => catalog.name().to_string(),
+        };
+        cache
+            .names_or_fetch(&scope, || {
+                self.fetch_completion_names(catalog, team.as_deref())
+            })
+            .await
+    }
+
+    /// Fetch the names `completion_names` caches.
+    async fn fetch_completion_names(
+        &self,
+        catalog: Catalog,
+        team: Option<&str>,
+    ) -> Result<Vec<String>, Error> {
+        let names = match catalog {
+            Catalog::Team => {
+                let data: response::TeamsData = self
+                    .client
+                    .execute(queries::LIST_TEAMS, serde_json::json!({}))
+                    .await?;
+                data.teams.nodes.into_iter().map(|t| t.key).collect()
+            }
+            Catalog::State => {
+                let mut vars = serde_json::json!({ "first": 250 });
+                if let Some(team) = team {
+                    let filter = filters::WorkflowStateFilter {
+                        name: None,
+                        team: Some(filters::TeamFilter {
+                            key: Some(filters::StringFilter::eq_ignore_case(team)),
+                            ..Default::default()
+                        }),
+                    };
+                    vars["filter"] = serde_json::to_value(filter).unwrap();
+                }
+                let data: response::WorkflowStatesData =
+                    self.client.execute(queries::LIST_STATES, vars).await?;
+                data.workflow_states
+                    .nodes
+                    .into_iter()
+                    .map(|s| s.name)
+                    .collect()
+            }
+            Catalog::Label => {
+                let data: response::ResolveLabelsData = self
+                    .client
+                    .execute(queries::RESOLVE_LABELS, serde_json::json!({}))
+                    .await?;
+                data.issue_labels
+                    .nodes
+                    .into_iter()
+                    .filter(|l| match (&l.team, team) {
+                        (Some(owner), Some(team)) => owner.key.eq_ignore_ascii_case(team),
+                        _ => true,
+                    })
+                    .map(|l| l.name)
+                    .collect()
+            }
+            Catalog::Project => {
+                let data: response::ProjectsData = self
+                    .client
+                    .execute(queries::LIST_PROJECTS, serde_json::json!({ "first": 250 }))
+                    .await?;
+                data.projects.nodes.into_iter().map(|p| p.name).collect()
+            }
+            Catalog::User => {
+                let data: response::UsersData = self
+                    .client
+                    .execute(queries::LIST_USERS, serde_json::json!({ "first": 250 }))
+                    .await?;
+                std::iter::once("me".to_string())
+                    .chain(
+                        data.users
+                            .nodes
+                            .into_iter()
+                            .map(|u| u.email.unwrap_or(u.display_name)),
+                    )
+                    .collect()
+            }
+            Catalog::Cycle => {
+                let mut names: Vec<String> =
+                    ["current", "next", "previous"].map(String::from).to_vec();
+                if let Some(team) = team {
+                    let team_id = self.resolve_team_id(team).await?;
+                    let data: response::ResolveCyclesData = self
+                        .client
+                        .execute(
+                            queries::RESOLVE_CYCLES,
+                            serde_json::json!({ "teamId": team_id }),
+                        )
+                        .await?;
+                    for cycle in data.team.cycles.nodes {
+                        names.push(cycle.number.to_string());
+                        names.extend(cycle.name);
+                    }
+                }
+                names
+            }
+            Catalog::Template => {
+                let data: response::TemplatesData = self
+                    .client
+                    .execute(queries::LIST_TEMPLATES, serde_json::json!({}))
+                    .await?;
+                data.templates.into_iter().map(|t| t.name).collect()
+            }
+        };
+        Ok(names)
+    }
+
+    fn resource_mime_type(&self) -> &'static str {
+        match self.output_format {
+            OutputFormat::Markdown => "text/markdown",
+            OutputFormat::Plain => "text/plain",
+        }
+    }
+
+    // ---- Shared helpers ----
+
+    /// Resolve an issue identifier (e.g. "ENG-123") or UUID to a UUID.
+    async fn resolve_issue_id(&self, id_or_identifier: &str) -> Result<String, Error> {
+        let mut ids = self.resolve_issue_ids(&[id_or_identifier]).await?;
+        Ok(ids.remove(0))
+    }
+
+    /// Resolve issue references (identifiers, URLs, branch names) or UUIDs
+    /// to UUIDs, in order.
+    ///
+    /// Identifiers are looked up together by team key + number in one
+    /// filtered query (shared with any other issue lookups running at the
+    /// same time). Those it misses, such as issues moved to another team,
+    /// are retried one by one through `issue(id:)`, which follows renames.
+    async fn resolve_issue_ids(&self, ids: &[&str]) -> Result<Vec<String>, Error> {
+        let mut parsed = Vec::with_capacity(ids.len());
+        for id in ids {
+            if is_uuid(id) {
+                parsed.push(None);
+            } else if let Some(identifier) = Identifier::parse(id) {
+                parsed.push(Some(identifier.to_string()));
+            } else {
+                return Err(self.issue_not_found(id, None).await);
+            }
+        }
+        let identifiers: Vec<String> = parsed.iter().flatten().cloned().collect();
+        let batched = self
+            .client
+            .cache()
+            .load_many(EntityKind::Issue, &identifiers, |keys| {
+                self.fetch_issue_ids(keys)
+            })
+            .await?;
+        let mut batched = identifiers.iter().zip(batched);
+
+        let mut resolved = Vec::with_capacity(ids.len());
+        for (id, identifier) in ids.iter().zip(&parsed) {
+            if identifier.is_none() {
+                resolved.push(id.to_string());
+                continue;
+            }
+            let (identifier, uuid) = batched.next().expect("one result per identifier");
+            match uuid {
+                Some(uuid) => resolved.push(uuid),
+                None => resolved.push(self.fetch_moved_issue_id(identifier).await?),
+            }
+        }
+        Ok(resolved)
+    }
+
+    /// Fetch the UUIDs of `KEY-NUMBER` identifiers, one query per 250. Also
+    /// caches each issue's team key, which state and label lookups need next.
+    async fn fetch_issue_ids(
+        &self,
+        identifiers: Vec<String>,
+    ) -> Result<HashMap<String, String>, Error> {
+        let cache = self.client.cache();
+        let mut found = HashMap::new();
+        for vars in resolve_issues_pages(&identifiers) {
+            let data: response::ResolveIssuesData =
+                self.client.execute(queries::RESOLVE_ISSUES, vars).await?;
+            for issue in data.issues.nodes {
+                cache.insert(EntityKind::IssueTeam, &issue.id, issue.team.key);
+                found.insert(issue.identifier.to_uppercase(), issue.id);
+            }
+        }
+        Ok(found)
+    }
+
+    /// Look up an identifier the team-key + number filter missed, following
+    /// it to the issue's current identifier if it has moved.
+    async fn fetch_moved_issue_id(&self, identifier: &str) -> Result<String, Error> {
+        let vars = serde_json::json!({ "id": identifier });
+        let issue = match self
+            .client
+            .execute::<response::ResolveIssueData>(queries::RESOLVE_ISSUE, vars)
+            .await
+        {
+            Ok(data) => data.issue,
+            Err(Error::NotFound(_)) => {
+                return Err(self
+                    .issue_not_found(identifier, Identifier::parse(identifier))
+                    .await);
+            }
+            Err(e) => return Err(e),
+        };
+        let cache = self.client.cache();
+        cache.insert(EntityKind::Issue, identifier, issue.id.clone());
+        cache.insert(EntityKind::IssueTeam, &issue.id, issue.team.key);
+        Ok(issue.id)
+    }
+
+    /// A not-found error for `input`, with "did you mean" suggestions: the
+    /// closest team keys when no team has the identifier's key, otherwise
+    /// the top search results for the input.
+    async fn issue_not_found(&self, input: &str, identifier: Option<Identifier>) -> Error {
+        let mut message = format!("Issue '{}' not found.", input);
+        let mut suggestions = Vec::new();
+        if let Some(identifier) = identifier
+            && let Ok(data) = self
+                .client
+                .execute::<response::TeamsData>(queries::LIST_TEAMS, serde_json::json!({}))
+                .await
+            && !data
+                .teams
+                .nodes
+                .iter()
+                .any(|t| t.key.eq_ignore_ascii_case(&identifier.team_key))
+        {
+            message = format!(
+                "Issue '{}' not found: no team has the key '{}'.",
+                input, identifier.team_key
+            );
+            let mut keys: Vec<(usize, &str)> = data
+                .teams
+                .nodes
+                .iter()
+                .map(|t| (edit_distance(&t.key, &identifier.team_key), t.key.as_str()))
+                .filter(|(distance, _)| *distance <= 2)
+                .collect();
+            keys.sort();
+            suggestions = keys
+                .iter()
+                .take(3)
+                .map(|(_, key)| format!("{}-{}", key, identifier.number))
+                .collect();
+        } else {
+            let vars = serde_json::json!({ "query": input, "first": 3 });
+            if let Ok(data) = self
+                .client
+                .execute::<response::SearchIssuesData>(queries::SEARCH_ISSUES, vars)
+                .await
+            {
+                suggestions = data
+                    .search_issues
+                    .nodes
+                    .iter()
+                    .map(|i| format!("{} ({})", i.identifier, i.title))
+                    .collect();
+            }
+        }
+        if !suggestions.is_empty() {
+            message = format!("{} Did you mean: {}?", message, suggestions.join(", "));
+        }
+        Error::NotFound(message)
+    }
+
+    /// The team key the caller gave, or the profile's `default_team`.
+    fn team_or_default(&self, team: Option<&str>) -> Result<String, Error> {
+        team.or(self.default_team.as_deref())
+            .map(String::from)
+            .ok_or_else(|| {
+                Error::InvalidInput("'team' is required (no default_team is configured).".into())
+            })
+    }
+
+    /// Parse the date argument `field`, rejecting unrecognized and impossible
+    /// dates before anything is sent.
+    fn parse_date(&self, field: &str, input: &str) -> Result<DateExpr, Error> {
+        self.dates
+            .parse(input)
+            .map_err(|message| Error::Validation {
+                message,
+                field: Some(field.to_string()),
+            })
+    }
+
+    /// Check every given date argument up front, so that a bad one fails the
+    /// call before any request is sent. `none` (clear the date) is allowed.
+    fn check_dates(&self, dates: &[(&str, Option<&String>)]) -> Result<(), Error> {
+        for (field, value) in dates {
+            if let Some(value) = value
+                && !value.eq_ignore_ascii_case("none")
+            {
+                self.parse_date(field, value)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// A date argument as Linear's `YYYY-MM-DD`. Periods (`Q3`, `next
+    /// month`) give their first or last day per `bound`; cycle dates are
+    /// looked up in `team` or the default team.
+    async fn date_arg(
+        &self,
+        field: &str,
+        input: &str,
+        bound: Bound,
+        team: Option<&str>,
+    ) -> Result<String, Error> {
+        let range = self.date_range(field, input, true, team).await?;
+        Ok(match bound {
+            Bound::Start => range.start,
+            Bound::End => range.end,
+        })
+    }
+
+    /// The days (`timeless`, first and last inclusive) or instants (end
+    /// exclusive) a date argument covers.
+    async fn date_range(
+        &self,
+        field: &str,
+        input: &str,
+        timeless: bool,
+        team: Option<&str>,
+    ) -> Result<DateRange, Error> {
+        let (first, last) = match self.parse_date(field, input)? {
+            DateExpr::Instant(at) if !timeless => {
+                let at = dates::timestamp(at);
+                return Ok(DateRange {
+                    start: at.clone(),
+                    end: at,
+                });
+            }
+            DateExpr::Cycle { cycle, end } => {
+                let (starts_at, ends_at) = self.cycle_dates(cycle, team).await?;
+                if !timeless {
+                    let at = dates::timestamp(if end { ends_at } else { starts_at });
+                    return Ok(DateRange {
+                        start: at.clone(),
+                        end: at,
+                    });
+                }
+                // `endsAt` is the instant the cycle is over, so its last day
+                // is the one just before.
+                let at = if end {
+                    ends_at - chrono::Duration::milliseconds(1)
+                } else {
+                    starts_at
+                };
+                let day = DateExpr::Instant(at)
+                    .day(Bound::Start, &self.dates)
+                    .expect("instants have a day");
+                (day, day)
+            }
+            expr => (
+                expr.day(Bound::Start, &self.dates)
+                    .expect("only cycle dates need a lookup"),
+                expr.day(Bound::End, &self.dates)
+                    .expect("only cycle dates need a lookup"),
+            ),
+        };
+        Ok(if timeless {
+            DateRange {
+                start: first.format("%Y-%m-%d").to_string(),
+                end: last.format("%Y-%m-%d").to_string(),
+            }
+        } else {
+            DateRange {
+                start: self.dates.start_of_day(first),
+                end: self.dates.start_of_day(last.succ_opt().unwrap_or(last)),
+            }
+        })
+    }
+
+    /// Parse a `filter` expression and check its dates, without sending
+    /// anything.
+    fn parse_query(&self, query: &str) -> Result<issue_query::Expr, Error> {
+        let expr = issue_query::parse(query)?;
+        for (_, value) in expr.dates() {
+            self.parse_date("filter", value)?;
+        }
+        Ok(expr)
+    }
+
+    /// Compile a parsed `filter` expression, looking up its cycle dates in
+    /// `team` or the default team.
+    async fn compile_query(
+        &self,
+        expr: &issue_query::Expr,
+        team: Option<&str>,
+    ) -> Result<filters::IssueFilter, Error> {
+        let mut values = issue_query::DateValues::new();
+        for (field, value) in expr.dates() {
+            let key = (field.is_timeless(), value.to_string());
+            if let std::collections::hash_map::Entry::Vacant(slot) = values.entry(key) {
+                slot.insert(
+                    self.date_range("filter", value, field.is_timeless(), team)
+                        .await?,
+                );
+            }
+        }
+        Ok(issue_query::compile(expr, &values))
+    }
+
+    /// A date argument as an ISO timestamp: days stand for their start in
+    /// the profile's timezone, and periods for their start, or with
+    /// [`Bound::End`] for the moment they are over.
+    async fn timestamp_arg(
+        &self,
+        field: &str,
+        input: &str,
+        bound: Bound,
+        team: Option<&str>,
+    ) -> Result<String, Error> {
+        let day = match self.parse_date(field, input)? {
+            DateExpr::Instant(at) => return Ok(dates::timestamp(at)),
+            DateExpr::Cycle { cycle, end } => {
+                let (starts_at, ends_at) = self.cycle_dates(cycle, team).await?;
+                return Ok(dates::timestamp(if end { ends_at } else { starts_at }));
+            }
+            DateExpr::Period(_, last) if bound == Bound::End => last.succ_opt().unwrap_or(last),
+            expr => expr
+                .day(Bound::Start, &self.dates)
+                .expect("only cycle dates need a lookup"),
+        };
+        Ok(self.dates.start_of_day(day))
+    }
+
+    /// Start and end of the current, next or previous cycle of `team` or
+    /// the default team.
+    async fn cycle_dates(
+        &self,
+        cycle: CycleRef,
+        team: Option<&str>,
+    ) -> Result<(chrono::DateTime<chrono::Utc>, chrono::DateTime<chrono::Utc>), Error> {
+        let team = self.resolve_team(&self.team_or_default(team)?).await?;
+        let vars = serde_json::json!({ "teamId": team.id });
+        let data: response::ResolveCyclesData =
+            self.client.execute(queries::RESOLVE_CYCLES, vars).await?;
+        let found = data.team.cycles.nodes.into_iter().find(|c| match cycle {
+            CycleRef::Current => c.is_active,
+            CycleRef::Next => c.is_next,
+            CycleRef::Previous => c.is_previous,
+        });
+        let Some(found) = found else {
+            return Err(Error::NotFound(format!(
+                "Team {} has no {} cycle",
+                team.key,
+                cycle.as_str()
+            )));
+        };
+        let parse = |at: &str| {
+            chrono::DateTime::parse_from_rfc3339(at)
+                .map(|at| at.with_timezone(&chrono::Utc))
+                .map_err(|e| Error::Server(format!("Unexpected cycle date '{}': {}", at, e)))
+        };
+        Ok((parse(&found.starts_at)?, parse(&found.ends_at)?))
+    }
+
+    /// Resolve a team key (e.g. "ENG") to a team ID.
+    async fn resolve_team_id(&self, team_key: &str) -> Result<String, Error> {
+        self.resolve_team_id_matching(team_key, Match::Fuzzy).await
+    }
+
+    /// Resolve a team key, name or UUID to the team's ID and key. Pass the
+    /// key, not what the caller typed, to state, label and cycle lookups.
+    async fn resolve_team(&self, team: &str) -> Result<types::TeamRef, Error> {
+        let id = self.resolve_team_id(team).await?;
+        let key = self
+            .client
+            .cache()
+            .get_or_fetch(EntityKind::TeamKey, &id, || async {
+                let vars = serde_json::json!({ "id": id });
+                let data: response::GetTeamData =
+                    self.client.execute(queries::GET_TEAM, vars).await?;
+                Ok::<_, Error>(data.team.key)
+            })
+            .await?;
+        Ok(types::TeamRef { id, key })
+    }
+
+    /// Resolve a team key, name or UUID to a team ID, matching names `how`.
+    async fn resolve_team_id_matching(&self, team_key: &str, how: Match) -> Result<String, Error> {
+        if is_uuid(team_key) {
+            return Ok(team_key.to_string());
+        }
+        let key = team_key.to_uppercase();
+        let fetch = || async {
+            let cache = self.client.cache();
+            let filter = filters::TeamFilter {
+                key: Some(filters::StringFilter::eq_exact(key.clone())),
+                ..Default::default()
+            };
+            let vars = serde_json::json!({ "filter": filter });
+            let data: response::TeamsData =
+                self.client.execute(queries::RESOLVE_TEAM, vars).await?;
+            if let Some(team) = data.teams.nodes.into_iter().next() {
+                cache.insert(EntityKind::TeamKey, &team.id, team.key);
+                return Ok(team.id);
+            }
+            // Not a key: match key or name ("eng", "Engineering").
+            let data: response::TeamsData = self
+                .client
+                .execute(queries::LIST_TEAMS, serde_json::json!({}))
+                .await?;
+            let candidates: Vec<Candidate> = data
+                .teams
+                .nodes
+                .iter()
+                .map(|t| {
+                    Candidate::new(t.id.clone(), format!("{} ({})", t.key, t.name))
+                        .name(t.key.clone())
+                        .name(t.name.clone())
+                })
+                .collect();
+            let id = how.pick("Team", team_key, &candidates)?.id.clone();
+            if let Some(team) = data.teams.nodes.into_iter().find(|t| t.id == id) {
+                cache.insert(EntityKind::TeamKey, &id, team.key);
+            }
+            Ok(id)
+        };
+        match how {
+            Match::Fuzzy => {
+                self.client
+                    .cache()
+                    .get_or_fetch(EntityKind::Team, &key, fetch)
+                    .await
+            }
+            Match::Exact => fetch().await,
+        }
+    }
+
+    /// Resolve a user given by email, name, display name, "me" or UUID to
+    /// a user ID.
+    async fn resolve_user_id(&self, user: &str) -> Result<String, Error> {
+        let mut ids = self.resolve_user_ids(&[user]).await?;
+        Ok(ids.remove(0))
+    }
+
+    /// Resolve users, in order. Emails are looked up together in one query
+    /// (shared with any other user lookups running at the same time);
+    /// names, and emails that don't match exactly, are matched fuzzily
+    /// against the workspace's users.
+    async fn resolve_user_ids(&self, users: &[&str]) -> Result<Vec<String>, Error> {
+        let emails: Vec<String> = users
+            .iter()
+            .filter(|u| u.contains('@'))
+            .map(|u| u.trim().to_lowercase())
+            .collect();
+        let by_email = self
+            .client
+            .cache()
+            .load_many(EntityKind::User, &emails, |emails| async move {
+                let or: Vec<serde_json::Value> = emails
+                    .iter()
+                    .map(|email| serde_json::json!({ "email": { "eqIgnoreCase": email } }))
+                    .collect();
+                let vars = serde_json::json!({ "filter": { "or": or }, "first": emails.len() });
+                let data: response::ResolveUsersData =
+                    self.client.execute(queries::RESOLVE_USERS, vars).await?;
+                Ok::<_, Error>(
+                    data.users
+                        .nodes
+                        .into_iter()
+                        .map(|u| (u.email.to_lowercase(), u.id))
+                        .collect(),
+                )
+            })
+            .await?;
+        let mut by_email = by_email.into_iter();
+
+        let cache = self.client.cache();
+        let mut candidates = None;
+        let mut ids = Vec::with_capacity(users.len());
+        for user in users {
+            let user = user.trim();
+            let found = if user.contains('@') {
+                by_email.next().flatten()
+            } else if is_uuid(user) {
+                Some(user.to_string())
+            } else if user.eq_ignore_ascii_case("me") {
+                Some(self.get_viewer().await?.id)
+            } else {
+                cache.get(EntityKind::User, &user.to_lowercase())
+            };
+            let id = match found {
+                Some(id) => id,
+                None => {
+                    if candidates.is_none() {
+                        candidates = Some(self.user_candidates().await?);
+                    }
+                    let candidates = candidates.as_deref().unwrap_or_default();
+                    let id = matching::pick("User", user, candidates)?.id.clone();
+                    cache.insert(EntityKind::User, &user.to_lowercase(), id.clone());
+                    id
+                }
+            };
+            ids.push(id);
+        }
+        Ok(ids)
+    }
+
+    /// Every active user, for fuzzy matching by name.
+    async fn user_candidates(&self) -> Result<Vec<Candidate>, Error> {
+        let data: response::UserCandidatesData = self
+            .client
+            .execute(queries::USER_CANDIDATES, serde_json::json!({}))
+            .await?;
+        Ok(data
+            .users
+            .nodes
+            .into_iter()
+            .map(|u| {
+                let label = match &u.email {
+                    Some(email) => format!("{} <{}>", u.name, email),
+                    None => u.name.clone(),
+                };
+                let mut candidate = Candidate::new(u.id, label)
+                    .name(u.name)
+                    .name(u.display_name);
+                if let Some(email) = u.email {
+                    candidate = candidate.name(email);
+                }
+                candidate
+            })
+            .collect())
+    }
+
+    /// Resolve a workflow state name + team key to a state ID. Exact names
+    /// are looked up together with any other state lookups running at the
+    /// same time; anything else is matched fuzzily against the team's states
+    /// (so "in progres" or "in_progress" find "In Progress").
+    async fn resolve_state_id(&self, state_name: &str, team_key: &str) -> Result<String, Error> {
+        let keys = [format!(
+            "{}:{}",
+            team_key.to_uppercase(),
+            state_name.to_lowercase()
+        )];
+        let [id] = self
+            .client
+            .cache()
+            .load_many(EntityKind::State, &keys, |keys| async move {
+                let or: Vec<serde_json::Value> = keys
+                    .iter()
+                    .filter_map(|key| key.split_once(':'))
+                    .map(|(team, name)| {
+                        serde_json::json!({
+                            "name": { "eqIgnoreCase": name },
+                            "team": { "key": { "eq": team } },
+                        })
+                    })
+                    .collect();
+                let vars = serde_json::json!({ "filter": { "or": or }, "first": keys.len() * 5 });
+                let data: response::ResolveStatesData =
+                    self.client.execute(queries::RESOLVE_STATES, vars).await?;
+                Ok::<_, Error>(
+                    data.workflow_states
+                        .nodes
+                        .into_iter()
+                        .map(|s| {
+                            (
+                                format!("{}:{}", s.team.key.to_uppercase(), s.name.to_lowercase()),
+                                s.id,
+                            )
+                        })
+                        .collect(),
+                )
+            })
+            .await?
+            .try_into()
+            .expect("one result per key");
+        if let Some(id) = id {
+            return Ok(id);
+        }
+
+        let filter = filters::WorkflowStateFilter {
+            name: None,
+            team: Some(filters::TeamFilter {
+                key: Some(filters::StringFilter::eq_ignore_case(team_key)),
+                ..Default::default()
+            }),
+        };
+        let vars = serde_json::json!({ "first": 100, "filter": filter });
+        let data: response::WorkflowStatesData =
+            self.client.execute(queries::LIST_STATES, vars).await?;
+        let candidates: Vec<Candidate> = data
+            .workflow_states
+            .nodes
+            .into_iter()
+            .map(|s| Candidate::new(s.id, s.name.clone()).name(s.name))
+            .collect();
+        let kind = format!("Workflow state (team {})", team_key.to_uppercase());
+        let id = matching::pick(&kind, state_name, &candidates)?.id.clone();
+        self.client
+            .cache()
+            .insert(EntityKind::State, &keys[0], id.clone());
+        Ok(id)
+    }
+
+    /// Get the authenticated viewer.
+    async fn get_viewer(&self) -> Result<types::Viewer, Error> {
+        let data: response::ViewerData = self
+            .client
+            .execute(queries::VIEWER, serde_json::json!({}))
+            .await?;
+        Ok(data.viewer)
+    }
+
+    // ---- list_issues ----
+
+    async fn handle_list_issues(
+        &self,
+        params: list_issues::ListIssuesParams,
+    ) -> Result<Output<types::Connection<types::Issue>>, Error> {
+        let query = params
+            .filter
+            .as_deref()
+            .map(|q| self.parse_query(q))
+            .transpose()?;
+        let mut issue_filters = Vec::new();
+
+        if let Some(ref team) = params.team {
+            issue_filters.push(filters::team_filter(team));
+        }
+        // Name filters take `none`, `any` and `not <name>`, the same as the
+        // filter expression they compile through.
+        let flag = |set: Option<bool>, yes: &'static str, no: &'static str| {
+            set.map(|set| if set { yes } else { no }.to_string())
+        };
+        let name_params = [
+            ("assignee", "assignee", params.assignee.clone()),
+            ("creator", "creator", params.creator.clone()),
+            ("status", "state", params.status.clone()),
+            ("project", "project", params.project.clone()),
+            ("label", "label", params.label.clone()),
+            ("cycle", "cycle", params.cycle.clone()),
+            ("milestone", "milestone", params.milestone.clone()),
+            ("parent", "parent", params.parent.clone()),
+            ("subscriber", "subscriber", params.subscriber.clone()),
+            ("customer", "customer", params.customer.clone()),
+            ("sla", "sla", params.sla.clone()),
+            ("triage", "triage", flag(params.triage, "true", "false")),
+            (
+                "hasEstimate",
+                "estimate",
+                flag(params.has_estimate, "any", "none"),
+            ),
+            (
+                "hasAttachments",
+                "attachments",
+                flag(params.has_attachments, "true", "false"),
+            ),
+        ];
+        for (param, field, value) in name_params {
+            if let Some(value) = value {
+                let test = issue_query::param(param, field, &value)?;
+                issue_filters.push(issue_query::compile(&test, &issue_query::DateValues::new()));
+            }
+        }
+        if let Some(ref priority) = params.priority {
+            issue_filters.push(filters::priority_filter(priority.to_number()));
+        }
+        if let Some(estimate) = params.estimate {
+            issue_filters.push(filters::estimate_filter(estimate));
+        }
+        if params.has_blocked_by_relation == Some(true) {
+            issue_filters.push(filters::blocking_relation_filter(true));
+        }
+        if params.has_blocking_relation == Some(true) {
+            issue_filters.push(filters::blocking_relation_filter(false));
+        }
+
+        // Date range filters (#8). Every date is checked before the first
+        // cycle lookup, so a typo never costs a request.
+        let date_params = [
+            ("dueBefore", params.due_before.as_ref()),
+            ("dueAfter", params.due_after.as_ref()),
+            ("createdBefore", params.created_before.as_ref()),
+            ("createdAfter", params.created_after.as_ref()),
+            ("updatedBefore", params.updated_before.as_ref()),
+            ("updatedAfter", params.updated_after.as_ref()),
+            ("completedBefore", params.completed_before.as_ref()),
+            ("completedAfter", params.completed_after.as_ref()),
+            ("canceledBefore", params.canceled_before.as_ref()),
+            ("canceledAfter", params.canceled_after.as_ref()),
+            ("snoozedUntilAfter", params.snoozed_until_after.as_ref()),
+        ];
+        self.check_dates(&date_params)?;
+        let mut dates = HashMap::new();
+        for (field, value) in date_params {
+            let Some(value) = value else { continue };
+            let team = params.team.as_deref();
+            // Due dates are calendar days; the rest are timestamps.
+            let normalized = if field.starts_with("due") {
+                self.date_arg(field, value, Bound::Start, team).await?
+            } else {
+                self.timestamp_arg(field, value, Bound::Start, team).await?
+            };
+            dates.insert(field, normalized);
+        }
+        let date = |field: &str| dates.get(field).map(String::as_str);
+        if params.due_before.is_some() || params.due_after.is_some() {
+            issue_filters.push(filters::due_date_filter(
+                date("dueBefore"),
+                date("dueAfter"),
+            ));
+        }
+        if params.created_before.is_some() || params.created_after.is_some() {
+            issue_filters.push(filters::created_at_filter(
+                date("createdBefore"),
+                date("createdAfter"),
+            ));
+        }
+        if params.updated_before.is_some() || params.updated_after.is_some() {
+            issue_filters.push(filters::updated_at_filter(
+                date("updatedBefore"),
+                date("updatedAfter"),
+            ));
+        }
+        if params.completed_before.is_some() || params.completed_after.is_some() {
+            issue_filters.push(filters::completed_at_filter(
+                date("completedBefore"),
+                date("completedAfter"),
+            ));
+        }
+        if params.canceled_before.is_some() || params.canceled_after.is_some() {
+            issue_filters.push(filters::canceled_at_filter(
+                date("canceledBefore"),
+                date("canceledAfter"),
+            ));
+        }
+        if let Some(snoozed) = date("snoozedUntilAfter") {
+            issue_filters.push(filters::snoozed_until_at_filter(snoozed));
+        }
+        if let Some(ref query) = query {
+            issue_filters.push(self.compile_query(query, params.team.as_deref()).await?);
+        }
+
+        let filter = filters::IssueFilter::combine(issue_filters);
+        let limit = params.limit.unwrap_or(25).min(100);
+        let order_by = params
+            .order_by
+            .as_ref()
+            .map(|o| o.as_str())
+            .unwrap_or("updatedAt");
+
+        let mut vars = serde_json::json!({
+            "first": limit,
+            "orderBy": order_by,
+        });
+        if let Some(ref cursor) = params.cursor {
+            vars["after"] = serde_json::Value::String(cursor.clone());
+        }
+        if let Some(f) = filter {
+            vars["filter"] = serde_json::to_value(f).unwrap();
+        }
+
+        let data: response::IssuesData = self.client.execute(queries::LIST_ISSUES, vars).await?;
+
+        let issues = &data.issues.nodes;
+        if issues.is_empty() {
+            return Ok(Output::new(
+                "No issues found matching the filters.",
+                data.issues,
+            ));
+        }
+
+        let lines: Vec<String> = issues.iter().map(format::format_issue_summary).collect();
+        let pagination = format::format_pagination_with_cursor(
+            data.issues.page_info.has_next_page,
+            issues.len(),
+            data.issues.page_info.end_cursor.as_deref(),
+        );
+
+        Ok(Output::new(
+            format!("{}{}", lines.join("\n"), pagination),
+            data.issues,
+        ))
+    }
+
+    // ---- search_issues ----
+
+    async fn handle_search_issues(
+        &self,
+        params: search_issues::SearchIssuesParams,
+    ) -> Result<Output<types::Connection<types::Issue>>, Error> {
+        let query = params
+            .filter
+            .as_deref()
+            .map(|q| self.parse_query(q))
+            .transpose()?;
+        let mut issue_filters = Vec::new();
+
+        if let Some(ref team) = params.team {
+            issue_filters.push(filters::team_filter(team));
+        }
+        if let Some(ref status) = params.status {
+            issue_filters.push(filters::status_filter(status));
+        }
+        if let Some(ref assignee) = params.assignee {
+            issue_filters.push(filters::assignee_filter(assignee));
+        }
+        if let Some(ref query) = query {
+            issue_filters.push(self.compile_query(query, params.team.as_deref()).await?);
+        }
+
+        let filter = filters::IssueFilter::combine(issue_filters);
+        let limit = params.limit.unwrap_or(25).min(100);
+
+        let mut vars = serde_json::json!({
+            "query": params.query,
+            "first": limit,
+        });
+        if let Some(ref cursor) = params.cursor {
+            vars["after"] = serde_json::Value::String(cursor.clone());
+        }
+        if let Some(f) = filter {
+            vars["filter"] = serde_json::to_value(f).unwrap();
+        }
+
+        let data: response::SearchIssuesData =
+            self.client.execute(queries::SEARCH_ISSUES, vars).await?;
+
+        let issues = &data.search_issues.nodes;
+        if issues.is_empty() {
+            return Ok(Output::new(
+                format!("No issues found matching \"{}\".", params.query),
+                data.search_issues,
+            ));
+        }
+
+        let lines: Vec<String> = issues.iter().map(format::format_issue_summary).collect();
         let pagination = format::format_pagination_with_cursor(
             data.search_issues.page_info.has_next_page,
             issues.len(),
             data.search_issues.page_info.end_cursor.as_deref(),
         );
 
-        Ok(format!(
-            "Search results for \"{}\":\n\n{}{}",
-            params.query,
-            lines.join("\n"),
-            pagination
+        Ok(Output::new(
+            format!(
+                "Search results for \"{}\":\n\n{}{}",
+                params.query,
+                lines.join("\n"),
+                pagination
+            ),
+            data.search_issues,
         ))
     }
 
     // ---- get_issue ----
 
-    async fn handle_get_issue(&self, params: get_issue::GetIssueParams) -> Result<String, Error> {
+    async fn handle_get_issue(
+        &self,
+        params: get_issue::GetIssueParams,
+    ) -> Result<Output<types::Issue>, Error> {
         let uuid = self.resolve_issue_id(&params.id).await?;
         let vars = serde_json::json!({ "id": uuid });
-        let data: response::IssueData = self
-            .client
-            .execute_json(queries::GET_ISSUE, vars)
-            .await?;
-        Ok(format::format_issue_detail(&data.issue))
+        let data: response::IssueData = self.client.execute(queries::GET_ISSUE, vars).await?;
+        Ok(Output::new(
+            format::format_issue_detail(&data.issue),
+            data.issue,
+        ))
     }
 
     // ---- list_teams ----
@@ -4234,13 +5854,13 @@ impl LinearMcp {
     async fn handle_list_teams(
         &self,
         params: list_teams::ListTeamsParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::NodeList<response::TeamWithMembers>>, Error> {
         let include_members = params.include_member_count.unwrap_or(false);
 
         if include_members {
             let data: response::TeamsWithMembersData = self
                 .client
-                .execute::<(), _>(queries::LIST_TEAMS_WITH_MEMBERS, None)
+                .execute(queries::LIST_TEAMS_WITH_MEMBERS, serde_json::json!({}))
                 .await?;
 
             let lines: Vec<String> = data
@@ -4262,11 +5882,14 @@ impl LinearMcp {
                 })
                 .collect();
 
-            Ok(format!("Teams:\n\n{}", lines.join("\n")))
+            Ok(Output::new(
+                format!("Teams:\n\n{}", lines.join("\n")),
+                data.teams,
+            ))
         } else {
             let data: response::TeamsData = self
                 .client
-                .execute::<(), _>(queries::LIST_TEAMS, None)
+                .execute(queries::LIST_TEAMS, serde_json::json!({}))
                 .await?;
 
             let lines: Vec<String> = data
@@ -4275,8 +5898,26 @@ impl LinearMcp {
                 .iter()
                 .map(|t| format::format_team(t, None))
                 .collect();
+            let teams = data
+                .teams
+                .nodes
+                .into_iter()
+                .map(|t| response::TeamWithMembers {
+                    id: t.id,
+                    key: t.key,
+                    name: t.name,
+                    description: t.description,
+                    timezone: t.timezone,
+                    triage_enabled: t.triage_enabled,
+                    default_issue_state: t.default_issue_state,
+                    members: None,
+                })
+                .collect();
 
-            Ok(format!("Teams:\n\n{}", lines.join("\n")))
+            Ok(Output::new(
+                format!("Teams:\n\n{}", lines.join("\n")),
+                types::NodeList { nodes: teams },
+            ))
         }
     }
 
@@ -4285,7 +5926,7 @@ impl LinearMcp {
     async fn handle_list_projects(
         &self,
         params: list_projects::ListProjectsParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::NodeList<types::Project>>, Error> {
         let desired_limit = params.limit.unwrap_or(50).min(100) as usize;
 
         let mut project_filters = Vec::new();
@@ -4298,18 +5939,7 @@ impl LinearMcp {
         }
         if let Some(ref lead) = params.lead {
             project_filters.push(filters::ProjectFilter {
-                lead: Some(filters::ProjectLeadFilter {
-                    or: Some(vec![
-                        filters::ProjectLeadFieldFilter {
-                            email: Some(filters::StringFilter::eq_ignore_case(lead)),
-                            display_name: None,
-                        },
-                        filters::ProjectLeadFieldFilter {
-                            email: None,
-                            display_name: Some(filters::StringFilter::eq_ignore_case(lead)),
-                        },
-                    ]),
-                }),
+                lead: Some(filters::UserFilter::named(lead)),
                 ..Default::default()
             });
         }
@@ -4332,23 +5962,24 @@ impl LinearMcp {
             vars["filter"] = serde_json::to_value(f).unwrap();
         }
 
-        let data: response::ProjectsData = self
-            .client
-            .execute_json(queries::LIST_PROJECTS, vars)
-            .await?;
+        let data: response::ProjectsData =
+            self.client.execute(queries::LIST_PROJECTS, vars).await?;
 
         let projects: Vec<&types::Project> = data.projects.nodes.iter().collect();
 
         if projects.is_empty() {
-            return Ok("No projects found matching the filters.".to_string());
+            return Ok(Output::new(
+                "No projects found matching the filters.",
+                data.projects,
+            ));
         }
 
-        let lines: Vec<String> = projects
-            .iter()
-            .map(|p| format::format_project(p))
-            .collect();
+        let lines: Vec<String> = projects.iter().map(|p| format::format_project(p)).collect();
 
-        Ok(format!("Projects:\n\n{}", lines.join("\n\n")))
+        Ok(Output::new(
+            format!("Projects:\n\n{}", lines.join("\n\n")),
+            data.projects,
+        ))
     }
 
     // ---- list_users ----
@@ -4356,23 +5987,18 @@ impl LinearMcp {
     async fn handle_list_users(
         &self,
         params: list_users::ListUsersParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::NodeList<types::User>>, Error> {
         let limit = params.limit.unwrap_or(50).min(100);
         let vars = serde_json::json!({ "first": limit });
 
-        let data: response::UsersData = self
-            .client
-            .execute_json(queries::LIST_USERS, vars)
-            .await?;
+        let data: response::UsersData = self.client.execute(queries::LIST_USERS, vars).await?;
 
-        let lines: Vec<String> = data
-            .users
-            .nodes
-            .iter()
-            .map(format::format_user)
-            .collect();
+        let lines: Vec<String> = data.users.nodes.iter().map(format::format_user).collect();
 
-        Ok(format!("Members:\n\n{}", lines.join("\n")))
+        Ok(Output::new(
+            format!("Members:\n\n{}", lines.join("\n")),
+            data.users,
+        ))
     }
 
     // ---- list_states ----
@@ -4380,7 +6006,7 @@ impl LinearMcp {
     async fn handle_list_states(
         &self,
         params: list_states::ListStatesParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::NodeList<response::WorkflowStateWithTeam>>, Error> {
         let mut vars = serde_json::json!({ "first": 200 });
         if let Some(ref team) = params.team {
             let filter = filters::WorkflowStateFilter {
@@ -4393,10 +6019,8 @@ impl LinearMcp {
             vars["filter"] = serde_json::to_value(filter).unwrap();
         }
 
-        let data: response::WorkflowStatesData = self
-            .client
-            .execute_json(queries::LIST_STATES, vars)
-            .await?;
+        let data: response::WorkflowStatesData =
+            self.client.execute(queries::LIST_STATES, vars).await?;
 
         // Group states by team
         let mut groups: BTreeMap<String, Vec<&response::WorkflowStateWithTeam>> = BTreeMap::new();
@@ -4409,11 +6033,17 @@ impl LinearMcp {
 
         let mut sections = Vec::new();
         for (team_key, states) in &groups {
-            let lines: Vec<String> = states.iter().map(|s| format::format_workflow_state(s)).collect();
+            let lines: Vec<String> = states
+                .iter()
+                .map(|s| format::format_workflow_state(s))
+                .collect();
             sections.push(format!("## {}\n{}", team_key, lines.join("\n")));
         }
 
-        Ok(format!("Workflow States:\n\n{}", sections.join("\n\n")))
+        Ok(Output::new(
+            format!("Workflow States:\n\n{}", sections.join("\n\n")),
+            data.workflow_states,
+        ))
     }
 
     // ---- my_issues ----
@@ -4421,7 +6051,12 @@ impl LinearMcp {
     async fn handle_my_issues(
         &self,
         params: my_issues::MyIssuesParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::Connection<types::Issue>>, Error> {
+        let query = params
+            .filter
+            .as_deref()
+            .map(|q| self.parse_query(q))
+            .transpose()?;
         let viewer = self.get_viewer().await?;
         let limit = params.limit.unwrap_or(50).min(100);
         let include_completed = params.include_completed.unwrap_or(false);
@@ -4436,6 +6071,9 @@ impl LinearMcp {
         if let Some(ref priority) = params.priority {
             issue_filters.push(filters::priority_filter(priority.to_number()));
         }
+        if let Some(ref query) = query {
+            issue_filters.push(self.compile_query(query, params.team.as_deref()).await?);
+        }
 
         let filter = filters::IssueFilter::combine(issue_filters);
 
@@ -4447,14 +6085,14 @@ impl LinearMcp {
             vars["filter"] = serde_json::to_value(f).unwrap();
         }
 
-        let data: response::IssuesData = self
-            .client
-            .execute_json(queries::MY_ISSUES, vars)
-            .await?;
+        let data: response::IssuesData = self.client.execute(queries::MY_ISSUES, vars).await?;
 
         let issues = &data.issues.nodes;
         if issues.is_empty() {
-            return Ok(format!("No issues assigned to {}.", viewer.display_name));
+            return Ok(Output::new(
+                format!("No issues assigned to {}.", viewer.display_name),
+                data.issues,
+            ));
         }
 
         // Group by status
@@ -4476,8 +6114,10 @@ impl LinearMcp {
         let sections: Vec<String> = groups
             .iter()
             .map(|(state_name, issues)| {
-                let lines: Vec<String> =
-                    issues.iter().map(|i| format::format_issue_summary(i)).collect();
+                let lines: Vec<String> = issues
+                    .iter()
+                    .map(|i| format::format_issue_summary(i))
+                    .collect();
                 format!("## {} ({})\n{}", state_name, issues.len(), lines.join("\n"))
             })
             .collect();
@@ -4488,11 +6128,14 @@ impl LinearMcp {
             data.issues.page_info.end_cursor.as_deref(),
         );
 
-        Ok(format!(
-            "Issues assigned to {}:\n\n{}{}",
-            viewer.display_name,
-            sections.join("\n\n"),
-            pagination
+        Ok(Output::new(
+            format!(
+                "Issues assigned to {}:\n\n{}{}",
+                viewer.display_name,
+                sections.join("\n\n"),
+                pagination
+            ),
+            data.issues,
         ))
     }
 
@@ -4501,11 +6144,14 @@ impl LinearMcp {
     async fn handle_create_issue(
         &self,
         params: create_issue::CreateIssueParams,
-    ) -> Result<String, Error> {
-        let team_id = self.resolve_team_id(&params.team).await?;
+    ) -> Result<Output<types::Issue>, Error> {
+        self.check_dates(&[("dueDate", params.due_date.as_ref())])?;
+        let team = self
+            .resolve_team(&self.team_or_default(params.team.as_deref())?)
+            .await?;
 
         let mut input = serde_json::json!({
-            "teamId": team_id,
+            "teamId": team.id,
             "title": params.title,
         });
 
@@ -4517,7 +6163,7 @@ impl LinearMcp {
             input["assigneeId"] = serde_json::Value::String(user_id);
         }
         if let Some(ref status) = params.status {
-            let state_id = self.resolve_state_id(status, &params.team).await?;
+            let state_id = self.resolve_state_id(status, &team.key).await?;
             input["stateId"] = serde_json::Value::String(state_id);
         }
         if let Some(ref priority) = params.priority {
@@ -4527,12 +6173,15 @@ impl LinearMcp {
             input["estimate"] = serde_json::json!(estimate);
         }
         if let Some(ref due_date) = params.due_date {
-            input["dueDate"] = serde_json::Value::String(due_date.clone());
+            let due_date = self
+                .date_arg("dueDate", due_date, Bound::End, Some(&team.key))
+                .await?;
+            input["dueDate"] = serde_json::Value::String(due_date);
         }
 
         // Task #6: labels, project, parent
         if let Some(ref label_names) = params.labels {
-            let label_ids = self.resolve_label_ids(label_names, Some(&params.team)).await?;
+            let label_ids = self.resolve_label_ids(label_names, Some(&team.key)).await?;
             input["labelIds"] = serde_json::json!(label_ids);
         }
         if let Some(ref project_name) = params.project {
@@ -4543,37 +6192,43 @@ impl LinearMcp {
             let parent_id = self.resolve_issue_id(parent_identifier).await?;
             input["parentId"] = serde_json::Value::String(parent_id);
         }
-        if let Some(ref cycle_id) = params.cycle_id {
-            input["cycleId"] = serde_json::Value::String(cycle_id.clone());
+        if let Some(ref cycle) = params.cycle_id {
+            let cycle_id = self
+                .resolve_cycle_id(cycle, &team.key, Match::Fuzzy)
+                .await?;
+            input["cycleId"] = serde_json::Value::String(cycle_id);
         }
         if let Some(ref subscriber_emails) = params.subscribers {
-            let emails: Vec<&str> = subscriber_emails.split(',').map(|s| s.trim()).filter(|s| !s.is_empty()).collect();
-            let mut subscriber_ids = Vec::new();
-            for email in emails {
-                subscriber_ids.push(self.resolve_user_id(email).await?);
-            }
+            let emails: Vec<&str> = subscriber_emails
+                .split(',')
+                .map(|s| s.trim())
+                .filter(|s| !s.is_empty())
+                .collect();
+            let subscriber_ids = self.resolve_user_ids(&emails).await?;
             input["subscriberIds"] = serde_json::json!(subscriber_ids);
         }
         if let Some(ref milestone_name) = params.project_milestone {
             if let Some(ref project_name) = params.project {
                 let project_id = self.resolve_project_id_or_uuid(project_name).await?;
-                let milestone_id = self.resolve_project_milestone_id(milestone_name, &project_id).await?;
+                let milestone_id = self
+                    .resolve_project_milestone_id(milestone_name, &project_id)
+                    .await?;
                 input["projectMilestoneId"] = serde_json::Value::String(milestone_id);
             } else {
-                return Err(Error::InvalidInput("projectMilestone requires a project to be specified".into()));
+                return Err(Error::InvalidInput(
+                    "projectMilestone requires a project to be specified".into(),
+                ));
             }
         }
 
         let vars = serde_json::json!({ "input": input });
-        let data: response::CreateIssueData = self
-            .client
-            .execute_json(queries::CREATE_ISSUE, vars)
-            .await?;
+        let data: response::CreateIssueData =
+            self.client.execute(queries::CREATE_ISSUE, vars).await?;
 
         match data.issue_create.issue {
             Some(issue) => {
                 let detail = format::format_issue_detail(&issue);
-                Ok(format!("Issue created:\n\n{}", detail))
+                Ok(Output::new(format!("Issue created:\n\n{}", detail), issue))
             }
             None => Err(Error::GraphQL("Issue creation returned no issue".into())),
         }
@@ -4584,7 +6239,8 @@ impl LinearMcp {
     async fn handle_update_issue(
         &self,
         params: update_issue::UpdateIssueParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::Issue>, Error> {
+        self.check_dates(&[("dueDate", params.due_date.as_ref())])?;
         let uuid = self.resolve_issue_id(&params.id).await?;
 
         let mut input = serde_json::Map::new();
@@ -4619,10 +6275,7 @@ impl LinearMcp {
                 input.insert("assigneeId".into(), serde_json::Value::Null);
             } else {
                 let user_id = self.resolve_user_id(assignee).await?;
-                input.insert(
-                    "assigneeId".into(),
-                    serde_json::Value::String(user_id),
-                );
+                input.insert("assigneeId".into(), serde_json::Value::String(user_id));
             }
             has_fields = true;
         }
@@ -4632,26 +6285,39 @@ impl LinearMcp {
             if due_date.eq_ignore_ascii_case("none") {
                 input.insert("dueDate".into(), serde_json::Value::Null);
             } else {
-                input.insert(
-                    "dueDate".into(),
-                    serde_json::Value::String(due_date.clone()),
-                );
+                // "end of cycle" means the issue's own team's cycle.
+                let team = match self.parse_date("dueDate", due_date)? {
+                    DateExpr::Cycle { .. } => Some(self.resolve_team_key_from_issue(&uuid).await?),
+                    _ => None,
+                };
+                let due_date = self
+                    .date_arg("dueDate", due_date, Bound::End, team.as_deref())
+                    .await?;
+                input.insert("dueDate".into(), serde_json::Value::String(due_date));
             }
             has_fields = true;
         }
 
         // Fetch issue metadata once if any dependent fields need it
-        let needs_issue_data = params.status.is_some() || params.labels.is_some() || params.project_milestone.is_some();
+        let needs_issue_data = params.status.is_some()
+            || params.labels.is_some()
+            || params.project_milestone.is_some();
         let issue_data = if needs_issue_data {
             let issue_vars = serde_json::json!({ "id": uuid });
-            Some(self.client.execute_json::<response::IssueData>(queries::GET_ISSUE, issue_vars).await?)
+            Some(
+                self.client
+                    .execute::<response::IssueData>(queries::GET_ISSUE, issue_vars)
+                    .await?,
+            )
         } else {
             None
         };
 
         let team_key = if params.status.is_some() || params.labels.is_some() {
             Some(
-                issue_data.as_ref().unwrap()
+                issue_data
+                    .as_ref()
+                    .unwrap()
                     .issue
                     .team
                     .as_ref()
@@ -4670,7 +6336,9 @@ impl LinearMcp {
         }
 
         if let Some(ref label_names) = params.labels {
-            let label_ids = self.resolve_label_ids(label_names, team_key.as_deref()).await?;
+            let label_ids = self
+                .resolve_label_ids(label_names, team_key.as_deref())
+                .await?;
             input.insert("labelIds".into(), serde_json::json!(label_ids));
             has_fields = true;
         }
@@ -4692,17 +6360,33 @@ impl LinearMcp {
             }
             has_fields = true;
         }
-        if let Some(ref cycle_id) = params.cycle_id {
-            input.insert("cycleId".into(), serde_json::Value::String(cycle_id.clone()));
+        if let Some(ref cycle) = params.cycle_id {
+            let team_key = self.resolve_team_key_from_issue(&uuid).await?;
+            let cycle_id = self
+                .resolve_cycle_id(cycle, &team_key, Match::Fuzzy)
+                .await?;
+            input.insert("cycleId".into(), serde_json::Value::String(cycle_id));
             has_fields = true;
         }
         // Subscribers are handled additively after the main update via issueSubscribe
         if let Some(ref milestone_name) = params.project_milestone {
-            let project_id = issue_data.as_ref().unwrap().issue.project.as_ref()
+            let project_id = issue_data
+                .as_ref()
+                .unwrap()
+                .issue
+                .project
+                .as_ref()
                 .map(|p| p.id.clone())
-                .ok_or_else(|| Error::InvalidInput("Issue has no project — cannot resolve milestone".into()))?;
-            let milestone_id = self.resolve_project_milestone_id(milestone_name, &project_id).await?;
-            input.insert("projectMilestoneId".into(), serde_json::Value::String(milestone_id));
+                .ok_or_else(|| {
+                    Error::InvalidInput("Issue has no project — cannot resolve milestone".into())
+                })?;
+            let milestone_id = self
+                .resolve_project_milestone_id(milestone_name, &project_id)
+                .await?;
+            input.insert(
+                "projectMilestoneId".into(),
+                serde_json::Value::String(milestone_id),
+            );
             has_fields = true;
         }
 
@@ -4716,28 +6400,29 @@ impl LinearMcp {
             "id": uuid,
             "input": serde_json::Value::Object(input),
         });
-        let data: response::UpdateIssueData = self
-            .client
-            .execute_json(queries::UPDATE_ISSUE, vars)
-            .await?;
+        let data: response::UpdateIssueData =
+            self.client.execute(queries::UPDATE_ISSUE, vars).await?;
 
         let result = match data.issue_update.issue {
             Some(issue) => {
                 let detail = format::format_issue_detail(&issue);
-                Ok(format!("Issue updated:\n\n{}", detail))
+                Ok(Output::new(format!("Issue updated:\n\n{}", detail), issue))
             }
             None => Err(Error::GraphQL("Issue update returned no issue".into())),
         };
 
         // Add subscribers additively via issueSubscribe (after the main update)
         if let Some(ref subscriber_emails) = params.subscribers {
-            let emails: Vec<&str> = subscriber_emails.split(',').map(|s| s.trim()).filter(|s| !s.is_empty()).collect();
-            for email in emails {
-                let user_id = self.resolve_user_id(email).await?;
+            let emails: Vec<&str> = subscriber_emails
+                .split(',')
+                .map(|s| s.trim())
+                .filter(|s| !s.is_empty())
+                .collect();
+            for user_id in self.resolve_user_ids(&emails).await? {
                 let sub_vars = serde_json::json!({ "id": uuid, "userId": user_id });
                 let _: response::SubscribeToIssueData = self
                     .client
-                    .execute_json(queries::SUBSCRIBE_TO_ISSUE, sub_vars)
+                    .execute(queries::SUBSCRIBE_TO_ISSUE, sub_vars)
                     .await?;
             }
         }
@@ -4750,15 +6435,13 @@ impl LinearMcp {
     async fn handle_add_comment(
         &self,
         params: add_comment::AddCommentParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::CommentMutationResult>, Error> {
         let uuid = self.resolve_issue_id(&params.issue_id).await?;
 
         // Fetch identifier for the confirmation message
         let issue_vars = serde_json::json!({ "id": uuid });
-        let issue_data: response::IssueData = self
-            .client
-            .execute_json(queries::GET_ISSUE, issue_vars)
-            .await?;
+        let issue_data: response::IssueData =
+            self.client.execute(queries::GET_ISSUE, issue_vars).await?;
         let identifier = &issue_data.issue.identifier;
 
         let mut comment_input = serde_json::json!({
@@ -4770,23 +6453,29 @@ impl LinearMcp {
         }
 
         let vars = serde_json::json!({ "input": comment_input });
-        let data: response::AddCommentData = self
-            .client
-            .execute_json(queries::ADD_COMMENT, vars)
-            .await?;
+        let data: response::AddCommentData =
+            self.client.execute(queries::ADD_COMMENT, vars).await?;
 
-        let reply_note = if params.parent_id.is_some() { " (reply)" } else { "" };
+        let reply_note = if params.parent_id.is_some() {
+            " (reply)"
+        } else {
+            ""
+        };
 
-        match data.comment_create.comment {
-            Some(comment) => {
-                let formatted = format::format_comment(&comment);
-                Ok(format!("Comment{} added to {}:\n\n{}", reply_note, identifier, formatted))
+        let text = match data.comment_create.comment {
+            Some(ref comment) => {
+                let formatted = format::format_comment(comment);
+                format!(
+                    "Comment{} added to {}:\n\n{}",
+                    reply_note, identifier, formatted
+                )
             }
-            None => Ok(format!(
+            None => format!(
                 "Comment{} added to {} but could not fetch details.",
                 reply_note, identifier
-            )),
-        }
+            ),
+        };
+        Ok(Output::new(text, data.comment_create))
     }
 
     // ---- update_comment (Task #10) ----
@@ -4794,25 +6483,24 @@ impl LinearMcp {
     async fn handle_update_comment(
         &self,
         params: update_comment::UpdateCommentParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::CommentMutationResult>, Error> {
         let vars = serde_json::json!({
             "id": params.id,
             "input": {
                 "body": params.body,
             }
         });
-        let data: response::UpdateCommentData = self
-            .client
-            .execute_json(queries::UPDATE_COMMENT, vars)
-            .await?;
+        let data: response::UpdateCommentData =
+            self.client.execute(queries::UPDATE_COMMENT, vars).await?;
 
-        match data.comment_update.comment {
-            Some(comment) => {
-                let formatted = format::format_comment(&comment);
-                Ok(format!("Comment updated:\n\n{}", formatted))
+        let text = match data.comment_update.comment {
+            Some(ref comment) => {
+                let formatted = format::format_comment(comment);
+                format!("Comment updated:\n\n{}", formatted)
             }
-            None => Ok("Comment updated but could not fetch details.".to_string()),
-        }
+            None => "Comment updated but could not fetch details.".to_string(),
+        };
+        Ok(Output::new(text, data.comment_update))
     }
 
     // ---- delete_comment (Task #10) ----
@@ -4820,15 +6508,16 @@ impl LinearMcp {
     async fn handle_delete_comment(
         &self,
         params: delete_comment::DeleteCommentParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let vars = serde_json::json!({ "id": params.id });
-        let data: response::DeleteCommentData = self
-            .client
-            .execute_json(queries::DELETE_COMMENT, vars)
-            .await?;
+        let data: response::DeleteCommentData =
+            self.client.execute(queries::DELETE_COMMENT, vars).await?;
 
         if data.comment_delete.success {
-            Ok(format!("Comment {} deleted.", params.id))
+            Ok(Output::new(
+                format!("Comment {} deleted.", params.id),
+                data.comment_delete,
+            ))
         } else {
             Err(Error::GraphQL("Comment deletion failed".into()))
         }
@@ -4839,26 +6528,31 @@ impl LinearMcp {
     async fn handle_list_cycles(
         &self,
         params: list_cycles::ListCyclesParams,
-    ) -> Result<String, Error> {
-        let team_id = self.resolve_team_id(&params.team).await?;
+    ) -> Result<Output<response::TeamCycles>, Error> {
+        let team = self.team_or_default(params.team.as_deref())?;
+        let team_id = self.resolve_team_id(&team).await?;
         let limit = params.limit.unwrap_or(25).min(100);
 
         let vars = serde_json::json!({
             "teamId": team_id,
             "first": limit,
         });
-        let data: response::TeamCyclesData = self
-            .client
-            .execute_json(queries::LIST_CYCLES, vars)
-            .await?;
+        let data: response::TeamCyclesData =
+            self.client.execute(queries::LIST_CYCLES, vars).await?;
 
         let cycles = &data.team.cycles.nodes;
         if cycles.is_empty() {
-            return Ok(format!("No cycles found for team {}.", params.team));
+            return Ok(Output::new(
+                format!("No cycles found for team {}.", team),
+                data.team,
+            ));
         }
 
         let lines: Vec<String> = cycles.iter().map(format::format_cycle_summary).collect();
-        Ok(format!("Cycles for {}:\n\n{}", params.team, lines.join("\n")))
+        Ok(Output::new(
+            format!("Cycles for {}:\n\n{}", team, lines.join("\n")),
+            data.team,
+        ))
     }
 
     // ---- get_cycle (Task #4) ----
@@ -4866,13 +6560,16 @@ impl LinearMcp {
     async fn handle_get_cycle(
         &self,
         params: get_cycle::GetCycleParams,
-    ) -> Result<String, Error> {
-        let vars = serde_json::json!({ "id": params.id });
-        let data: response::CycleData = self
-            .client
-            .execute_json(queries::GET_CYCLE, vars)
-            .await?;
-        Ok(format::format_cycle_detail(&data.cycle))
+    ) -> Result<Output<types::Cycle>, Error> {
+        let id = self
+            .resolve_cycle_param(&params.id, params.team.as_deref(), Match::Fuzzy)
+            .await?;
+        let vars = serde_json::json!({ "id": id });
+        let data: response::CycleData = self.client.execute(queries::GET_CYCLE, vars).await?;
+        Ok(Output::new(
+            format::format_cycle_detail(&data.cycle),
+            data.cycle,
+        ))
     }
 
     // ---- add_issue_to_cycle (Task #4) ----
@@ -4880,24 +6577,29 @@ impl LinearMcp {
     async fn handle_add_issue_to_cycle(
         &self,
         params: add_issue_to_cycle::AddIssueToCycleParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::Issue>, Error> {
         let issue_uuid = self.resolve_issue_id(&params.issue_id).await?;
+        let team_key = self.resolve_team_key_from_issue(&issue_uuid).await?;
+        let cycle_id = self
+            .resolve_cycle_id(&params.cycle_id, &team_key, Match::Fuzzy)
+            .await?;
 
         let vars = serde_json::json!({
             "id": issue_uuid,
             "input": {
-                "cycleId": params.cycle_id,
+                "cycleId": cycle_id,
             }
         });
-        let data: response::UpdateIssueData = self
-            .client
-            .execute_json(queries::UPDATE_ISSUE, vars)
-            .await?;
+        let data: response::UpdateIssueData =
+            self.client.execute(queries::UPDATE_ISSUE, vars).await?;
 
         match data.issue_update.issue {
-            Some(issue) => Ok(format!(
-                "Issue {} added to cycle {}.",
-                issue.identifier, params.cycle_id
+            Some(issue) => Ok(Output::new(
+                format!(
+                    "Issue {} added to cycle {}.",
+                    issue.identifier, params.cycle_id
+                ),
+                issue,
             )),
             None => Err(Error::GraphQL("Failed to add issue to cycle".into())),
         }
@@ -4908,7 +6610,7 @@ impl LinearMcp {
     async fn handle_remove_issue_from_cycle(
         &self,
         params: remove_issue_from_cycle::RemoveIssueFromCycleParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::Issue>, Error> {
         let issue_uuid = self.resolve_issue_id(&params.issue_id).await?;
 
         let vars = serde_json::json!({
@@ -4917,15 +6619,13 @@ impl LinearMcp {
                 "cycleId": serde_json::Value::Null,
             }
         });
-        let data: response::UpdateIssueData = self
-            .client
-            .execute_json(queries::UPDATE_ISSUE, vars)
-            .await?;
+        let data: response::UpdateIssueData =
+            self.client.execute(queries::UPDATE_ISSUE, vars).await?;
 
         match data.issue_update.issue {
-            Some(issue) => Ok(format!(
-                "Issue {} removed from cycle.",
-                issue.identifier
+            Some(issue) => Ok(Output::new(
+                format!("Issue {} removed from cycle.", issue.identifier),
+                issue,
             )),
             None => Err(Error::GraphQL("Failed to remove issue from cycle".into())),
         }
@@ -4936,7 +6636,7 @@ impl LinearMcp {
     async fn handle_list_labels(
         &self,
         params: list_labels::ListLabelsParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::NodeList<types::Label>>, Error> {
         let limit = params.limit.unwrap_or(50).min(100);
 
         let mut vars = serde_json::json!({ "first": limit });
@@ -4952,18 +6652,18 @@ impl LinearMcp {
             vars["filter"] = serde_json::to_value(filter).unwrap();
         }
 
-        let data: response::LabelsData = self
-            .client
-            .execute_json(queries::LIST_LABELS, vars)
-            .await?;
+        let data: response::LabelsData = self.client.execute(queries::LIST_LABELS, vars).await?;
 
         let labels = &data.issue_labels.nodes;
         if labels.is_empty() {
-            return Ok("No labels found.".to_string());
+            return Ok(Output::new("No labels found.", data.issue_labels));
         }
 
         let lines: Vec<String> = labels.iter().map(format::format_label).collect();
-        Ok(format!("Labels:\n\n{}", lines.join("\n")))
+        Ok(Output::new(
+            format!("Labels:\n\n{}", lines.join("\n")),
+            data.issue_labels,
+        ))
     }
 
     // ---- create_label (Task #5) ----
@@ -4971,7 +6671,7 @@ impl LinearMcp {
     async fn handle_create_label(
         &self,
         params: create_label::CreateLabelParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::Label>, Error> {
         let mut input = serde_json::json!({
             "name": params.name,
         });
@@ -4985,13 +6685,14 @@ impl LinearMcp {
         }
 
         let vars = serde_json::json!({ "input": input });
-        let data: response::CreateLabelData = self
-            .client
-            .execute_json(queries::CREATE_LABEL, vars)
-            .await?;
+        let data: response::CreateLabelData =
+            self.client.execute(queries::CREATE_LABEL, vars).await?;
 
         match data.issue_label_create.issue_label {
-            Some(label) => Ok(format!("Label created: {} [id: {}]", label.name, label.id)),
+            Some(label) => Ok(Output::new(
+                format!("Label created: {} [id: {}]", label.name, label.id),
+                label,
+            )),
             None => Err(Error::GraphQL("Label creation returned no label".into())),
         }
     }
@@ -5001,7 +6702,7 @@ impl LinearMcp {
     async fn handle_create_issue_relation(
         &self,
         params: create_issue_relation::CreateIssueRelationParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::IssueRelation>, Error> {
         let issue_uuid = self.resolve_issue_id(&params.issue_id).await?;
         let related_uuid = self.resolve_issue_id(&params.related_issue_id).await?;
 
@@ -5014,15 +6715,20 @@ impl LinearMcp {
         });
         let data: response::CreateIssueRelationData = self
             .client
-            .execute_json(queries::CREATE_ISSUE_RELATION, vars)
+            .execute(queries::CREATE_ISSUE_RELATION, vars)
             .await?;
 
         match data.issue_relation_create.issue_relation {
             Some(relation) => {
                 let formatted = format::format_issue_relation(&relation);
-                Ok(format!("Relation created:\n\n{}", formatted))
+                Ok(Output::new(
+                    format!("Relation created:\n\n{}", formatted),
+                    relation,
+                ))
             }
-            None => Err(Error::GraphQL("Issue relation creation returned no relation".into())),
+            None => Err(Error::GraphQL(
+                "Issue relation creation returned no relation".into(),
+            )),
         }
     }
 
@@ -5031,15 +6737,18 @@ impl LinearMcp {
     async fn handle_delete_issue_relation(
         &self,
         params: delete_issue_relation::DeleteIssueRelationParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::DeleteIssueRelationData = self
             .client
-            .execute_json(queries::DELETE_ISSUE_RELATION, vars)
+            .execute(queries::DELETE_ISSUE_RELATION, vars)
             .await?;
 
         if data.issue_relation_delete.success {
-            Ok(format!("Issue relation {} deleted.", params.id))
+            Ok(Output::new(
+                format!("Issue relation {} deleted.", params.id),
+                data.issue_relation_delete,
+            ))
         } else {
             Err(Error::GraphQL("Issue relation deletion failed".into()))
         }
@@ -5050,25 +6759,24 @@ impl LinearMcp {
     async fn handle_archive_issue(
         &self,
         params: archive_issue::ArchiveIssueParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let uuid = self.resolve_issue_id(&params.id).await?;
 
         // Fetch identifier for the confirmation message
         let issue_vars = serde_json::json!({ "id": uuid });
-        let issue_data: response::IssueData = self
-            .client
-            .execute_json(queries::GET_ISSUE, issue_vars)
-            .await?;
+        let issue_data: response::IssueData =
+            self.client.execute(queries::GET_ISSUE, issue_vars).await?;
         let identifier = &issue_data.issue.identifier;
 
         let vars = serde_json::json!({ "id": uuid });
-        let data: response::ArchiveIssueData = self
-            .client
-            .execute_json(queries::ARCHIVE_ISSUE, vars)
-            .await?;
+        let data: response::ArchiveIssueData =
+            self.client.execute(queries::ARCHIVE_ISSUE, vars).await?;
 
         if data.issue_archive.success {
-            Ok(format!("Issue {} archived.", identifier))
+            Ok(Output::new(
+                format!("Issue {} archived.", identifier),
+                data.issue_archive,
+            ))
         } else {
             Err(Error::GraphQL("Issue archive failed".into()))
         }
@@ -5079,138 +6787,200 @@ impl LinearMcp {
     /// Resolve comma-separated label names to a list of label UUIDs.
     /// When `team_key` is provided, labels are scoped to that team (avoids
     /// ambiguity when workspace and team labels share the same name).
-    async fn resolve_label_ids(&self, label_names: &str, team_key: Option<&str>) -> Result<Vec<String>, Error> {
-        let names: Vec<&str> = label_names.split(',').map(|s| s.trim()).filter(|s| !s.is_empty()).collect();
+    /// All names, and any other label lookups running at the same time, are
+    /// resolved in one query.
+    async fn resolve_label_ids(
+        &self,
+        label_names: &str,
+        team_key: Option<&str>,
+    ) -> Result<Vec<String>, Error> {
+        let names: Vec<&str> = label_names
+            .split(',')
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+            .collect();
         if names.is_empty() {
             return Ok(Vec::new());
         }
+        let scope = team_key.map_or("*".to_string(), |k| k.to_uppercase());
+        let keys: Vec<String> = names
+            .iter()
+            .map(|name| format!("{}:{}", scope, name.to_lowercase()))
+            .collect();
+        let ids = self
+            .client
+            .cache()
+            .load_many(EntityKind::Label, &keys, |keys| self.fetch_label_ids(keys))
+            .await?;
+        if ids.iter().all(Option::is_some) {
+            return Ok(ids.into_iter().flatten().collect());
+        }
+
+        // Match the rest fuzzily against every label in scope.
+        let filter = team_key.map(|key| filters::IssueLabelFilter {
+            name: None,
+            team: Some(if is_uuid(key) {
+                filters::TeamFilter {
+                    id: Some(filters::StringFilter::eq_exact(key)),
+                    ..Default::default()
+                }
+            } else {
+                filters::TeamFilter {
+                    key: Some(filters::StringFilter::eq_ignore_case(key)),
+                    ..Default::default()
+                }
+            }),
+            or: None,
+        });
+        let data: response::ResolveLabelsData = self
+            .client
+            .execute(
+                queries::RESOLVE_LABELS,
+                serde_json::json!({ "filter": filter }),
+            )
+            .await?;
+        let candidates: Vec<Candidate> = data
+            .issue_labels
+            .nodes
+            .into_iter()
+            .map(|l| Candidate::new(l.id, l.name.clone()).name(l.name))
+            .collect();
+        let cache = self.client.cache();
+        names
+            .iter()
+            .zip(keys)
+            .zip(ids)
+            .map(|((name, key), id)| match id {
+                Some(id) => Ok(id),
+                None => {
+                    let id = matching::pick("Label", name, &candidates)?.id.clone();
+                    cache.insert(EntityKind::Label, &key, id.clone());
+                    Ok(id)
+                }
+            })
+            .collect()
+    }
 
-        // Build an OR filter for names, with team scoping at the top level
-        let or_filters: Vec<filters::IssueLabelFilter> = names
+    /// Fetch labels for `SCOPE:name` keys in one query, where `SCOPE` is a
+    /// team key or UUID, or `*` for any team.
+    async fn fetch_label_ids(&self, keys: Vec<String>) -> Result<HashMap<String, String>, Error> {
+        let scoped: Vec<(&str, &str)> = keys.iter().filter_map(|k| k.split_once(':')).collect();
+        let or: Vec<filters::IssueLabelFilter> = scoped
             .iter()
-            .map(|name| filters::IssueLabelFilter {
-                name: Some(filters::StringFilter::eq_ignore_case(*name)),
-                team: None,
+            .map(|&(scope, name)| filters::IssueLabelFilter {
+                name: Some(filters::StringFilter::eq_ignore_case(name)),
+                team: match scope {
+                    "*" => None,
+                    id if is_uuid(id) => Some(filters::TeamFilter {
+                        id: Some(filters::StringFilter::eq_exact(id.to_lowercase())),
+                        ..Default::default()
+                    }),
+                    key => Some(filters::TeamFilter {
+                        key: Some(filters::StringFilter::eq_ignore_case(key)),
+                        ..Default::default()
+                    }),
+                },
                 or: None,
             })
             .collect();
-
         let filter = filters::IssueLabelFilter {
             name: None,
-            team: team_key.map(|key| {
-                let is_uuid = key.len() == 36 && key.chars().all(|c| c.is_ascii_hexdigit() || c == '-');
-                if is_uuid {
-                    filters::TeamFilter { id: Some(filters::StringFilter::eq_exact(key)), ..Default::default() }
-                } else {
-                    filters::TeamFilter { key: Some(filters::StringFilter::eq_ignore_case(key)), ..Default::default() }
-                }
-            }),
-            or: Some(or_filters),
+            team: None,
+            or: Some(or),
         };
-
         let vars = serde_json::json!({ "filter": filter });
-        let data: response::LabelsData = self
-            .client
-            .execute_json(queries::RESOLVE_LABELS, vars)
-            .await?;
+        let data: response::ResolveLabelsData =
+            self.client.execute(queries::RESOLVE_LABELS, vars).await?;
 
-        // Check all names were found
-        let mut ids = Vec::new();
-        for name in &names {
-            let found = data.issue_labels.nodes.iter().find(|l| {
+        let mut found = HashMap::new();
+        for (key, &(scope, name)) in keys.iter().zip(&scoped) {
+            let label = data.issue_labels.nodes.iter().find(|l| {
                 l.name.eq_ignore_ascii_case(name)
+                    && (scope == "*"
+                        || l.team.as_ref().is_some_and(|t| {
+                            t.key.eq_ignore_ascii_case(scope) || t.id.eq_ignore_ascii_case(scope)
+                        }))
             });
-            match found {
-                Some(label) => ids.push(label.id.clone()),
-                None => return Err(Error::NotFound(format!("Label '{}' not found", name))),
+            if let Some(label) = label {
+                found.insert(key.clone(), label.id.clone());
             }
         }
-
-        Ok(ids)
-    }
-
-    /// Resolve a single label name to its UUID.
-    async fn resolve_label_id(&self, label_name: &str, team_key: Option<&str>) -> Result<String, Error> {
-        let ids = self.resolve_label_ids(label_name, team_key).await?;
-        ids.into_iter()
-            .next()
-            .ok_or_else(|| Error::NotFound(format!("Label '{}' not found", label_name)))
+        Ok(found)
     }
 
     /// Resolve an issue UUID to its team key (e.g. "ENG").
     async fn resolve_team_key_from_issue(&self, issue_uuid: &str) -> Result<String, Error> {
-        let vars = serde_json::json!({ "id": issue_uuid });
-        let data: serde_json::Value = self
-            .client
-            .execute_json(
-                r#"query($id: String!) { issue(id: $id) { team { id key } } }"#,
-                vars,
-            )
+        let mut keys = self
+            .resolve_team_keys_from_issues(&[issue_uuid.to_string()])
             .await?;
-        data.get("issue")
-            .and_then(|i| i.get("team"))
-            .and_then(|t| t.get("key"))
-            .and_then(|k| k.as_str())
-            .map(|s| s.to_string())
-            .ok_or_else(|| Error::NotFound(format!("Could not resolve team key for issue {}", issue_uuid)))
+        Ok(keys.remove(0))
     }
 
     /// Batch-resolve issue UUIDs to their team keys in a single query.
     async fn resolve_team_keys_from_issues(&self, uuids: &[String]) -> Result<Vec<String>, Error> {
-        let vars = serde_json::json!({
-            "first": uuids.len(),
-            "filter": { "id": { "in": uuids } },
-        });
-        let data: serde_json::Value = self
-            .client
-            .execute_json(
-                r#"query($first: Int!, $filter: IssueFilter) { issues(first: $first, filter: $filter) { nodes { id team { key } } } }"#,
-                vars,
-            )
+        let keys = self
+            .client
+            .cache()
+            .load_many(EntityKind::IssueTeam, uuids, |missing| async move {
+                let vars = serde_json::json!({
+                    "first": missing.len(),
+                    "filter": { "id": { "in": missing } },
+                });
+                let data: response::IssueTeamsData =
+                    self.client.execute(queries::GET_ISSUES_TEAMS, vars).await?;
+                Ok::<_, Error>(
+                    data.issues
+                        .nodes
+                        .into_iter()
+                        .map(|issue| (issue.id, issue.team.key))
+                        .collect(),
+                )
+            })
             .await?;
-        let nodes = data.get("issues")
-            .and_then(|i| i.get("nodes"))
-            .and_then(|n| n.as_array())
-            .ok_or_else(|| Error::NotFound("Failed to batch-resolve issue teams".into()))?;
-
-        // Build a map from issue UUID to team key
-        let mut map = std::collections::HashMap::new();
-        for node in nodes {
-            if let (Some(id), Some(key)) = (
-                node.get("id").and_then(|v| v.as_str()),
-                node.get("team").and_then(|t| t.get("key")).and_then(|k| k.as_str()),
-            ) {
-                map.insert(id.to_string(), key.to_string());
-            }
-        }
-
-        // Return keys in the same order as input UUIDs
-        uuids.iter().map(|uuid| {
-            map.get(uuid).cloned().ok_or_else(|| {
-                Error::NotFound(format!("Could not resolve team key for issue {}", uuid))
+        uuids
+            .iter()
+            .zip(keys)
+            .map(|(uuid, key)| {
+                key.ok_or_else(|| {
+                    Error::NotFound(format!("Could not resolve team key for issue {}", uuid))
+                })
             })
-        }).collect()
+            .collect()
     }
 
     /// Resolve a project name to a project UUID.
     /// Prefers exact case-insensitive match; returns ambiguity error if multiple partial matches.
     async fn resolve_project_id(&self, project_name: &str) -> Result<String, Error> {
+        let key = project_name.to_lowercase();
+        self.client
+            .cache()
+            .get_or_fetch(EntityKind::Project, &key, || {
+                self.fetch_project_id(project_name)
+            })
+            .await
+    }
+
+    async fn fetch_project_id(&self, project_name: &str) -> Result<String, Error> {
         let filter = filters::ProjectNameResolveFilter {
             name: filters::StringFilter::contains_ignore_case(project_name),
         };
         let vars = serde_json::json!({ "filter": filter });
-        let data: response::ResolveProjectData = self
-            .client
-            .execute_json(queries::RESOLVE_PROJECT, vars)
-            .await?;
+        let data: response::ResolveProjectData =
+            self.client.execute(queries::RESOLVE_PROJECT, vars).await?;
 
         let matches = &data.projects.nodes;
         if matches.is_empty() {
-            return Err(Error::NotFound(format!("Project '{}' not found", project_name)));
+            return Err(Error::NotFound(format!(
+                "Project '{}' not found",
+                project_name
+            )));
         }
 
         // Prefer exact case-insensitive match
-        if let Some(exact) = matches.iter().find(|p| p.name.eq_ignore_ascii_case(project_name)) {
+        if let Some(exact) = matches
+            .iter()
+            .find(|p| p.name.eq_ignore_ascii_case(project_name))
+        {
             return Ok(exact.id.clone());
         }
 
@@ -5220,21 +6990,15 @@ impl LinearMcp {
         }
 
         // Multiple partial matches — ambiguous
-        let names: Vec<&str> = matches.iter().map(|p| p.name.as_str()).collect();
-        Err(Error::InvalidInput(format!(
-            "Ambiguous project name '{}'. Matches: {}",
-            project_name,
-            names.join(", ")
-        )))
+        Err(Error::Ambiguous {
+            message: format!("Project '{}' is ambiguous.", project_name),
+            candidates: matches.iter().map(|p| p.name.clone()).collect(),
+        })
     }
 
     /// Resolve a project name or UUID to a UUID.
     async fn resolve_project_id_or_uuid(&self, id_or_name: &str) -> Result<String, Error> {
-        let is_uuid = id_or_name.len() == 36
-            && id_or_name
-                .chars()
-                .all(|c| c.is_ascii_hexdigit() || c == '-');
-        if is_uuid {
+        if is_uuid(id_or_name) {
             return Ok(id_or_name.to_string());
         }
         self.resolve_project_id(id_or_name).await
@@ -5245,39 +7009,41 @@ impl LinearMcp {
     async fn handle_list_documents(
         &self,
         params: list_documents::ListDocumentsParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::NodeList<types::Document>>, Error> {
         let limit = params.limit.unwrap_or(50).min(100);
         let vars = serde_json::json!({ "first": limit });
-        let data: response::DocumentsData = self
-            .client
-            .execute_json(queries::LIST_DOCUMENTS, vars)
-            .await?;
+        let data: response::DocumentsData =
+            self.client.execute(queries::LIST_DOCUMENTS, vars).await?;
 
         let docs = &data.documents.nodes;
         if docs.is_empty() {
-            return Ok("No documents found.".to_string());
+            return Ok(Output::new("No documents found.", data.documents));
         }
 
         let lines: Vec<String> = docs.iter().map(format::format_document_summary).collect();
-        Ok(format!("Documents:\n\n{}", lines.join("\n\n")))
+        Ok(Output::new(
+            format!("Documents:\n\n{}", lines.join("\n\n")),
+            data.documents,
+        ))
     }
 
     async fn handle_get_document(
         &self,
         params: get_document::GetDocumentParams,
-    ) -> Result<String, Error> {
-        let vars = serde_json::json!({ "id": params.id });
-        let data: response::DocumentData = self
-            .client
-            .execute_json(queries::GET_DOCUMENT, vars)
-            .await?;
-        Ok(format::format_document_detail(&data.document))
+    ) -> Result<Output<types::Document>, Error> {
+        let id = self.resolve_document_id(&params.id, Match::Fuzzy).await?;
+        let vars = serde_json::json!({ "id": id });
+        let data: response::DocumentData = self.client.execute(queries::GET_DOCUMENT, vars).await?;
+        Ok(Output::new(
+            format::format_document_detail(&data.document),
+            data.document,
+        ))
     }
 
     async fn handle_create_document(
         &self,
         params: create_document::CreateDocumentParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::Document>, Error> {
         let project_id = self.resolve_project_id_or_uuid(&params.project).await?;
         let mut input = serde_json::json!({
             "title": params.title,
@@ -5293,17 +7059,20 @@ impl LinearMcp {
         }
 
         let vars = serde_json::json!({ "input": input });
-        let data: response::CreateDocumentData = self
-            .client
-            .execute_json(queries::CREATE_DOCUMENT, vars)
-            .await?;
+        let data: response::CreateDocumentData =
+            self.client.execute(queries::CREATE_DOCUMENT, vars).await?;
 
         match data.document_create.document {
-            Some(doc) => Ok(format!(
-                "Document created:\n\n{}",
-                format::format_document_detail(&doc)
+            Some(doc) => Ok(Output::new(
+                format!(
+                    "Document created:\n\n{}",
+                    format::format_document_detail(&doc)
+                ),
+                doc,
+            )),
+            None => Err(Error::GraphQL(
+                "Document creation returned no document".into(),
             )),
-            None => Err(Error::GraphQL("Document creation returned no document".into())),
         }
     }
 
@@ -5312,20 +7081,25 @@ impl LinearMcp {
     async fn handle_get_project(
         &self,
         params: get_project::GetProjectParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::ProjectDetail>, Error> {
         let project_id = self.resolve_project_id_or_uuid(&params.id).await?;
         let vars = serde_json::json!({ "id": project_id });
-        let data: response::ProjectDetailData = self
-            .client
-            .execute_json(queries::GET_PROJECT, vars)
-            .await?;
-        Ok(format::format_project_detail(&data.project))
+        let data: response::ProjectDetailData =
+            self.client.execute(queries::GET_PROJECT, vars).await?;
+        Ok(Output::new(
+            format::format_project_detail(&data.project),
+            data.project,
+        ))
     }
 
     async fn handle_create_project(
         &self,
         params: create_project::CreateProjectParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::ProjectDetail>, Error> {
+        self.check_dates(&[
+            ("targetDate", params.target_date.as_ref()),
+            ("startDate", params.start_date.as_ref()),
+        ])?;
         let mut input = serde_json::json!({
             "name": params.name,
         });
@@ -5334,7 +7108,11 @@ impl LinearMcp {
             input["description"] = serde_json::Value::String(desc.clone());
         }
         if let Some(ref teams) = params.teams {
-            let team_keys: Vec<&str> = teams.split(',').map(|s| s.trim()).filter(|s| !s.is_empty()).collect();
+            let team_keys: Vec<&str> = teams
+                .split(',')
+                .map(|s| s.trim())
+                .filter(|s| !s.is_empty())
+                .collect();
             let mut team_ids = Vec::new();
             for key in team_keys {
                 team_ids.push(self.resolve_team_id(key).await?);
@@ -5346,31 +7124,44 @@ impl LinearMcp {
             input["leadId"] = serde_json::Value::String(lead_id);
         }
         if let Some(ref target_date) = params.target_date {
-            input["targetDate"] = serde_json::Value::String(target_date.clone());
+            let target_date = self
+                .date_arg("targetDate", target_date, Bound::End, None)
+                .await?;
+            input["targetDate"] = serde_json::Value::String(target_date);
         }
         if let Some(ref start_date) = params.start_date {
-            input["startDate"] = serde_json::Value::String(start_date.clone());
+            let start_date = self
+                .date_arg("startDate", start_date, Bound::Start, None)
+                .await?;
+            input["startDate"] = serde_json::Value::String(start_date);
         }
 
         let vars = serde_json::json!({ "input": input });
-        let data: response::CreateProjectData = self
-            .client
-            .execute_json(queries::CREATE_PROJECT, vars)
-            .await?;
+        let data: response::CreateProjectData =
+            self.client.execute(queries::CREATE_PROJECT, vars).await?;
 
         match data.project_create.project {
-            Some(project) => Ok(format!(
-                "Project created:\n\n{}",
-                format::format_project_detail(&project)
+            Some(project) => Ok(Output::new(
+                format!(
+                    "Project created:\n\n{}",
+                    format::format_project_detail(&project)
+                ),
+                project,
+            )),
+            None => Err(Error::GraphQL(
+                "Project creation returned no project".into(),
             )),
-            None => Err(Error::GraphQL("Project creation returned no project".into())),
         }
     }
 
     async fn handle_update_project(
         &self,
         params: update_project::UpdateProjectParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::ProjectDetail>, Error> {
+        self.check_dates(&[
+            ("targetDate", params.target_date.as_ref()),
+            ("startDate", params.start_date.as_ref()),
+        ])?;
         let project_id = self.resolve_project_id_or_uuid(&params.id).await?;
 
         let mut input = serde_json::Map::new();
@@ -5381,7 +7172,10 @@ impl LinearMcp {
             has_fields = true;
         }
         if let Some(ref desc) = params.description {
-            input.insert("description".into(), serde_json::Value::String(desc.clone()));
+            input.insert(
+                "description".into(),
+                serde_json::Value::String(desc.clone()),
+            );
             has_fields = true;
         }
         if let Some(ref state) = params.state {
@@ -5401,7 +7195,10 @@ impl LinearMcp {
             if target_date.eq_ignore_ascii_case("none") {
                 input.insert("targetDate".into(), serde_json::Value::Null);
             } else {
-                input.insert("targetDate".into(), serde_json::Value::String(target_date.clone()));
+                let target_date = self
+                    .date_arg("targetDate", target_date, Bound::End, None)
+                    .await?;
+                input.insert("targetDate".into(), serde_json::Value::String(target_date));
             }
             has_fields = true;
         }
@@ -5409,7 +7206,10 @@ impl LinearMcp {
             if start_date.eq_ignore_ascii_case("none") {
                 input.insert("startDate".into(), serde_json::Value::Null);
             } else {
-                input.insert("startDate".into(), serde_json::Value::String(start_date.clone()));
+                let start_date = self
+                    .date_arg("startDate", start_date, Bound::Start, None)
+                    .await?;
+                input.insert("startDate".into(), serde_json::Value::String(start_date));
             }
             has_fields = true;
         }
@@ -5424,15 +7224,16 @@ impl LinearMcp {
             "id": project_id,
             "input": serde_json::Value::Object(input),
         });
-        let data: response::UpdateProjectData = self
-            .client
-            .execute_json(queries::UPDATE_PROJECT, vars)
-            .await?;
+        let data: response::UpdateProjectData =
+            self.client.execute(queries::UPDATE_PROJECT, vars).await?;
 
         match data.project_update.project {
-            Some(project) => Ok(format!(
-                "Project updated:\n\n{}",
-                format::format_project_detail(&project)
+            Some(project) => Ok(Output::new(
+                format!(
+                    "Project updated:\n\n{}",
+                    format::format_project_detail(&project)
+                ),
+                project,
             )),
             None => Err(Error::GraphQL("Project update returned no project".into())),
         }
@@ -5443,27 +7244,30 @@ impl LinearMcp {
     async fn handle_list_project_updates(
         &self,
         params: list_project_updates::ListProjectUpdatesParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<response::ProjectWithUpdates>, Error> {
         let project_id = self.resolve_project_id_or_uuid(&params.project).await?;
         let vars = serde_json::json!({ "id": project_id });
         let data: response::ProjectUpdatesData = self
             .client
-            .execute_json(queries::LIST_PROJECT_UPDATES, vars)
+            .execute(queries::LIST_PROJECT_UPDATES, vars)
             .await?;
 
         let updates = &data.project.project_updates.nodes;
         if updates.is_empty() {
-            return Ok("No project updates found.".to_string());
+            return Ok(Output::new("No project updates found.", data.project));
         }
 
         let lines: Vec<String> = updates.iter().map(format::format_project_update).collect();
-        Ok(format!("Project Updates:\n\n{}", lines.join("\n\n")))
+        Ok(Output::new(
+            format!("Project Updates:\n\n{}", lines.join("\n\n")),
+            data.project,
+        ))
     }
 
     async fn handle_create_project_update(
         &self,
         params: create_project_update::CreateProjectUpdateParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::ProjectUpdate>, Error> {
         let project_id = self.resolve_project_id_or_uuid(&params.project).await?;
 
         let mut input = serde_json::json!({
@@ -5478,13 +7282,16 @@ impl LinearMcp {
         let vars = serde_json::json!({ "input": input });
         let data: response::CreateProjectUpdateData = self
             .client
-            .execute_json(queries::CREATE_PROJECT_UPDATE, vars)
+            .execute(queries::CREATE_PROJECT_UPDATE, vars)
             .await?;
 
         match data.project_update_create.project_update {
-            Some(update) => Ok(format!(
-                "Project update created:\n\n{}",
-                format::format_project_update(&update)
+            Some(update) => Ok(Output::new(
+                format!(
+                    "Project update created:\n\n{}",
+                    format::format_project_update(&update)
+                ),
+                update,
             )),
             None => Err(Error::GraphQL("Project update creation failed".into())),
         }
@@ -5495,30 +7302,37 @@ impl LinearMcp {
     async fn handle_list_project_milestones(
         &self,
         params: list_project_milestones::ListProjectMilestonesParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<response::ProjectWithMilestones>, Error> {
         let project_id = self.resolve_project_id_or_uuid(&params.project).await?;
         let vars = serde_json::json!({ "id": project_id });
         let data: response::ProjectMilestonesData = self
             .client
-            .execute_json(queries::LIST_PROJECT_MILESTONES, vars)
+            .execute(queries::LIST_PROJECT_MILESTONES, vars)
             .await?;
 
         let milestones = &data.project.project_milestones.nodes;
         if milestones.is_empty() {
-            return Ok("No milestones found for this project.".to_string());
+            return Ok(Output::new(
+                "No milestones found for this project.",
+                data.project,
+            ));
         }
 
         let lines: Vec<String> = milestones
             .iter()
             .map(format::format_project_milestone)
             .collect();
-        Ok(format!("Project Milestones:\n\n{}", lines.join("\n\n")))
+        Ok(Output::new(
+            format!("Project Milestones:\n\n{}", lines.join("\n\n")),
+            data.project,
+        ))
     }
 
     async fn handle_create_project_milestone(
         &self,
         params: create_project_milestone::CreateProjectMilestoneParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::ProjectMilestone>, Error> {
+        self.check_dates(&[("targetDate", params.target_date.as_ref())])?;
         let project_id = self.resolve_project_id_or_uuid(&params.project).await?;
 
         let mut input = serde_json::json!({
@@ -5530,19 +7344,25 @@ impl LinearMcp {
             input["description"] = serde_json::Value::String(desc.clone());
         }
         if let Some(ref target_date) = params.target_date {
-            input["targetDate"] = serde_json::Value::String(target_date.clone());
+            let target_date = self
+                .date_arg("targetDate", target_date, Bound::End, None)
+                .await?;
+            input["targetDate"] = serde_json::Value::String(target_date);
         }
 
         let vars = serde_json::json!({ "input": input });
         let data: response::CreateProjectMilestoneData = self
             .client
-            .execute_json(queries::CREATE_PROJECT_MILESTONE, vars)
+            .execute(queries::CREATE_PROJECT_MILESTONE, vars)
             .await?;
 
         match data.project_milestone_create.project_milestone {
-            Some(milestone) => Ok(format!(
-                "Milestone created:\n\n{}",
-                format::format_project_milestone(&milestone)
+            Some(milestone) => Ok(Output::new(
+                format!(
+                    "Milestone created:\n\n{}",
+                    format::format_project_milestone(&milestone)
+                ),
+                milestone,
             )),
             None => Err(Error::GraphQL("Milestone creation failed".into())),
         }
@@ -5553,21 +7373,22 @@ impl LinearMcp {
     async fn handle_list_initiatives(
         &self,
         params: list_initiatives::ListInitiativesParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::NodeList<types::Initiative>>, Error> {
         let limit = params.limit.unwrap_or(50).min(100);
         let vars = serde_json::json!({ "first": limit });
-        let data: response::InitiativesData = self
-            .client
-            .execute_json(queries::LIST_INITIATIVES, vars)
-            .await?;
+        let data: response::InitiativesData =
+            self.client.execute(queries::LIST_INITIATIVES, vars).await?;
 
         let initiatives = &data.initiatives.nodes;
         if initiatives.is_empty() {
-            return Ok("No initiatives found.".to_string());
+            return Ok(Output::new("No initiatives found.", data.initiatives));
         }
 
         let lines: Vec<String> = initiatives.iter().map(format::format_initiative).collect();
-        Ok(format!("Initiatives:\n\n{}", lines.join("\n\n")))
+        Ok(Output::new(
+            format!("Initiatives:\n\n{}", lines.join("\n\n")),
+            data.initiatives,
+        ))
     }
 
     // ---- Notification handlers ----
@@ -5575,41 +7396,47 @@ impl LinearMcp {
     async fn handle_list_notifications(
         &self,
         params: list_notifications::ListNotificationsParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::NodeList<types::Notification>>, Error> {
         let limit = params.limit.unwrap_or(50).min(100);
         let vars = serde_json::json!({ "first": limit });
         let data: response::NotificationsData = self
             .client
-            .execute_json(queries::LIST_NOTIFICATIONS, vars)
+            .execute(queries::LIST_NOTIFICATIONS, vars)
             .await?;
 
         let notifications = &data.notifications.nodes;
         if notifications.is_empty() {
-            return Ok("No notifications.".to_string());
+            return Ok(Output::new("No notifications.", data.notifications));
         }
 
         let lines: Vec<String> = notifications
             .iter()
             .map(format::format_notification)
             .collect();
-        Ok(format!("Notifications:\n\n{}", lines.join("\n\n")))
+        Ok(Output::new(
+            format!("Notifications:\n\n{}", lines.join("\n\n")),
+            data.notifications,
+        ))
     }
 
     async fn handle_mark_notification_read(
         &self,
         params: mark_notification_read::MarkNotificationReadParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::NotificationMutationResult>, Error> {
         let vars = serde_json::json!({
             "id": params.id,
             "input": { "readAt": chrono_now_iso() }
         });
         let data: response::MarkNotificationReadData = self
             .client
-            .execute_json(queries::MARK_NOTIFICATION_READ, vars)
+            .execute(queries::MARK_NOTIFICATION_READ, vars)
             .await?;
 
         if data.notification_update.success {
-            Ok(format!("Notification {} marked as read.", params.id))
+            Ok(Output::new(
+                format!("Notification {} marked as read.", params.id),
+                data.notification_update,
+            ))
         } else {
             Err(Error::GraphQL("Failed to mark notification as read".into()))
         }
@@ -5620,21 +7447,22 @@ impl LinearMcp {
     async fn handle_list_views(
         &self,
         params: list_views::ListViewsParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::NodeList<types::CustomView>>, Error> {
         let limit = params.limit.unwrap_or(50).min(100);
         let vars = serde_json::json!({ "first": limit });
-        let data: response::CustomViewsData = self
-            .client
-            .execute_json(queries::LIST_VIEWS, vars)
-            .await?;
+        let data: response::CustomViewsData =
+            self.client.execute(queries::LIST_VIEWS, vars).await?;
 
         let views = &data.custom_views.nodes;
         if views.is_empty() {
-            return Ok("No custom views found.".to_string());
+            return Ok(Output::new("No custom views found.", data.custom_views));
         }
 
         let lines: Vec<String> = views.iter().map(format::format_custom_view).collect();
-        Ok(format!("Custom Views:\n\n{}", lines.join("\n\n")))
+        Ok(Output::new(
+            format!("Custom Views:\n\n{}", lines.join("\n\n")),
+            data.custom_views,
+        ))
     }
 
     // ---- Attachment handlers ----
@@ -5642,27 +7470,31 @@ impl LinearMcp {
     async fn handle_list_attachments(
         &self,
         params: list_attachments::ListAttachmentsParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<response::IssueWithAttachments>, Error> {
         let issue_uuid = self.resolve_issue_id(&params.issue_id).await?;
         let vars = serde_json::json!({ "id": issue_uuid });
-        let data: response::AttachmentsData = self
-            .client
-            .execute_json(queries::LIST_ATTACHMENTS, vars)
-            .await?;
+        let data: response::AttachmentsData =
+            self.client.execute(queries::LIST_ATTACHMENTS, vars).await?;
 
         let attachments = &data.issue.attachments.nodes;
         if attachments.is_empty() {
-            return Ok("No attachments found on this issue.".to_string());
+            return Ok(Output::new(
+                "No attachments found on this issue.",
+                data.issue,
+            ));
         }
 
         let lines: Vec<String> = attachments.iter().map(format::format_attachment).collect();
-        Ok(format!("Attachments:\n\n{}", lines.join("\n\n")))
+        Ok(Output::new(
+            format!("Attachments:\n\n{}", lines.join("\n\n")),
+            data.issue,
+        ))
     }
 
     async fn handle_add_attachment(
         &self,
         params: add_attachment::AddAttachmentParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::Attachment>, Error> {
         let issue_uuid = self.resolve_issue_id(&params.issue_id).await?;
 
         let input = serde_json::json!({
@@ -5672,15 +7504,16 @@ impl LinearMcp {
         });
 
         let vars = serde_json::json!({ "input": input });
-        let data: response::AddAttachmentData = self
-            .client
-            .execute_json(queries::ADD_ATTACHMENT, vars)
-            .await?;
+        let data: response::AddAttachmentData =
+            self.client.execute(queries::ADD_ATTACHMENT, vars).await?;
 
         match data.attachment_create.attachment {
-            Some(attachment) => Ok(format!(
-                "Attachment added:\n\n{}",
-                format::format_attachment(&attachment)
+            Some(attachment) => Ok(Output::new(
+                format!(
+                    "Attachment added:\n\n{}",
+                    format::format_attachment(&attachment)
+                ),
+                attachment,
             )),
             None => Err(Error::GraphQL("Attachment creation failed".into())),
         }
@@ -5691,22 +7524,20 @@ impl LinearMcp {
     async fn handle_add_reaction(
         &self,
         params: add_reaction::AddReactionParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::Reaction>, Error> {
         let input = serde_json::json!({
             "commentId": params.comment_id,
             "emoji": params.emoji,
         });
 
         let vars = serde_json::json!({ "input": input });
-        let data: response::AddReactionData = self
-            .client
-            .execute_json(queries::ADD_REACTION, vars)
-            .await?;
+        let data: response::AddReactionData =
+            self.client.execute(queries::ADD_REACTION, vars).await?;
 
         match data.reaction_create.reaction {
-            Some(reaction) => Ok(format!(
-                "Reaction added: {} [id: {}]",
-                reaction.emoji, reaction.id
+            Some(reaction) => Ok(Output::new(
+                format!("Reaction added: {} [id: {}]", reaction.emoji, reaction.id),
+                reaction,
             )),
             None => Err(Error::GraphQL("Reaction creation failed".into())),
         }
@@ -5715,15 +7546,16 @@ impl LinearMcp {
     async fn handle_remove_reaction(
         &self,
         params: remove_reaction::RemoveReactionParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::DeleteMutationResult>, Error> {
         let vars = serde_json::json!({ "id": params.id });
-        let data: response::RemoveReactionData = self
-            .client
-            .execute_json(queries::REMOVE_REACTION, vars)
-            .await?;
+        let data: response::RemoveReactionData =
+            self.client.execute(queries::REMOVE_REACTION, vars).await?;
 
         if data.reaction_delete.success {
-            Ok(format!("Reaction {} removed.", params.id))
+            Ok(Output::new(
+                format!("Reaction {} removed.", params.id),
+                data.reaction_delete,
+            ))
         } else {
             Err(Error::GraphQL("Reaction removal failed".into()))
         }
@@ -5734,27 +7566,28 @@ impl LinearMcp {
     async fn handle_list_favorites(
         &self,
         params: list_favorites::ListFavoritesParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::NodeList<types::Favorite>>, Error> {
         let limit = params.limit.unwrap_or(50).min(100);
         let vars = serde_json::json!({ "first": limit });
-        let data: response::FavoritesData = self
-            .client
-            .execute_json(queries::LIST_FAVORITES, vars)
-            .await?;
+        let data: response::FavoritesData =
+            self.client.execute(queries::LIST_FAVORITES, vars).await?;
 
         let favorites = &data.favorites.nodes;
         if favorites.is_empty() {
-            return Ok("No favorites found.".to_string());
+            return Ok(Output::new("No favorites found.", data.favorites));
         }
 
         let lines: Vec<String> = favorites.iter().map(format::format_favorite).collect();
-        Ok(format!("Favorites:\n\n{}", lines.join("\n\n")))
+        Ok(Output::new(
+            format!("Favorites:\n\n{}", lines.join("\n\n")),
+            data.favorites,
+        ))
     }
 
     async fn handle_add_favorite(
         &self,
         params: add_favorite::AddFavoriteParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::Favorite>, Error> {
         let mut input = serde_json::Map::new();
 
         if let Some(ref issue_id) = params.issue_id {
@@ -5770,15 +7603,13 @@ impl LinearMcp {
         }
 
         let vars = serde_json::json!({ "input": serde_json::Value::Object(input) });
-        let data: response::AddFavoriteData = self
-            .client
-            .execute_json(queries::ADD_FAVORITE, vars)
-            .await?;
+        let data: response::AddFavoriteData =
+            self.client.execute(queries::ADD_FAVORITE, vars).await?;
 
         match data.favorite_create.favorite {
-            Some(favorite) => Ok(format!(
-                "Favorite added:\n\n{}",
-                format::format_favorite(&favorite)
+            Some(favorite) => Ok(Output::new(
+                format!("Favorite added:\n\n{}", format::format_favorite(&favorite)),
+                favorite,
             )),
             None => Err(Error::GraphQL("Favorite creation failed".into())),
         }
@@ -5787,15 +7618,16 @@ impl LinearMcp {
     async fn handle_remove_favorite(
         &self,
         params: remove_favorite::RemoveFavoriteParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::DeleteMutationResult>, Error> {
         let vars = serde_json::json!({ "id": params.id });
-        let data: response::RemoveFavoriteData = self
-            .client
-            .execute_json(queries::REMOVE_FAVORITE, vars)
-            .await?;
+        let data: response::RemoveFavoriteData =
+            self.client.execute(queries::REMOVE_FAVORITE, vars).await?;
 
         if data.favorite_delete.success {
-            Ok(format!("Favorite {} removed.", params.id))
+            Ok(Output::new(
+                format!("Favorite {} removed.", params.id),
+                data.favorite_delete,
+            ))
         } else {
             Err(Error::GraphQL("Favorite removal failed".into()))
         }
@@ -5806,20 +7638,27 @@ impl LinearMcp {
     async fn handle_list_templates(
         &self,
         _params: list_templates::ListTemplatesParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::NodeList<types::Template>>, Error> {
         let vars = serde_json::json!({});
-        let data: response::TemplatesData = self
-            .client
-            .execute_json(queries::LIST_TEMPLATES, vars)
-            .await?;
+        let data: response::TemplatesData =
+            self.client.execute(queries::LIST_TEMPLATES, vars).await?;
 
-        let templates = &data.templates;
-        if templates.is_empty() {
-            return Ok("No templates found.".to_string());
+        let templates = types::NodeList {
+            nodes: data.templates,
+        };
+        if templates.nodes.is_empty() {
+            return Ok(Output::new("No templates found.", templates));
         }
 
-        let lines: Vec<String> = templates.iter().map(format::format_template).collect();
-        Ok(format!("Templates:\n\n{}", lines.join("\n\n")))
+        let lines: Vec<String> = templates
+            .nodes
+            .iter()
+            .map(format::format_template)
+            .collect();
+        Ok(Output::new(
+            format!("Templates:\n\n{}", lines.join("\n\n")),
+            templates,
+        ))
     }
 
     // ---- Issue history handlers ----
@@ -5827,23 +7666,29 @@ impl LinearMcp {
     async fn handle_get_issue_history(
         &self,
         params: get_issue_history::GetIssueHistoryParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<response::IssueWithHistory>, Error> {
         let uuid = self.resolve_issue_id(&params.id).await?;
         let limit = params.limit.unwrap_or(50).min(100);
 
         let vars = serde_json::json!({ "id": uuid, "first": limit });
         let data: response::IssueHistoryData = self
             .client
-            .execute_json(queries::GET_ISSUE_HISTORY, vars)
+            .execute(queries::GET_ISSUE_HISTORY, vars)
             .await?;
 
         let entries = &data.issue.history.nodes;
         if entries.is_empty() {
-            return Ok("No history entries found for this issue.".to_string());
+            return Ok(Output::new(
+                "No history entries found for this issue.",
+                data.issue,
+            ));
         }
 
         let lines: Vec<String> = entries.iter().map(format::format_history_entry).collect();
-        Ok(format!("Issue History:\n\n{}", lines.join("\n\n")))
+        Ok(Output::new(
+            format!("Issue History:\n\n{}", lines.join("\n\n")),
+            data.issue,
+        ))
     }
 
     // ---- Webhook handlers ----
@@ -5851,27 +7696,28 @@ impl LinearMcp {
     async fn handle_list_webhooks(
         &self,
         params: list_webhooks::ListWebhooksParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::NodeList<types::Webhook>>, Error> {
         let limit = params.limit.unwrap_or(50).min(100);
         let vars = serde_json::json!({ "first": limit });
-        let data: response::WebhooksData = self
-            .client
-            .execute_json(queries::LIST_WEBHOOKS, vars)
-            .await?;
+        let data: response::WebhooksData =
+            self.client.execute(queries::LIST_WEBHOOKS, vars).await?;
 
         let webhooks = &data.webhooks.nodes;
         if webhooks.is_empty() {
-            return Ok("No webhooks found.".to_string());
+            return Ok(Output::new("No webhooks found.", data.webhooks));
         }
 
         let lines: Vec<String> = webhooks.iter().map(format::format_webhook).collect();
-        Ok(format!("Webhooks:\n\n{}", lines.join("\n\n")))
+        Ok(Output::new(
+            format!("Webhooks:\n\n{}", lines.join("\n\n")),
+            data.webhooks,
+        ))
     }
 
     async fn handle_create_webhook(
         &self,
         params: create_webhook::CreateWebhookParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::Webhook>, Error> {
         let mut input = serde_json::json!({
             "url": params.url,
         });
@@ -5895,15 +7741,13 @@ impl LinearMcp {
         }
 
         let vars = serde_json::json!({ "input": input });
-        let data: response::CreateWebhookData = self
-            .client
-            .execute_json(queries::CREATE_WEBHOOK, vars)
-            .await?;
+        let data: response::CreateWebhookData =
+            self.client.execute(queries::CREATE_WEBHOOK, vars).await?;
 
         match data.webhook_create.webhook {
-            Some(webhook) => Ok(format!(
-                "Webhook created:\n\n{}",
-                format::format_webhook(&webhook)
+            Some(webhook) => Ok(Output::new(
+                format!("Webhook created:\n\n{}", format::format_webhook(&webhook)),
+                webhook,
             )),
             None => Err(Error::GraphQL("Webhook creation failed".into())),
         }
@@ -5912,15 +7756,16 @@ impl LinearMcp {
     async fn handle_delete_webhook(
         &self,
         params: delete_webhook::DeleteWebhookParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::DeleteMutationResult>, Error> {
         let vars = serde_json::json!({ "id": params.id });
-        let data: response::DeleteWebhookData = self
-            .client
-            .execute_json(queries::DELETE_WEBHOOK, vars)
-            .await?;
+        let data: response::DeleteWebhookData =
+            self.client.execute(queries::DELETE_WEBHOOK, vars).await?;
 
         if data.webhook_delete.success {
-            Ok(format!("Webhook {} deleted.", params.id))
+            Ok(Output::new(
+                format!("Webhook {} deleted.", params.id),
+                data.webhook_delete,
+            ))
         } else {
             Err(Error::GraphQL("Webhook deletion failed".into()))
         }
@@ -5931,24 +7776,27 @@ impl LinearMcp {
     async fn handle_list_integrations(
         &self,
         params: list_integrations::ListIntegrationsParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::NodeList<types::Integration>>, Error> {
         let limit = params.limit.unwrap_or(50).min(100);
         let vars = serde_json::json!({ "first": limit });
         let data: response::IntegrationsData = self
             .client
-            .execute_json(queries::LIST_INTEGRATIONS, vars)
+            .execute(queries::LIST_INTEGRATIONS, vars)
             .await?;
 
         let integrations = &data.integrations.nodes;
         if integrations.is_empty() {
-            return Ok("No integrations found.".to_string());
+            return Ok(Output::new("No integrations found.", data.integrations));
         }
 
         let lines: Vec<String> = integrations
             .iter()
             .map(format::format_integration)
             .collect();
-        Ok(format!("Integrations:\n\n{}", lines.join("\n\n")))
+        Ok(Output::new(
+            format!("Integrations:\n\n{}", lines.join("\n\n")),
+            data.integrations,
+        ))
     }
 
     // ---- Audit log handlers ----
@@ -5956,21 +7804,25 @@ impl LinearMcp {
     async fn handle_query_audit_log(
         &self,
         params: query_audit_log::QueryAuditLogParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::NodeList<types::AuditEntry>>, Error> {
         let limit = params.limit.unwrap_or(50).min(100);
         let vars = serde_json::json!({ "first": limit });
-        let data: response::AuditLogData = self
-            .client
-            .execute_json(queries::QUERY_AUDIT_LOG, vars)
-            .await?;
+        let data: response::AuditLogData =
+            self.client.execute(queries::QUERY_AUDIT_LOG, vars).await?;
 
         let entries = &data.audit_entries.nodes;
         if entries.is_empty() {
-            return Ok("No audit log entries found.".to_string());
+            return Ok(Output::new(
+                "No audit log entries found.",
+                data.audit_entries,
+            ));
         }
 
         let lines: Vec<String> = entries.iter().map(format::format_audit_entry).collect();
-        Ok(format!("Audit Log:\n\n{}", lines.join("\n\n")))
+        Ok(Output::new(
+            format!("Audit Log:\n\n{}", lines.join("\n\n")),
+            data.audit_entries,
+        ))
     }
 
     // ---- Team management handlers ----
@@ -5978,7 +7830,7 @@ impl LinearMcp {
     async fn handle_create_team(
         &self,
         params: create_team::CreateTeamParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::TeamDetail>, Error> {
         let mut input = serde_json::json!({
             "name": params.name,
         });
@@ -5994,15 +7846,13 @@ impl LinearMcp {
         }
 
         let vars = serde_json::json!({ "input": input });
-        let data: response::CreateTeamData = self
-            .client
-            .execute_json(queries::CREATE_TEAM, vars)
-            .await?;
+        let data: response::CreateTeamData =
+            self.client.execute(queries::CREATE_TEAM, vars).await?;
 
         match data.team_create.team {
-            Some(team) => Ok(format!(
-                "Team created:\n\n{}",
-                format::format_team_detail(&team)
+            Some(team) => Ok(Output::new(
+                format!("Team created:\n\n{}", format::format_team_detail(&team)),
+                team,
             )),
             None => Err(Error::GraphQL("Team creation failed".into())),
         }
@@ -6011,17 +7861,8 @@ impl LinearMcp {
     async fn handle_update_team(
         &self,
         params: update_team::UpdateTeamParams,
-    ) -> Result<String, Error> {
-        // Resolve team key to UUID if needed
-        let team_id = {
-            let is_uuid = params.id.len() == 36
-                && params.id.chars().all(|c| c.is_ascii_hexdigit() || c == '-');
-            if is_uuid {
-                params.id.clone()
-            } else {
-                self.resolve_team_id(&params.id).await?
-            }
-        };
+    ) -> Result<Output<types::TeamDetail>, Error> {
+        let team_id = self.resolve_team_id(&params.id).await?;
 
         let mut input = serde_json::Map::new();
         let mut has_fields = false;
@@ -6031,7 +7872,10 @@ impl LinearMcp {
             has_fields = true;
         }
         if let Some(ref desc) = params.description {
-            input.insert("description".into(), serde_json::Value::String(desc.clone()));
+            input.insert(
+                "description".into(),
+                serde_json::Value::String(desc.clone()),
+            );
             has_fields = true;
         }
         if let Some(ref tz) = params.timezone {
@@ -6049,15 +7893,13 @@ impl LinearMcp {
             "id": team_id,
             "input": serde_json::Value::Object(input),
         });
-        let data: response::UpdateTeamData = self
-            .client
-            .execute_json(queries::UPDATE_TEAM, vars)
-            .await?;
+        let data: response::UpdateTeamData =
+            self.client.execute(queries::UPDATE_TEAM, vars).await?;
 
         match data.team_update.team {
-            Some(team) => Ok(format!(
-                "Team updated:\n\n{}",
-                format::format_team_detail(&team)
+            Some(team) => Ok(Output::new(
+                format!("Team updated:\n\n{}", format::format_team_detail(&team)),
+                team,
             )),
             None => Err(Error::GraphQL("Team update failed".into())),
         }
@@ -6068,16 +7910,17 @@ impl LinearMcp {
     async fn handle_archive_project(
         &self,
         params: archive_project::ArchiveProjectParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::DeleteMutationResult>, Error> {
         let project_id = self.resolve_project_id_or_uuid(&params.id).await?;
         let vars = serde_json::json!({ "id": project_id });
-        let data: response::ArchiveProjectData = self
-            .client
-            .execute_json(queries::ARCHIVE_PROJECT, vars)
-            .await?;
+        let data: response::ArchiveProjectData =
+            self.client.execute(queries::ARCHIVE_PROJECT, vars).await?;
 
         if data.project_archive.success {
-            Ok(format!("Project '{}' archived.", params.id))
+            Ok(Output::new(
+                format!("Project '{}' archived.", params.id),
+                data.project_archive,
+            ))
         } else {
             Err(Error::GraphQL("Project archive failed".into()))
         }
@@ -6086,7 +7929,8 @@ impl LinearMcp {
     async fn handle_update_document(
         &self,
         params: update_document::UpdateDocumentParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::Document>, Error> {
+        let id = self.resolve_document_id(&params.id, Match::Fuzzy).await?;
         let mut input = serde_json::Map::new();
         let mut has_fields = false;
 
@@ -6106,33 +7950,47 @@ impl LinearMcp {
         }
 
         let vars = serde_json::json!({
-            "id": params.id,
+            "id": id,
             "input": serde_json::Value::Object(input),
         });
-        let data: response::UpdateDocumentData = self
-            .client
-            .execute_json(queries::UPDATE_DOCUMENT, vars)
-            .await?;
+        let data: response::UpdateDocumentData =
+            self.client.execute(queries::UPDATE_DOCUMENT, vars).await?;
 
         match data.document_update.document {
-            Some(doc) => Ok(format!(
-                "Document updated:\n\n{}",
-                format::format_document_detail(&doc)
+            Some(doc) => Ok(Output::new(
+                format!(
+                    "Document updated:\n\n{}",
+                    format::format_document_detail(&doc)
+                ),
+                doc,
+            )),
+            None => Err(Error::GraphQL(
+                "Document update returned no document".into(),
             )),
-            None => Err(Error::GraphQL("Document update returned no document".into())),
         }
     }
 
     async fn handle_create_cycle(
         &self,
         params: create_cycle::CreateCycleParams,
-    ) -> Result<String, Error> {
-        let team_id = self.resolve_team_id(&params.team).await?;
+    ) -> Result<Output<types::CycleDetail>, Error> {
+        self.check_dates(&[
+            ("startsAt", Some(&params.starts_at)),
+            ("endsAt", Some(&params.ends_at)),
+        ])?;
+        let team = self.team_or_default(params.team.as_deref())?;
+        let team_id = self.resolve_team_id(&team).await?;
+        let starts_at = self
+            .timestamp_arg("startsAt", &params.starts_at, Bound::Start, Some(&team))
+            .await?;
+        let ends_at = self
+            .timestamp_arg("endsAt", &params.ends_at, Bound::End, Some(&team))
+            .await?;
 
         let mut input = serde_json::json!({
             "teamId": team_id,
-            "startsAt": params.starts_at,
-            "endsAt": params.ends_at,
+            "startsAt": starts_at,
+            "endsAt": ends_at,
         });
 
         if let Some(ref name) = params.name {
@@ -6140,15 +7998,13 @@ impl LinearMcp {
         }
 
         let vars = serde_json::json!({ "input": input });
-        let data: response::CreateCycleData = self
-            .client
-            .execute_json(queries::CREATE_CYCLE, vars)
-            .await?;
+        let data: response::CreateCycleData =
+            self.client.execute(queries::CREATE_CYCLE, vars).await?;
 
         match data.cycle_create.cycle {
-            Some(cycle) => Ok(format!(
-                "Cycle created:\n\n{}",
-                format::format_cycle_created(&cycle)
+            Some(cycle) => Ok(Output::new(
+                format!("Cycle created:\n\n{}", format::format_cycle_created(&cycle)),
+                cycle,
             )),
             None => Err(Error::GraphQL("Cycle creation failed".into())),
         }
@@ -6157,7 +8013,7 @@ impl LinearMcp {
     async fn handle_update_label(
         &self,
         params: update_label::UpdateLabelParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::Label>, Error> {
         let mut input = serde_json::Map::new();
         let mut has_fields = false;
 
@@ -6180,13 +8036,14 @@ impl LinearMcp {
             "id": params.id,
             "input": serde_json::Value::Object(input),
         });
-        let data: response::UpdateLabelData = self
-            .client
-            .execute_json(queries::UPDATE_LABEL, vars)
-            .await?;
+        let data: response::UpdateLabelData =
+            self.client.execute(queries::UPDATE_LABEL, vars).await?;
 
         match data.issue_label_update.issue_label {
-            Some(label) => Ok(format!("Label updated: {} [id: {}]", label.name, label.id)),
+            Some(label) => Ok(Output::new(
+                format!("Label updated: {} [id: {}]", label.name, label.id),
+                label,
+            )),
             None => Err(Error::GraphQL("Label update failed".into())),
         }
     }
@@ -6194,15 +8051,16 @@ impl LinearMcp {
     async fn handle_archive_label(
         &self,
         params: archive_label::ArchiveLabelParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::DeleteMutationResult>, Error> {
         let vars = serde_json::json!({ "id": params.id });
-        let data: response::DeleteLabelData = self
-            .client
-            .execute_json(queries::DELETE_LABEL, vars)
-            .await?;
+        let data: response::DeleteLabelData =
+            self.client.execute(queries::DELETE_LABEL, vars).await?;
 
         if data.issue_label_delete.success {
-            Ok(format!("Label {} deleted.", params.id))
+            Ok(Output::new(
+                format!("Label {} deleted.", params.id),
+                data.issue_label_delete,
+            ))
         } else {
             Err(Error::GraphQL("Label deletion failed".into()))
         }
@@ -6211,16 +8069,17 @@ impl LinearMcp {
     async fn handle_unarchive_issue(
         &self,
         params: unarchive_issue::UnarchiveIssueParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::DeleteMutationResult>, Error> {
         let uuid = self.resolve_issue_id(&params.id).await?;
         let vars = serde_json::json!({ "id": uuid });
-        let data: response::UnarchiveIssueData = self
-            .client
-            .execute_json(queries::UNARCHIVE_ISSUE, vars)
-            .await?;
+        let data: response::UnarchiveIssueData =
+            self.client.execute(queries::UNARCHIVE_ISSUE, vars).await?;
 
         if data.issue_unarchive.success {
-            Ok(format!("Issue '{}' unarchived.", params.id))
+            Ok(Output::new(
+                format!("Issue '{}' unarchived.", params.id),
+                data.issue_unarchive,
+            ))
         } else {
             Err(Error::GraphQL("Issue unarchive failed".into()))
         }
@@ -6231,98 +8090,155 @@ impl LinearMcp {
     async fn handle_bulk_update_issues(
         &self,
         params: bulk_update_issues::BulkUpdateIssuesParams,
-    ) -> Result<String, Error> {
-        let id_strs: Vec<&str> = params.ids.split(',').map(|s| s.trim()).filter(|s| !s.is_empty()).collect();
+    ) -> Result<Output<types::BatchUpdateResult>, Error> {
+        let id_strs: Vec<&str> = params
+            .ids
+            .split(',')
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+            .collect();
         if id_strs.is_empty() {
             return Err(Error::InvalidInput("No issue IDs provided.".into()));
         }
         if id_strs.len() > 50 {
-            return Err(Error::InvalidInput("Maximum 50 issues per batch update.".into()));
-        }
-
-        // Resolve all IDs to UUIDs
-        let mut uuids = Vec::new();
-        for id_str in &id_strs {
-            uuids.push(self.resolve_issue_id(id_str).await?);
+            return Err(Error::InvalidInput(
+                "Maximum 50 issues per batch update.".into(),
+            ));
         }
 
-        let mut input = serde_json::Map::new();
-
         if params.state.is_some() && params.team.is_some() {
             return Err(Error::InvalidInput(
                 "Cannot set both 'state' and 'team' in the same batch update. Change team first, then update state in a separate call.".into()
             ));
         }
+        let priority = match params.priority.as_deref().map(str::to_lowercase).as_deref() {
+            None => None,
+            Some("urgent") => Some(1),
+            Some("high") => Some(2),
+            Some("normal" | "medium") => Some(3),
+            Some("low") => Some(4),
+            Some("none") => Some(0),
+            Some(_) => {
+                return Err(Error::InvalidInput(format!(
+                    "Unknown priority: {}",
+                    params.priority.as_deref().unwrap_or_default()
+                )));
+            }
+        };
 
-        if let Some(ref state) = params.state {
-            // States are team-scoped — resolve team keys in a single batch query.
+        // One query for all identifiers; it also caches each issue's team,
+        // so the team check below normally costs nothing.
+        let uuids = self.resolve_issue_ids(&id_strs).await?;
+
+        // States, cycles and labels are team-scoped, so all issues must share a team.
+        let needs_team = params.state.is_some()
+            || params.cycle.is_some()
+            || params.add_labels.is_some()
+            || params.remove_labels.is_some();
+        let bulk_team_key = if needs_team {
             let team_keys = self.resolve_team_keys_from_issues(&uuids).await?;
             let first_key = &team_keys[0];
             if team_keys.iter().any(|k| k != first_key) {
-                return Err(Error::InvalidInput(
-                    "Cannot batch-update state across multiple teams. All issues must belong to the same team.".into()
-                ));
+                let what = if params.state.is_some() {
+                    "state"
+                } else if params.cycle.is_some() {
+                    "cycle"
+                } else {
+                    "labels"
+                };
+                return Err(Error::InvalidInput(format!(
+                    "Cannot batch-update {} across multiple teams. All issues must belong to the same team.",
+                    what
+                )));
             }
-            let state_id = self.resolve_state_id(state, first_key).await?;
+            Some(first_key.clone())
+        } else {
+            None
+        };
+        let team_key = bulk_team_key.as_deref();
+
+        // Independent lookups run concurrently; lookups of the same kind
+        // (e.g. added and removed labels) share a single query.
+        let (
+            state_id,
+            cycle_id,
+            assignee_id,
+            project_id,
+            team_id,
+            added_label_ids,
+            removed_label_ids,
+        ) = tokio::try_join!(
+            async {
+                match (&params.state, team_key) {
+                    (Some(state), Some(key)) => self.resolve_state_id(state, key).await.map(Some),
+                    _ => Ok(None),
+                }
+            },
+            async {
+                match (&params.cycle, team_key) {
+                    (Some(cycle), Some(key)) => self
+                        .resolve_cycle_id(cycle, key, Match::Fuzzy)
+                        .await
+                        .map(Some),
+                    _ => Ok(None),
+                }
+            },
+            async {
+                match params.assignee.as_deref() {
+                    Some(a) if a.eq_ignore_ascii_case("none") => Ok(Some(serde_json::Value::Null)),
+                    Some(a) => self.resolve_user_id(a).await.map(|id| Some(id.into())),
+                    None => Ok(None),
+                }
+            },
+            async {
+                match &params.project {
+                    Some(project) => self.resolve_project_id_or_uuid(project).await.map(Some),
+                    None => Ok(None),
+                }
+            },
+            async {
+                match &params.team {
+                    Some(team) => self.resolve_team_id(team).await.map(Some),
+                    None => Ok(None),
+                }
+            },
+            async {
+                match &params.add_labels {
+                    Some(labels) => self.resolve_label_ids(labels, team_key).await.map(Some),
+                    None => Ok(None),
+                }
+            },
+            async {
+                match &params.remove_labels {
+                    Some(labels) => self.resolve_label_ids(labels, team_key).await.map(Some),
+                    None => Ok(None),
+                }
+            },
+        )?;
+
+        let mut input = serde_json::Map::new();
+        if let Some(state_id) = state_id {
             input.insert("stateId".into(), serde_json::Value::String(state_id));
         }
-        if let Some(ref assignee) = params.assignee {
-            if assignee.eq_ignore_ascii_case("none") {
-                input.insert("assigneeId".into(), serde_json::Value::Null);
-            } else {
-                let user_id = self.resolve_user_id(assignee).await?;
-                input.insert("assigneeId".into(), serde_json::Value::String(user_id));
-            }
+        if let Some(assignee_id) = assignee_id {
+            input.insert("assigneeId".into(), assignee_id);
         }
-        if let Some(ref priority) = params.priority {
-            let p = match priority.to_lowercase().as_str() {
-                "urgent" => 1,
-                "high" => 2,
-                "normal" | "medium" => 3,
-                "low" => 4,
-                "none" => 0,
-                _ => return Err(Error::InvalidInput(format!("Unknown priority: {}", priority))),
-            };
+        if let Some(p) = priority {
             input.insert("priority".into(), serde_json::json!(p));
         }
-        if let Some(ref project) = params.project {
-            let project_id = self.resolve_project_id_or_uuid(project).await?;
+        if let Some(project_id) = project_id {
             input.insert("projectId".into(), serde_json::Value::String(project_id));
         }
-        if let Some(ref cycle) = params.cycle {
-            input.insert("cycleId".into(), serde_json::Value::String(cycle.clone()));
+        if let Some(cycle_id) = cycle_id {
+            input.insert("cycleId".into(), serde_json::Value::String(cycle_id));
         }
-        if let Some(ref team) = params.team {
-            let team_id = self.resolve_team_id(team).await?;
+        if let Some(team_id) = team_id {
             input.insert("teamId".into(), serde_json::Value::String(team_id));
         }
-        // Resolve team key for label scoping when adding/removing labels
-        let bulk_team_key = if params.add_labels.is_some() || params.remove_labels.is_some() {
-            let team_keys = self.resolve_team_keys_from_issues(&uuids).await?;
-            let first_key = &team_keys[0];
-            if team_keys.iter().any(|k| k != first_key) {
-                return Err(Error::InvalidInput(
-                    "Cannot batch-update labels across multiple teams. All issues must belong to the same team.".into()
-                ));
-            }
-            Some(first_key.clone())
-        } else {
-            None
-        };
-        if let Some(ref add_labels) = params.add_labels {
-            let label_names: Vec<&str> = add_labels.split(',').map(|s| s.trim()).filter(|s| !s.is_empty()).collect();
-            let mut label_ids = Vec::new();
-            for name in label_names {
-                label_ids.push(self.resolve_label_id(name, bulk_team_key.as_deref()).await?);
-            }
+        if let Some(label_ids) = added_label_ids {
             input.insert("addedLabelIds".into(), serde_json::json!(label_ids));
         }
-        if let Some(ref remove_labels) = params.remove_labels {
-            let label_names: Vec<&str> = remove_labels.split(',').map(|s| s.trim()).filter(|s| !s.is_empty()).collect();
-            let mut label_ids = Vec::new();
-            for name in label_names {
-                label_ids.push(self.resolve_label_id(name, bulk_team_key.as_deref()).await?);
-            }
+        if let Some(label_ids) = removed_label_ids {
             input.insert("removedLabelIds".into(), serde_json::json!(label_ids));
         }
 
@@ -6336,7 +8252,7 @@ impl LinearMcp {
         });
         let data: response::BatchUpdateIssuesData = self
             .client
-            .execute_json(queries::BATCH_UPDATE_ISSUES, vars)
+            .execute(queries::BATCH_UPDATE_ISSUES, vars)
             .await?;
 
         if !data.issue_batch_update.success {
@@ -6351,42 +8267,58 @@ impl LinearMcp {
                 format!("{} {} [{}]", i.identifier, i.title, state)
             })
             .collect();
-        Ok(format!("Updated {} issues:\n\n{}", issues.len(), lines.join("\n")))
+        Ok(Output::new(
+            format!("Updated {} issues:\n\n{}", issues.len(), lines.join("\n")),
+            data.issue_batch_update,
+        ))
     }
 
     async fn handle_search_documents(
         &self,
         params: search_documents::SearchDocumentsParams,
-    ) -> Result<String, Error> {
-        let limit = params.limit.unwrap_or(20).max(1).min(100);
+    ) -> Result<Output<types::DocumentSearchConnection>, Error> {
+        let limit = params.limit.unwrap_or(20).clamp(1, 100);
         let include_comments = params.include_comments.unwrap_or(false);
         let vars = serde_json::json!({
             "term": params.term,
             "first": limit,
             "includeComments": include_comments,
         });
-        let data: response::SearchDocumentsData = self
-            .client
-            .execute_json(queries::SEARCH_DOCUMENTS, vars)
-            .await?;
+        let data: response::SearchDocumentsData =
+            self.client.execute(queries::SEARCH_DOCUMENTS, vars).await?;
 
         let docs = &data.search_documents.nodes;
         if docs.is_empty() {
-            return Ok(format!("No documents found for \"{}\".", params.term));
+            return Ok(Output::new(
+                format!("No documents found for \"{}\".", params.term),
+                data.search_documents,
+            ));
         }
 
-        let total = data.search_documents.total_count.unwrap_or(docs.len() as i64);
-        let lines: Vec<String> = docs.iter().map(format::format_document_search_result).collect();
-        Ok(format!(
-            "Document search for \"{}\" ({} results):\n\n{}",
-            params.term, total, lines.join("\n")
+        let total = data
+            .search_documents
+            .total_count
+            .unwrap_or(docs.len() as i64);
+        let lines: Vec<String> = docs
+            .iter()
+            .map(format::format_document_search_result)
+            .collect();
+        Ok(Output::new(
+            format!(
+                "Document search for \"{}\" ({} results):\n\n{}",
+                params.term,
+                total,
+                lines.join("\n")
+            ),
+            data.search_documents,
         ))
     }
 
     async fn handle_create_initiative(
         &self,
         params: create_initiative::CreateInitiativeParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::InitiativeDetail>, Error> {
+        self.check_dates(&[("targetDate", params.target_date.as_ref())])?;
         let mut input = serde_json::json!({ "name": params.name });
 
         if let Some(ref desc) = params.description {
@@ -6400,19 +8332,25 @@ impl LinearMcp {
             input["ownerId"] = serde_json::Value::String(owner_id);
         }
         if let Some(ref target_date) = params.target_date {
-            input["targetDate"] = serde_json::Value::String(target_date.clone());
+            let target_date = self
+                .date_arg("targetDate", target_date, Bound::End, None)
+                .await?;
+            input["targetDate"] = serde_json::Value::String(target_date);
         }
 
         let vars = serde_json::json!({ "input": input });
         let data: response::CreateInitiativeData = self
             .client
-            .execute_json(queries::CREATE_INITIATIVE, vars)
+            .execute(queries::CREATE_INITIATIVE, vars)
             .await?;
 
         match data.initiative_create.initiative {
-            Some(initiative) => Ok(format!(
-                "Initiative created:\n\n{}",
-                format::format_initiative_detail(&initiative)
+            Some(initiative) => Ok(Output::new(
+                format!(
+                    "Initiative created:\n\n{}",
+                    format::format_initiative_detail(&initiative)
+                ),
+                initiative,
             )),
             None => Err(Error::GraphQL("Initiative creation failed".into())),
         }
@@ -6421,7 +8359,8 @@ impl LinearMcp {
     async fn handle_update_initiative(
         &self,
         params: update_initiative::UpdateInitiativeParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::InitiativeDetail>, Error> {
+        self.check_dates(&[("targetDate", params.target_date.as_ref())])?;
         let mut input = serde_json::Map::new();
         let mut has_fields = false;
 
@@ -6430,7 +8369,10 @@ impl LinearMcp {
             has_fields = true;
         }
         if let Some(ref desc) = params.description {
-            input.insert("description".into(), serde_json::Value::String(desc.clone()));
+            input.insert(
+                "description".into(),
+                serde_json::Value::String(desc.clone()),
+            );
             has_fields = true;
         }
         if let Some(ref status) = params.status {
@@ -6447,7 +8389,10 @@ impl LinearMcp {
             has_fields = true;
         }
         if let Some(ref target_date) = params.target_date {
-            input.insert("targetDate".into(), serde_json::Value::String(target_date.clone()));
+            let target_date = self
+                .date_arg("targetDate", target_date, Bound::End, None)
+                .await?;
+            input.insert("targetDate".into(), serde_json::Value::String(target_date));
             has_fields = true;
         }
 
@@ -6455,16 +8400,20 @@ impl LinearMcp {
             return Err(Error::InvalidInput("No update fields provided.".into()));
         }
 
-        let vars = serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
+        let vars =
+            serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
         let data: response::UpdateInitiativeData = self
             .client
-            .execute_json(queries::UPDATE_INITIATIVE, vars)
+            .execute(queries::UPDATE_INITIATIVE, vars)
             .await?;
 
         match data.initiative_update.initiative {
-            Some(initiative) => Ok(format!(
-                "Initiative updated:\n\n{}",
-                format::format_initiative_detail(&initiative)
+            Some(initiative) => Ok(Output::new(
+                format!(
+                    "Initiative updated:\n\n{}",
+                    format::format_initiative_detail(&initiative)
+                ),
+                initiative,
             )),
             None => Err(Error::GraphQL("Initiative update failed".into())),
         }
@@ -6473,54 +8422,64 @@ impl LinearMcp {
     async fn handle_delete_initiative(
         &self,
         params: delete_initiative::DeleteInitiativeParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::DeleteInitiativeData = self
             .client
-            .execute_json(queries::DELETE_INITIATIVE, vars)
+            .execute(queries::DELETE_INITIATIVE, vars)
             .await?;
 
         if data.initiative_delete.success {
-            Ok(format!("Initiative {} deleted.", params.id))
+            Ok(Output::new(
+                format!("Initiative {} deleted.", params.id),
+                data.initiative_delete,
+            ))
         } else {
             Err(Error::GraphQL("Initiative deletion failed".into()))
         }
     }
 
-    async fn handle_get_view_issues(
-        &self,
-        params: get_view_issues::GetViewIssuesParams,
-    ) -> Result<String, Error> {
-        let limit = params.limit.unwrap_or(50).max(1).min(100);
-        let vars = serde_json::json!({ "id": params.id, "first": limit });
-        let data: response::ViewIssuesData = self
-            .client
-            .execute_json(queries::GET_VIEW_ISSUES, vars)
-            .await?;
-
-        let view = data.custom_view.as_ref().ok_or_else(|| {
-            Error::NotFound(format!("Custom view '{}' not found or not accessible.", params.id))
+    async fn handle_get_view_issues(
+        &self,
+        params: get_view_issues::GetViewIssuesParams,
+    ) -> Result<Output<types::CustomViewWithIssues>, Error> {
+        let id = self.resolve_view_id(&params.id, Match::Fuzzy).await?;
+        let limit = params.limit.unwrap_or(50).clamp(1, 100);
+        let vars = serde_json::json!({ "id": id, "first": limit });
+        let data: response::ViewIssuesData =
+            self.client.execute(queries::GET_VIEW_ISSUES, vars).await?;
+
+        let view = data.custom_view.ok_or_else(|| {
+            Error::NotFound(format!(
+                "Custom view '{}' not found or not accessible.",
+                params.id
+            ))
         })?;
         let issues = &view.issues.nodes;
         if issues.is_empty() {
-            return Ok(format!("No issues in view \"{}\".", view.name));
+            return Ok(Output::new(
+                format!("No issues in view \"{}\".", view.name),
+                view,
+            ));
         }
 
         let lines: Vec<String> = issues.iter().map(format::format_issue_summary).collect();
-        Ok(format!(
+        let text = format!(
             "Issues in \"{}\" ({} results):\n\n{}",
             view.name,
             issues.len(),
             lines.join("\n")
-        ))
+        );
+        Ok(Output::new(text, view))
     }
 
     async fn handle_list_triage_issues(
         &self,
         params: list_triage_issues::ListTriageIssuesParams,
-    ) -> Result<String, Error> {
-        let limit = params.limit.unwrap_or(50).max(1).min(100);
-        let team_id = self.resolve_team_id(&params.team).await?;
+    ) -> Result<Output<types::Connection<types::Issue>>, Error> {
+        let limit = params.limit.unwrap_or(50).clamp(1, 100);
+        let team = self.team_or_default(params.team.as_deref())?;
+        let team_id = self.resolve_team_id(&team).await?;
         let filter = serde_json::json!({
             "state": { "type": { "eq": "triage" } },
             "team": { "id": { "eq": team_id } },
@@ -6528,27 +8487,33 @@ impl LinearMcp {
         let vars = serde_json::json!({ "first": limit, "filter": filter });
         let data: response::IssuesData = self
             .client
-            .execute_json(queries::LIST_TRIAGE_ISSUES, vars)
+            .execute(queries::LIST_TRIAGE_ISSUES, vars)
             .await?;
 
         let issues = &data.issues.nodes;
         if issues.is_empty() {
-            return Ok(format!("No triage issues for team {}.", params.team));
+            return Ok(Output::new(
+                format!("No triage issues for team {}.", team),
+                data.issues,
+            ));
         }
 
         let lines: Vec<String> = issues.iter().map(format::format_issue_summary).collect();
-        Ok(format!(
-            "Triage issues for {} ({}):\n\n{}",
-            params.team,
-            issues.len(),
-            lines.join("\n")
+        Ok(Output::new(
+            format!(
+                "Triage issues for {} ({}):\n\n{}",
+                team,
+                issues.len(),
+                lines.join("\n")
+            ),
+            data.issues,
         ))
     }
 
     async fn handle_triage_issue(
         &self,
         params: triage_issue::TriageIssueParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::Issue>, Error> {
         let issue_id = self.resolve_issue_id(&params.id).await?;
         let team_key = self.resolve_team_key_from_issue(&issue_id).await?;
         let state_id = self.resolve_state_id(&params.state, &team_key).await?;
@@ -6566,23 +8531,29 @@ impl LinearMcp {
                 "normal" | "medium" => 3,
                 "low" => 4,
                 "none" => 0,
-                _ => return Err(Error::InvalidInput(format!("Unknown priority: {}", priority))),
+                _ => {
+                    return Err(Error::InvalidInput(format!(
+                        "Unknown priority: {}",
+                        priority
+                    )));
+                }
             };
             input["priority"] = serde_json::json!(p);
         }
 
         let vars = serde_json::json!({ "id": issue_id, "input": input });
-        let data: response::UpdateIssueData = self
-            .client
-            .execute_json(queries::UPDATE_ISSUE, vars)
-            .await?;
+        let data: response::UpdateIssueData =
+            self.client.execute(queries::UPDATE_ISSUE, vars).await?;
 
         match data.issue_update.issue {
             Some(issue) => {
                 let state_name = issue.state.as_ref().map(|s| s.name.as_str()).unwrap_or("?");
-                Ok(format!(
-                    "Issue {} triaged → {} [{}]",
-                    issue.identifier, state_name, issue.title
+                Ok(Output::new(
+                    format!(
+                        "Issue {} triaged → {} [{}]",
+                        issue.identifier, state_name, issue.title
+                    ),
+                    issue,
                 ))
             }
             None => Err(Error::GraphQL("Triage update failed".into())),
@@ -6592,11 +8563,13 @@ impl LinearMcp {
     async fn handle_create_issue_from_template(
         &self,
         params: create_issue_from_template::CreateIssueFromTemplateParams,
-    ) -> Result<String, Error> {
-        let team_id = self.resolve_team_id(&params.team).await?;
+    ) -> Result<Output<types::Issue>, Error> {
+        let team = self.team_or_default(params.team.as_deref())?;
+        let team_id = self.resolve_team_id(&team).await?;
+        let template_id = self.resolve_template_id(&params.template_id).await?;
         let mut input = serde_json::json!({
             "teamId": team_id,
-            "templateId": params.template_id,
+            "templateId": template_id,
         });
 
         if let Some(ref title) = params.title {
@@ -6616,21 +8589,27 @@ impl LinearMcp {
                 "normal" | "medium" => 3,
                 "low" => 4,
                 "none" => 0,
-                _ => return Err(Error::InvalidInput(format!("Unknown priority: {}", priority))),
+                _ => {
+                    return Err(Error::InvalidInput(format!(
+                        "Unknown priority: {}",
+                        priority
+                    )));
+                }
             };
             input["priority"] = serde_json::json!(p);
         }
 
         let vars = serde_json::json!({ "input": input });
-        let data: response::CreateIssueData = self
-            .client
-            .execute_json(queries::CREATE_ISSUE, vars)
-            .await?;
+        let data: response::CreateIssueData =
+            self.client.execute(queries::CREATE_ISSUE, vars).await?;
 
         match data.issue_create.issue {
-            Some(issue) => Ok(format!(
-                "Issue created from template:\n\n{}",
-                format::format_issue_detail(&issue)
+            Some(issue) => Ok(Output::new(
+                format!(
+                    "Issue created from template:\n\n{}",
+                    format::format_issue_detail(&issue)
+                ),
+                issue,
             )),
             None => Err(Error::GraphQL("Issue creation from template failed".into())),
         }
@@ -6639,37 +8618,306 @@ impl LinearMcp {
     // ---- New resolvers ----
 
     /// Resolve a project milestone name to a UUID within a given project.
-    async fn resolve_project_milestone_id(&self, name: &str, project_id: &str) -> Result<String, Error> {
-        let vars = serde_json::json!({ "id": project_id });
-        let data: response::ProjectMilestonesData = self
+    async fn resolve_project_milestone_id(
+        &self,
+        name: &str,
+        project_id: &str,
+    ) -> Result<String, Error> {
+        let key = format!("{}:{}", project_id, name.to_lowercase());
+        self.client
+            .cache()
+            .get_or_fetch(EntityKind::Milestone, &key, || async {
+                let vars = serde_json::json!({ "id": project_id });
+                let data: response::ProjectMilestonesData = self
+                    .client
+                    .execute(queries::LIST_PROJECT_MILESTONES, vars)
+                    .await?;
+
+                let candidates: Vec<Candidate> = data
+                    .project
+                    .project_milestones
+                    .nodes
+                    .iter()
+                    .map(|m| Candidate::new(m.id.clone(), m.name.clone()).name(m.name.clone()))
+                    .collect();
+                Ok(matching::pick("Milestone", name, &candidates)?.id.clone())
+            })
+            .await
+    }
+
+    /// Resolve a cycle of `team_key` given as a UUID, `current`, `next`,
+    /// `previous`, a number (`12`, `#12`, `Cycle 12`) or a name.
+    async fn resolve_cycle_id(
+        &self,
+        cycle: &str,
+        team_key: &str,
+        how: Match,
+    ) -> Result<String, Error> {
+        if is_uuid(cycle) {
+            return Ok(cycle.to_string());
+        }
+        let wanted = cycle.trim().to_lowercase();
+        // Relative cycles move on by themselves, so they're never cached.
+        let relative = matches!(
+            wanted.as_str(),
+            "current" | "active" | "next" | "upcoming" | "previous" | "last"
+        );
+        let team = self.resolve_team(team_key).await?;
+        let key = format!("{}:{}", team.key, wanted);
+        let cache = self.client.cache();
+        if !relative
+            && how == Match::Fuzzy
+            && let Some(id) = cache.get(EntityKind::Cycle, &key)
+        {
+            return Ok(id);
+        }
+
+        let vars = serde_json::json!({ "teamId": team.id });
+        let data: response::ResolveCyclesData =
+            self.client.execute(queries::RESOLVE_CYCLES, vars).await?;
+        let cycles = &data.team.cycles.nodes;
+        let number = wanted
+            .trim_start_matches("cycle")
+            .trim()
+            .trim_start_matches('#')
+            .parse::<i32>()
+            .ok();
+        let found = match (wanted.as_str(), number) {
+            ("current" | "active", _) => cycles.iter().find(|c| c.is_active),
+            ("next" | "upcoming", _) => cycles.iter().find(|c| c.is_next),
+            ("previous" | "last", _) => cycles.iter().find(|c| c.is_previous),
+            (_, Some(n)) => cycles.iter().find(|c| c.number == n),
+            _ => None,
+        };
+        let id = match found {
+            Some(c) => c.id.clone(),
+            None if relative || number.is_some() => {
+                return Err(Error::NotFound(format!(
+                    "Team {} has no cycle '{}'",
+                    team.key, cycle
+                )));
+            }
+            None => {
+                let candidates: Vec<Candidate> = cycles
+                    .iter()
+                    .filter_map(|c| {
+                        let name = c.name.as_ref()?;
+                        let label = format!("Cycle {} ({})", c.number, name);
+                        Some(Candidate::new(c.id.clone(), label).name(name.clone()))
+                    })
+                    .collect();
+                let kind = format!("Cycle (team {})", team.key);
+                how.pick(&kind, cycle, &candidates)?.id.clone()
+            }
+        };
+        if !relative {
+            cache.insert(EntityKind::Cycle, &key, id.clone());
+        }
+        Ok(id)
+    }
+
+    /// Resolve a cycle argument of a cycle tool: a UUID as is, anything else
+    /// within `team` or the profile's `default_team`.
+    async fn resolve_cycle_param(
+        &self,
+        cycle: &str,
+        team: Option<&str>,
+        how: Match,
+    ) -> Result<String, Error> {
+        if is_uuid(cycle) {
+            return Ok(cycle.to_string());
+        }
+        let team = self.team_or_default(team)?;
+        self.resolve_cycle_id(cycle, &team, how).await
+    }
+
+    /// Resolve a custom view name or UUID to a UUID.
+    async fn resolve_view_id(&self, id_or_name: &str, how: Match) -> Result<String, Error> {
+        if is_uuid(id_or_name) {
+            return Ok(id_or_name.to_string());
+        }
+        let fetch = || async {
+            let filter = serde_json::json!({ "name": how.filter(id_or_name) });
+            let mut candidates = self.view_candidates(filter).await?;
+            if candidates.is_empty() && how == Match::Fuzzy {
+                // Nothing contains the name: look for a typo among all views.
+                candidates = self.view_candidates(serde_json::Value::Null).await?;
+            }
+            Ok(how.pick("View", id_or_name, &candidates)?.id.clone())
+        };
+        match how {
+            Match::Fuzzy => {
+                self.client
+                    .cache()
+                    .get_or_fetch(EntityKind::View, &id_or_name.to_lowercase(), fetch)
+                    .await
+            }
+            // A fuzzy match cached under the same name must not be reused.
+            Match::Exact => fetch().await,
+        }
+    }
+
+    async fn view_candidates(&self, filter: serde_json::Value) -> Result<Vec<Candidate>, Error> {
+        let data: response::ResolveViewsData = self
+            .client
+            .execute(
+                queries::RESOLVE_VIEWS,
+                serde_json::json!({ "filter": filter }),
+            )
+            .await?;
+        Ok(data
+            .custom_views
+            .nodes
+            .into_iter()
+            .map(|v| Candidate::new(v.id, v.name.clone()).name(v.name))
+            .collect())
+    }
+
+    /// Resolve a template's exact name (ignoring case) or UUID to a UUID.
+    /// Not fuzzy: a near miss would create an issue from the wrong template.
+    async fn resolve_template_id(&self, id_or_name: &str) -> Result<String, Error> {
+        if is_uuid(id_or_name) {
+            return Ok(id_or_name.to_string());
+        }
+        let data: response::TemplatesData = self
             .client
-            .execute_json(queries::LIST_PROJECT_MILESTONES, vars)
+            .execute(queries::LIST_TEMPLATES, serde_json::json!({}))
             .await?;
+        let candidates: Vec<Candidate> = data
+            .templates
+            .into_iter()
+            .map(|t| Candidate::new(t.id, t.name.clone()).name(t.name))
+            .collect();
+        Ok(Match::Exact
+            .pick("Template", id_or_name, &candidates)?
+            .id
+            .clone())
+    }
+
+    /// Resolve a document title or UUID to a UUID.
+    async fn resolve_document_id(&self, id_or_title: &str, how: Match) -> Result<String, Error> {
+        if is_uuid(id_or_title) {
+            return Ok(id_or_title.to_string());
+        }
+        let fetch = || async {
+            let filter = serde_json::json!({ "title": how.filter(id_or_title) });
+            let mut candidates = self.document_candidates(filter).await?;
+            if candidates.is_empty() && how == Match::Fuzzy {
+                candidates = self.document_candidates(serde_json::Value::Null).await?;
+            }
+            Ok(how.pick("Document", id_or_title, &candidates)?.id.clone())
+        };
+        match how {
+            Match::Fuzzy => {
+                self.client
+                    .cache()
+                    .get_or_fetch(EntityKind::Document, &id_or_title.to_lowercase(), fetch)
+                    .await
+            }
+            Match::Exact => fetch().await,
+        }
+    }
 
-        data.project.project_milestones.nodes.iter()
-            .find(|m| m.name.eq_ignore_ascii_case(name))
-            .map(|m| m.id.clone())
-            .ok_or_else(|| Error::NotFound(format!("Milestone '{}' not found in project", name)))
+    async fn document_candidates(
+        &self,
+        filter: serde_json::Value,
+    ) -> Result<Vec<Candidate>, Error> {
+        let data: response::ResolveDocumentsData = self
+            .client
+            .execute(
+                queries::RESOLVE_DOCUMENTS,
+                serde_json::json!({ "filter": filter }),
+            )
+            .await?;
+        Ok(data
+            .documents
+            .nodes
+            .into_iter()
+            .map(|d| {
+                let label = match d.project {
+                    Some(project) => format!("{} ({})", d.title, project.name),
+                    None => d.title.clone(),
+                };
+                Candidate::new(d.id, label).name(d.title)
+            })
+            .collect())
     }
 
-    /// Resolve an initiative name or UUID to a UUID.
-    async fn resolve_initiative_id_or_uuid(&self, id_or_name: &str) -> Result<String, Error> {
-        let is_uuid = id_or_name.len() == 36
-            && id_or_name.chars().all(|c| c.is_ascii_hexdigit() || c == '-');
-        if is_uuid {
+    /// Resolve a project milestone name or UUID to a UUID, across all
+    /// projects. Use `resolve_project_milestone_id` when the project is known.
+    async fn resolve_milestone_id(&self, id_or_name: &str, how: Match) -> Result<String, Error> {
+        if is_uuid(id_or_name) {
             return Ok(id_or_name.to_string());
         }
+        let fetch = || async {
+            let filter = serde_json::json!({ "name": how.filter(id_or_name) });
+            let mut candidates = self.milestone_candidates(filter).await?;
+            if candidates.is_empty() && how == Match::Fuzzy {
+                candidates = self.milestone_candidates(serde_json::Value::Null).await?;
+            }
+            Ok(how.pick("Milestone", id_or_name, &candidates)?.id.clone())
+        };
+        match how {
+            Match::Fuzzy => {
+                let key = format!("*:{}", id_or_name.to_lowercase());
+                self.client
+                    .cache()
+                    .get_or_fetch(EntityKind::Milestone, &key, fetch)
+                    .await
+            }
+            Match::Exact => fetch().await,
+        }
+    }
 
-        let vars = serde_json::json!({ "first": 100 });
-        let data: response::InitiativesData = self
+    async fn milestone_candidates(
+        &self,
+        filter: serde_json::Value,
+    ) -> Result<Vec<Candidate>, Error> {
+        let data: response::ResolveMilestonesData = self
             .client
-            .execute_json(queries::LIST_INITIATIVES, vars)
+            .execute(
+                queries::RESOLVE_MILESTONES,
+                serde_json::json!({ "filter": filter }),
+            )
             .await?;
+        Ok(data
+            .project_milestones
+            .nodes
+            .into_iter()
+            .map(|m| {
+                let label = match m.project {
+                    Some(project) => format!("{} ({})", m.name, project.name),
+                    None => m.name.clone(),
+                };
+                Candidate::new(m.id, label).name(m.name)
+            })
+            .collect())
+    }
+
+    /// Resolve an initiative name or UUID to a UUID.
+    async fn resolve_initiative_id_or_uuid(&self, id_or_name: &str) -> Result<String, Error> {
+        if is_uuid(id_or_name) {
+            return Ok(id_or_name.to_string());
+        }
 
-        data.initiatives.nodes.iter()
-            .find(|i| i.name.eq_ignore_ascii_case(id_or_name))
-            .map(|i| i.id.clone())
-            .ok_or_else(|| Error::NotFound(format!("Initiative '{}' not found", id_or_name)))
+        let key = id_or_name.to_lowercase();
+        self.client
+            .cache()
+            .get_or_fetch(EntityKind::Initiative, &key, || async {
+                let vars = serde_json::json!({ "first": 100 });
+                let data: response::InitiativesData =
+                    self.client.execute(queries::LIST_INITIATIVES, vars).await?;
+
+                data.initiatives
+                    .nodes
+                    .iter()
+                    .find(|i| i.name.eq_ignore_ascii_case(id_or_name))
+                    .map(|i| i.id.clone())
+                    .ok_or_else(|| {
+                        Error::NotFound(format!("Initiative '{}' not found", id_or_name))
+                    })
+            })
+            .await
     }
 
     // ---- Phase 2: Delete/Archive handlers ----
@@ -6677,14 +8925,16 @@ impl LinearMcp {
     async fn handle_delete_document(
         &self,
         params: delete_document::DeleteDocumentParams,
-    ) -> Result<String, Error> {
-        let vars = serde_json::json!({ "id": params.id });
-        let data: response::DeleteDocumentData = self
-            .client
-            .execute_json(queries::DELETE_DOCUMENT, vars)
-            .await?;
+    ) -> Result<Output<types::SuccessResult>, Error> {
+        let id = self.resolve_document_id(&params.id, Match::Exact).await?;
+        let vars = serde_json::json!({ "id": id });
+        let data: response::DeleteDocumentData =
+            self.client.execute(queries::DELETE_DOCUMENT, vars).await?;
         if data.document_delete.success {
-            Ok(format!("Document {} deleted.", params.id))
+            Ok(Output::new(
+                format!("Document {} deleted.", params.id),
+                data.document_delete,
+            ))
         } else {
             Err(Error::GraphQL("Document deletion failed".into()))
         }
@@ -6693,14 +8943,18 @@ impl LinearMcp {
     async fn handle_delete_project_milestone(
         &self,
         params: delete_project_milestone::DeleteProjectMilestoneParams,
-    ) -> Result<String, Error> {
-        let vars = serde_json::json!({ "id": params.id });
+    ) -> Result<Output<types::SuccessResult>, Error> {
+        let id = self.resolve_milestone_id(&params.id, Match::Exact).await?;
+        let vars = serde_json::json!({ "id": id });
         let data: response::DeleteProjectMilestoneData = self
             .client
-            .execute_json(queries::DELETE_PROJECT_MILESTONE, vars)
+            .execute(queries::DELETE_PROJECT_MILESTONE, vars)
             .await?;
         if data.project_milestone_delete.success {
-            Ok(format!("Project milestone {} deleted.", params.id))
+            Ok(Output::new(
+                format!("Project milestone {} deleted.", params.id),
+                data.project_milestone_delete,
+            ))
         } else {
             Err(Error::GraphQL("Project milestone deletion failed".into()))
         }
@@ -6709,14 +8963,17 @@ impl LinearMcp {
     async fn handle_delete_project_update(
         &self,
         params: delete_project_update::DeleteProjectUpdateParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::DeleteProjectUpdateData = self
             .client
-            .execute_json(queries::DELETE_PROJECT_UPDATE, vars)
+            .execute(queries::DELETE_PROJECT_UPDATE, vars)
             .await?;
         if data.project_update_delete.success {
-            Ok(format!("Project update {} deleted.", params.id))
+            Ok(Output::new(
+                format!("Project update {} deleted.", params.id),
+                data.project_update_delete,
+            ))
         } else {
             Err(Error::GraphQL("Project update deletion failed".into()))
         }
@@ -6725,14 +8982,17 @@ impl LinearMcp {
     async fn handle_delete_attachment(
         &self,
         params: delete_attachment::DeleteAttachmentParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::DeleteAttachmentData = self
             .client
-            .execute_json(queries::DELETE_ATTACHMENT, vars)
+            .execute(queries::DELETE_ATTACHMENT, vars)
             .await?;
         if data.attachment_delete.success {
-            Ok(format!("Attachment {} deleted.", params.id))
+            Ok(Output::new(
+                format!("Attachment {} deleted.", params.id),
+                data.attachment_delete,
+            ))
         } else {
             Err(Error::GraphQL("Attachment deletion failed".into()))
         }
@@ -6741,15 +9001,16 @@ impl LinearMcp {
     async fn handle_delete_issue(
         &self,
         params: delete_issue::DeleteIssueParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let uuid = self.resolve_issue_id(&params.id).await?;
         let vars = serde_json::json!({ "id": uuid });
-        let data: response::DeleteIssueData = self
-            .client
-            .execute_json(queries::DELETE_ISSUE, vars)
-            .await?;
+        let data: response::DeleteIssueData =
+            self.client.execute(queries::DELETE_ISSUE, vars).await?;
         if data.issue_delete.success {
-            Ok(format!("Issue '{}' permanently deleted.", params.id))
+            Ok(Output::new(
+                format!("Issue '{}' permanently deleted.", params.id),
+                data.issue_delete,
+            ))
         } else {
             Err(Error::GraphQL("Issue deletion failed".into()))
         }
@@ -6758,14 +9019,16 @@ impl LinearMcp {
     async fn handle_delete_view(
         &self,
         params: delete_view::DeleteViewParams,
-    ) -> Result<String, Error> {
-        let vars = serde_json::json!({ "id": params.id });
-        let data: response::DeleteViewData = self
-            .client
-            .execute_json(queries::DELETE_VIEW, vars)
-            .await?;
+    ) -> Result<Output<types::SuccessResult>, Error> {
+        let id = self.resolve_view_id(&params.id, Match::Exact).await?;
+        let vars = serde_json::json!({ "id": id });
+        let data: response::DeleteViewData =
+            self.client.execute(queries::DELETE_VIEW, vars).await?;
         if data.custom_view_delete.success {
-            Ok(format!("Custom view {} deleted.", params.id))
+            Ok(Output::new(
+                format!("Custom view {} deleted.", params.id),
+                data.custom_view_delete,
+            ))
         } else {
             Err(Error::GraphQL("Custom view deletion failed".into()))
         }
@@ -6774,14 +9037,18 @@ impl LinearMcp {
     async fn handle_archive_cycle(
         &self,
         params: archive_cycle::ArchiveCycleParams,
-    ) -> Result<String, Error> {
-        let vars = serde_json::json!({ "id": params.id });
-        let data: response::ArchiveCycleData = self
-            .client
-            .execute_json(queries::ARCHIVE_CYCLE, vars)
+    ) -> Result<Output<types::SuccessResult>, Error> {
+        let id = self
+            .resolve_cycle_param(&params.id, params.team.as_deref(), Match::Exact)
             .await?;
+        let vars = serde_json::json!({ "id": id });
+        let data: response::ArchiveCycleData =
+            self.client.execute(queries::ARCHIVE_CYCLE, vars).await?;
         if data.cycle_archive.success {
-            Ok(format!("Cycle {} archived.", params.id))
+            Ok(Output::new(
+                format!("Cycle {} archived.", params.id),
+                data.cycle_archive,
+            ))
         } else {
             Err(Error::GraphQL("Cycle archive failed".into()))
         }
@@ -6792,7 +9059,14 @@ impl LinearMcp {
     async fn handle_update_cycle(
         &self,
         params: update_cycle::UpdateCycleParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::CycleDetail>, Error> {
+        self.check_dates(&[
+            ("startsAt", params.starts_at.as_ref()),
+            ("endsAt", params.ends_at.as_ref()),
+        ])?;
+        let id = self
+            .resolve_cycle_param(&params.id, params.team.as_deref(), Match::Fuzzy)
+            .await?;
         let mut input = serde_json::Map::new();
         let mut has_fields = false;
 
@@ -6801,15 +9075,24 @@ impl LinearMcp {
             has_fields = true;
         }
         if let Some(ref desc) = params.description {
-            input.insert("description".into(), serde_json::Value::String(desc.clone()));
+            input.insert(
+                "description".into(),
+                serde_json::Value::String(desc.clone()),
+            );
             has_fields = true;
         }
         if let Some(ref starts_at) = params.starts_at {
-            input.insert("startsAt".into(), serde_json::Value::String(starts_at.clone()));
+            let starts_at = self
+                .timestamp_arg("startsAt", starts_at, Bound::Start, params.team.as_deref())
+                .await?;
+            input.insert("startsAt".into(), serde_json::Value::String(starts_at));
             has_fields = true;
         }
         if let Some(ref ends_at) = params.ends_at {
-            input.insert("endsAt".into(), serde_json::Value::String(ends_at.clone()));
+            let ends_at = self
+                .timestamp_arg("endsAt", ends_at, Bound::End, params.team.as_deref())
+                .await?;
+            input.insert("endsAt".into(), serde_json::Value::String(ends_at));
             has_fields = true;
         }
 
@@ -6817,14 +9100,15 @@ impl LinearMcp {
             return Err(Error::InvalidInput("No fields to update.".into()));
         }
 
-        let vars = serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
-        let data: response::UpdateCycleData = self
-            .client
-            .execute_json(queries::UPDATE_CYCLE, vars)
-            .await?;
+        let vars = serde_json::json!({ "id": id, "input": serde_json::Value::Object(input) });
+        let data: response::UpdateCycleData =
+            self.client.execute(queries::UPDATE_CYCLE, vars).await?;
 
         match data.cycle_update.cycle {
-            Some(cycle) => Ok(format!("Cycle updated:\n\n{}", format::format_cycle_created(&cycle))),
+            Some(cycle) => Ok(Output::new(
+                format!("Cycle updated:\n\n{}", format::format_cycle_created(&cycle)),
+                cycle,
+            )),
             None => Err(Error::GraphQL("Cycle update failed".into())),
         }
     }
@@ -6832,7 +9116,9 @@ impl LinearMcp {
     async fn handle_update_project_milestone(
         &self,
         params: update_project_milestone::UpdateProjectMilestoneParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::ProjectMilestone>, Error> {
+        self.check_dates(&[("targetDate", params.target_date.as_ref())])?;
+        let id = self.resolve_milestone_id(&params.id, Match::Fuzzy).await?;
         let mut input = serde_json::Map::new();
         let mut has_fields = false;
 
@@ -6841,11 +9127,17 @@ impl LinearMcp {
             has_fields = true;
         }
         if let Some(ref desc) = params.description {
-            input.insert("description".into(), serde_json::Value::String(desc.clone()));
+            input.insert(
+                "description".into(),
+                serde_json::Value::String(desc.clone()),
+            );
             has_fields = true;
         }
         if let Some(ref target_date) = params.target_date {
-            input.insert("targetDate".into(), serde_json::Value::String(target_date.clone()));
+            let target_date = self
+                .date_arg("targetDate", target_date, Bound::End, None)
+                .await?;
+            input.insert("targetDate".into(), serde_json::Value::String(target_date));
             has_fields = true;
         }
 
@@ -6853,14 +9145,20 @@ impl LinearMcp {
             return Err(Error::InvalidInput("No fields to update.".into()));
         }
 
-        let vars = serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
+        let vars = serde_json::json!({ "id": id, "input": serde_json::Value::Object(input) });
         let data: response::UpdateProjectMilestoneData = self
             .client
-            .execute_json(queries::UPDATE_PROJECT_MILESTONE, vars)
+            .execute(queries::UPDATE_PROJECT_MILESTONE, vars)
             .await?;
 
         match data.project_milestone_update.project_milestone {
-            Some(milestone) => Ok(format!("Milestone updated:\n\n{}", format::format_project_milestone(&milestone))),
+            Some(milestone) => Ok(Output::new(
+                format!(
+                    "Milestone updated:\n\n{}",
+                    format::format_project_milestone(&milestone)
+                ),
+                milestone,
+            )),
             None => Err(Error::GraphQL("Milestone update failed".into())),
         }
     }
@@ -6868,7 +9166,7 @@ impl LinearMcp {
     async fn handle_update_project_update(
         &self,
         params: update_project_update::UpdateProjectUpdateParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::ProjectUpdate>, Error> {
         let mut input = serde_json::Map::new();
         let mut has_fields = false;
 
@@ -6885,14 +9183,21 @@ impl LinearMcp {
             return Err(Error::InvalidInput("No fields to update.".into()));
         }
 
-        let vars = serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
+        let vars =
+            serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
         let data: response::UpdateProjectUpdateData = self
             .client
-            .execute_json(queries::UPDATE_PROJECT_UPDATE, vars)
+            .execute(queries::UPDATE_PROJECT_UPDATE, vars)
             .await?;
 
         match data.project_update_update.project_update {
-            Some(update) => Ok(format!("Project update updated:\n\n{}", format::format_project_update(&update))),
+            Some(update) => Ok(Output::new(
+                format!(
+                    "Project update updated:\n\n{}",
+                    format::format_project_update(&update)
+                ),
+                update,
+            )),
             None => Err(Error::GraphQL("Project update update failed".into())),
         }
     }
@@ -6900,7 +9205,7 @@ impl LinearMcp {
     async fn handle_update_webhook(
         &self,
         params: update_webhook::UpdateWebhookParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::Webhook>, Error> {
         let mut input = serde_json::Map::new();
         let mut has_fields = false;
 
@@ -6917,7 +9222,11 @@ impl LinearMcp {
             has_fields = true;
         }
         if let Some(ref resource_types) = params.resource_types {
-            let types: Vec<&str> = resource_types.split(',').map(|s| s.trim()).filter(|s| !s.is_empty()).collect();
+            let types: Vec<&str> = resource_types
+                .split(',')
+                .map(|s| s.trim())
+                .filter(|s| !s.is_empty())
+                .collect();
             input.insert("resourceTypes".into(), serde_json::json!(types));
             has_fields = true;
         }
@@ -6926,14 +9235,16 @@ impl LinearMcp {
             return Err(Error::InvalidInput("No fields to update.".into()));
         }
 
-        let vars = serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
-        let data: response::UpdateWebhookData = self
-            .client
-            .execute_json(queries::UPDATE_WEBHOOK, vars)
-            .await?;
+        let vars =
+            serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
+        let data: response::UpdateWebhookData =
+            self.client.execute(queries::UPDATE_WEBHOOK, vars).await?;
 
         match data.webhook_update.webhook {
-            Some(webhook) => Ok(format!("Webhook updated:\n\n{}", format::format_webhook(&webhook))),
+            Some(webhook) => Ok(Output::new(
+                format!("Webhook updated:\n\n{}", format::format_webhook(&webhook)),
+                webhook,
+            )),
             None => Err(Error::GraphQL("Webhook update failed".into())),
         }
     }
@@ -6941,7 +9252,7 @@ impl LinearMcp {
     async fn handle_update_attachment(
         &self,
         params: update_attachment::UpdateAttachmentParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::Attachment>, Error> {
         let mut input = serde_json::json!({ "title": params.title });
 
         if let Some(ref subtitle) = params.subtitle {
@@ -6951,11 +9262,17 @@ impl LinearMcp {
         let vars = serde_json::json!({ "id": params.id, "input": input });
         let data: response::UpdateAttachmentData = self
             .client
-            .execute_json(queries::UPDATE_ATTACHMENT, vars)
+            .execute(queries::UPDATE_ATTACHMENT, vars)
             .await?;
 
         match data.attachment_update.attachment {
-            Some(attachment) => Ok(format!("Attachment updated:\n\n{}", format::format_attachment(&attachment))),
+            Some(attachment) => Ok(Output::new(
+                format!(
+                    "Attachment updated:\n\n{}",
+                    format::format_attachment(&attachment)
+                ),
+                attachment,
+            )),
             None => Err(Error::GraphQL("Attachment update failed".into())),
         }
     }
@@ -6963,7 +9280,8 @@ impl LinearMcp {
     async fn handle_update_view(
         &self,
         params: update_view::UpdateViewParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::CustomView>, Error> {
+        let id = self.resolve_view_id(&params.id, Match::Fuzzy).await?;
         let mut input = serde_json::Map::new();
         let mut has_fields = false;
 
@@ -6972,7 +9290,10 @@ impl LinearMcp {
             has_fields = true;
         }
         if let Some(ref desc) = params.description {
-            input.insert("description".into(), serde_json::Value::String(desc.clone()));
+            input.insert(
+                "description".into(),
+                serde_json::Value::String(desc.clone()),
+            );
             has_fields = true;
         }
         if let Some(ref color) = params.color {
@@ -6992,14 +9313,15 @@ impl LinearMcp {
             return Err(Error::InvalidInput("No fields to update.".into()));
         }
 
-        let vars = serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
-        let data: response::UpdateViewData = self
-            .client
-            .execute_json(queries::UPDATE_VIEW, vars)
-            .await?;
+        let vars = serde_json::json!({ "id": id, "input": serde_json::Value::Object(input) });
+        let data: response::UpdateViewData =
+            self.client.execute(queries::UPDATE_VIEW, vars).await?;
 
         match data.custom_view_update.custom_view {
-            Some(view) => Ok(format!("View updated:\n\n{}", format::format_custom_view(&view))),
+            Some(view) => Ok(Output::new(
+                format!("View updated:\n\n{}", format::format_custom_view(&view)),
+                view,
+            )),
             None => Err(Error::GraphQL("View update failed".into())),
         }
     }
@@ -7009,36 +9331,38 @@ impl LinearMcp {
     async fn handle_list_comments(
         &self,
         params: list_comments::ListCommentsParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<response::IssueWithComments>, Error> {
         let uuid = self.resolve_issue_id(&params.issue).await?;
         let limit = params.limit.unwrap_or(25).min(100);
         let vars = serde_json::json!({ "id": uuid, "first": limit });
-        let data: response::ListCommentsData = self
-            .client
-            .execute_json(queries::LIST_COMMENTS, vars)
-            .await?;
+        let data: response::ListCommentsData =
+            self.client.execute(queries::LIST_COMMENTS, vars).await?;
 
         let comments = &data.issue.comments.nodes;
         if comments.is_empty() {
-            return Ok("No comments on this issue.".to_string());
+            return Ok(Output::new("No comments on this issue.", data.issue));
         }
 
         let lines: Vec<String> = comments.iter().map(format::format_comment_detail).collect();
-        Ok(format!("Comments:\n\n{}", lines.join("\n\n")))
+        Ok(Output::new(
+            format!("Comments:\n\n{}", lines.join("\n\n")),
+            data.issue,
+        ))
     }
 
     async fn handle_resolve_comment(
         &self,
         params: resolve_comment::ResolveCommentParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::Comment>, Error> {
         let vars = serde_json::json!({ "id": params.id });
-        let data: response::ResolveCommentData = self
-            .client
-            .execute_json(queries::RESOLVE_COMMENT, vars)
-            .await?;
+        let data: response::ResolveCommentData =
+            self.client.execute(queries::RESOLVE_COMMENT, vars).await?;
 
         match data.comment_resolve.comment {
-            Some(_) => Ok(format!("Comment {} resolved.", params.id)),
+            Some(comment) => Ok(Output::new(
+                format!("Comment {} resolved.", params.id),
+                comment,
+            )),
             None => Err(Error::GraphQL("Comment resolve failed".into())),
         }
     }
@@ -7046,15 +9370,18 @@ impl LinearMcp {
     async fn handle_unresolve_comment(
         &self,
         params: unresolve_comment::UnresolveCommentParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::Comment>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::UnresolveCommentData = self
             .client
-            .execute_json(queries::UNRESOLVE_COMMENT, vars)
+            .execute(queries::UNRESOLVE_COMMENT, vars)
             .await?;
 
         match data.comment_unresolve.comment {
-            Some(_) => Ok(format!("Comment {} unresolved.", params.id)),
+            Some(comment) => Ok(Output::new(
+                format!("Comment {} unresolved.", params.id),
+                comment,
+            )),
             None => Err(Error::GraphQL("Comment unresolve failed".into())),
         }
     }
@@ -7064,7 +9391,7 @@ impl LinearMcp {
     async fn handle_subscribe_to_issue(
         &self,
         params: subscribe_to_issue::SubscribeToIssueParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let uuid = self.resolve_issue_id(&params.issue).await?;
         let mut vars = serde_json::json!({ "id": uuid });
 
@@ -7075,11 +9402,14 @@ impl LinearMcp {
 
         let data: response::SubscribeToIssueData = self
             .client
-            .execute_json(queries::SUBSCRIBE_TO_ISSUE, vars)
+            .execute(queries::SUBSCRIBE_TO_ISSUE, vars)
             .await?;
 
         if data.issue_subscribe.success {
-            Ok(format!("Subscribed to issue '{}'.", params.issue))
+            Ok(Output::new(
+                format!("Subscribed to issue '{}'.", params.issue),
+                data.issue_subscribe,
+            ))
         } else {
             Err(Error::GraphQL("Issue subscribe failed".into()))
         }
@@ -7088,7 +9418,7 @@ impl LinearMcp {
     async fn handle_unsubscribe_from_issue(
         &self,
         params: unsubscribe_from_issue::UnsubscribeFromIssueParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let uuid = self.resolve_issue_id(&params.issue).await?;
         let mut vars = serde_json::json!({ "id": uuid });
 
@@ -7099,11 +9429,14 @@ impl LinearMcp {
 
         let data: response::UnsubscribeFromIssueData = self
             .client
-            .execute_json(queries::UNSUBSCRIBE_FROM_ISSUE, vars)
+            .execute(queries::UNSUBSCRIBE_FROM_ISSUE, vars)
             .await?;
 
         if data.issue_unsubscribe.success {
-            Ok(format!("Unsubscribed from issue '{}'.", params.issue))
+            Ok(Output::new(
+                format!("Unsubscribed from issue '{}'.", params.issue),
+                data.issue_unsubscribe,
+            ))
         } else {
             Err(Error::GraphQL("Issue unsubscribe failed".into()))
         }
@@ -7114,7 +9447,7 @@ impl LinearMcp {
     async fn handle_create_view(
         &self,
         params: create_view::CreateViewParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::CustomView>, Error> {
         let mut input = serde_json::json!({ "name": params.name });
 
         if let Some(ref desc) = params.description {
@@ -7135,13 +9468,14 @@ impl LinearMcp {
         }
 
         let vars = serde_json::json!({ "input": input });
-        let data: response::CreateViewData = self
-            .client
-            .execute_json(queries::CREATE_VIEW, vars)
-            .await?;
+        let data: response::CreateViewData =
+            self.client.execute(queries::CREATE_VIEW, vars).await?;
 
         match data.custom_view_create.custom_view {
-            Some(view) => Ok(format!("View created:\n\n{}", format::format_custom_view(&view))),
+            Some(view) => Ok(Output::new(
+                format!("View created:\n\n{}", format::format_custom_view(&view)),
+                view,
+            )),
             None => Err(Error::GraphQL("View creation failed".into())),
         }
     }
@@ -7151,40 +9485,50 @@ impl LinearMcp {
     async fn handle_search_projects(
         &self,
         params: search_projects::SearchProjectsParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::ProjectSearchConnection>, Error> {
         let limit = params.limit.unwrap_or(10).min(50);
         let vars = serde_json::json!({ "term": params.query, "first": limit });
-        let data: response::SearchProjectsData = self
-            .client
-            .execute_json(queries::SEARCH_PROJECTS, vars)
-            .await?;
+        let data: response::SearchProjectsData =
+            self.client.execute(queries::SEARCH_PROJECTS, vars).await?;
 
         let results = &data.search_projects.nodes;
         if results.is_empty() {
-            return Ok(format!("No projects found for \"{}\".", params.query));
+            return Ok(Output::new(
+                format!("No projects found for \"{}\".", params.query),
+                data.search_projects,
+            ));
         }
 
-        let lines: Vec<String> = results.iter().map(format::format_project_search_result).collect();
-        Ok(format!(
-            "Project search for \"{}\":\n\n{}",
-            params.query, lines.join("\n\n")
+        let lines: Vec<String> = results
+            .iter()
+            .map(format::format_project_search_result)
+            .collect();
+        Ok(Output::new(
+            format!(
+                "Project search for \"{}\":\n\n{}",
+                params.query,
+                lines.join("\n\n")
+            ),
+            data.search_projects,
         ))
     }
 
     async fn handle_issue_vcs_branch_search(
         &self,
         params: issue_vcs_branch_search::IssueVcsBranchSearchParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::NodeList<types::Issue>>, Error> {
         let vars = serde_json::json!({ "branchName": params.branch_name });
         let data: response::IssueVcsBranchSearchData = self
             .client
-            .execute_json(queries::ISSUE_VCS_BRANCH_SEARCH, vars)
+            .execute(queries::ISSUE_VCS_BRANCH_SEARCH, vars)
             .await?;
 
-        match data.issue_vcs_branch_search {
-            Some(issue) => Ok(format::format_issue_detail(&issue)),
-            None => Ok(format!("No issue found for branch '{}'.", params.branch_name)),
-        }
+        let text = match data.issue_vcs_branch_search {
+            Some(ref issue) => format::format_issue_detail(issue),
+            None => format!("No issue found for branch '{}'.", params.branch_name),
+        };
+        let nodes = data.issue_vcs_branch_search.into_iter().collect();
+        Ok(Output::new(text, types::NodeList { nodes }))
     }
 
     // ---- Phase 8: Agent Session handlers ----
@@ -7192,12 +9536,16 @@ impl LinearMcp {
     async fn handle_create_agent_session(
         &self,
         params: create_agent_session::CreateAgentSessionParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::AgentSession>, Error> {
         if params.issue.is_none() && params.comment.is_none() {
-            return Err(Error::InvalidInput("Provide either 'issue' or 'comment'.".into()));
+            return Err(Error::InvalidInput(
+                "Provide either 'issue' or 'comment'.".into(),
+            ));
         }
         if params.issue.is_some() && params.comment.is_some() {
-            return Err(Error::InvalidInput("Provide either 'issue' or 'comment', not both.".into()));
+            return Err(Error::InvalidInput(
+                "Provide either 'issue' or 'comment', not both.".into(),
+            ));
         }
 
         if let Some(ref issue_id) = params.issue {
@@ -7209,10 +9557,16 @@ impl LinearMcp {
             let vars = serde_json::json!({ "input": input });
             let data: response::AgentSessionCreateOnIssueData = self
                 .client
-                .execute_json(queries::AGENT_SESSION_CREATE_ON_ISSUE, vars)
+                .execute(queries::AGENT_SESSION_CREATE_ON_ISSUE, vars)
                 .await?;
             match data.agent_session_create_on_issue.agent_session {
-                Some(session) => Ok(format!("Agent session created:\n\n{}", format::format_agent_session_summary(&session))),
+                Some(session) => Ok(Output::new(
+                    format!(
+                        "Agent session created:\n\n{}",
+                        format::format_agent_session_summary(&session)
+                    ),
+                    session,
+                )),
                 None => Err(Error::GraphQL("Agent session creation failed".into())),
             }
         } else {
@@ -7224,10 +9578,16 @@ impl LinearMcp {
             let vars = serde_json::json!({ "input": input });
             let data: response::AgentSessionCreateOnCommentData = self
                 .client
-                .execute_json(queries::AGENT_SESSION_CREATE_ON_COMMENT, vars)
+                .execute(queries::AGENT_SESSION_CREATE_ON_COMMENT, vars)
                 .await?;
             match data.agent_session_create_on_comment.agent_session {
-                Some(session) => Ok(format!("Agent session created:\n\n{}", format::format_agent_session_summary(&session))),
+                Some(session) => Ok(Output::new(
+                    format!(
+                        "Agent session created:\n\n{}",
+                        format::format_agent_session_summary(&session)
+                    ),
+                    session,
+                )),
                 None => Err(Error::GraphQL("Agent session creation failed".into())),
             }
         }
@@ -7236,7 +9596,7 @@ impl LinearMcp {
     async fn handle_update_agent_session(
         &self,
         params: update_agent_session::UpdateAgentSessionParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::AgentSession>, Error> {
         let mut input = serde_json::Map::new();
         let mut has_fields = false;
 
@@ -7247,7 +9607,10 @@ impl LinearMcp {
             has_fields = true;
         }
         if let Some(ref link) = params.external_link {
-            input.insert("externalLink".into(), serde_json::Value::String(link.clone()));
+            input.insert(
+                "externalLink".into(),
+                serde_json::Value::String(link.clone()),
+            );
             has_fields = true;
         }
 
@@ -7255,14 +9618,21 @@ impl LinearMcp {
             return Err(Error::InvalidInput("No fields to update.".into()));
         }
 
-        let vars = serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
+        let vars =
+            serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
         let data: response::UpdateAgentSessionData = self
             .client
-            .execute_json(queries::UPDATE_AGENT_SESSION, vars)
+            .execute(queries::UPDATE_AGENT_SESSION, vars)
             .await?;
 
         match data.agent_session_update.agent_session {
-            Some(session) => Ok(format!("Agent session updated:\n\n{}", format::format_agent_session_summary(&session))),
+            Some(session) => Ok(Output::new(
+                format!(
+                    "Agent session updated:\n\n{}",
+                    format::format_agent_session_summary(&session)
+                ),
+                session,
+            )),
             None => Err(Error::GraphQL("Agent session update failed".into())),
         }
     }
@@ -7270,7 +9640,7 @@ impl LinearMcp {
     async fn handle_create_agent_activity(
         &self,
         params: create_agent_activity::CreateAgentActivityParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::AgentActivityMutationResult>, Error> {
         let mut content = serde_json::Map::new();
         if let Some(ref body) = params.body {
             content.insert("body".into(), serde_json::Value::String(body.clone()));
@@ -7279,7 +9649,10 @@ impl LinearMcp {
             content.insert("action".into(), serde_json::Value::String(action.clone()));
         }
         if let Some(ref parameter) = params.parameter {
-            content.insert("parameter".into(), serde_json::Value::String(parameter.clone()));
+            content.insert(
+                "parameter".into(),
+                serde_json::Value::String(parameter.clone()),
+            );
         }
         if let Some(ref result) = params.result {
             content.insert("result".into(), serde_json::Value::String(result.clone()));
@@ -7299,8 +9672,7 @@ impl LinearMcp {
         // Map to signal enum when the activity type matches a control signal
         match params.activity_type.to_lowercase().as_str() {
             "stop" | "continue" | "auth" | "select" => {
-                input["signal"] =
-                    serde_json::Value::String(params.activity_type.to_lowercase());
+                input["signal"] = serde_json::Value::String(params.activity_type.to_lowercase());
             }
             _ => {} // Non-signal types are carried in content.type
         }
@@ -7312,11 +9684,14 @@ impl LinearMcp {
         let vars = serde_json::json!({ "input": input });
         let data: response::CreateAgentActivityData = self
             .client
-            .execute_json(queries::CREATE_AGENT_ACTIVITY, vars)
+            .execute(queries::CREATE_AGENT_ACTIVITY, vars)
             .await?;
 
         if data.agent_activity_create.success {
-            Ok("Agent activity logged.".to_string())
+            Ok(Output::new(
+                "Agent activity logged.",
+                data.agent_activity_create,
+            ))
         } else {
             Err(Error::GraphQL("Agent activity creation failed".into()))
         }
@@ -7325,33 +9700,42 @@ impl LinearMcp {
     async fn handle_list_agent_sessions(
         &self,
         params: list_agent_sessions::ListAgentSessionsParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::Connection<types::AgentSession>>, Error> {
         let limit = params.limit.unwrap_or(25).min(100);
         let vars = serde_json::json!({ "first": limit });
         let data: response::AgentSessionsData = self
             .client
-            .execute_json(queries::LIST_AGENT_SESSIONS, vars)
+            .execute(queries::LIST_AGENT_SESSIONS, vars)
             .await?;
 
         let sessions = &data.agent_sessions.nodes;
         if sessions.is_empty() {
-            return Ok("No agent sessions found.".to_string());
+            return Ok(Output::new("No agent sessions found.", data.agent_sessions));
         }
 
-        let lines: Vec<String> = sessions.iter().map(format::format_agent_session_summary).collect();
-        Ok(format!("Agent Sessions:\n\n{}", lines.join("\n\n")))
+        let lines: Vec<String> = sessions
+            .iter()
+            .map(format::format_agent_session_summary)
+            .collect();
+        Ok(Output::new(
+            format!("Agent Sessions:\n\n{}", lines.join("\n\n")),
+            data.agent_sessions,
+        ))
     }
 
     async fn handle_get_agent_session(
         &self,
         params: get_agent_session::GetAgentSessionParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::AgentSession>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::AgentSessionData = self
             .client
-            .execute_json(queries::GET_AGENT_SESSION, vars)
+            .execute(queries::GET_AGENT_SESSION, vars)
             .await?;
-        Ok(format::format_agent_session_detail(&data.agent_session))
+        Ok(Output::new(
+            format::format_agent_session_detail(&data.agent_session),
+            data.agent_session,
+        ))
     }
 
     // ---- Phase 9: Customer handlers ----
@@ -7359,43 +9743,51 @@ impl LinearMcp {
     async fn handle_list_customers(
         &self,
         params: list_customers::ListCustomersParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::Connection<types::Customer>>, Error> {
         let limit = params.limit.unwrap_or(25).min(100);
         let vars = serde_json::json!({ "first": limit });
-        let data: response::CustomersData = self
-            .client
-            .execute_json(queries::LIST_CUSTOMERS, vars)
-            .await?;
+        let data: response::CustomersData =
+            self.client.execute(queries::LIST_CUSTOMERS, vars).await?;
 
         let customers = &data.customers.nodes;
         if customers.is_empty() {
-            return Ok("No customers found.".to_string());
+            return Ok(Output::new("No customers found.", data.customers));
         }
 
-        let lines: Vec<String> = customers.iter().map(format::format_customer_summary).collect();
-        Ok(format!("Customers:\n\n{}", lines.join("\n\n")))
+        let lines: Vec<String> = customers
+            .iter()
+            .map(format::format_customer_summary)
+            .collect();
+        Ok(Output::new(
+            format!("Customers:\n\n{}", lines.join("\n\n")),
+            data.customers,
+        ))
     }
 
     async fn handle_get_customer(
         &self,
         params: get_customer::GetCustomerParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::Customer>, Error> {
         let vars = serde_json::json!({ "id": params.id });
-        let data: response::CustomerData = self
-            .client
-            .execute_json(queries::GET_CUSTOMER, vars)
-            .await?;
-        Ok(format::format_customer_detail(&data.customer))
+        let data: response::CustomerData = self.client.execute(queries::GET_CUSTOMER, vars).await?;
+        Ok(Output::new(
+            format::format_customer_detail(&data.customer),
+            data.customer,
+        ))
     }
 
     async fn handle_create_customer(
         &self,
         params: create_customer::CreateCustomerParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::Customer>, Error> {
         let mut input = serde_json::json!({ "name": params.name });
 
         if let Some(ref domains) = params.domains {
-            let domain_list: Vec<&str> = domains.split(',').map(|s| s.trim()).filter(|s| !s.is_empty()).collect();
+            let domain_list: Vec<&str> = domains
+                .split(',')
+                .map(|s| s.trim())
+                .filter(|s| !s.is_empty())
+                .collect();
             input["domains"] = serde_json::json!(domain_list);
         }
         if let Some(ref owner) = params.owner {
@@ -7410,13 +9802,17 @@ impl LinearMcp {
         }
 
         let vars = serde_json::json!({ "input": input });
-        let data: response::CreateCustomerData = self
-            .client
-            .execute_json(queries::CREATE_CUSTOMER, vars)
-            .await?;
+        let data: response::CreateCustomerData =
+            self.client.execute(queries::CREATE_CUSTOMER, vars).await?;
 
         match data.customer_create.customer {
-            Some(customer) => Ok(format!("Customer created:\n\n{}", format::format_customer_detail(&customer))),
+            Some(customer) => Ok(Output::new(
+                format!(
+                    "Customer created:\n\n{}",
+                    format::format_customer_detail(&customer)
+                ),
+                customer,
+            )),
             None => Err(Error::GraphQL("Customer creation failed".into())),
         }
     }
@@ -7424,7 +9820,7 @@ impl LinearMcp {
     async fn handle_update_customer(
         &self,
         params: update_customer::UpdateCustomerParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::Customer>, Error> {
         let mut input = serde_json::Map::new();
         let mut has_fields = false;
 
@@ -7433,7 +9829,11 @@ impl LinearMcp {
             has_fields = true;
         }
         if let Some(ref domains) = params.domains {
-            let domain_list: Vec<&str> = domains.split(',').map(|s| s.trim()).filter(|s| !s.is_empty()).collect();
+            let domain_list: Vec<&str> = domains
+                .split(',')
+                .map(|s| s.trim())
+                .filter(|s| !s.is_empty())
+                .collect();
             input.insert("domains".into(), serde_json::json!(domain_list));
             has_fields = true;
         }
@@ -7455,14 +9855,19 @@ impl LinearMcp {
             return Err(Error::InvalidInput("No fields to update.".into()));
         }
 
-        let vars = serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
-        let data: response::UpdateCustomerData = self
-            .client
-            .execute_json(queries::UPDATE_CUSTOMER, vars)
-            .await?;
+        let vars =
+            serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
+        let data: response::UpdateCustomerData =
+            self.client.execute(queries::UPDATE_CUSTOMER, vars).await?;
 
         match data.customer_update.customer {
-            Some(customer) => Ok(format!("Customer updated:\n\n{}", format::format_customer_detail(&customer))),
+            Some(customer) => Ok(Output::new(
+                format!(
+                    "Customer updated:\n\n{}",
+                    format::format_customer_detail(&customer)
+                ),
+                customer,
+            )),
             None => Err(Error::GraphQL("Customer update failed".into())),
         }
     }
@@ -7470,14 +9875,15 @@ impl LinearMcp {
     async fn handle_delete_customer(
         &self,
         params: delete_customer::DeleteCustomerParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let vars = serde_json::json!({ "id": params.id });
-        let data: response::DeleteCustomerData = self
-            .client
-            .execute_json(queries::DELETE_CUSTOMER, vars)
-            .await?;
+        let data: response::DeleteCustomerData =
+            self.client.execute(queries::DELETE_CUSTOMER, vars).await?;
         if data.customer_delete.success {
-            Ok(format!("Customer {} deleted.", params.id))
+            Ok(Output::new(
+                format!("Customer {} deleted.", params.id),
+                data.customer_delete,
+            ))
         } else {
             Err(Error::GraphQL("Customer deletion failed".into()))
         }
@@ -7486,27 +9892,30 @@ impl LinearMcp {
     async fn handle_list_customer_needs(
         &self,
         params: list_customer_needs::ListCustomerNeedsParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::Connection<types::CustomerNeed>>, Error> {
         let limit = params.limit.unwrap_or(25).min(100);
         let vars = serde_json::json!({ "first": limit });
         let data: response::CustomerNeedsData = self
             .client
-            .execute_json(queries::LIST_CUSTOMER_NEEDS, vars)
+            .execute(queries::LIST_CUSTOMER_NEEDS, vars)
             .await?;
 
         let needs = &data.customer_needs.nodes;
         if needs.is_empty() {
-            return Ok("No customer needs found.".to_string());
+            return Ok(Output::new("No customer needs found.", data.customer_needs));
         }
 
         let lines: Vec<String> = needs.iter().map(format::format_customer_need).collect();
-        Ok(format!("Customer Needs:\n\n{}", lines.join("\n\n")))
+        Ok(Output::new(
+            format!("Customer Needs:\n\n{}", lines.join("\n\n")),
+            data.customer_needs,
+        ))
     }
 
     async fn handle_create_customer_need(
         &self,
         params: create_customer_need::CreateCustomerNeedParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::CustomerNeed>, Error> {
         let issue_uuid = self.resolve_issue_id(&params.issue).await?;
         let mut input = serde_json::json!({
             "issueId": issue_uuid,
@@ -7523,11 +9932,17 @@ impl LinearMcp {
         let vars = serde_json::json!({ "input": input });
         let data: response::CreateCustomerNeedData = self
             .client
-            .execute_json(queries::CREATE_CUSTOMER_NEED, vars)
+            .execute(queries::CREATE_CUSTOMER_NEED, vars)
             .await?;
 
         match data.customer_need_create.need {
-            Some(need) => Ok(format!("Customer need created:\n\n{}", format::format_customer_need(&need))),
+            Some(need) => Ok(Output::new(
+                format!(
+                    "Customer need created:\n\n{}",
+                    format::format_customer_need(&need)
+                ),
+                need,
+            )),
             None => Err(Error::GraphQL("Customer need creation failed".into())),
         }
     }
@@ -7535,7 +9950,7 @@ impl LinearMcp {
     async fn handle_update_customer_need(
         &self,
         params: update_customer_need::UpdateCustomerNeedParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::CustomerNeed>, Error> {
         let mut input = serde_json::Map::new();
         let mut has_fields = false;
 
@@ -7545,7 +9960,10 @@ impl LinearMcp {
             has_fields = true;
         }
         if let Some(ref customer) = params.customer {
-            input.insert("customerId".into(), serde_json::Value::String(customer.clone()));
+            input.insert(
+                "customerId".into(),
+                serde_json::Value::String(customer.clone()),
+            );
             has_fields = true;
         }
         if let Some(ref body) = params.body {
@@ -7561,14 +9979,21 @@ impl LinearMcp {
             return Err(Error::InvalidInput("No fields to update.".into()));
         }
 
-        let vars = serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
+        let vars =
+            serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
         let data: response::UpdateCustomerNeedData = self
             .client
-            .execute_json(queries::UPDATE_CUSTOMER_NEED, vars)
+            .execute(queries::UPDATE_CUSTOMER_NEED, vars)
             .await?;
 
         match data.customer_need_update.need {
-            Some(need) => Ok(format!("Customer need updated:\n\n{}", format::format_customer_need(&need))),
+            Some(need) => Ok(Output::new(
+                format!(
+                    "Customer need updated:\n\n{}",
+                    format::format_customer_need(&need)
+                ),
+                need,
+            )),
             None => Err(Error::GraphQL("Customer need update failed".into())),
         }
     }
@@ -7578,29 +10003,39 @@ impl LinearMcp {
     async fn handle_list_initiative_updates(
         &self,
         params: list_initiative_updates::ListInitiativeUpdatesParams,
-    ) -> Result<String, Error> {
-        let initiative_id = self.resolve_initiative_id_or_uuid(&params.initiative).await?;
+    ) -> Result<Output<response::InitiativeWithUpdates>, Error> {
+        let initiative_id = self
+            .resolve_initiative_id_or_uuid(&params.initiative)
+            .await?;
         let limit = params.limit.unwrap_or(10).min(50);
         let vars = serde_json::json!({ "id": initiative_id, "first": limit });
         let data: response::InitiativeUpdatesData = self
             .client
-            .execute_json(queries::LIST_INITIATIVE_UPDATES, vars)
+            .execute(queries::LIST_INITIATIVE_UPDATES, vars)
             .await?;
 
         let updates = &data.initiative.initiative_updates.nodes;
         if updates.is_empty() {
-            return Ok("No initiative updates found.".to_string());
+            return Ok(Output::new("No initiative updates found.", data.initiative));
         }
 
-        let lines: Vec<String> = updates.iter().map(format::format_initiative_update).collect();
-        Ok(format!("Initiative Updates:\n\n{}", lines.join("\n\n")))
+        let lines: Vec<String> = updates
+            .iter()
+            .map(format::format_initiative_update)
+            .collect();
+        Ok(Output::new(
+            format!("Initiative Updates:\n\n{}", lines.join("\n\n")),
+            data.initiative,
+        ))
     }
 
     async fn handle_create_initiative_update(
         &self,
         params: create_initiative_update::CreateInitiativeUpdateParams,
-    ) -> Result<String, Error> {
-        let initiative_id = self.resolve_initiative_id_or_uuid(&params.initiative).await?;
+    ) -> Result<Output<types::InitiativeStatusUpdate>, Error> {
+        let initiative_id = self
+            .resolve_initiative_id_or_uuid(&params.initiative)
+            .await?;
         let mut input = serde_json::json!({
             "initiativeId": initiative_id,
             "body": params.body,
@@ -7613,11 +10048,17 @@ impl LinearMcp {
         let vars = serde_json::json!({ "input": input });
         let data: response::CreateInitiativeUpdateData = self
             .client
-            .execute_json(queries::CREATE_INITIATIVE_UPDATE, vars)
+            .execute(queries::CREATE_INITIATIVE_UPDATE, vars)
             .await?;
 
         match data.initiative_update_create.initiative_update {
-            Some(update) => Ok(format!("Initiative update created:\n\n{}", format::format_initiative_update(&update))),
+            Some(update) => Ok(Output::new(
+                format!(
+                    "Initiative update created:\n\n{}",
+                    format::format_initiative_update(&update)
+                ),
+                update,
+            )),
             None => Err(Error::GraphQL("Initiative update creation failed".into())),
         }
     }
@@ -7625,8 +10066,10 @@ impl LinearMcp {
     async fn handle_add_project_to_initiative(
         &self,
         params: add_project_to_initiative::AddProjectToInitiativeParams,
-    ) -> Result<String, Error> {
-        let initiative_id = self.resolve_initiative_id_or_uuid(&params.initiative).await?;
+    ) -> Result<Output<types::InitiativeToProject>, Error> {
+        let initiative_id = self
+            .resolve_initiative_id_or_uuid(&params.initiative)
+            .await?;
         let project_id = self.resolve_project_id_or_uuid(&params.project).await?;
 
         let input = serde_json::json!({
@@ -7637,29 +10080,42 @@ impl LinearMcp {
         let vars = serde_json::json!({ "input": input });
         let data: response::AddProjectToInitiativeData = self
             .client
-            .execute_json(queries::ADD_PROJECT_TO_INITIATIVE, vars)
+            .execute(queries::ADD_PROJECT_TO_INITIATIVE, vars)
             .await?;
 
         match data.initiative_to_project_create.initiative_to_project {
-            Some(link) => Ok(format!("Project linked to initiative:\n\n{}", format::format_initiative_to_project(&link))),
-            None => Err(Error::GraphQL("Failed to link project to initiative".into())),
+            Some(link) => Ok(Output::new(
+                format!(
+                    "Project linked to initiative:\n\n{}",
+                    format::format_initiative_to_project(&link)
+                ),
+                link,
+            )),
+            None => Err(Error::GraphQL(
+                "Failed to link project to initiative".into(),
+            )),
         }
     }
 
     async fn handle_remove_project_from_initiative(
         &self,
         params: remove_project_from_initiative::RemoveProjectFromInitiativeParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::RemoveProjectFromInitiativeData = self
             .client
-            .execute_json(queries::REMOVE_PROJECT_FROM_INITIATIVE, vars)
+            .execute(queries::REMOVE_PROJECT_FROM_INITIATIVE, vars)
             .await?;
 
         if data.initiative_to_project_delete.success {
-            Ok(format!("Initiative-project link {} removed.", params.id))
+            Ok(Output::new(
+                format!("Initiative-project link {} removed.", params.id),
+                data.initiative_to_project_delete,
+            ))
         } else {
-            Err(Error::GraphQL("Failed to remove initiative-project link".into()))
+            Err(Error::GraphQL(
+                "Failed to remove initiative-project link".into(),
+            ))
         }
     }
 
@@ -7668,9 +10124,11 @@ impl LinearMcp {
     async fn handle_create_project_relation(
         &self,
         params: create_project_relation::CreateProjectRelationParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::ProjectRelation>, Error> {
         let project_id = self.resolve_project_id_or_uuid(&params.project).await?;
-        let related_id = self.resolve_project_id_or_uuid(&params.related_project).await?;
+        let related_id = self
+            .resolve_project_id_or_uuid(&params.related_project)
+            .await?;
 
         // Linear project relations use type="dependency" with anchor types:
         //   "start", "end", or "milestone"
@@ -7680,7 +10138,12 @@ impl LinearMcp {
             "blocks" => ("end", "start"),
             "dependson" | "depends_on" => ("start", "end"),
             "related" => ("end", "end"),
-            _ => return Err(Error::InvalidInput(format!("Unknown relation type: {}. Use 'blocks', 'dependsOn', or 'related'.", params.relation_type))),
+            _ => {
+                return Err(Error::InvalidInput(format!(
+                    "Unknown relation type: {}. Use 'blocks', 'dependsOn', or 'related'.",
+                    params.relation_type
+                )));
+            }
         };
 
         let input = serde_json::json!({
@@ -7694,11 +10157,17 @@ impl LinearMcp {
         let vars = serde_json::json!({ "input": input });
         let data: response::CreateProjectRelationData = self
             .client
-            .execute_json(queries::CREATE_PROJECT_RELATION, vars)
+            .execute(queries::CREATE_PROJECT_RELATION, vars)
             .await?;
 
         match data.project_relation_create.project_relation {
-            Some(relation) => Ok(format!("Project relation created:\n\n{}", format::format_project_relation(&relation))),
+            Some(relation) => Ok(Output::new(
+                format!(
+                    "Project relation created:\n\n{}",
+                    format::format_project_relation(&relation)
+                ),
+                relation,
+            )),
             None => Err(Error::GraphQL("Project relation creation failed".into())),
         }
     }
@@ -7706,15 +10175,18 @@ impl LinearMcp {
     async fn handle_delete_project_relation(
         &self,
         params: delete_project_relation::DeleteProjectRelationParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::DeleteProjectRelationData = self
             .client
-            .execute_json(queries::DELETE_PROJECT_RELATION, vars)
+            .execute(queries::DELETE_PROJECT_RELATION, vars)
             .await?;
 
         if data.project_relation_delete.success {
-            Ok(format!("Project relation {} deleted.", params.id))
+            Ok(Output::new(
+                format!("Project relation {} deleted.", params.id),
+                data.project_relation_delete,
+            ))
         } else {
             Err(Error::GraphQL("Project relation deletion failed".into()))
         }
@@ -7723,21 +10195,27 @@ impl LinearMcp {
     async fn handle_list_project_relations(
         &self,
         params: list_project_relations::ListProjectRelationsParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<response::ProjectWithRelations>, Error> {
         let project_id = self.resolve_project_id_or_uuid(&params.project).await?;
         let vars = serde_json::json!({ "id": project_id });
         let data: response::ProjectRelationsData = self
             .client
-            .execute_json(queries::LIST_PROJECT_RELATIONS, vars)
+            .execute(queries::LIST_PROJECT_RELATIONS, vars)
             .await?;
 
         let relations = &data.project.relations.nodes;
         if relations.is_empty() {
-            return Ok("No project relations found.".to_string());
+            return Ok(Output::new("No project relations found.", data.project));
         }
 
-        let lines: Vec<String> = relations.iter().map(format::format_project_relation).collect();
-        Ok(format!("Project Relations:\n\n{}", lines.join("\n\n")))
+        let lines: Vec<String> = relations
+            .iter()
+            .map(format::format_project_relation)
+            .collect();
+        Ok(Output::new(
+            format!("Project Relations:\n\n{}", lines.join("\n\n")),
+            data.project,
+        ))
     }
 
     // ---- Phase 12: Release handlers ----
@@ -7745,27 +10223,35 @@ impl LinearMcp {
     async fn handle_list_releases(
         &self,
         params: list_releases::ListReleasesParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::Connection<types::Release>>, Error> {
         let limit = params.limit.unwrap_or(25).min(100);
         let vars = serde_json::json!({ "first": limit });
-        let data: response::ReleasesData = self
-            .client
-            .execute_json(queries::LIST_RELEASES, vars)
-            .await?;
+        let data: response::ReleasesData =
+            self.client.execute(queries::LIST_RELEASES, vars).await?;
 
         let releases = &data.releases.nodes;
         if releases.is_empty() {
-            return Ok("No releases found.".to_string());
+            return Ok(Output::new("No releases found.", data.releases));
         }
 
-        let lines: Vec<String> = releases.iter().map(format::format_release_summary).collect();
-        Ok(format!("Releases:\n\n{}", lines.join("\n\n")))
+        let lines: Vec<String> = releases
+            .iter()
+            .map(format::format_release_summary)
+            .collect();
+        Ok(Output::new(
+            format!("Releases:\n\n{}", lines.join("\n\n")),
+            data.releases,
+        ))
     }
 
     async fn handle_create_release(
         &self,
         params: create_release::CreateReleaseParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::Release>, Error> {
+        self.check_dates(&[
+            ("startDate", params.start_date.as_ref()),
+            ("targetDate", params.target_date.as_ref()),
+        ])?;
         let mut input = serde_json::json!({
             "name": params.name,
             "pipelineId": params.pipeline,
@@ -7781,20 +10267,30 @@ impl LinearMcp {
             input["commitSha"] = serde_json::Value::String(sha.clone());
         }
         if let Some(ref start) = params.start_date {
-            input["startDate"] = serde_json::Value::String(start.clone());
+            let start = self
+                .date_arg("startDate", start, Bound::Start, None)
+                .await?;
+            input["startDate"] = serde_json::Value::String(start);
         }
         if let Some(ref target) = params.target_date {
-            input["targetDate"] = serde_json::Value::String(target.clone());
+            let target = self
+                .date_arg("targetDate", target, Bound::End, None)
+                .await?;
+            input["targetDate"] = serde_json::Value::String(target);
         }
 
         let vars = serde_json::json!({ "input": input });
-        let data: response::CreateReleaseData = self
-            .client
-            .execute_json(queries::CREATE_RELEASE, vars)
-            .await?;
+        let data: response::CreateReleaseData =
+            self.client.execute(queries::CREATE_RELEASE, vars).await?;
 
         match data.release_create.release {
-            Some(release) => Ok(format!("Release created:\n\n{}", format::format_release_detail(&release))),
+            Some(release) => Ok(Output::new(
+                format!(
+                    "Release created:\n\n{}",
+                    format::format_release_detail(&release)
+                ),
+                release,
+            )),
             None => Err(Error::GraphQL("Release creation failed".into())),
         }
     }
@@ -7802,7 +10298,7 @@ impl LinearMcp {
     async fn handle_update_release(
         &self,
         params: update_release::UpdateReleaseParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::Release>, Error> {
         let mut input = serde_json::Map::new();
         let mut has_fields = false;
 
@@ -7811,7 +10307,10 @@ impl LinearMcp {
             has_fields = true;
         }
         if let Some(ref desc) = params.description {
-            input.insert("description".into(), serde_json::Value::String(desc.clone()));
+            input.insert(
+                "description".into(),
+                serde_json::Value::String(desc.clone()),
+            );
             has_fields = true;
         }
         if let Some(ref version) = params.version {
@@ -7831,14 +10330,19 @@ impl LinearMcp {
             return Err(Error::InvalidInput("No fields to update.".into()));
         }
 
-        let vars = serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
-        let data: response::UpdateReleaseData = self
-            .client
-            .execute_json(queries::UPDATE_RELEASE, vars)
-            .await?;
+        let vars =
+            serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
+        let data: response::UpdateReleaseData =
+            self.client.execute(queries::UPDATE_RELEASE, vars).await?;
 
         match data.release_update.release {
-            Some(release) => Ok(format!("Release updated:\n\n{}", format::format_release_detail(&release))),
+            Some(release) => Ok(Output::new(
+                format!(
+                    "Release updated:\n\n{}",
+                    format::format_release_detail(&release)
+                ),
+                release,
+            )),
             None => Err(Error::GraphQL("Release update failed".into())),
         }
     }
@@ -7848,19 +10352,28 @@ impl LinearMcp {
     async fn handle_get_workflow_state(
         &self,
         params: get_workflow_state::GetWorkflowStateParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::WorkflowState>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::WorkflowStateData = self
             .client
-            .execute_json(queries::GET_WORKFLOW_STATE, vars)
+            .execute(queries::GET_WORKFLOW_STATE, vars)
             .await?;
-        Ok(format!("**{}** [{}] color:{} [id: {}]", data.workflow_state.name, data.workflow_state.state_type, data.workflow_state.color, data.workflow_state.id))
+        Ok(Output::new(
+            format!(
+                "**{}** [{}] color:{} [id: {}]",
+                data.workflow_state.name,
+                data.workflow_state.state_type,
+                data.workflow_state.color,
+                data.workflow_state.id
+            ),
+            data.workflow_state,
+        ))
     }
 
     async fn handle_create_workflow_state(
         &self,
         params: create_workflow_state::CreateWorkflowStateParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::WorkflowState>, Error> {
         let team_id = self.resolve_team_id(&params.team).await?;
         let mut input = serde_json::json!({
             "teamId": team_id,
@@ -7877,10 +10390,16 @@ impl LinearMcp {
         let vars = serde_json::json!({ "input": input });
         let data: response::CreateWorkflowStateData = self
             .client
-            .execute_json(queries::CREATE_WORKFLOW_STATE, vars)
+            .execute(queries::CREATE_WORKFLOW_STATE, vars)
             .await?;
         match data.workflow_state_create.workflow_state {
-            Some(state) => Ok(format!("Workflow state created:\n\n{}", format!("**{}** [{}] color:{} [id: {}]", state.name, state.state_type, state.color, state.id))),
+            Some(state) => Ok(Output::new(
+                format!(
+                    "Workflow state created:\n\n**{}** [{}] color:{} [id: {}]",
+                    state.name, state.state_type, state.color, state.id
+                ),
+                state,
+            )),
             None => Err(Error::GraphQL("Workflow state creation failed".into())),
         }
     }
@@ -7888,7 +10407,7 @@ impl LinearMcp {
     async fn handle_update_workflow_state(
         &self,
         params: update_workflow_state::UpdateWorkflowStateParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::WorkflowState>, Error> {
         let mut input = serde_json::Map::new();
         let mut has_fields = false;
         if let Some(ref name) = params.name {
@@ -7900,7 +10419,10 @@ impl LinearMcp {
             has_fields = true;
         }
         if let Some(ref desc) = params.description {
-            input.insert("description".into(), serde_json::Value::String(desc.clone()));
+            input.insert(
+                "description".into(),
+                serde_json::Value::String(desc.clone()),
+            );
             has_fields = true;
         }
         if let Some(pos) = params.position {
@@ -7910,13 +10432,20 @@ impl LinearMcp {
         if !has_fields {
             return Err(Error::InvalidInput("No fields to update.".into()));
         }
-        let vars = serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
+        let vars =
+            serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
         let data: response::UpdateWorkflowStateData = self
             .client
-            .execute_json(queries::UPDATE_WORKFLOW_STATE, vars)
+            .execute(queries::UPDATE_WORKFLOW_STATE, vars)
             .await?;
         match data.workflow_state_update.workflow_state {
-            Some(state) => Ok(format!("Workflow state updated:\n\n{}", format!("**{}** [{}] color:{} [id: {}]", state.name, state.state_type, state.color, state.id))),
+            Some(state) => Ok(Output::new(
+                format!(
+                    "Workflow state updated:\n\n**{}** [{}] color:{} [id: {}]",
+                    state.name, state.state_type, state.color, state.id
+                ),
+                state,
+            )),
             None => Err(Error::GraphQL("Workflow state update failed".into())),
         }
     }
@@ -7924,14 +10453,17 @@ impl LinearMcp {
     async fn handle_archive_workflow_state(
         &self,
         params: archive_workflow_state::ArchiveWorkflowStateParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::ArchiveWorkflowStateData = self
             .client
-            .execute_json(queries::ARCHIVE_WORKFLOW_STATE, vars)
+            .execute(queries::ARCHIVE_WORKFLOW_STATE, vars)
             .await?;
         if data.workflow_state_archive.success {
-            Ok(format!("Workflow state {} archived.", params.id))
+            Ok(Output::new(
+                format!("Workflow state {} archived.", params.id),
+                data.workflow_state_archive,
+            ))
         } else {
             Err(Error::GraphQL("Workflow state archive failed".into()))
         }
@@ -7939,40 +10471,26 @@ impl LinearMcp {
 
     // ---- Phase 1B: Issue Extras handlers ----
 
-    /// Resolve the team key for an issue, whether given as identifier ("ENG-123") or UUID.
-    async fn resolve_issue_team_key(&self, issue_input: &str, issue_id: &str) -> Result<Option<String>, Error> {
-        let is_uuid = issue_input.len() == 36
-            && issue_input.chars().all(|c| c.is_ascii_hexdigit() || c == '-');
-        if !is_uuid {
-            // Extract team key from identifier like "ENG-123"
-            let key = issue_input.split('-').next()
-                .filter(|k| !k.is_empty() && k.chars().all(|c| c.is_ascii_alphabetic()));
-            return Ok(key.map(|k| k.to_string()));
-        }
-        // UUID input — fetch the issue's team
-        let vars = serde_json::json!({ "id": issue_id });
-        let data: response::IssueTeamData = self
-            .client
-            .execute_json(queries::GET_ISSUE_TEAM, vars)
-            .await?;
-        Ok(Some(data.issue.team.key))
-    }
-
     async fn handle_add_issue_label(
         &self,
         params: add_issue_label::AddIssueLabelParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::MutationResult>, Error> {
         let issue_id = self.resolve_issue_id(&params.issue).await?;
-        let team_key = self.resolve_issue_team_key(&params.issue, &issue_id).await?;
-        let label_ids = self.resolve_label_ids(&params.label, team_key.as_deref()).await?;
-        let label_id = label_ids.first().ok_or_else(|| Error::NotFound(format!("Label '{}' not found", params.label)))?;
-        let vars = serde_json::json!({ "id": issue_id, "labelId": label_id });
-        let data: response::IssueAddLabelData = self
-            .client
-            .execute_json(queries::ISSUE_ADD_LABEL, vars)
+        let team_key = self.resolve_team_key_from_issue(&issue_id).await?;
+        let label_ids = self
+            .resolve_label_ids(&params.label, Some(&team_key))
             .await?;
+        let label_id = label_ids
+            .first()
+            .ok_or_else(|| Error::NotFound(format!("Label '{}' not found", params.label)))?;
+        let vars = serde_json::json!({ "id": issue_id, "labelId": label_id });
+        let data: response::IssueAddLabelData =
+            self.client.execute(queries::ISSUE_ADD_LABEL, vars).await?;
         if data.issue_add_label.success {
-            Ok(format!("Label '{}' added to issue {}.", params.label, params.issue))
+            Ok(Output::new(
+                format!("Label '{}' added to issue {}.", params.label, params.issue),
+                data.issue_add_label,
+            ))
         } else {
             Err(Error::GraphQL("Add label failed".into()))
         }
@@ -7981,18 +10499,28 @@ impl LinearMcp {
     async fn handle_remove_issue_label(
         &self,
         params: remove_issue_label::RemoveIssueLabelParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::MutationResult>, Error> {
         let issue_id = self.resolve_issue_id(&params.issue).await?;
-        let team_key = self.resolve_issue_team_key(&params.issue, &issue_id).await?;
-        let label_ids = self.resolve_label_ids(&params.label, team_key.as_deref()).await?;
-        let label_id = label_ids.first().ok_or_else(|| Error::NotFound(format!("Label '{}' not found", params.label)))?;
+        let team_key = self.resolve_team_key_from_issue(&issue_id).await?;
+        let label_ids = self
+            .resolve_label_ids(&params.label, Some(&team_key))
+            .await?;
+        let label_id = label_ids
+            .first()
+            .ok_or_else(|| Error::NotFound(format!("Label '{}' not found", params.label)))?;
         let vars = serde_json::json!({ "id": issue_id, "labelId": label_id });
         let data: response::IssueRemoveLabelData = self
             .client
-            .execute_json(queries::ISSUE_REMOVE_LABEL, vars)
+            .execute(queries::ISSUE_REMOVE_LABEL, vars)
             .await?;
         if data.issue_remove_label.success {
-            Ok(format!("Label '{}' removed from issue {}.", params.label, params.issue))
+            Ok(Output::new(
+                format!(
+                    "Label '{}' removed from issue {}.",
+                    params.label, params.issue
+                ),
+                data.issue_remove_label,
+            ))
         } else {
             Err(Error::GraphQL("Remove label failed".into()))
         }
@@ -8001,13 +10529,15 @@ impl LinearMcp {
     async fn handle_batch_create_issues(
         &self,
         params: batch_create_issues::BatchCreateIssuesParams,
-    ) -> Result<String, Error> {
-        let team_id = self.resolve_team_id(&params.team).await?;
+    ) -> Result<Output<types::BatchCreateResult>, Error> {
+        let team = self.team_or_default(params.team.as_deref())?;
+        let team_id = self.resolve_team_id(&team).await?;
         let issues: Vec<serde_json::Value> = serde_json::from_str(&params.issues)
             .map_err(|e| Error::InvalidInput(format!("Invalid JSON: {}", e)))?;
         let mut inputs = Vec::with_capacity(issues.len());
         for issue in &issues {
-            let mut input = issue.as_object()
+            let mut input = issue
+                .as_object()
                 .ok_or_else(|| Error::InvalidInput("Each issue must be a JSON object".into()))?
                 .clone();
             input.insert("teamId".into(), serde_json::Value::String(team_id.clone()));
@@ -8016,14 +10546,20 @@ impl LinearMcp {
         let vars = serde_json::json!({ "input": { "issues": inputs } });
         let data: response::BatchCreateIssuesData = self
             .client
-            .execute_json(queries::BATCH_CREATE_ISSUES, vars)
+            .execute(queries::BATCH_CREATE_ISSUES, vars)
             .await?;
         if data.issue_batch_create.success {
             let count = data.issue_batch_create.issues.len();
-            let summaries: Vec<String> = data.issue_batch_create.issues.iter()
+            let summaries: Vec<String> = data
+                .issue_batch_create
+                .issues
+                .iter()
                 .map(|i| format!("- {} {}", i.identifier, i.title))
                 .collect();
-            Ok(format!("{} issues created:\n{}", count, summaries.join("\n")))
+            Ok(Output::new(
+                format!("{} issues created:\n{}", count, summaries.join("\n")),
+                data.issue_batch_create,
+            ))
         } else {
             Err(Error::GraphQL("Batch create issues failed".into()))
         }
@@ -8032,14 +10568,21 @@ impl LinearMcp {
     async fn handle_update_issue_relation(
         &self,
         params: update_issue_relation::UpdateIssueRelationParams,
-    ) -> Result<String, Error> {
-        let vars = serde_json::json!({ "id": params.id, "input": { "type": params.relation_type } });
+    ) -> Result<Output<types::IssueRelationMutationResult>, Error> {
+        let vars =
+            serde_json::json!({ "id": params.id, "input": { "type": params.relation_type } });
         let data: response::UpdateIssueRelationData = self
             .client
-            .execute_json(queries::UPDATE_ISSUE_RELATION, vars)
+            .execute(queries::UPDATE_ISSUE_RELATION, vars)
             .await?;
         if data.issue_relation_update.success {
-            Ok(format!("Issue relation {} updated to type '{}'.", params.id, params.relation_type))
+            Ok(Output::new(
+                format!(
+                    "Issue relation {} updated to type '{}'.",
+                    params.id, params.relation_type
+                ),
+                data.issue_relation_update,
+            ))
         } else {
             Err(Error::GraphQL("Issue relation update failed".into()))
         }
@@ -8048,15 +10591,23 @@ impl LinearMcp {
     async fn handle_get_issue_priority_values(
         &self,
         _params: get_issue_priority_values::GetIssuePriorityValuesParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::NodeList<types::IssuePriorityValue>>, Error> {
         let data: response::IssuePriorityValuesData = self
             .client
-            .execute::<(), _>(queries::GET_ISSUE_PRIORITY_VALUES, None)
+            .execute(queries::GET_ISSUE_PRIORITY_VALUES, serde_json::json!({}))
             .await?;
-        let lines: Vec<String> = data.issue_priority_values.iter()
-            .map(|p| format::format_priority_value(p))
+        let lines: Vec<String> = data
+            .issue_priority_values
+            .iter()
+            .map(format::format_priority_value)
             .collect();
-        Ok(format!("Priority values:\n{}", lines.join("\n")))
+        let values = types::NodeList {
+            nodes: data.issue_priority_values,
+        };
+        Ok(Output::new(
+            format!("Priority values:\n{}", lines.join("\n")),
+            values,
+        ))
     }
 
     // ---- Phase 1C: Project Extras handlers ----
@@ -8064,15 +10615,16 @@ impl LinearMcp {
     async fn handle_delete_project(
         &self,
         params: delete_project::DeleteProjectParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let project_id = self.resolve_project_id_or_uuid(&params.id).await?;
         let vars = serde_json::json!({ "id": project_id });
-        let data: response::DeleteProjectData = self
-            .client
-            .execute_json(queries::DELETE_PROJECT, vars)
-            .await?;
+        let data: response::DeleteProjectData =
+            self.client.execute(queries::DELETE_PROJECT, vars).await?;
         if data.project_delete.success {
-            Ok(format!("Project '{}' permanently deleted.", params.id))
+            Ok(Output::new(
+                format!("Project '{}' permanently deleted.", params.id),
+                data.project_delete,
+            ))
         } else {
             Err(Error::GraphQL("Project deletion failed".into()))
         }
@@ -8081,15 +10633,18 @@ impl LinearMcp {
     async fn handle_unarchive_project(
         &self,
         params: unarchive_project::UnarchiveProjectParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let project_id = self.resolve_project_id_or_uuid(&params.id).await?;
         let vars = serde_json::json!({ "id": project_id });
         let data: response::UnarchiveProjectData = self
             .client
-            .execute_json(queries::UNARCHIVE_PROJECT, vars)
+            .execute(queries::UNARCHIVE_PROJECT, vars)
             .await?;
         if data.project_unarchive.success {
-            Ok(format!("Project '{}' unarchived.", params.id))
+            Ok(Output::new(
+                format!("Project '{}' unarchived.", params.id),
+                data.project_unarchive,
+            ))
         } else {
             Err(Error::GraphQL("Project unarchive failed".into()))
         }
@@ -8098,7 +10653,7 @@ impl LinearMcp {
     async fn handle_update_project_relation(
         &self,
         params: update_project_relation::UpdateProjectRelationParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::ProjectRelationMutationResult>, Error> {
         let mut input = serde_json::Map::new();
         let mut has_fields = false;
         if let Some(ref at) = params.anchor_type {
@@ -8106,19 +10661,26 @@ impl LinearMcp {
             has_fields = true;
         }
         if let Some(ref rat) = params.related_anchor_type {
-            input.insert("relatedAnchorType".into(), serde_json::Value::String(rat.clone()));
+            input.insert(
+                "relatedAnchorType".into(),
+                serde_json::Value::String(rat.clone()),
+            );
             has_fields = true;
         }
         if !has_fields {
             return Err(Error::InvalidInput("No fields to update.".into()));
         }
-        let vars = serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
+        let vars =
+            serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
         let data: response::UpdateProjectRelationData = self
             .client
-            .execute_json(queries::UPDATE_PROJECT_RELATION, vars)
+            .execute(queries::UPDATE_PROJECT_RELATION, vars)
             .await?;
         if data.project_relation_update.success {
-            Ok(format!("Project relation {} updated.", params.id))
+            Ok(Output::new(
+                format!("Project relation {} updated.", params.id),
+                data.project_relation_update,
+            ))
         } else {
             Err(Error::GraphQL("Project relation update failed".into()))
         }
@@ -8127,13 +10689,17 @@ impl LinearMcp {
     async fn handle_get_project_milestone(
         &self,
         params: get_project_milestone::GetProjectMilestoneParams,
-    ) -> Result<String, Error> {
-        let vars = serde_json::json!({ "id": params.id });
+    ) -> Result<Output<types::ProjectMilestone>, Error> {
+        let id = self.resolve_milestone_id(&params.id, Match::Fuzzy).await?;
+        let vars = serde_json::json!({ "id": id });
         let data: response::GetProjectMilestoneData = self
             .client
-            .execute_json(queries::GET_PROJECT_MILESTONE, vars)
+            .execute(queries::GET_PROJECT_MILESTONE, vars)
             .await?;
-        Ok(format::format_project_milestone(&data.project_milestone))
+        Ok(Output::new(
+            format::format_project_milestone(&data.project_milestone),
+            data.project_milestone,
+        ))
     }
 
     // ---- Phase 1D: Team Extras handlers ----
@@ -8141,15 +10707,18 @@ impl LinearMcp {
     async fn handle_delete_team(
         &self,
         params: delete_team::DeleteTeamParams,
-    ) -> Result<String, Error> {
-        let team_id = self.resolve_team_id(&params.id).await?;
-        let vars = serde_json::json!({ "id": team_id });
-        let data: response::DeleteTeamData = self
-            .client
-            .execute_json(queries::DELETE_TEAM, vars)
+    ) -> Result<Output<types::SuccessResult>, Error> {
+        let team_id = self
+            .resolve_team_id_matching(&params.id, Match::Exact)
             .await?;
+        let vars = serde_json::json!({ "id": team_id });
+        let data: response::DeleteTeamData =
+            self.client.execute(queries::DELETE_TEAM, vars).await?;
         if data.team_delete.success {
-            Ok(format!("Team '{}' permanently deleted.", params.id))
+            Ok(Output::new(
+                format!("Team '{}' permanently deleted.", params.id),
+                data.team_delete,
+            ))
         } else {
             Err(Error::GraphQL("Team deletion failed".into()))
         }
@@ -8158,15 +10727,16 @@ impl LinearMcp {
     async fn handle_unarchive_team(
         &self,
         params: unarchive_team::UnarchiveTeamParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let team_id = self.resolve_team_id(&params.id).await?;
         let vars = serde_json::json!({ "id": team_id });
-        let data: response::UnarchiveTeamData = self
-            .client
-            .execute_json(queries::UNARCHIVE_TEAM, vars)
-            .await?;
+        let data: response::UnarchiveTeamData =
+            self.client.execute(queries::UNARCHIVE_TEAM, vars).await?;
         if data.team_unarchive.success {
-            Ok(format!("Team '{}' unarchived.", params.id))
+            Ok(Output::new(
+                format!("Team '{}' unarchived.", params.id),
+                data.team_unarchive,
+            ))
         } else {
             Err(Error::GraphQL("Team unarchive failed".into()))
         }
@@ -8175,34 +10745,31 @@ impl LinearMcp {
     async fn handle_get_team(
         &self,
         params: get_team::GetTeamParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::Team>, Error> {
         let team_id = self.resolve_team_id(&params.id).await?;
         let vars = serde_json::json!({ "id": team_id });
-        let data: response::GetTeamData = self
-            .client
-            .execute_json(queries::GET_TEAM, vars)
-            .await?;
+        let data: response::GetTeamData = self.client.execute(queries::GET_TEAM, vars).await?;
         let t = &data.team;
         let mut parts = vec![format!("{} | {}", t.key, t.name)];
         let mut meta = Vec::new();
-        if let Some(ref desc) = t.description {
-            if !desc.is_empty() {
-                meta.push(desc.clone());
-            }
+        if let Some(ref desc) = t.description
+            && !desc.is_empty()
+        {
+            meta.push(desc.clone());
         }
         if let Some(ref tz) = t.timezone {
             meta.push(format!("timezone: {}", tz));
         }
-        if let Some(triage) = t.triage_enabled {
-            if triage {
-                meta.push("triage enabled".into());
-            }
+        if let Some(triage) = t.triage_enabled
+            && triage
+        {
+            meta.push("triage enabled".into());
         }
         meta.push(format!("id: {}", t.id));
         if !meta.is_empty() {
             parts.push(meta.join(" | "));
         }
-        Ok(parts.join("\n"))
+        Ok(Output::new(parts.join("\n"), data.team))
     }
 
     // ---- Phase 1E: Document Extras handlers ----
@@ -8210,14 +10777,18 @@ impl LinearMcp {
     async fn handle_unarchive_document(
         &self,
         params: unarchive_document::UnarchiveDocumentParams,
-    ) -> Result<String, Error> {
-        let vars = serde_json::json!({ "id": params.id });
+    ) -> Result<Output<types::SuccessResult>, Error> {
+        let id = self.resolve_document_id(&params.id, Match::Fuzzy).await?;
+        let vars = serde_json::json!({ "id": id });
         let data: response::UnarchiveDocumentData = self
             .client
-            .execute_json(queries::UNARCHIVE_DOCUMENT, vars)
+            .execute(queries::UNARCHIVE_DOCUMENT, vars)
             .await?;
         if data.document_unarchive.success {
-            Ok(format!("Document {} unarchived.", params.id))
+            Ok(Output::new(
+                format!("Document {} unarchived.", params.id),
+                data.document_unarchive,
+            ))
         } else {
             Err(Error::GraphQL("Document unarchive failed".into()))
         }
@@ -8226,45 +10797,53 @@ impl LinearMcp {
     async fn handle_get_document_content_history(
         &self,
         params: get_document_content_history::GetDocumentContentHistoryParams,
-    ) -> Result<String, Error> {
-        let vars = serde_json::json!({ "id": params.id });
+    ) -> Result<Output<response::DocumentContentHistoryPayload>, Error> {
+        let id = self.resolve_document_id(&params.id, Match::Fuzzy).await?;
+        let vars = serde_json::json!({ "id": id });
         let data: response::DocumentContentHistoryData = self
             .client
-            .execute_json(queries::GET_DOCUMENT_CONTENT_HISTORY, vars)
+            .execute(queries::GET_DOCUMENT_CONTENT_HISTORY, vars)
             .await?;
         if !data.document_content_history.success {
-            return Err(Error::GraphQL("Failed to retrieve document content history.".into()));
+            return Err(Error::GraphQL(
+                "Failed to retrieve document content history.".into(),
+            ));
         }
         let history = &data.document_content_history.history;
         if history.is_empty() {
-            return Ok("No content history found.".into());
+            return Ok(Output::new(
+                "No content history found.",
+                data.document_content_history,
+            ));
         }
         let limit = params.limit.unwrap_or(50).max(1) as usize;
-        let lines: Vec<String> = history.iter()
+        let lines: Vec<String> = history
+            .iter()
             .take(limit)
-            .map(|e| format::format_document_content_history_entry(e))
+            .map(format::format_document_content_history_entry)
             .collect();
-        Ok(lines.join("\n"))
+        Ok(Output::new(lines.join("\n"), data.document_content_history))
     }
 
     // ---- Phase 1F: Misc High-Value handlers ----
 
-    async fn handle_get_viewer_tool(&self) -> Result<String, Error> {
+    async fn handle_get_viewer_tool(&self) -> Result<Output<types::User>, Error> {
         let viewer = self.get_viewer().await?;
-        Ok(format::format_user(&types::User {
+        let user = types::User {
             id: viewer.id,
             display_name: viewer.display_name,
             email: viewer.email,
             admin: None,
             guest: None,
             active: None,
-        }))
+        };
+        Ok(Output::new(format::format_user(&user), user))
     }
 
     async fn handle_get_user(
         &self,
         params: get_user::GetUserParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::User>, Error> {
         // If it looks like an email, resolve first
         let user_id = if params.id.contains('@') {
             self.resolve_user_id(&params.id).await?
@@ -8272,17 +10851,14 @@ impl LinearMcp {
             params.id.clone()
         };
         let vars = serde_json::json!({ "id": user_id });
-        let data: response::GetUserData = self
-            .client
-            .execute_json(queries::GET_USER, vars)
-            .await?;
-        Ok(format::format_user(&data.user))
+        let data: response::GetUserData = self.client.execute(queries::GET_USER, vars).await?;
+        Ok(Output::new(format::format_user(&data.user), data.user))
     }
 
     async fn handle_update_user(
         &self,
         params: update_user::UpdateUserParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::User>, Error> {
         let user_id = if params.id.contains('@') {
             self.resolve_user_id(&params.id).await?
         } else {
@@ -8291,31 +10867,44 @@ impl LinearMcp {
         let mut input = serde_json::Map::new();
         let mut has_fields = false;
         if let Some(ref name) = params.display_name {
-            input.insert("displayName".into(), serde_json::Value::String(name.clone()));
+            input.insert(
+                "displayName".into(),
+                serde_json::Value::String(name.clone()),
+            );
             has_fields = true;
         }
         if let Some(ref desc) = params.description {
-            input.insert("description".into(), serde_json::Value::String(desc.clone()));
+            input.insert(
+                "description".into(),
+                serde_json::Value::String(desc.clone()),
+            );
             has_fields = true;
         }
         if let Some(ref emoji) = params.status_emoji {
-            input.insert("statusEmoji".into(), serde_json::Value::String(emoji.clone()));
+            input.insert(
+                "statusEmoji".into(),
+                serde_json::Value::String(emoji.clone()),
+            );
             has_fields = true;
         }
         if let Some(ref label) = params.status_label {
-            input.insert("statusLabel".into(), serde_json::Value::String(label.clone()));
+            input.insert(
+                "statusLabel".into(),
+                serde_json::Value::String(label.clone()),
+            );
             has_fields = true;
         }
         if !has_fields {
             return Err(Error::InvalidInput("No fields to update.".into()));
         }
         let vars = serde_json::json!({ "id": user_id, "input": serde_json::Value::Object(input) });
-        let data: response::UpdateUserData = self
-            .client
-            .execute_json(queries::UPDATE_USER, vars)
-            .await?;
+        let data: response::UpdateUserData =
+            self.client.execute(queries::UPDATE_USER, vars).await?;
         match data.user_update.user {
-            Some(user) => Ok(format!("User updated:\n\n{}", format::format_user(&user))),
+            Some(user) => Ok(Output::new(
+                format!("User updated:\n\n{}", format::format_user(&user)),
+                user,
+            )),
             None => Err(Error::GraphQL("User update failed".into())),
         }
     }
@@ -8323,43 +10912,46 @@ impl LinearMcp {
     async fn handle_get_attachment(
         &self,
         params: get_attachment::GetAttachmentParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::Attachment>, Error> {
         let vars = serde_json::json!({ "id": params.id });
-        let data: response::GetAttachmentData = self
-            .client
-            .execute_json(queries::GET_ATTACHMENT, vars)
-            .await?;
-        Ok(format::format_attachment(&data.attachment))
+        let data: response::GetAttachmentData =
+            self.client.execute(queries::GET_ATTACHMENT, vars).await?;
+        Ok(Output::new(
+            format::format_attachment(&data.attachment),
+            data.attachment,
+        ))
     }
 
     async fn handle_get_comment(
         &self,
         params: get_comment::GetCommentParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::Comment>, Error> {
         let vars = serde_json::json!({ "id": params.id });
-        let data: response::GetCommentData = self
-            .client
-            .execute_json(queries::GET_COMMENT, vars)
-            .await?;
-        Ok(format::format_comment_detail(&data.comment))
+        let data: response::GetCommentData =
+            self.client.execute(queries::GET_COMMENT, vars).await?;
+        Ok(Output::new(
+            format::format_comment_detail(&data.comment),
+            data.comment,
+        ))
     }
 
     async fn handle_get_favorite(
         &self,
         params: get_favorite::GetFavoriteParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::Favorite>, Error> {
         let vars = serde_json::json!({ "id": params.id });
-        let data: response::GetFavoriteData = self
-            .client
-            .execute_json(queries::GET_FAVORITE, vars)
-            .await?;
-        Ok(format::format_favorite(&data.favorite))
+        let data: response::GetFavoriteData =
+            self.client.execute(queries::GET_FAVORITE, vars).await?;
+        Ok(Output::new(
+            format::format_favorite(&data.favorite),
+            data.favorite,
+        ))
     }
 
     async fn handle_update_favorite(
         &self,
         params: update_favorite::UpdateFavoriteParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::Favorite>, Error> {
         let mut input = serde_json::Map::new();
         let mut has_fields = false;
         if let Some(order) = params.sort_order {
@@ -8377,13 +10969,15 @@ impl LinearMcp {
         if !has_fields {
             return Err(Error::InvalidInput("No fields to update.".into()));
         }
-        let vars = serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
-        let data: response::UpdateFavoriteData = self
-            .client
-            .execute_json(queries::UPDATE_FAVORITE, vars)
-            .await?;
+        let vars =
+            serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
+        let data: response::UpdateFavoriteData =
+            self.client.execute(queries::UPDATE_FAVORITE, vars).await?;
         match data.favorite_update.favorite {
-            Some(fav) => Ok(format!("Favorite updated:\n\n{}", format::format_favorite(&fav))),
+            Some(fav) => Ok(Output::new(
+                format!("Favorite updated:\n\n{}", format::format_favorite(&fav)),
+                fav,
+            )),
             None => Err(Error::GraphQL("Favorite update failed".into())),
         }
     }
@@ -8391,13 +10985,14 @@ impl LinearMcp {
     async fn handle_get_notification(
         &self,
         params: get_notification::GetNotificationParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::Notification>, Error> {
         let vars = serde_json::json!({ "id": params.id });
-        let data: response::GetNotificationData = self
-            .client
-            .execute_json(queries::GET_NOTIFICATION, vars)
-            .await?;
-        Ok(format::format_notification(&data.notification))
+        let data: response::GetNotificationData =
+            self.client.execute(queries::GET_NOTIFICATION, vars).await?;
+        Ok(Output::new(
+            format::format_notification(&data.notification),
+            data.notification,
+        ))
     }
 
     // ---- Phase 2A: Customer Status CRUD handlers ----
@@ -8405,38 +11000,47 @@ impl LinearMcp {
     async fn handle_list_customer_statuses(
         &self,
         params: list_customer_statuses::ListCustomerStatusesParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::NodeList<types::CustomerStatusFull>>, Error> {
         let first = params.limit.unwrap_or(50);
         let vars = serde_json::json!({ "first": first });
         let data: response::CustomerStatusesData = self
             .client
-            .execute_json(queries::LIST_CUSTOMER_STATUSES, vars)
+            .execute(queries::LIST_CUSTOMER_STATUSES, vars)
             .await?;
         if data.customer_statuses.nodes.is_empty() {
-            return Ok("No customer statuses found.".into());
+            return Ok(Output::new(
+                "No customer statuses found.",
+                data.customer_statuses,
+            ));
         }
-        let lines: Vec<String> = data.customer_statuses.nodes.iter()
-            .map(|s| format::format_customer_status(s))
+        let lines: Vec<String> = data
+            .customer_statuses
+            .nodes
+            .iter()
+            .map(format::format_customer_status)
             .collect();
-        Ok(lines.join("\n\n"))
+        Ok(Output::new(lines.join("\n\n"), data.customer_statuses))
     }
 
     async fn handle_get_customer_status(
         &self,
         params: get_customer_status::GetCustomerStatusParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::CustomerStatusFull>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::CustomerStatusData = self
             .client
-            .execute_json(queries::GET_CUSTOMER_STATUS, vars)
+            .execute(queries::GET_CUSTOMER_STATUS, vars)
             .await?;
-        Ok(format::format_customer_status(&data.customer_status))
+        Ok(Output::new(
+            format::format_customer_status(&data.customer_status),
+            data.customer_status,
+        ))
     }
 
     async fn handle_create_customer_status(
         &self,
         params: create_customer_status::CreateCustomerStatusParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::CustomerStatusFull>, Error> {
         let mut input = serde_json::json!({
             "name": params.name,
             "color": params.color,
@@ -8453,10 +11057,16 @@ impl LinearMcp {
         let vars = serde_json::json!({ "input": input });
         let data: response::CreateCustomerStatusData = self
             .client
-            .execute_json(queries::CREATE_CUSTOMER_STATUS, vars)
+            .execute(queries::CREATE_CUSTOMER_STATUS, vars)
             .await?;
         match data.customer_status_create.status {
-            Some(s) => Ok(format!("Customer status created:\n\n{}", format::format_customer_status(&s))),
+            Some(s) => Ok(Output::new(
+                format!(
+                    "Customer status created:\n\n{}",
+                    format::format_customer_status(&s)
+                ),
+                s,
+            )),
             None => Err(Error::GraphQL("Customer status creation failed".into())),
         }
     }
@@ -8464,7 +11074,7 @@ impl LinearMcp {
     async fn handle_update_customer_status(
         &self,
         params: update_customer_status::UpdateCustomerStatusParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::CustomerStatusFull>, Error> {
         let mut input = serde_json::Map::new();
         let mut has_fields = false;
         if let Some(ref name) = params.name {
@@ -8476,7 +11086,10 @@ impl LinearMcp {
             has_fields = true;
         }
         if let Some(ref desc) = params.description {
-            input.insert("description".into(), serde_json::Value::String(desc.clone()));
+            input.insert(
+                "description".into(),
+                serde_json::Value::String(desc.clone()),
+            );
             has_fields = true;
         }
         if let Some(pos) = params.position {
@@ -8490,13 +11103,20 @@ impl LinearMcp {
         if !has_fields {
             return Err(Error::InvalidInput("No fields to update.".into()));
         }
-        let vars = serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
+        let vars =
+            serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
         let data: response::UpdateCustomerStatusData = self
             .client
-            .execute_json(queries::UPDATE_CUSTOMER_STATUS, vars)
+            .execute(queries::UPDATE_CUSTOMER_STATUS, vars)
             .await?;
         match data.customer_status_update.status {
-            Some(s) => Ok(format!("Customer status updated:\n\n{}", format::format_customer_status(&s))),
+            Some(s) => Ok(Output::new(
+                format!(
+                    "Customer status updated:\n\n{}",
+                    format::format_customer_status(&s)
+                ),
+                s,
+            )),
             None => Err(Error::GraphQL("Customer status update failed".into())),
         }
     }
@@ -8504,14 +11124,17 @@ impl LinearMcp {
     async fn handle_delete_customer_status(
         &self,
         params: delete_customer_status::DeleteCustomerStatusParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::DeleteCustomerStatusData = self
             .client
-            .execute_json(queries::DELETE_CUSTOMER_STATUS, vars)
+            .execute(queries::DELETE_CUSTOMER_STATUS, vars)
             .await?;
         if data.customer_status_delete.success {
-            Ok(format!("Customer status {} deleted.", params.id))
+            Ok(Output::new(
+                format!("Customer status {} deleted.", params.id),
+                data.customer_status_delete,
+            ))
         } else {
             Err(Error::GraphQL("Customer status deletion failed".into()))
         }
@@ -8522,38 +11145,44 @@ impl LinearMcp {
     async fn handle_list_customer_tiers(
         &self,
         params: list_customer_tiers::ListCustomerTiersParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::NodeList<types::CustomerTierFull>>, Error> {
         let first = params.limit.unwrap_or(50);
         let vars = serde_json::json!({ "first": first });
         let data: response::CustomerTiersData = self
             .client
-            .execute_json(queries::LIST_CUSTOMER_TIERS, vars)
+            .execute(queries::LIST_CUSTOMER_TIERS, vars)
             .await?;
         if data.customer_tiers.nodes.is_empty() {
-            return Ok("No customer tiers found.".into());
+            return Ok(Output::new("No customer tiers found.", data.customer_tiers));
         }
-        let lines: Vec<String> = data.customer_tiers.nodes.iter()
-            .map(|t| format::format_customer_tier(t))
+        let lines: Vec<String> = data
+            .customer_tiers
+            .nodes
+            .iter()
+            .map(format::format_customer_tier)
             .collect();
-        Ok(lines.join("\n\n"))
+        Ok(Output::new(lines.join("\n\n"), data.customer_tiers))
     }
 
     async fn handle_get_customer_tier(
         &self,
         params: get_customer_tier::GetCustomerTierParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::CustomerTierFull>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::CustomerTierData = self
             .client
-            .execute_json(queries::GET_CUSTOMER_TIER, vars)
+            .execute(queries::GET_CUSTOMER_TIER, vars)
             .await?;
-        Ok(format::format_customer_tier(&data.customer_tier))
+        Ok(Output::new(
+            format::format_customer_tier(&data.customer_tier),
+            data.customer_tier,
+        ))
     }
 
     async fn handle_create_customer_tier(
         &self,
         params: create_customer_tier::CreateCustomerTierParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::CustomerTierFull>, Error> {
         let mut input = serde_json::json!({
             "name": params.name,
             "color": params.color,
@@ -8570,10 +11199,16 @@ impl LinearMcp {
         let vars = serde_json::json!({ "input": input });
         let data: response::CreateCustomerTierData = self
             .client
-            .execute_json(queries::CREATE_CUSTOMER_TIER, vars)
+            .execute(queries::CREATE_CUSTOMER_TIER, vars)
             .await?;
         match data.customer_tier_create.tier {
-            Some(t) => Ok(format!("Customer tier created:\n\n{}", format::format_customer_tier(&t))),
+            Some(t) => Ok(Output::new(
+                format!(
+                    "Customer tier created:\n\n{}",
+                    format::format_customer_tier(&t)
+                ),
+                t,
+            )),
             None => Err(Error::GraphQL("Customer tier creation failed".into())),
         }
     }
@@ -8581,7 +11216,7 @@ impl LinearMcp {
     async fn handle_update_customer_tier(
         &self,
         params: update_customer_tier::UpdateCustomerTierParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::CustomerTierFull>, Error> {
         let mut input = serde_json::Map::new();
         let mut has_fields = false;
         if let Some(ref name) = params.name {
@@ -8593,7 +11228,10 @@ impl LinearMcp {
             has_fields = true;
         }
         if let Some(ref desc) = params.description {
-            input.insert("description".into(), serde_json::Value::String(desc.clone()));
+            input.insert(
+                "description".into(),
+                serde_json::Value::String(desc.clone()),
+            );
             has_fields = true;
         }
         if let Some(pos) = params.position {
@@ -8607,13 +11245,20 @@ impl LinearMcp {
         if !has_fields {
             return Err(Error::InvalidInput("No fields to update.".into()));
         }
-        let vars = serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
+        let vars =
+            serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
         let data: response::UpdateCustomerTierData = self
             .client
-            .execute_json(queries::UPDATE_CUSTOMER_TIER, vars)
+            .execute(queries::UPDATE_CUSTOMER_TIER, vars)
             .await?;
         match data.customer_tier_update.tier {
-            Some(t) => Ok(format!("Customer tier updated:\n\n{}", format::format_customer_tier(&t))),
+            Some(t) => Ok(Output::new(
+                format!(
+                    "Customer tier updated:\n\n{}",
+                    format::format_customer_tier(&t)
+                ),
+                t,
+            )),
             None => Err(Error::GraphQL("Customer tier update failed".into())),
         }
     }
@@ -8621,14 +11266,17 @@ impl LinearMcp {
     async fn handle_delete_customer_tier(
         &self,
         params: delete_customer_tier::DeleteCustomerTierParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::DeleteCustomerTierData = self
             .client
-            .execute_json(queries::DELETE_CUSTOMER_TIER, vars)
+            .execute(queries::DELETE_CUSTOMER_TIER, vars)
             .await?;
         if data.customer_tier_delete.success {
-            Ok(format!("Customer tier {} deleted.", params.id))
+            Ok(Output::new(
+                format!("Customer tier {} deleted.", params.id),
+                data.customer_tier_delete,
+            ))
         } else {
             Err(Error::GraphQL("Customer tier deletion failed".into()))
         }
@@ -8639,14 +11287,18 @@ impl LinearMcp {
     async fn handle_merge_customers(
         &self,
         params: merge_customers::MergeCustomersParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let vars = serde_json::json!({ "sourceCustomerId": params.source_id, "targetCustomerId": params.target_id });
-        let data: response::MergeCustomersData = self
-            .client
-            .execute_json(queries::MERGE_CUSTOMERS, vars)
-            .await?;
+        let data: response::MergeCustomersData =
+            self.client.execute(queries::MERGE_CUSTOMERS, vars).await?;
         if data.customer_merge.success {
-            Ok(format!("Customer {} merged into {}.", params.source_id, params.target_id))
+            Ok(Output::new(
+                format!(
+                    "Customer {} merged into {}.",
+                    params.source_id, params.target_id
+                ),
+                data.customer_merge,
+            ))
         } else {
             Err(Error::GraphQL("Customer merge failed".into()))
         }
@@ -8655,26 +11307,32 @@ impl LinearMcp {
     async fn handle_get_customer_need(
         &self,
         params: get_customer_need::GetCustomerNeedParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::CustomerNeed>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::GetCustomerNeedData = self
             .client
-            .execute_json(queries::GET_CUSTOMER_NEED, vars)
+            .execute(queries::GET_CUSTOMER_NEED, vars)
             .await?;
-        Ok(format::format_customer_need(&data.customer_need))
+        Ok(Output::new(
+            format::format_customer_need(&data.customer_need),
+            data.customer_need,
+        ))
     }
 
     async fn handle_archive_customer_need(
         &self,
         params: archive_customer_need::ArchiveCustomerNeedParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::ArchiveCustomerNeedData = self
             .client
-            .execute_json(queries::ARCHIVE_CUSTOMER_NEED, vars)
+            .execute(queries::ARCHIVE_CUSTOMER_NEED, vars)
             .await?;
         if data.customer_need_archive.success {
-            Ok(format!("Customer need {} archived.", params.id))
+            Ok(Output::new(
+                format!("Customer need {} archived.", params.id),
+                data.customer_need_archive,
+            ))
         } else {
             Err(Error::GraphQL("Customer need archive failed".into()))
         }
@@ -8683,14 +11341,17 @@ impl LinearMcp {
     async fn handle_unarchive_customer_need(
         &self,
         params: unarchive_customer_need::UnarchiveCustomerNeedParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::UnarchiveCustomerNeedData = self
             .client
-            .execute_json(queries::UNARCHIVE_CUSTOMER_NEED, vars)
+            .execute(queries::UNARCHIVE_CUSTOMER_NEED, vars)
             .await?;
         if data.customer_need_unarchive.success {
-            Ok(format!("Customer need {} unarchived.", params.id))
+            Ok(Output::new(
+                format!("Customer need {} unarchived.", params.id),
+                data.customer_need_unarchive,
+            ))
         } else {
             Err(Error::GraphQL("Customer need unarchive failed".into()))
         }
@@ -8699,14 +11360,17 @@ impl LinearMcp {
     async fn handle_delete_customer_need(
         &self,
         params: delete_customer_need::DeleteCustomerNeedParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::DeleteCustomerNeedData = self
             .client
-            .execute_json(queries::DELETE_CUSTOMER_NEED, vars)
+            .execute(queries::DELETE_CUSTOMER_NEED, vars)
             .await?;
         if data.customer_need_delete.success {
-            Ok(format!("Customer need {} deleted.", params.id))
+            Ok(Output::new(
+                format!("Customer need {} deleted.", params.id),
+                data.customer_need_delete,
+            ))
         } else {
             Err(Error::GraphQL("Customer need deletion failed".into()))
         }
@@ -8717,15 +11381,18 @@ impl LinearMcp {
     async fn handle_archive_initiative(
         &self,
         params: archive_initiative::ArchiveInitiativeParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let initiative_id = self.resolve_initiative_id_or_uuid(&params.id).await?;
         let vars = serde_json::json!({ "id": initiative_id });
         let data: response::ArchiveInitiativeData = self
             .client
-            .execute_json(queries::ARCHIVE_INITIATIVE, vars)
+            .execute(queries::ARCHIVE_INITIATIVE, vars)
             .await?;
         if data.initiative_archive.success {
-            Ok(format!("Initiative '{}' archived.", params.id))
+            Ok(Output::new(
+                format!("Initiative '{}' archived.", params.id),
+                data.initiative_archive,
+            ))
         } else {
             Err(Error::GraphQL("Initiative archive failed".into()))
         }
@@ -8734,15 +11401,18 @@ impl LinearMcp {
     async fn handle_unarchive_initiative(
         &self,
         params: unarchive_initiative::UnarchiveInitiativeParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let initiative_id = self.resolve_initiative_id_or_uuid(&params.id).await?;
         let vars = serde_json::json!({ "id": initiative_id });
         let data: response::UnarchiveInitiativeData = self
             .client
-            .execute_json(queries::UNARCHIVE_INITIATIVE, vars)
+            .execute(queries::UNARCHIVE_INITIATIVE, vars)
             .await?;
         if data.initiative_unarchive.success {
-            Ok(format!("Initiative '{}' unarchived.", params.id))
+            Ok(Output::new(
+                format!("Initiative '{}' unarchived.", params.id),
+                data.initiative_unarchive,
+            ))
         } else {
             Err(Error::GraphQL("Initiative unarchive failed".into()))
         }
@@ -8751,7 +11421,7 @@ impl LinearMcp {
     async fn handle_update_initiative_to_project(
         &self,
         params: update_initiative_to_project::UpdateInitiativeToProjectParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::InitiativeToProjectMutationResult>, Error> {
         let mut input = serde_json::Map::new();
         if let Some(order) = params.sort_order {
             input.insert("sortOrder".into(), serde_json::json!(order));
@@ -8759,13 +11429,17 @@ impl LinearMcp {
         if input.is_empty() {
             return Err(Error::InvalidInput("No fields to update.".into()));
         }
-        let vars = serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
+        let vars =
+            serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
         let data: response::UpdateInitiativeToProjectData = self
             .client
-            .execute_json(queries::UPDATE_INITIATIVE_TO_PROJECT, vars)
+            .execute(queries::UPDATE_INITIATIVE_TO_PROJECT, vars)
             .await?;
         if data.initiative_to_project_update.success {
-            Ok(format!("Initiative-to-project link {} updated.", params.id))
+            Ok(Output::new(
+                format!("Initiative-to-project link {} updated.", params.id),
+                data.initiative_to_project_update,
+            ))
         } else {
             Err(Error::GraphQL("Initiative-to-project update failed".into()))
         }
@@ -8774,14 +11448,17 @@ impl LinearMcp {
     async fn handle_archive_initiative_update(
         &self,
         params: archive_initiative_update::ArchiveInitiativeUpdateParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::ArchiveInitiativeUpdateData = self
             .client
-            .execute_json(queries::ARCHIVE_INITIATIVE_UPDATE, vars)
+            .execute(queries::ARCHIVE_INITIATIVE_UPDATE, vars)
             .await?;
         if data.initiative_update_archive.success {
-            Ok(format!("Initiative update {} archived.", params.id))
+            Ok(Output::new(
+                format!("Initiative update {} archived.", params.id),
+                data.initiative_update_archive,
+            ))
         } else {
             Err(Error::GraphQL("Initiative update archive failed".into()))
         }
@@ -8790,14 +11467,17 @@ impl LinearMcp {
     async fn handle_unarchive_initiative_update(
         &self,
         params: unarchive_initiative_update::UnarchiveInitiativeUpdateParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::UnarchiveInitiativeUpdateData = self
             .client
-            .execute_json(queries::UNARCHIVE_INITIATIVE_UPDATE, vars)
+            .execute(queries::UNARCHIVE_INITIATIVE_UPDATE, vars)
             .await?;
         if data.initiative_update_unarchive.success {
-            Ok(format!("Initiative update {} unarchived.", params.id))
+            Ok(Output::new(
+                format!("Initiative update {} unarchived.", params.id),
+                data.initiative_update_unarchive,
+            ))
         } else {
             Err(Error::GraphQL("Initiative update unarchive failed".into()))
         }
@@ -8808,26 +11488,28 @@ impl LinearMcp {
     async fn handle_get_release(
         &self,
         params: get_release::GetReleaseParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::Release>, Error> {
         let vars = serde_json::json!({ "id": params.id });
-        let data: response::GetReleaseData = self
-            .client
-            .execute_json(queries::GET_RELEASE, vars)
-            .await?;
-        Ok(format::format_release_detail(&data.release))
+        let data: response::GetReleaseData =
+            self.client.execute(queries::GET_RELEASE, vars).await?;
+        Ok(Output::new(
+            format::format_release_detail(&data.release),
+            data.release,
+        ))
     }
 
     async fn handle_archive_release(
         &self,
         params: archive_release::ArchiveReleaseParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let vars = serde_json::json!({ "id": params.id });
-        let data: response::ArchiveReleaseData = self
-            .client
-            .execute_json(queries::ARCHIVE_RELEASE, vars)
-            .await?;
+        let data: response::ArchiveReleaseData =
+            self.client.execute(queries::ARCHIVE_RELEASE, vars).await?;
         if data.release_archive.success {
-            Ok(format!("Release {} archived.", params.id))
+            Ok(Output::new(
+                format!("Release {} archived.", params.id),
+                data.release_archive,
+            ))
         } else {
             Err(Error::GraphQL("Release archive failed".into()))
         }
@@ -8836,14 +11518,15 @@ impl LinearMcp {
     async fn handle_delete_release(
         &self,
         params: delete_release::DeleteReleaseParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let vars = serde_json::json!({ "id": params.id });
-        let data: response::DeleteReleaseData = self
-            .client
-            .execute_json(queries::DELETE_RELEASE, vars)
-            .await?;
+        let data: response::DeleteReleaseData =
+            self.client.execute(queries::DELETE_RELEASE, vars).await?;
         if data.release_delete.success {
-            Ok(format!("Release {} deleted.", params.id))
+            Ok(Output::new(
+                format!("Release {} deleted.", params.id),
+                data.release_delete,
+            ))
         } else {
             Err(Error::GraphQL("Release deletion failed".into()))
         }
@@ -8852,14 +11535,17 @@ impl LinearMcp {
     async fn handle_unarchive_release(
         &self,
         params: unarchive_release::UnarchiveReleaseParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::UnarchiveReleaseData = self
             .client
-            .execute_json(queries::UNARCHIVE_RELEASE, vars)
+            .execute(queries::UNARCHIVE_RELEASE, vars)
             .await?;
         if data.release_unarchive.success {
-            Ok(format!("Release {} unarchived.", params.id))
+            Ok(Output::new(
+                format!("Release {} unarchived.", params.id),
+                data.release_unarchive,
+            ))
         } else {
             Err(Error::GraphQL("Release unarchive failed".into()))
         }
@@ -8868,20 +11554,21 @@ impl LinearMcp {
     async fn handle_search_releases(
         &self,
         params: search_releases::SearchReleasesParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::ReleaseSearchConnection>, Error> {
         let first = params.limit.unwrap_or(25);
         let vars = serde_json::json!({ "term": params.query, "first": first });
-        let data: response::SearchReleasesData = self
-            .client
-            .execute_json(queries::SEARCH_RELEASES, vars)
-            .await?;
+        let data: response::SearchReleasesData =
+            self.client.execute(queries::SEARCH_RELEASES, vars).await?;
         if data.release_search.nodes.is_empty() {
-            return Ok("No releases found.".into());
+            return Ok(Output::new("No releases found.", data.release_search));
         }
-        let lines: Vec<String> = data.release_search.nodes.iter()
-            .map(|r| format::format_release_summary(r))
+        let lines: Vec<String> = data
+            .release_search
+            .nodes
+            .iter()
+            .map(format::format_release_summary)
             .collect();
-        Ok(lines.join("\n"))
+        Ok(Output::new(lines.join("\n"), data.release_search))
     }
 
     // ---- Phase 3B: Release Pipeline CRUD handlers ----
@@ -8889,53 +11576,72 @@ impl LinearMcp {
     async fn handle_list_release_pipelines(
         &self,
         params: list_release_pipelines::ListReleasePipelinesParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::NodeList<types::ReleasePipelineFull>>, Error> {
         let first = params.limit.unwrap_or(50);
         let vars = serde_json::json!({ "first": first });
         let data: response::ReleasePipelinesData = self
             .client
-            .execute_json(queries::LIST_RELEASE_PIPELINES, vars)
+            .execute(queries::LIST_RELEASE_PIPELINES, vars)
             .await?;
         if data.release_pipelines.nodes.is_empty() {
-            return Ok("No release pipelines found.".into());
+            return Ok(Output::new(
+                "No release pipelines found.",
+                data.release_pipelines,
+            ));
         }
-        let lines: Vec<String> = data.release_pipelines.nodes.iter()
-            .map(|p| format::format_release_pipeline(p))
+        let lines: Vec<String> = data
+            .release_pipelines
+            .nodes
+            .iter()
+            .map(format::format_release_pipeline)
             .collect();
-        Ok(lines.join("\n\n"))
+        Ok(Output::new(lines.join("\n\n"), data.release_pipelines))
     }
 
     async fn handle_get_release_pipeline(
         &self,
         params: get_release_pipeline::GetReleasePipelineParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::ReleasePipelineFull>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::ReleasePipelineData = self
             .client
-            .execute_json(queries::GET_RELEASE_PIPELINE, vars)
+            .execute(queries::GET_RELEASE_PIPELINE, vars)
             .await?;
-        Ok(format::format_release_pipeline(&data.release_pipeline))
+        Ok(Output::new(
+            format::format_release_pipeline(&data.release_pipeline),
+            data.release_pipeline,
+        ))
     }
 
     async fn handle_create_release_pipeline(
         &self,
         params: create_release_pipeline::CreateReleasePipelineParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::ReleasePipelineFull>, Error> {
         let mut input = serde_json::json!({ "name": params.name });
         if let Some(ref t) = params.pipeline_type {
             input["type"] = serde_json::Value::String(t.clone());
         }
         if let Some(ref patterns) = params.include_path_patterns {
-            let patterns: Vec<&str> = patterns.split(',').map(|s| s.trim()).filter(|s| !s.is_empty()).collect();
+            let patterns: Vec<&str> = patterns
+                .split(',')
+                .map(|s| s.trim())
+                .filter(|s| !s.is_empty())
+                .collect();
             input["includePathPatterns"] = serde_json::json!(patterns);
         }
         let vars = serde_json::json!({ "input": input });
         let data: response::CreateReleasePipelineData = self
             .client
-            .execute_json(queries::CREATE_RELEASE_PIPELINE, vars)
+            .execute(queries::CREATE_RELEASE_PIPELINE, vars)
             .await?;
         match data.release_pipeline_create.release_pipeline {
-            Some(p) => Ok(format!("Release pipeline created:\n\n{}", format::format_release_pipeline(&p))),
+            Some(p) => Ok(Output::new(
+                format!(
+                    "Release pipeline created:\n\n{}",
+                    format::format_release_pipeline(&p)
+                ),
+                p,
+            )),
             None => Err(Error::GraphQL("Release pipeline creation failed".into())),
         }
     }
@@ -8943,7 +11649,7 @@ impl LinearMcp {
     async fn handle_update_release_pipeline(
         &self,
         params: update_release_pipeline::UpdateReleasePipelineParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::ReleasePipelineFull>, Error> {
         let mut input = serde_json::Map::new();
         let mut has_fields = false;
         if let Some(ref name) = params.name {
@@ -8955,20 +11661,31 @@ impl LinearMcp {
             has_fields = true;
         }
         if let Some(ref patterns) = params.include_path_patterns {
-            let patterns: Vec<&str> = patterns.split(',').map(|s| s.trim()).filter(|s| !s.is_empty()).collect();
+            let patterns: Vec<&str> = patterns
+                .split(',')
+                .map(|s| s.trim())
+                .filter(|s| !s.is_empty())
+                .collect();
             input.insert("includePathPatterns".into(), serde_json::json!(patterns));
             has_fields = true;
         }
         if !has_fields {
             return Err(Error::InvalidInput("No fields to update.".into()));
         }
-        let vars = serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
+        let vars =
+            serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
         let data: response::UpdateReleasePipelineData = self
             .client
-            .execute_json(queries::UPDATE_RELEASE_PIPELINE, vars)
+            .execute(queries::UPDATE_RELEASE_PIPELINE, vars)
             .await?;
         match data.release_pipeline_update.release_pipeline {
-            Some(p) => Ok(format!("Release pipeline updated:\n\n{}", format::format_release_pipeline(&p))),
+            Some(p) => Ok(Output::new(
+                format!(
+                    "Release pipeline updated:\n\n{}",
+                    format::format_release_pipeline(&p)
+                ),
+                p,
+            )),
             None => Err(Error::GraphQL("Release pipeline update failed".into())),
         }
     }
@@ -8976,14 +11693,17 @@ impl LinearMcp {
     async fn handle_delete_release_pipeline(
         &self,
         params: delete_release_pipeline::DeleteReleasePipelineParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::DeleteReleasePipelineData = self
             .client
-            .execute_json(queries::DELETE_RELEASE_PIPELINE, vars)
+            .execute(queries::DELETE_RELEASE_PIPELINE, vars)
             .await?;
         if data.release_pipeline_delete.success {
-            Ok(format!("Release pipeline {} deleted.", params.id))
+            Ok(Output::new(
+                format!("Release pipeline {} deleted.", params.id),
+                data.release_pipeline_delete,
+            ))
         } else {
             Err(Error::GraphQL("Release pipeline deletion failed".into()))
         }
@@ -8994,38 +11714,44 @@ impl LinearMcp {
     async fn handle_list_release_stages(
         &self,
         params: list_release_stages::ListReleaseStagesParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::NodeList<types::ReleaseStageFull>>, Error> {
         let first = params.limit.unwrap_or(50);
         let vars = serde_json::json!({ "first": first });
         let data: response::ReleaseStagesData = self
             .client
-            .execute_json(queries::LIST_RELEASE_STAGES, vars)
+            .execute(queries::LIST_RELEASE_STAGES, vars)
             .await?;
         if data.release_stages.nodes.is_empty() {
-            return Ok("No release stages found.".into());
+            return Ok(Output::new("No release stages found.", data.release_stages));
         }
-        let lines: Vec<String> = data.release_stages.nodes.iter()
-            .map(|s| format::format_release_stage(s))
+        let lines: Vec<String> = data
+            .release_stages
+            .nodes
+            .iter()
+            .map(format::format_release_stage)
             .collect();
-        Ok(lines.join("\n\n"))
+        Ok(Output::new(lines.join("\n\n"), data.release_stages))
     }
 
     async fn handle_get_release_stage(
         &self,
         params: get_release_stage::GetReleaseStageParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::ReleaseStageFull>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::ReleaseStageData = self
             .client
-            .execute_json(queries::GET_RELEASE_STAGE, vars)
+            .execute(queries::GET_RELEASE_STAGE, vars)
             .await?;
-        Ok(format::format_release_stage(&data.release_stage))
+        Ok(Output::new(
+            format::format_release_stage(&data.release_stage),
+            data.release_stage,
+        ))
     }
 
     async fn handle_create_release_stage(
         &self,
         params: create_release_stage::CreateReleaseStageParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::ReleaseStageFull>, Error> {
         let input = serde_json::json!({
             "name": params.name,
             "color": params.color,
@@ -9037,10 +11763,16 @@ impl LinearMcp {
         let vars = serde_json::json!({ "input": input });
         let data: response::CreateReleaseStageData = self
             .client
-            .execute_json(queries::CREATE_RELEASE_STAGE, vars)
+            .execute(queries::CREATE_RELEASE_STAGE, vars)
             .await?;
         match data.release_stage_create.release_stage {
-            Some(s) => Ok(format!("Release stage created:\n\n{}", format::format_release_stage(&s))),
+            Some(s) => Ok(Output::new(
+                format!(
+                    "Release stage created:\n\n{}",
+                    format::format_release_stage(&s)
+                ),
+                s,
+            )),
             None => Err(Error::GraphQL("Release stage creation failed".into())),
         }
     }
@@ -9048,7 +11780,7 @@ impl LinearMcp {
     async fn handle_update_release_stage(
         &self,
         params: update_release_stage::UpdateReleaseStageParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::ReleaseStageFull>, Error> {
         let mut input = serde_json::Map::new();
         let mut has_fields = false;
         if let Some(ref name) = params.name {
@@ -9070,13 +11802,20 @@ impl LinearMcp {
         if !has_fields {
             return Err(Error::InvalidInput("No fields to update.".into()));
         }
-        let vars = serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
+        let vars =
+            serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
         let data: response::UpdateReleaseStageData = self
             .client
-            .execute_json(queries::UPDATE_RELEASE_STAGE, vars)
+            .execute(queries::UPDATE_RELEASE_STAGE, vars)
             .await?;
         match data.release_stage_update.release_stage {
-            Some(s) => Ok(format!("Release stage updated:\n\n{}", format::format_release_stage(&s))),
+            Some(s) => Ok(Output::new(
+                format!(
+                    "Release stage updated:\n\n{}",
+                    format::format_release_stage(&s)
+                ),
+                s,
+            )),
             None => Err(Error::GraphQL("Release stage update failed".into())),
         }
     }
@@ -9086,46 +11825,62 @@ impl LinearMcp {
     async fn handle_list_issue_to_releases(
         &self,
         params: list_issue_to_releases::ListIssueToReleasesParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::NodeList<types::IssueToRelease>>, Error> {
         let first = params.limit.unwrap_or(50);
         let vars = serde_json::json!({ "first": first });
         let data: response::IssueToReleasesData = self
             .client
-            .execute_json(queries::LIST_ISSUE_TO_RELEASES, vars)
+            .execute(queries::LIST_ISSUE_TO_RELEASES, vars)
             .await?;
         if data.issue_to_releases.nodes.is_empty() {
-            return Ok("No issue-to-release links found.".into());
+            return Ok(Output::new(
+                "No issue-to-release links found.",
+                data.issue_to_releases,
+            ));
         }
-        let lines: Vec<String> = data.issue_to_releases.nodes.iter()
-            .map(|l| format::format_issue_to_release(l))
+        let lines: Vec<String> = data
+            .issue_to_releases
+            .nodes
+            .iter()
+            .map(format::format_issue_to_release)
             .collect();
-        Ok(lines.join("\n"))
+        Ok(Output::new(lines.join("\n"), data.issue_to_releases))
     }
 
     async fn handle_get_issue_to_release(
         &self,
         params: get_issue_to_release::GetIssueToReleaseParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::IssueToRelease>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::IssueToReleaseData = self
             .client
-            .execute_json(queries::GET_ISSUE_TO_RELEASE, vars)
+            .execute(queries::GET_ISSUE_TO_RELEASE, vars)
             .await?;
-        Ok(format::format_issue_to_release(&data.issue_to_release))
+        Ok(Output::new(
+            format::format_issue_to_release(&data.issue_to_release),
+            data.issue_to_release,
+        ))
     }
 
     async fn handle_add_issue_to_release(
         &self,
         params: add_issue_to_release::AddIssueToReleaseParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::IssueToReleaseMutationResult>, Error> {
         let issue_id = self.resolve_issue_id(&params.issue).await?;
-        let vars = serde_json::json!({ "input": { "issueId": issue_id, "releaseId": params.release } });
+        let vars =
+            serde_json::json!({ "input": { "issueId": issue_id, "releaseId": params.release } });
         let data: response::AddIssueToReleaseData = self
             .client
-            .execute_json(queries::ADD_ISSUE_TO_RELEASE, vars)
+            .execute(queries::ADD_ISSUE_TO_RELEASE, vars)
             .await?;
         if data.issue_to_release_create.success {
-            Ok(format!("Issue {} added to release {}.", params.issue, params.release))
+            Ok(Output::new(
+                format!(
+                    "Issue {} added to release {}.",
+                    params.issue, params.release
+                ),
+                data.issue_to_release_create,
+            ))
         } else {
             Err(Error::GraphQL("Add issue to release failed".into()))
         }
@@ -9134,14 +11889,17 @@ impl LinearMcp {
     async fn handle_remove_issue_from_release(
         &self,
         params: remove_issue_from_release::RemoveIssueFromReleaseParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::RemoveIssueFromReleaseData = self
             .client
-            .execute_json(queries::REMOVE_ISSUE_FROM_RELEASE, vars)
+            .execute(queries::REMOVE_ISSUE_FROM_RELEASE, vars)
             .await?;
         if data.issue_to_release_delete.success {
-            Ok(format!("Issue-to-release link {} removed.", params.id))
+            Ok(Output::new(
+                format!("Issue-to-release link {} removed.", params.id),
+                data.issue_to_release_delete,
+            ))
         } else {
             Err(Error::GraphQL("Remove issue from release failed".into()))
         }
@@ -9152,38 +11910,47 @@ impl LinearMcp {
     async fn handle_list_project_statuses(
         &self,
         params: list_project_statuses::ListProjectStatusesParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::NodeList<types::ProjectStatusFull>>, Error> {
         let first = params.limit.unwrap_or(50);
         let vars = serde_json::json!({ "first": first });
         let data: response::ProjectStatusesData = self
             .client
-            .execute_json(queries::LIST_PROJECT_STATUSES, vars)
+            .execute(queries::LIST_PROJECT_STATUSES, vars)
             .await?;
         if data.project_statuses.nodes.is_empty() {
-            return Ok("No project statuses found.".into());
+            return Ok(Output::new(
+                "No project statuses found.",
+                data.project_statuses,
+            ));
         }
-        let lines: Vec<String> = data.project_statuses.nodes.iter()
-            .map(|s| format::format_project_status(s))
+        let lines: Vec<String> = data
+            .project_statuses
+            .nodes
+            .iter()
+            .map(format::format_project_status)
             .collect();
-        Ok(lines.join("\n\n"))
+        Ok(Output::new(lines.join("\n\n"), data.project_statuses))
     }
 
     async fn handle_get_project_status(
         &self,
         params: get_project_status::GetProjectStatusParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::ProjectStatusFull>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::ProjectStatusData = self
             .client
-            .execute_json(queries::GET_PROJECT_STATUS, vars)
+            .execute(queries::GET_PROJECT_STATUS, vars)
             .await?;
-        Ok(format::format_project_status(&data.project_status))
+        Ok(Output::new(
+            format::format_project_status(&data.project_status),
+            data.project_status,
+        ))
     }
 
     async fn handle_create_project_status(
         &self,
         params: create_project_status::CreateProjectStatusParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::ProjectStatusFull>, Error> {
         let mut input = serde_json::json!({
             "name": params.name,
             "color": params.color,
@@ -9199,10 +11966,16 @@ impl LinearMcp {
         let vars = serde_json::json!({ "input": input });
         let data: response::CreateProjectStatusData = self
             .client
-            .execute_json(queries::CREATE_PROJECT_STATUS, vars)
+            .execute(queries::CREATE_PROJECT_STATUS, vars)
             .await?;
         match data.project_status_create.status {
-            Some(s) => Ok(format!("Project status created:\n\n{}", format::format_project_status(&s))),
+            Some(s) => Ok(Output::new(
+                format!(
+                    "Project status created:\n\n{}",
+                    format::format_project_status(&s)
+                ),
+                s,
+            )),
             None => Err(Error::GraphQL("Project status creation failed".into())),
         }
     }
@@ -9210,7 +11983,7 @@ impl LinearMcp {
     async fn handle_update_project_status(
         &self,
         params: update_project_status::UpdateProjectStatusParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::ProjectStatusFull>, Error> {
         let mut input = serde_json::Map::new();
         let mut has_fields = false;
         if let Some(ref name) = params.name {
@@ -9222,7 +11995,10 @@ impl LinearMcp {
             has_fields = true;
         }
         if let Some(ref desc) = params.description {
-            input.insert("description".into(), serde_json::Value::String(desc.clone()));
+            input.insert(
+                "description".into(),
+                serde_json::Value::String(desc.clone()),
+            );
             has_fields = true;
         }
         if let Some(pos) = params.position {
@@ -9240,13 +12016,20 @@ impl LinearMcp {
         if !has_fields {
             return Err(Error::InvalidInput("No fields to update.".into()));
         }
-        let vars = serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
+        let vars =
+            serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
         let data: response::UpdateProjectStatusData = self
             .client
-            .execute_json(queries::UPDATE_PROJECT_STATUS, vars)
+            .execute(queries::UPDATE_PROJECT_STATUS, vars)
             .await?;
         match data.project_status_update.status {
-            Some(s) => Ok(format!("Project status updated:\n\n{}", format::format_project_status(&s))),
+            Some(s) => Ok(Output::new(
+                format!(
+                    "Project status updated:\n\n{}",
+                    format::format_project_status(&s)
+                ),
+                s,
+            )),
             None => Err(Error::GraphQL("Project status update failed".into())),
         }
     }
@@ -9254,14 +12037,17 @@ impl LinearMcp {
     async fn handle_archive_project_status(
         &self,
         params: archive_project_status::ArchiveProjectStatusParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::ArchiveProjectStatusData = self
             .client
-            .execute_json(queries::ARCHIVE_PROJECT_STATUS, vars)
+            .execute(queries::ARCHIVE_PROJECT_STATUS, vars)
             .await?;
         if data.project_status_archive.success {
-            Ok(format!("Project status {} archived.", params.id))
+            Ok(Output::new(
+                format!("Project status {} archived.", params.id),
+                data.project_status_archive,
+            ))
         } else {
             Err(Error::GraphQL("Project status archive failed".into()))
         }
@@ -9270,14 +12056,17 @@ impl LinearMcp {
     async fn handle_unarchive_project_status(
         &self,
         params: unarchive_project_status::UnarchiveProjectStatusParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::UnarchiveProjectStatusData = self
             .client
-            .execute_json(queries::UNARCHIVE_PROJECT_STATUS, vars)
+            .execute(queries::UNARCHIVE_PROJECT_STATUS, vars)
             .await?;
         if data.project_status_unarchive.success {
-            Ok(format!("Project status {} unarchived.", params.id))
+            Ok(Output::new(
+                format!("Project status {} unarchived.", params.id),
+                data.project_status_unarchive,
+            ))
         } else {
             Err(Error::GraphQL("Project status unarchive failed".into()))
         }
@@ -9288,38 +12077,44 @@ impl LinearMcp {
     async fn handle_list_project_labels(
         &self,
         params: list_project_labels::ListProjectLabelsParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::NodeList<types::ProjectLabel>>, Error> {
         let first = params.limit.unwrap_or(50);
         let vars = serde_json::json!({ "first": first });
         let data: response::ProjectLabelsData = self
             .client
-            .execute_json(queries::LIST_PROJECT_LABELS, vars)
+            .execute(queries::LIST_PROJECT_LABELS, vars)
             .await?;
         if data.project_labels.nodes.is_empty() {
-            return Ok("No project labels found.".into());
+            return Ok(Output::new("No project labels found.", data.project_labels));
         }
-        let lines: Vec<String> = data.project_labels.nodes.iter()
-            .map(|l| format::format_project_label(l))
+        let lines: Vec<String> = data
+            .project_labels
+            .nodes
+            .iter()
+            .map(format::format_project_label)
             .collect();
-        Ok(lines.join("\n\n"))
+        Ok(Output::new(lines.join("\n\n"), data.project_labels))
     }
 
     async fn handle_get_project_label(
         &self,
         params: get_project_label::GetProjectLabelParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::ProjectLabel>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::ProjectLabelData = self
             .client
-            .execute_json(queries::GET_PROJECT_LABEL, vars)
+            .execute(queries::GET_PROJECT_LABEL, vars)
             .await?;
-        Ok(format::format_project_label(&data.project_label))
+        Ok(Output::new(
+            format::format_project_label(&data.project_label),
+            data.project_label,
+        ))
     }
 
     async fn handle_create_project_label(
         &self,
         params: create_project_label::CreateProjectLabelParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::ProjectLabel>, Error> {
         let mut input = serde_json::json!({ "name": params.name });
         if let Some(ref color) = params.color {
             input["color"] = serde_json::Value::String(color.clone());
@@ -9336,10 +12131,16 @@ impl LinearMcp {
         let vars = serde_json::json!({ "input": input });
         let data: response::CreateProjectLabelData = self
             .client
-            .execute_json(queries::CREATE_PROJECT_LABEL, vars)
+            .execute(queries::CREATE_PROJECT_LABEL, vars)
             .await?;
         match data.project_label_create.project_label {
-            Some(l) => Ok(format!("Project label created:\n\n{}", format::format_project_label(&l))),
+            Some(l) => Ok(Output::new(
+                format!(
+                    "Project label created:\n\n{}",
+                    format::format_project_label(&l)
+                ),
+                l,
+            )),
             None => Err(Error::GraphQL("Project label creation failed".into())),
         }
     }
@@ -9347,7 +12148,7 @@ impl LinearMcp {
     async fn handle_update_project_label(
         &self,
         params: update_project_label::UpdateProjectLabelParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::ProjectLabel>, Error> {
         let mut input = serde_json::Map::new();
         let mut has_fields = false;
         if let Some(ref name) = params.name {
@@ -9359,7 +12160,10 @@ impl LinearMcp {
             has_fields = true;
         }
         if let Some(ref desc) = params.description {
-            input.insert("description".into(), serde_json::Value::String(desc.clone()));
+            input.insert(
+                "description".into(),
+                serde_json::Value::String(desc.clone()),
+            );
             has_fields = true;
         }
         if let Some(ref pid) = params.parent_id {
@@ -9373,13 +12177,20 @@ impl LinearMcp {
         if !has_fields {
             return Err(Error::InvalidInput("No fields to update.".into()));
         }
-        let vars = serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
+        let vars =
+            serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
         let data: response::UpdateProjectLabelData = self
             .client
-            .execute_json(queries::UPDATE_PROJECT_LABEL, vars)
+            .execute(queries::UPDATE_PROJECT_LABEL, vars)
             .await?;
         match data.project_label_update.project_label {
-            Some(l) => Ok(format!("Project label updated:\n\n{}", format::format_project_label(&l))),
+            Some(l) => Ok(Output::new(
+                format!(
+                    "Project label updated:\n\n{}",
+                    format::format_project_label(&l)
+                ),
+                l,
+            )),
             None => Err(Error::GraphQL("Project label update failed".into())),
         }
     }
@@ -9387,14 +12198,17 @@ impl LinearMcp {
     async fn handle_delete_project_label(
         &self,
         params: delete_project_label::DeleteProjectLabelParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::DeleteProjectLabelData = self
             .client
-            .execute_json(queries::DELETE_PROJECT_LABEL, vars)
+            .execute(queries::DELETE_PROJECT_LABEL, vars)
             .await?;
         if data.project_label_delete.success {
-            Ok(format!("Project label {} deleted.", params.id))
+            Ok(Output::new(
+                format!("Project label {} deleted.", params.id),
+                data.project_label_delete,
+            ))
         } else {
             Err(Error::GraphQL("Project label deletion failed".into()))
         }
@@ -9405,49 +12219,53 @@ impl LinearMcp {
     async fn handle_list_team_memberships(
         &self,
         params: list_team_memberships::ListTeamMembershipsParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::NodeList<types::TeamMembership>>, Error> {
         let first = params.limit.unwrap_or(50);
         let nodes = if let Some(ref team_filter) = params.team {
             let team_id = self.resolve_team_id(team_filter).await?;
             let vars = serde_json::json!({ "teamId": team_id, "first": first });
             let data: response::TeamMembershipsByTeamData = self
                 .client
-                .execute_json(queries::LIST_TEAM_MEMBERSHIPS_BY_TEAM, vars)
+                .execute(queries::LIST_TEAM_MEMBERSHIPS_BY_TEAM, vars)
                 .await?;
             data.team.memberships.nodes
         } else {
             let vars = serde_json::json!({ "first": first });
             let data: response::TeamMembershipsData = self
                 .client
-                .execute_json(queries::LIST_TEAM_MEMBERSHIPS, vars)
+                .execute(queries::LIST_TEAM_MEMBERSHIPS, vars)
                 .await?;
             data.team_memberships.nodes
         };
         if nodes.is_empty() {
-            return Ok("No team memberships found.".into());
+            return Ok(Output::new(
+                "No team memberships found.",
+                types::NodeList { nodes },
+            ));
         }
-        let lines: Vec<String> = nodes.iter()
-            .map(|m| format::format_team_membership(m))
-            .collect();
-        Ok(lines.join("\n"))
+        let lines: Vec<String> = nodes.iter().map(format::format_team_membership).collect();
+        Ok(Output::new(lines.join("\n"), types::NodeList { nodes }))
     }
 
     async fn handle_get_team_membership(
         &self,
         params: get_team_membership::GetTeamMembershipParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::TeamMembership>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::TeamMembershipData = self
             .client
-            .execute_json(queries::GET_TEAM_MEMBERSHIP, vars)
+            .execute(queries::GET_TEAM_MEMBERSHIP, vars)
             .await?;
-        Ok(format::format_team_membership(&data.team_membership))
+        Ok(Output::new(
+            format::format_team_membership(&data.team_membership),
+            data.team_membership,
+        ))
     }
 
     async fn handle_create_team_membership(
         &self,
         params: create_team_membership::CreateTeamMembershipParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::TeamMembership>, Error> {
         let user_id = self.resolve_user_id(&params.user).await?;
         let team_id = self.resolve_team_id(&params.team).await?;
         let mut input = serde_json::json!({
@@ -9460,10 +12278,16 @@ impl LinearMcp {
         let vars = serde_json::json!({ "input": input });
         let data: response::CreateTeamMembershipData = self
             .client
-            .execute_json(queries::CREATE_TEAM_MEMBERSHIP, vars)
+            .execute(queries::CREATE_TEAM_MEMBERSHIP, vars)
             .await?;
         match data.team_membership_create.team_membership {
-            Some(m) => Ok(format!("Team membership created:\n\n{}", format::format_team_membership(&m))),
+            Some(m) => Ok(Output::new(
+                format!(
+                    "Team membership created:\n\n{}",
+                    format::format_team_membership(&m)
+                ),
+                m,
+            )),
             None => Err(Error::GraphQL("Team membership creation failed".into())),
         }
     }
@@ -9471,7 +12295,7 @@ impl LinearMcp {
     async fn handle_update_team_membership(
         &self,
         params: update_team_membership::UpdateTeamMembershipParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::TeamMembership>, Error> {
         let mut input = serde_json::Map::new();
         if let Some(owner) = params.owner {
             input.insert("owner".into(), serde_json::json!(owner));
@@ -9479,13 +12303,20 @@ impl LinearMcp {
         if input.is_empty() {
             return Err(Error::InvalidInput("No fields to update.".into()));
         }
-        let vars = serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
+        let vars =
+            serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
         let data: response::UpdateTeamMembershipData = self
             .client
-            .execute_json(queries::UPDATE_TEAM_MEMBERSHIP, vars)
+            .execute(queries::UPDATE_TEAM_MEMBERSHIP, vars)
             .await?;
         match data.team_membership_update.team_membership {
-            Some(m) => Ok(format!("Team membership updated:\n\n{}", format::format_team_membership(&m))),
+            Some(m) => Ok(Output::new(
+                format!(
+                    "Team membership updated:\n\n{}",
+                    format::format_team_membership(&m)
+                ),
+                m,
+            )),
             None => Err(Error::GraphQL("Team membership update failed".into())),
         }
     }
@@ -9493,14 +12324,17 @@ impl LinearMcp {
     async fn handle_delete_team_membership(
         &self,
         params: delete_team_membership::DeleteTeamMembershipParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::DeleteTeamMembershipData = self
             .client
-            .execute_json(queries::DELETE_TEAM_MEMBERSHIP, vars)
+            .execute(queries::DELETE_TEAM_MEMBERSHIP, vars)
             .await?;
         if data.team_membership_delete.success {
-            Ok(format!("Team membership {} deleted.", params.id))
+            Ok(Output::new(
+                format!("Team membership {} deleted.", params.id),
+                data.team_membership_delete,
+            ))
         } else {
             Err(Error::GraphQL("Team membership deletion failed".into()))
         }
@@ -9511,59 +12345,82 @@ impl LinearMcp {
     async fn handle_list_notification_subscriptions(
         &self,
         params: list_notification_subscriptions::ListNotificationSubscriptionsParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::NodeList<types::NotificationSubscription>>, Error> {
         let first = params.limit.unwrap_or(50);
         let vars = serde_json::json!({ "first": first });
         let data: response::NotificationSubscriptionsData = self
             .client
-            .execute_json(queries::LIST_NOTIFICATION_SUBSCRIPTIONS, vars)
+            .execute(queries::LIST_NOTIFICATION_SUBSCRIPTIONS, vars)
             .await?;
         if data.notification_subscriptions.nodes.is_empty() {
-            return Ok("No notification subscriptions found.".into());
+            return Ok(Output::new(
+                "No notification subscriptions found.",
+                data.notification_subscriptions,
+            ));
         }
-        let lines: Vec<String> = data.notification_subscriptions.nodes.iter()
-            .map(|s| format::format_notification_subscription(s))
+        let lines: Vec<String> = data
+            .notification_subscriptions
+            .nodes
+            .iter()
+            .map(format::format_notification_subscription)
             .collect();
-        Ok(lines.join("\n"))
+        Ok(Output::new(
+            lines.join("\n"),
+            data.notification_subscriptions,
+        ))
     }
 
     async fn handle_get_notification_subscription(
         &self,
         params: get_notification_subscription::GetNotificationSubscriptionParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::NotificationSubscription>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::NotificationSubscriptionData = self
             .client
-            .execute_json(queries::GET_NOTIFICATION_SUBSCRIPTION, vars)
+            .execute(queries::GET_NOTIFICATION_SUBSCRIPTION, vars)
             .await?;
-        Ok(format::format_notification_subscription(&data.notification_subscription))
+        Ok(Output::new(
+            format::format_notification_subscription(&data.notification_subscription),
+            data.notification_subscription,
+        ))
     }
 
     async fn handle_create_notification_subscription(
         &self,
         params: create_notification_subscription::CreateNotificationSubscriptionParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::NotificationSubscription>, Error> {
         let mut input = serde_json::Map::new();
         if let Some(ref types) = params.types {
-            let type_list: Vec<&str> = types.split(',').map(|s| s.trim()).filter(|s| !s.is_empty()).collect();
-            input.insert("notificationSubscriptionTypes".into(), serde_json::json!(type_list));
+            let type_list: Vec<&str> = types
+                .split(',')
+                .map(|s| s.trim())
+                .filter(|s| !s.is_empty())
+                .collect();
+            input.insert(
+                "notificationSubscriptionTypes".into(),
+                serde_json::json!(type_list),
+            );
         }
-        if let Some(ref team) = params.team {
-            let team_id = self.resolve_team_id(team).await?;
-            input.insert("teamId".into(), serde_json::Value::String(team_id));
+        let team = match params.team {
+            Some(ref team) => Some(self.resolve_team(team).await?),
+            None => None,
+        };
+        if let Some(ref team) = team {
+            input.insert("teamId".into(), serde_json::Value::String(team.id.clone()));
         }
         if let Some(ref project) = params.project {
             let project_id = self.resolve_project_id_or_uuid(project).await?;
             input.insert("projectId".into(), serde_json::Value::String(project_id));
         }
         if let Some(ref label) = params.label {
-            let is_uuid = label.len() == 36
-                && label.chars().all(|c| c.is_ascii_hexdigit() || c == '-');
-            let label_id = if is_uuid {
+            let label_id = if is_uuid(label) {
                 label.clone()
             } else {
-                let label_ids = self.resolve_label_ids(label, params.team.as_deref()).await?;
-                label_ids.into_iter().next()
+                let team_key = team.as_ref().map(|t| t.key.as_str());
+                let label_ids = self.resolve_label_ids(label, team_key).await?;
+                label_ids
+                    .into_iter()
+                    .next()
                     .ok_or_else(|| Error::NotFound(format!("Label '{}' not found", label)))?
             };
             input.insert("labelId".into(), serde_json::Value::String(label_id));
@@ -9574,23 +12431,41 @@ impl LinearMcp {
         let vars = serde_json::json!({ "input": serde_json::Value::Object(input) });
         let data: response::CreateNotificationSubscriptionData = self
             .client
-            .execute_json(queries::CREATE_NOTIFICATION_SUBSCRIPTION, vars)
-            .await?;
-        match data.notification_subscription_create.notification_subscription {
-            Some(s) => Ok(format!("Notification subscription created:\n\n{}", format::format_notification_subscription(&s))),
-            None => Err(Error::GraphQL("Notification subscription creation failed".into())),
+            .execute(queries::CREATE_NOTIFICATION_SUBSCRIPTION, vars)
+            .await?;
+        match data
+            .notification_subscription_create
+            .notification_subscription
+        {
+            Some(s) => Ok(Output::new(
+                format!(
+                    "Notification subscription created:\n\n{}",
+                    format::format_notification_subscription(&s)
+                ),
+                s,
+            )),
+            None => Err(Error::GraphQL(
+                "Notification subscription creation failed".into(),
+            )),
         }
     }
 
     async fn handle_update_notification_subscription(
         &self,
         params: update_notification_subscription::UpdateNotificationSubscriptionParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::NotificationSubscription>, Error> {
         let mut input = serde_json::Map::new();
         let mut has_fields = false;
         if let Some(ref types) = params.types {
-            let type_list: Vec<&str> = types.split(',').map(|s| s.trim()).filter(|s| !s.is_empty()).collect();
-            input.insert("notificationSubscriptionTypes".into(), serde_json::json!(type_list));
+            let type_list: Vec<&str> = types
+                .split(',')
+                .map(|s| s.trim())
+                .filter(|s| !s.is_empty())
+                .collect();
+            input.insert(
+                "notificationSubscriptionTypes".into(),
+                serde_json::json!(type_list),
+            );
             has_fields = true;
         }
         if let Some(active) = params.active {
@@ -9600,23 +12475,43 @@ impl LinearMcp {
         if !has_fields {
             return Err(Error::InvalidInput("No fields to update.".into()));
         }
-        let vars = serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
+        let vars =
+            serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
         let data: response::UpdateNotificationSubscriptionData = self
             .client
-            .execute_json(queries::UPDATE_NOTIFICATION_SUBSCRIPTION, vars)
-            .await?;
-        match data.notification_subscription_update.notification_subscription {
-            Some(s) => Ok(format!("Notification subscription updated:\n\n{}", format::format_notification_subscription(&s))),
-            None => Err(Error::GraphQL("Notification subscription update failed".into())),
+            .execute(queries::UPDATE_NOTIFICATION_SUBSCRIPTION, vars)
+            .await?;
+        match data
+            .notification_subscription_update
+            .notification_subscription
+        {
+            Some(s) => Ok(Output::new(
+                format!(
+                    "Notification subscription updated:\n\n{}",
+                    format::format_notification_subscription(&s)
+                ),
+                s,
+            )),
+            None => Err(Error::GraphQL(
+                "Notification subscription update failed".into(),
+            )),
         }
     }
 
-    async fn handle_get_notifications_unread_count(&self) -> Result<String, Error> {
+    async fn handle_get_notifications_unread_count(
+        &self,
+    ) -> Result<Output<response::NotificationsUnreadCountData>, Error> {
         let data: response::NotificationsUnreadCountData = self
             .client
-            .execute::<(), _>(queries::GET_NOTIFICATIONS_UNREAD_COUNT, None)
+            .execute(
+                queries::GET_NOTIFICATIONS_UNREAD_COUNT,
+                serde_json::json!({}),
+            )
             .await?;
-        Ok(format!("Unread notifications: {}", data.notifications_unread_count))
+        Ok(Output::new(
+            format!("Unread notifications: {}", data.notifications_unread_count),
+            data,
+        ))
     }
 
     // ---- Phase 6A: Template CRUD handlers ----
@@ -9624,19 +12519,19 @@ impl LinearMcp {
     async fn handle_get_template(
         &self,
         params: get_template::GetTemplateParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::Template>, Error> {
         let vars = serde_json::json!({ "id": params.id });
-        let data: response::TemplateData = self
-            .client
-            .execute_json(queries::GET_TEMPLATE, vars)
-            .await?;
-        Ok(format::format_template(&data.template))
+        let data: response::TemplateData = self.client.execute(queries::GET_TEMPLATE, vars).await?;
+        Ok(Output::new(
+            format::format_template(&data.template),
+            data.template,
+        ))
     }
 
     async fn handle_create_template(
         &self,
         params: create_template::CreateTemplateParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::Template>, Error> {
         let template_data: serde_json::Value = serde_json::from_str(&params.template_data)
             .map_err(|e| Error::InvalidInput(format!("Invalid template data JSON: {}", e)))?;
         let mut input = serde_json::json!({
@@ -9652,12 +12547,13 @@ impl LinearMcp {
             input["description"] = serde_json::Value::String(desc.clone());
         }
         let vars = serde_json::json!({ "input": input });
-        let data: response::CreateTemplateData = self
-            .client
-            .execute_json(queries::CREATE_TEMPLATE, vars)
-            .await?;
+        let data: response::CreateTemplateData =
+            self.client.execute(queries::CREATE_TEMPLATE, vars).await?;
         match data.template_create.template {
-            Some(t) => Ok(format!("Template created:\n\n{}", format::format_template(&t))),
+            Some(t) => Ok(Output::new(
+                format!("Template created:\n\n{}", format::format_template(&t)),
+                t,
+            )),
             None => Err(Error::GraphQL("Template creation failed".into())),
         }
     }
@@ -9665,7 +12561,7 @@ impl LinearMcp {
     async fn handle_update_template(
         &self,
         params: update_template::UpdateTemplateParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::Template>, Error> {
         let mut input = serde_json::Map::new();
         let mut has_fields = false;
         if let Some(ref name) = params.name {
@@ -9673,7 +12569,10 @@ impl LinearMcp {
             has_fields = true;
         }
         if let Some(ref desc) = params.description {
-            input.insert("description".into(), serde_json::Value::String(desc.clone()));
+            input.insert(
+                "description".into(),
+                serde_json::Value::String(desc.clone()),
+            );
             has_fields = true;
         }
         if let Some(ref td) = params.template_data {
@@ -9690,13 +12589,15 @@ impl LinearMcp {
         if !has_fields {
             return Err(Error::InvalidInput("No fields to update.".into()));
         }
-        let vars = serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
-        let data: response::UpdateTemplateData = self
-            .client
-            .execute_json(queries::UPDATE_TEMPLATE, vars)
-            .await?;
+        let vars =
+            serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
+        let data: response::UpdateTemplateData =
+            self.client.execute(queries::UPDATE_TEMPLATE, vars).await?;
         match data.template_update.template {
-            Some(t) => Ok(format!("Template updated:\n\n{}", format::format_template(&t))),
+            Some(t) => Ok(Output::new(
+                format!("Template updated:\n\n{}", format::format_template(&t)),
+                t,
+            )),
             None => Err(Error::GraphQL("Template update failed".into())),
         }
     }
@@ -9704,14 +12605,15 @@ impl LinearMcp {
     async fn handle_delete_template(
         &self,
         params: delete_template::DeleteTemplateParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let vars = serde_json::json!({ "id": params.id });
-        let data: response::DeleteTemplateData = self
-            .client
-            .execute_json(queries::DELETE_TEMPLATE, vars)
-            .await?;
+        let data: response::DeleteTemplateData =
+            self.client.execute(queries::DELETE_TEMPLATE, vars).await?;
         if data.template_delete.success {
-            Ok(format!("Template {} deleted.", params.id))
+            Ok(Output::new(
+                format!("Template {} deleted.", params.id),
+                data.template_delete,
+            ))
         } else {
             Err(Error::GraphQL("Template deletion failed".into()))
         }
@@ -9722,19 +12624,22 @@ impl LinearMcp {
     async fn handle_get_entity_external_link(
         &self,
         params: get_entity_external_link::GetEntityExternalLinkParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::EntityExternalLink>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::EntityExternalLinkData = self
             .client
-            .execute_json(queries::GET_ENTITY_EXTERNAL_LINK, vars)
+            .execute(queries::GET_ENTITY_EXTERNAL_LINK, vars)
             .await?;
-        Ok(format::format_entity_external_link(&data.entity_external_link))
+        Ok(Output::new(
+            format::format_entity_external_link(&data.entity_external_link),
+            data.entity_external_link,
+        ))
     }
 
     async fn handle_create_entity_external_link(
         &self,
         params: create_entity_external_link::CreateEntityExternalLinkParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::EntityExternalLink>, Error> {
         let mut input = serde_json::json!({
             "url": params.url,
             "label": params.label,
@@ -9754,18 +12659,26 @@ impl LinearMcp {
         let vars = serde_json::json!({ "input": input });
         let data: response::CreateEntityExternalLinkData = self
             .client
-            .execute_json(queries::CREATE_ENTITY_EXTERNAL_LINK, vars)
+            .execute(queries::CREATE_ENTITY_EXTERNAL_LINK, vars)
             .await?;
         match data.entity_external_link_create.entity_external_link {
-            Some(l) => Ok(format!("Entity external link created:\n\n{}", format::format_entity_external_link(&l))),
-            None => Err(Error::GraphQL("Entity external link creation failed".into())),
+            Some(l) => Ok(Output::new(
+                format!(
+                    "Entity external link created:\n\n{}",
+                    format::format_entity_external_link(&l)
+                ),
+                l,
+            )),
+            None => Err(Error::GraphQL(
+                "Entity external link creation failed".into(),
+            )),
         }
     }
 
     async fn handle_update_entity_external_link(
         &self,
         params: update_entity_external_link::UpdateEntityExternalLinkParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::EntityExternalLink>, Error> {
         let mut input = serde_json::Map::new();
         let mut has_fields = false;
         if let Some(ref url) = params.url {
@@ -9779,13 +12692,20 @@ impl LinearMcp {
         if !has_fields {
             return Err(Error::InvalidInput("No fields to update.".into()));
         }
-        let vars = serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
+        let vars =
+            serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
         let data: response::UpdateEntityExternalLinkData = self
             .client
-            .execute_json(queries::UPDATE_ENTITY_EXTERNAL_LINK, vars)
+            .execute(queries::UPDATE_ENTITY_EXTERNAL_LINK, vars)
             .await?;
         match data.entity_external_link_update.entity_external_link {
-            Some(l) => Ok(format!("Entity external link updated:\n\n{}", format::format_entity_external_link(&l))),
+            Some(l) => Ok(Output::new(
+                format!(
+                    "Entity external link updated:\n\n{}",
+                    format::format_entity_external_link(&l)
+                ),
+                l,
+            )),
             None => Err(Error::GraphQL("Entity external link update failed".into())),
         }
     }
@@ -9793,16 +12713,21 @@ impl LinearMcp {
     async fn handle_delete_entity_external_link(
         &self,
         params: delete_entity_external_link::DeleteEntityExternalLinkParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::DeleteEntityExternalLinkData = self
             .client
-            .execute_json(queries::DELETE_ENTITY_EXTERNAL_LINK, vars)
+            .execute(queries::DELETE_ENTITY_EXTERNAL_LINK, vars)
             .await?;
         if data.entity_external_link_delete.success {
-            Ok(format!("Entity external link {} deleted.", params.id))
+            Ok(Output::new(
+                format!("Entity external link {} deleted.", params.id),
+                data.entity_external_link_delete,
+            ))
         } else {
-            Err(Error::GraphQL("Entity external link deletion failed".into()))
+            Err(Error::GraphQL(
+                "Entity external link deletion failed".into(),
+            ))
         }
     }
 
@@ -9811,49 +12736,42 @@ impl LinearMcp {
     async fn handle_list_emojis(
         &self,
         params: list_emojis::ListEmojisParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::NodeList<types::Emoji>>, Error> {
         let first = params.limit.unwrap_or(50);
         let vars = serde_json::json!({ "first": first });
-        let data: response::EmojisData = self
-            .client
-            .execute_json(queries::LIST_EMOJIS, vars)
-            .await?;
+        let data: response::EmojisData = self.client.execute(queries::LIST_EMOJIS, vars).await?;
         if data.emojis.nodes.is_empty() {
-            return Ok("No custom emojis found.".into());
+            return Ok(Output::new("No custom emojis found.", data.emojis));
         }
-        let lines: Vec<String> = data.emojis.nodes.iter()
-            .map(|e| format::format_emoji(e))
-            .collect();
-        Ok(lines.join("\n\n"))
+        let lines: Vec<String> = data.emojis.nodes.iter().map(format::format_emoji).collect();
+        Ok(Output::new(lines.join("\n\n"), data.emojis))
     }
 
     async fn handle_get_emoji(
         &self,
         params: get_emoji::GetEmojiParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::Emoji>, Error> {
         let vars = serde_json::json!({ "id": params.id });
-        let data: response::EmojiData = self
-            .client
-            .execute_json(queries::GET_EMOJI, vars)
-            .await?;
-        Ok(format::format_emoji(&data.emoji))
+        let data: response::EmojiData = self.client.execute(queries::GET_EMOJI, vars).await?;
+        Ok(Output::new(format::format_emoji(&data.emoji), data.emoji))
     }
 
     async fn handle_create_emoji(
         &self,
         params: create_emoji::CreateEmojiParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::Emoji>, Error> {
         let input = serde_json::json!({
             "name": params.name,
             "url": params.url,
         });
         let vars = serde_json::json!({ "input": input });
-        let data: response::CreateEmojiData = self
-            .client
-            .execute_json(queries::CREATE_EMOJI, vars)
-            .await?;
+        let data: response::CreateEmojiData =
+            self.client.execute(queries::CREATE_EMOJI, vars).await?;
         match data.emoji_create.emoji {
-            Some(e) => Ok(format!("Emoji created:\n\n{}", format::format_emoji(&e))),
+            Some(e) => Ok(Output::new(
+                format!("Emoji created:\n\n{}", format::format_emoji(&e)),
+                e,
+            )),
             None => Err(Error::GraphQL("Emoji creation failed".into())),
         }
     }
@@ -9861,14 +12779,15 @@ impl LinearMcp {
     async fn handle_delete_emoji(
         &self,
         params: delete_emoji::DeleteEmojiParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let vars = serde_json::json!({ "id": params.id });
-        let data: response::DeleteEmojiData = self
-            .client
-            .execute_json(queries::DELETE_EMOJI, vars)
-            .await?;
+        let data: response::DeleteEmojiData =
+            self.client.execute(queries::DELETE_EMOJI, vars).await?;
         if data.emoji_delete.success {
-            Ok(format!("Emoji {} deleted.", params.id))
+            Ok(Output::new(
+                format!("Emoji {} deleted.", params.id),
+                data.emoji_delete,
+            ))
         } else {
             Err(Error::GraphQL("Emoji deletion failed".into()))
         }
@@ -9879,40 +12798,53 @@ impl LinearMcp {
     async fn handle_list_initiative_relations(
         &self,
         params: list_initiative_relations::ListInitiativeRelationsParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::NodeList<types::InitiativeRelation>>, Error> {
         let first = params.limit.unwrap_or(50);
         let vars = serde_json::json!({ "first": first });
         let data: response::InitiativeRelationsData = self
             .client
-            .execute_json(queries::LIST_INITIATIVE_RELATIONS, vars)
+            .execute(queries::LIST_INITIATIVE_RELATIONS, vars)
             .await?;
         if data.initiative_relations.nodes.is_empty() {
-            return Ok("No initiative relations found.".into());
+            return Ok(Output::new(
+                "No initiative relations found.",
+                data.initiative_relations,
+            ));
         }
-        let lines: Vec<String> = data.initiative_relations.nodes.iter()
-            .map(|r| format::format_initiative_relation(r))
+        let lines: Vec<String> = data
+            .initiative_relations
+            .nodes
+            .iter()
+            .map(format::format_initiative_relation)
             .collect();
-        Ok(lines.join("\n"))
+        Ok(Output::new(lines.join("\n"), data.initiative_relations))
     }
 
     async fn handle_get_initiative_relation(
         &self,
         params: get_initiative_relation::GetInitiativeRelationParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::InitiativeRelation>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::InitiativeRelationData = self
             .client
-            .execute_json(queries::GET_INITIATIVE_RELATION, vars)
+            .execute(queries::GET_INITIATIVE_RELATION, vars)
             .await?;
-        Ok(format::format_initiative_relation(&data.initiative_relation))
+        Ok(Output::new(
+            format::format_initiative_relation(&data.initiative_relation),
+            data.initiative_relation,
+        ))
     }
 
     async fn handle_create_initiative_relation(
         &self,
         params: create_initiative_relation::CreateInitiativeRelationParams,
-    ) -> Result<String, Error> {
-        let init_id = self.resolve_initiative_id_or_uuid(&params.initiative).await?;
-        let related_id = self.resolve_initiative_id_or_uuid(&params.related_initiative).await?;
+    ) -> Result<Output<types::InitiativeRelation>, Error> {
+        let init_id = self
+            .resolve_initiative_id_or_uuid(&params.initiative)
+            .await?;
+        let related_id = self
+            .resolve_initiative_id_or_uuid(&params.related_initiative)
+            .await?;
         let input = serde_json::json!({
             "initiativeId": init_id,
             "relatedInitiativeId": related_id,
@@ -9920,10 +12852,16 @@ impl LinearMcp {
         let vars = serde_json::json!({ "input": input });
         let data: response::CreateInitiativeRelationData = self
             .client
-            .execute_json(queries::CREATE_INITIATIVE_RELATION, vars)
+            .execute(queries::CREATE_INITIATIVE_RELATION, vars)
             .await?;
         match data.initiative_relation_create.initiative_relation {
-            Some(r) => Ok(format!("Initiative relation created:\n\n{}", format::format_initiative_relation(&r))),
+            Some(r) => Ok(Output::new(
+                format!(
+                    "Initiative relation created:\n\n{}",
+                    format::format_initiative_relation(&r)
+                ),
+                r,
+            )),
             None => Err(Error::GraphQL("Initiative relation creation failed".into())),
         }
     }
@@ -9931,7 +12869,7 @@ impl LinearMcp {
     async fn handle_update_initiative_relation(
         &self,
         params: update_initiative_relation::UpdateInitiativeRelationParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::InitiativeRelation>, Error> {
         let mut input = serde_json::Map::new();
         if let Some(order) = params.sort_order {
             input.insert("sortOrder".into(), serde_json::json!(order));
@@ -9939,13 +12877,20 @@ impl LinearMcp {
         if input.is_empty() {
             return Err(Error::InvalidInput("No fields to update.".into()));
         }
-        let vars = serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
+        let vars =
+            serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
         let data: response::UpdateInitiativeRelationData = self
             .client
-            .execute_json(queries::UPDATE_INITIATIVE_RELATION, vars)
+            .execute(queries::UPDATE_INITIATIVE_RELATION, vars)
             .await?;
         match data.initiative_relation_update.initiative_relation {
-            Some(r) => Ok(format!("Initiative relation updated:\n\n{}", format::format_initiative_relation(&r))),
+            Some(r) => Ok(Output::new(
+                format!(
+                    "Initiative relation updated:\n\n{}",
+                    format::format_initiative_relation(&r)
+                ),
+                r,
+            )),
             None => Err(Error::GraphQL("Initiative relation update failed".into())),
         }
     }
@@ -9953,14 +12898,17 @@ impl LinearMcp {
     async fn handle_delete_initiative_relation(
         &self,
         params: delete_initiative_relation::DeleteInitiativeRelationParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::DeleteInitiativeRelationData = self
             .client
-            .execute_json(queries::DELETE_INITIATIVE_RELATION, vars)
+            .execute(queries::DELETE_INITIATIVE_RELATION, vars)
             .await?;
         if data.initiative_relation_delete.success {
-            Ok(format!("Initiative relation {} deleted.", params.id))
+            Ok(Output::new(
+                format!("Initiative relation {} deleted.", params.id),
+                data.initiative_relation_delete,
+            ))
         } else {
             Err(Error::GraphQL("Initiative relation deletion failed".into()))
         }
@@ -9971,38 +12919,44 @@ impl LinearMcp {
     async fn handle_list_time_schedules(
         &self,
         params: list_time_schedules::ListTimeSchedulesParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::NodeList<types::TimeSchedule>>, Error> {
         let first = params.limit.unwrap_or(50);
         let vars = serde_json::json!({ "first": first });
         let data: response::TimeSchedulesData = self
             .client
-            .execute_json(queries::LIST_TIME_SCHEDULES, vars)
+            .execute(queries::LIST_TIME_SCHEDULES, vars)
             .await?;
         if data.time_schedules.nodes.is_empty() {
-            return Ok("No time schedules found.".into());
+            return Ok(Output::new("No time schedules found.", data.time_schedules));
         }
-        let lines: Vec<String> = data.time_schedules.nodes.iter()
-            .map(|s| format::format_time_schedule(s))
+        let lines: Vec<String> = data
+            .time_schedules
+            .nodes
+            .iter()
+            .map(format::format_time_schedule)
             .collect();
-        Ok(lines.join("\n\n"))
+        Ok(Output::new(lines.join("\n\n"), data.time_schedules))
     }
 
     async fn handle_get_time_schedule(
         &self,
         params: get_time_schedule::GetTimeScheduleParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::TimeSchedule>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::TimeScheduleData = self
             .client
-            .execute_json(queries::GET_TIME_SCHEDULE, vars)
+            .execute(queries::GET_TIME_SCHEDULE, vars)
             .await?;
-        Ok(format::format_time_schedule(&data.time_schedule))
+        Ok(Output::new(
+            format::format_time_schedule(&data.time_schedule),
+            data.time_schedule,
+        ))
     }
 
     async fn handle_create_time_schedule(
         &self,
         params: create_time_schedule::CreateTimeScheduleParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::TimeSchedule>, Error> {
         let entries: serde_json::Value = serde_json::from_str(&params.entries)
             .map_err(|e| Error::InvalidInput(format!("Invalid entries JSON: {}", e)))?;
         let mut input = serde_json::json!({
@@ -10018,10 +12972,16 @@ impl LinearMcp {
         let vars = serde_json::json!({ "input": input });
         let data: response::CreateTimeScheduleData = self
             .client
-            .execute_json(queries::CREATE_TIME_SCHEDULE, vars)
+            .execute(queries::CREATE_TIME_SCHEDULE, vars)
             .await?;
         match data.time_schedule_create.time_schedule {
-            Some(s) => Ok(format!("Time schedule created:\n\n{}", format::format_time_schedule(&s))),
+            Some(s) => Ok(Output::new(
+                format!(
+                    "Time schedule created:\n\n{}",
+                    format::format_time_schedule(&s)
+                ),
+                s,
+            )),
             None => Err(Error::GraphQL("Time schedule creation failed".into())),
         }
     }
@@ -10029,7 +12989,7 @@ impl LinearMcp {
     async fn handle_update_time_schedule(
         &self,
         params: update_time_schedule::UpdateTimeScheduleParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::TimeSchedule>, Error> {
         let mut input = serde_json::Map::new();
         let mut has_fields = false;
         if let Some(ref name) = params.name {
@@ -10043,23 +13003,36 @@ impl LinearMcp {
             has_fields = true;
         }
         if let Some(ref ext_id) = params.external_id {
-            input.insert("externalId".into(), serde_json::Value::String(ext_id.clone()));
+            input.insert(
+                "externalId".into(),
+                serde_json::Value::String(ext_id.clone()),
+            );
             has_fields = true;
         }
         if let Some(ref ext_url) = params.external_url {
-            input.insert("externalUrl".into(), serde_json::Value::String(ext_url.clone()));
+            input.insert(
+                "externalUrl".into(),
+                serde_json::Value::String(ext_url.clone()),
+            );
             has_fields = true;
         }
         if !has_fields {
             return Err(Error::InvalidInput("No fields to update.".into()));
         }
-        let vars = serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
+        let vars =
+            serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
         let data: response::UpdateTimeScheduleData = self
             .client
-            .execute_json(queries::UPDATE_TIME_SCHEDULE, vars)
+            .execute(queries::UPDATE_TIME_SCHEDULE, vars)
             .await?;
         match data.time_schedule_update.time_schedule {
-            Some(s) => Ok(format!("Time schedule updated:\n\n{}", format::format_time_schedule(&s))),
+            Some(s) => Ok(Output::new(
+                format!(
+                    "Time schedule updated:\n\n{}",
+                    format::format_time_schedule(&s)
+                ),
+                s,
+            )),
             None => Err(Error::GraphQL("Time schedule update failed".into())),
         }
     }
@@ -10067,14 +13040,17 @@ impl LinearMcp {
     async fn handle_delete_time_schedule(
         &self,
         params: delete_time_schedule::DeleteTimeScheduleParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::DeleteTimeScheduleData = self
             .client
-            .execute_json(queries::DELETE_TIME_SCHEDULE, vars)
+            .execute(queries::DELETE_TIME_SCHEDULE, vars)
             .await?;
         if data.time_schedule_delete.success {
-            Ok(format!("Time schedule {} deleted.", params.id))
+            Ok(Output::new(
+                format!("Time schedule {} deleted.", params.id),
+                data.time_schedule_delete,
+            ))
         } else {
             Err(Error::GraphQL("Time schedule deletion failed".into()))
         }
@@ -10085,38 +13061,47 @@ impl LinearMcp {
     async fn handle_list_triage_responsibilities(
         &self,
         params: list_triage_responsibilities::ListTriageResponsibilitiesParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::NodeList<types::TriageResponsibility>>, Error> {
         let first = params.limit.unwrap_or(50);
         let vars = serde_json::json!({ "first": first });
         let data: response::TriageResponsibilitiesData = self
             .client
-            .execute_json(queries::LIST_TRIAGE_RESPONSIBILITIES, vars)
+            .execute(queries::LIST_TRIAGE_RESPONSIBILITIES, vars)
             .await?;
         if data.triage_responsibilities.nodes.is_empty() {
-            return Ok("No triage responsibilities found.".into());
+            return Ok(Output::new(
+                "No triage responsibilities found.",
+                data.triage_responsibilities,
+            ));
         }
-        let lines: Vec<String> = data.triage_responsibilities.nodes.iter()
-            .map(|r| format::format_triage_responsibility(r))
+        let lines: Vec<String> = data
+            .triage_responsibilities
+            .nodes
+            .iter()
+            .map(format::format_triage_responsibility)
             .collect();
-        Ok(lines.join("\n"))
+        Ok(Output::new(lines.join("\n"), data.triage_responsibilities))
     }
 
     async fn handle_get_triage_responsibility(
         &self,
         params: get_triage_responsibility::GetTriageResponsibilityParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::TriageResponsibility>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::TriageResponsibilityData = self
             .client
-            .execute_json(queries::GET_TRIAGE_RESPONSIBILITY, vars)
+            .execute(queries::GET_TRIAGE_RESPONSIBILITY, vars)
             .await?;
-        Ok(format::format_triage_responsibility(&data.triage_responsibility))
+        Ok(Output::new(
+            format::format_triage_responsibility(&data.triage_responsibility),
+            data.triage_responsibility,
+        ))
     }
 
     async fn handle_create_triage_responsibility(
         &self,
         params: create_triage_responsibility::CreateTriageResponsibilityParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::TriageResponsibility>, Error> {
         let team_id = self.resolve_team_id(&params.team).await?;
         let mut input = serde_json::json!({
             "teamId": team_id,
@@ -10128,18 +13113,26 @@ impl LinearMcp {
         let vars = serde_json::json!({ "input": input });
         let data: response::CreateTriageResponsibilityData = self
             .client
-            .execute_json(queries::CREATE_TRIAGE_RESPONSIBILITY, vars)
+            .execute(queries::CREATE_TRIAGE_RESPONSIBILITY, vars)
             .await?;
         match data.triage_responsibility_create.triage_responsibility {
-            Some(r) => Ok(format!("Triage responsibility created:\n\n{}", format::format_triage_responsibility(&r))),
-            None => Err(Error::GraphQL("Triage responsibility creation failed".into())),
+            Some(r) => Ok(Output::new(
+                format!(
+                    "Triage responsibility created:\n\n{}",
+                    format::format_triage_responsibility(&r)
+                ),
+                r,
+            )),
+            None => Err(Error::GraphQL(
+                "Triage responsibility creation failed".into(),
+            )),
         }
     }
 
     async fn handle_update_triage_responsibility(
         &self,
         params: update_triage_responsibility::UpdateTriageResponsibilityParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::TriageResponsibility>, Error> {
         let mut input = serde_json::Map::new();
         let mut has_fields = false;
         if let Some(ref action) = params.action {
@@ -10147,19 +13140,29 @@ impl LinearMcp {
             has_fields = true;
         }
         if let Some(ref ts_id) = params.time_schedule_id {
-            input.insert("timeScheduleId".into(), serde_json::Value::String(ts_id.clone()));
+            input.insert(
+                "timeScheduleId".into(),
+                serde_json::Value::String(ts_id.clone()),
+            );
             has_fields = true;
         }
         if !has_fields {
             return Err(Error::InvalidInput("No fields to update.".into()));
         }
-        let vars = serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
+        let vars =
+            serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
         let data: response::UpdateTriageResponsibilityData = self
             .client
-            .execute_json(queries::UPDATE_TRIAGE_RESPONSIBILITY, vars)
+            .execute(queries::UPDATE_TRIAGE_RESPONSIBILITY, vars)
             .await?;
         match data.triage_responsibility_update.triage_responsibility {
-            Some(r) => Ok(format!("Triage responsibility updated:\n\n{}", format::format_triage_responsibility(&r))),
+            Some(r) => Ok(Output::new(
+                format!(
+                    "Triage responsibility updated:\n\n{}",
+                    format::format_triage_responsibility(&r)
+                ),
+                r,
+            )),
             None => Err(Error::GraphQL("Triage responsibility update failed".into())),
         }
     }
@@ -10167,16 +13170,21 @@ impl LinearMcp {
     async fn handle_delete_triage_responsibility(
         &self,
         params: delete_triage_responsibility::DeleteTriageResponsibilityParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::DeleteTriageResponsibilityData = self
             .client
-            .execute_json(queries::DELETE_TRIAGE_RESPONSIBILITY, vars)
+            .execute(queries::DELETE_TRIAGE_RESPONSIBILITY, vars)
             .await?;
         if data.triage_responsibility_delete.success {
-            Ok(format!("Triage responsibility {} deleted.", params.id))
+            Ok(Output::new(
+                format!("Triage responsibility {} deleted.", params.id),
+                data.triage_responsibility_delete,
+            ))
         } else {
-            Err(Error::GraphQL("Triage responsibility deletion failed".into()))
+            Err(Error::GraphQL(
+                "Triage responsibility deletion failed".into(),
+            ))
         }
     }
 
@@ -10185,7 +13193,7 @@ impl LinearMcp {
     async fn handle_create_git_automation_state(
         &self,
         params: create_git_automation_state::CreateGitAutomationStateParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::GitAutomationState>, Error> {
         let team_id = self.resolve_team_id(&params.team).await?;
         let mut input = serde_json::json!({
             "teamId": team_id,
@@ -10200,26 +13208,40 @@ impl LinearMcp {
         let vars = serde_json::json!({ "input": input });
         let data: response::CreateGitAutomationStateData = self
             .client
-            .execute_json(queries::CREATE_GIT_AUTOMATION_STATE, vars)
+            .execute(queries::CREATE_GIT_AUTOMATION_STATE, vars)
             .await?;
         match data.git_automation_state_create.git_automation_state {
-            Some(s) => Ok(format!("Git automation state created:\n\n{}", format::format_git_automation_state(&s))),
-            None => Err(Error::GraphQL("Git automation state creation failed".into())),
+            Some(s) => Ok(Output::new(
+                format!(
+                    "Git automation state created:\n\n{}",
+                    format::format_git_automation_state(&s)
+                ),
+                s,
+            )),
+            None => Err(Error::GraphQL(
+                "Git automation state creation failed".into(),
+            )),
         }
     }
 
     async fn handle_update_git_automation_state(
         &self,
         params: update_git_automation_state::UpdateGitAutomationStateParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::GitAutomationState>, Error> {
         let mut input = serde_json::Map::new();
         let mut has_fields = false;
         if let Some(ref state_id) = params.state_id {
-            input.insert("stateId".into(), serde_json::Value::String(state_id.clone()));
+            input.insert(
+                "stateId".into(),
+                serde_json::Value::String(state_id.clone()),
+            );
             has_fields = true;
         }
         if let Some(ref tb_id) = params.target_branch_id {
-            input.insert("targetBranchId".into(), serde_json::Value::String(tb_id.clone()));
+            input.insert(
+                "targetBranchId".into(),
+                serde_json::Value::String(tb_id.clone()),
+            );
             has_fields = true;
         }
         if let Some(ref event) = params.event {
@@ -10229,13 +13251,20 @@ impl LinearMcp {
         if !has_fields {
             return Err(Error::InvalidInput("No fields to update.".into()));
         }
-        let vars = serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
+        let vars =
+            serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
         let data: response::UpdateGitAutomationStateData = self
             .client
-            .execute_json(queries::UPDATE_GIT_AUTOMATION_STATE, vars)
+            .execute(queries::UPDATE_GIT_AUTOMATION_STATE, vars)
             .await?;
         match data.git_automation_state_update.git_automation_state {
-            Some(s) => Ok(format!("Git automation state updated:\n\n{}", format::format_git_automation_state(&s))),
+            Some(s) => Ok(Output::new(
+                format!(
+                    "Git automation state updated:\n\n{}",
+                    format::format_git_automation_state(&s)
+                ),
+                s,
+            )),
             None => Err(Error::GraphQL("Git automation state update failed".into())),
         }
     }
@@ -10243,23 +13272,28 @@ impl LinearMcp {
     async fn handle_delete_git_automation_state(
         &self,
         params: delete_git_automation_state::DeleteGitAutomationStateParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::DeleteGitAutomationStateData = self
             .client
-            .execute_json(queries::DELETE_GIT_AUTOMATION_STATE, vars)
+            .execute(queries::DELETE_GIT_AUTOMATION_STATE, vars)
             .await?;
         if data.git_automation_state_delete.success {
-            Ok(format!("Git automation state {} deleted.", params.id))
+            Ok(Output::new(
+                format!("Git automation state {} deleted.", params.id),
+                data.git_automation_state_delete,
+            ))
         } else {
-            Err(Error::GraphQL("Git automation state deletion failed".into()))
+            Err(Error::GraphQL(
+                "Git automation state deletion failed".into(),
+            ))
         }
     }
 
     async fn handle_create_git_automation_target_branch(
         &self,
         params: create_git_automation_target_branch::CreateGitAutomationTargetBranchParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::GitAutomationTargetBranch>, Error> {
         let team_id = self.resolve_team_id(&params.team).await?;
         let mut input = serde_json::json!({
             "teamId": team_id,
@@ -10271,22 +13305,33 @@ impl LinearMcp {
         let vars = serde_json::json!({ "input": input });
         let data: response::CreateGitAutomationTargetBranchData = self
             .client
-            .execute_json(queries::CREATE_GIT_AUTOMATION_TARGET_BRANCH, vars)
+            .execute(queries::CREATE_GIT_AUTOMATION_TARGET_BRANCH, vars)
             .await?;
         match data.git_automation_target_branch_create.target_branch {
-            Some(b) => Ok(format!("Git automation target branch created:\n\n{}", format::format_git_automation_target_branch(&b))),
-            None => Err(Error::GraphQL("Git automation target branch creation failed".into())),
+            Some(b) => Ok(Output::new(
+                format!(
+                    "Git automation target branch created:\n\n{}",
+                    format::format_git_automation_target_branch(&b)
+                ),
+                b,
+            )),
+            None => Err(Error::GraphQL(
+                "Git automation target branch creation failed".into(),
+            )),
         }
     }
 
     async fn handle_update_git_automation_target_branch(
         &self,
         params: update_git_automation_target_branch::UpdateGitAutomationTargetBranchParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::GitAutomationTargetBranch>, Error> {
         let mut input = serde_json::Map::new();
         let mut has_fields = false;
         if let Some(ref pattern) = params.branch_pattern {
-            input.insert("branchPattern".into(), serde_json::Value::String(pattern.clone()));
+            input.insert(
+                "branchPattern".into(),
+                serde_json::Value::String(pattern.clone()),
+            );
             has_fields = true;
         }
         if let Some(is_regex) = params.is_regex {
@@ -10296,30 +13341,44 @@ impl LinearMcp {
         if !has_fields {
             return Err(Error::InvalidInput("No fields to update.".into()));
         }
-        let vars = serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
+        let vars =
+            serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
         let data: response::UpdateGitAutomationTargetBranchData = self
             .client
-            .execute_json(queries::UPDATE_GIT_AUTOMATION_TARGET_BRANCH, vars)
+            .execute(queries::UPDATE_GIT_AUTOMATION_TARGET_BRANCH, vars)
             .await?;
         match data.git_automation_target_branch_update.target_branch {
-            Some(b) => Ok(format!("Git automation target branch updated:\n\n{}", format::format_git_automation_target_branch(&b))),
-            None => Err(Error::GraphQL("Git automation target branch update failed".into())),
+            Some(b) => Ok(Output::new(
+                format!(
+                    "Git automation target branch updated:\n\n{}",
+                    format::format_git_automation_target_branch(&b)
+                ),
+                b,
+            )),
+            None => Err(Error::GraphQL(
+                "Git automation target branch update failed".into(),
+            )),
         }
     }
 
     async fn handle_delete_git_automation_target_branch(
         &self,
         params: delete_git_automation_target_branch::DeleteGitAutomationTargetBranchParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::DeleteGitAutomationTargetBranchData = self
             .client
-            .execute_json(queries::DELETE_GIT_AUTOMATION_TARGET_BRANCH, vars)
+            .execute(queries::DELETE_GIT_AUTOMATION_TARGET_BRANCH, vars)
             .await?;
         if data.git_automation_target_branch_delete.success {
-            Ok(format!("Git automation target branch {} deleted.", params.id))
+            Ok(Output::new(
+                format!("Git automation target branch {} deleted.", params.id),
+                data.git_automation_target_branch_delete,
+            ))
         } else {
-            Err(Error::GraphQL("Git automation target branch deletion failed".into()))
+            Err(Error::GraphQL(
+                "Git automation target branch deletion failed".into(),
+            ))
         }
     }
 
@@ -10328,26 +13387,32 @@ impl LinearMcp {
     async fn handle_get_email_intake_address(
         &self,
         params: get_email_intake_address::GetEmailIntakeAddressParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::EmailIntakeAddress>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::EmailIntakeAddressData = self
             .client
-            .execute_json(queries::GET_EMAIL_INTAKE_ADDRESS, vars)
+            .execute(queries::GET_EMAIL_INTAKE_ADDRESS, vars)
             .await?;
-        Ok(format::format_email_intake_address(&data.email_intake_address))
+        Ok(Output::new(
+            format::format_email_intake_address(&data.email_intake_address),
+            data.email_intake_address,
+        ))
     }
 
     async fn handle_create_email_intake_address(
         &self,
         params: create_email_intake_address::CreateEmailIntakeAddressParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::EmailIntakeAddress>, Error> {
         let mut input = serde_json::Map::new();
         if let Some(ref team) = params.team {
             let team_id = self.resolve_team_id(team).await?;
             input.insert("teamId".into(), serde_json::Value::String(team_id));
         }
         if let Some(ref template) = params.template {
-            input.insert("templateId".into(), serde_json::Value::String(template.clone()));
+            input.insert(
+                "templateId".into(),
+                serde_json::Value::String(template.clone()),
+            );
         }
         if let Some(ref name) = params.sender_name {
             input.insert("senderName".into(), serde_json::Value::String(name.clone()));
@@ -10356,23 +13421,34 @@ impl LinearMcp {
             input.insert("repliesEnabled".into(), serde_json::json!(replies));
         }
         if let Some(customer) = params.customer_requests_enabled {
-            input.insert("customerRequestsEnabled".into(), serde_json::json!(customer));
+            input.insert(
+                "customerRequestsEnabled".into(),
+                serde_json::json!(customer),
+            );
         }
         let vars = serde_json::json!({ "input": serde_json::Value::Object(input) });
         let data: response::CreateEmailIntakeAddressData = self
             .client
-            .execute_json(queries::CREATE_EMAIL_INTAKE_ADDRESS, vars)
+            .execute(queries::CREATE_EMAIL_INTAKE_ADDRESS, vars)
             .await?;
         match data.email_intake_address_create.email_intake_address {
-            Some(a) => Ok(format!("Email intake address created:\n\n{}", format::format_email_intake_address(&a))),
-            None => Err(Error::GraphQL("Email intake address creation failed".into())),
+            Some(a) => Ok(Output::new(
+                format!(
+                    "Email intake address created:\n\n{}",
+                    format::format_email_intake_address(&a)
+                ),
+                a,
+            )),
+            None => Err(Error::GraphQL(
+                "Email intake address creation failed".into(),
+            )),
         }
     }
 
     async fn handle_update_email_intake_address(
         &self,
         params: update_email_intake_address::UpdateEmailIntakeAddressParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::EmailIntakeAddress>, Error> {
         let mut input = serde_json::Map::new();
         let mut has_fields = false;
         if let Some(enabled) = params.enabled {
@@ -10389,7 +13465,10 @@ impl LinearMcp {
             has_fields = true;
         }
         if let Some(ref template) = params.template {
-            input.insert("templateId".into(), serde_json::Value::String(template.clone()));
+            input.insert(
+                "templateId".into(),
+                serde_json::Value::String(template.clone()),
+            );
             has_fields = true;
         }
         if let Some(replies) = params.replies_enabled {
@@ -10397,19 +13476,29 @@ impl LinearMcp {
             has_fields = true;
         }
         if let Some(customer) = params.customer_requests_enabled {
-            input.insert("customerRequestsEnabled".into(), serde_json::json!(customer));
+            input.insert(
+                "customerRequestsEnabled".into(),
+                serde_json::json!(customer),
+            );
             has_fields = true;
         }
         if !has_fields {
             return Err(Error::InvalidInput("No fields to update.".into()));
         }
-        let vars = serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
+        let vars =
+            serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
         let data: response::UpdateEmailIntakeAddressData = self
             .client
-            .execute_json(queries::UPDATE_EMAIL_INTAKE_ADDRESS, vars)
+            .execute(queries::UPDATE_EMAIL_INTAKE_ADDRESS, vars)
             .await?;
         match data.email_intake_address_update.email_intake_address {
-            Some(a) => Ok(format!("Email intake address updated:\n\n{}", format::format_email_intake_address(&a))),
+            Some(a) => Ok(Output::new(
+                format!(
+                    "Email intake address updated:\n\n{}",
+                    format::format_email_intake_address(&a)
+                ),
+                a,
+            )),
             None => Err(Error::GraphQL("Email intake address update failed".into())),
         }
     }
@@ -10417,16 +13506,21 @@ impl LinearMcp {
     async fn handle_delete_email_intake_address(
         &self,
         params: delete_email_intake_address::DeleteEmailIntakeAddressParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::SuccessResult>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::DeleteEmailIntakeAddressData = self
             .client
-            .execute_json(queries::DELETE_EMAIL_INTAKE_ADDRESS, vars)
+            .execute(queries::DELETE_EMAIL_INTAKE_ADDRESS, vars)
             .await?;
         if data.email_intake_address_delete.success {
-            Ok(format!("Email intake address {} deleted.", params.id))
+            Ok(Output::new(
+                format!("Email intake address {} deleted.", params.id),
+                data.email_intake_address_delete,
+            ))
         } else {
-            Err(Error::GraphQL("Email intake address deletion failed".into()))
+            Err(Error::GraphQL(
+                "Email intake address deletion failed".into(),
+            ))
         }
     }
 
@@ -10435,67 +13529,88 @@ impl LinearMcp {
     async fn handle_list_archived_teams(
         &self,
         params: list_archived_teams::ListArchivedTeamsParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::NodeList<types::TeamDetail>>, Error> {
         let data: response::ArchivedTeamsData = self
             .client
-            .execute::<(), _>(queries::LIST_ARCHIVED_TEAMS, None)
+            .execute(queries::LIST_ARCHIVED_TEAMS, serde_json::json!({}))
             .await?;
-        if data.archived_teams.is_empty() {
-            return Ok("No archived teams found.".into());
+        let mut teams = data.archived_teams;
+        if teams.is_empty() {
+            return Ok(Output::new(
+                "No archived teams found.",
+                types::NodeList { nodes: teams },
+            ));
         }
         let limit = params.limit.unwrap_or(50).max(1) as usize;
-        let lines: Vec<String> = data.archived_teams.iter()
-            .take(limit)
-            .map(|t| format::format_team_detail(t))
-            .collect();
-        Ok(lines.join("\n"))
+        teams.truncate(limit);
+        let lines: Vec<String> = teams.iter().map(format::format_team_detail).collect();
+        Ok(Output::new(
+            lines.join("\n"),
+            types::NodeList { nodes: teams },
+        ))
     }
 
-    async fn handle_get_rate_limit_status(&self) -> Result<String, Error> {
+    async fn handle_get_rate_limit_status(&self) -> Result<Output<types::RateLimitStatus>, Error> {
         let data: response::RateLimitStatusData = self
             .client
-            .execute::<(), _>(queries::GET_RATE_LIMIT_STATUS, None)
+            .execute(queries::GET_RATE_LIMIT_STATUS, serde_json::json!({}))
             .await?;
-        Ok(format::format_rate_limit_status(&data.rate_limit_status))
+        let mut text = format::format_rate_limit_status(&data.rate_limit_status);
+        let budget = self.client.rate_limit_budget();
+        if !budget.is_empty() {
+            text.push_str("\n\n");
+            text.push_str(&format::format_rate_limit_budget(&budget));
+        }
+        Ok(Output::new(text, data.rate_limit_status))
     }
 
-    async fn handle_get_organization(&self) -> Result<String, Error> {
+    async fn handle_get_organization(&self) -> Result<Output<types::Organization>, Error> {
         let data: response::OrganizationData = self
             .client
-            .execute::<(), _>(queries::GET_ORGANIZATION, None)
+            .execute(queries::GET_ORGANIZATION, serde_json::json!({}))
             .await?;
-        Ok(format::format_organization(&data.organization))
+        Ok(Output::new(
+            format::format_organization(&data.organization),
+            data.organization,
+        ))
     }
 
     async fn handle_get_application_info(
         &self,
         params: get_application_info::GetApplicationInfoParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::ApplicationInfo>, Error> {
         let vars = serde_json::json!({ "clientId": params.client_id });
         let data: response::ApplicationInfoData = self
             .client
-            .execute_json(queries::GET_APPLICATION_INFO, vars)
+            .execute(queries::GET_APPLICATION_INFO, vars)
             .await?;
-        Ok(format::format_application_info(&data.application_info))
+        Ok(Output::new(
+            format::format_application_info(&data.application_info),
+            data.application_info,
+        ))
     }
 
     async fn handle_semantic_search(
         &self,
         params: semantic_search::SemanticSearchParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<response::SemanticSearchPayload>, Error> {
         let max_results = params.limit.unwrap_or(25);
         let vars = serde_json::json!({ "query": params.query, "maxResults": max_results });
-        let data: response::SemanticSearchData = self
-            .client
-            .execute_json(queries::SEMANTIC_SEARCH, vars)
-            .await?;
+        let data: response::SemanticSearchData =
+            self.client.execute(queries::SEMANTIC_SEARCH, vars).await?;
         if data.semantic_search.results.is_empty() {
-            return Ok("No results found.".into());
+            return Ok(Output::new("No results found.", data.semantic_search));
         }
-        let lines: Vec<String> = data.semantic_search.results.iter()
+        let lines: Vec<String> = data
+            .semantic_search
+            .results
+            .iter()
             .map(|r| {
                 if let Some(ref issue) = r.issue {
-                    format!("[{}] {} {} [id: {}]", r.result_type, issue.identifier, issue.title, issue.id)
+                    format!(
+                        "[{}] {} {} [id: {}]",
+                        r.result_type, issue.identifier, issue.title, issue.id
+                    )
                 } else if let Some(ref project) = r.project {
                     format!("[{}] {} [id: {}]", r.result_type, project.name, project.id)
                 } else if let Some(ref doc) = r.document {
@@ -10505,13 +13620,13 @@ impl LinearMcp {
                 }
             })
             .collect();
-        Ok(lines.join("\n"))
+        Ok(Output::new(lines.join("\n"), data.semantic_search))
     }
 
     async fn handle_attach_link_url(
         &self,
         params: attach_link_url::AttachLinkUrlParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::AttachmentMutationResult>, Error> {
         let issue_id = self.resolve_issue_id(&params.issue).await?;
         let mut vars = serde_json::json!({
             "issueId": issue_id,
@@ -10520,12 +13635,13 @@ impl LinearMcp {
         if let Some(ref title) = params.title {
             vars["title"] = serde_json::Value::String(title.clone());
         }
-        let data: response::AttachLinkUrlData = self
-            .client
-            .execute_json(queries::ATTACH_LINK_URL, vars)
-            .await?;
+        let data: response::AttachLinkUrlData =
+            self.client.execute(queries::ATTACH_LINK_URL, vars).await?;
         if data.attachment_link_url.success {
-            Ok(format!("URL attached to issue {}.", params.issue))
+            Ok(Output::new(
+                format!("URL attached to issue {}.", params.issue),
+                data.attachment_link_url,
+            ))
         } else {
             Err(Error::GraphQL("Attach link URL failed".into()))
         }
@@ -10534,49 +13650,69 @@ impl LinearMcp {
     async fn handle_get_attachments_for_url(
         &self,
         params: get_attachments_for_url::GetAttachmentsForUrlParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::NodeList<types::Attachment>>, Error> {
         let vars = serde_json::json!({ "url": params.url });
         let data: response::AttachmentsForUrlData = self
             .client
-            .execute_json(queries::GET_ATTACHMENTS_FOR_URL, vars)
+            .execute(queries::GET_ATTACHMENTS_FOR_URL, vars)
             .await?;
         if data.attachments_for_url.nodes.is_empty() {
-            return Ok("No attachments found for this URL.".into());
+            return Ok(Output::new(
+                "No attachments found for this URL.",
+                data.attachments_for_url,
+            ));
         }
-        let lines: Vec<String> = data.attachments_for_url.nodes.iter()
-            .map(|a| format::format_attachment(a))
+        let lines: Vec<String> = data
+            .attachments_for_url
+            .nodes
+            .iter()
+            .map(format::format_attachment)
             .collect();
-        Ok(lines.join("\n\n"))
+        Ok(Output::new(lines.join("\n\n"), data.attachments_for_url))
     }
 
     async fn handle_get_issue_filter_suggestion(
         &self,
         params: get_issue_filter_suggestion::GetIssueFilterSuggestionParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<response::FilterSuggestionResult>, Error> {
         let vars = serde_json::json!({ "prompt": params.prompt });
         let data: response::IssueFilterSuggestionData = self
             .client
-            .execute_json(queries::GET_ISSUE_FILTER_SUGGESTION, vars)
+            .execute(queries::GET_ISSUE_FILTER_SUGGESTION, vars)
             .await?;
-        Ok(format!("Filter: {}", serde_json::to_string_pretty(&data.issue_filter_suggestion.filter).unwrap_or_default()))
+        Ok(Output::new(
+            format!(
+                "Filter: {}",
+                serde_json::to_string_pretty(&data.issue_filter_suggestion.filter)
+                    .unwrap_or_default()
+            ),
+            data.issue_filter_suggestion,
+        ))
     }
 
     async fn handle_get_project_filter_suggestion(
         &self,
         params: get_project_filter_suggestion::GetProjectFilterSuggestionParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<response::FilterSuggestionResult>, Error> {
         let vars = serde_json::json!({ "prompt": params.prompt });
         let data: response::ProjectFilterSuggestionData = self
             .client
-            .execute_json(queries::GET_PROJECT_FILTER_SUGGESTION, vars)
+            .execute(queries::GET_PROJECT_FILTER_SUGGESTION, vars)
             .await?;
-        Ok(format!("Filter: {}", serde_json::to_string_pretty(&data.project_filter_suggestion.filter).unwrap_or_default()))
+        Ok(Output::new(
+            format!(
+                "Filter: {}",
+                serde_json::to_string_pretty(&data.project_filter_suggestion.filter)
+                    .unwrap_or_default()
+            ),
+            data.project_filter_suggestion,
+        ))
     }
 
     async fn handle_get_custom_view_suggestion(
         &self,
         params: get_custom_view_suggestion::GetCustomViewSuggestionParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<response::CustomViewSuggestionResult>, Error> {
         let filter = params.filter.unwrap_or(serde_json::json!({}));
         if !filter.is_object() {
             return Err(Error::InvalidInput("filter must be a JSON object".into()));
@@ -10584,7 +13720,7 @@ impl LinearMcp {
         let vars = serde_json::json!({ "modelName": params.model_name, "filter": filter });
         let data: response::CustomViewSuggestionData = self
             .client
-            .execute_json(queries::GET_CUSTOM_VIEW_SUGGESTION, vars)
+            .execute(queries::GET_CUSTOM_VIEW_SUGGESTION, vars)
             .await?;
         let s = &data.custom_view_details_suggestion;
         let mut lines = Vec::new();
@@ -10597,40 +13733,51 @@ impl LinearMcp {
         if let Some(ref icon) = s.icon {
             lines.push(format!("Icon: {}", icon));
         }
-        Ok(lines.join("\n"))
+        Ok(Output::new(
+            lines.join("\n"),
+            data.custom_view_details_suggestion,
+        ))
     }
 
     async fn handle_check_custom_view_has_subscribers(
         &self,
         params: check_custom_view_has_subscribers::CheckCustomViewHasSubscribersParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<response::CustomViewHasSubscribersPayload>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::CustomViewHasSubscribersData = self
             .client
-            .execute_json(queries::CHECK_CUSTOM_VIEW_HAS_SUBSCRIBERS, vars)
+            .execute(queries::CHECK_CUSTOM_VIEW_HAS_SUBSCRIBERS, vars)
             .await?;
-        Ok(format!("Has subscribers: {}", data.custom_view_has_subscribers.has_subscribers))
+        Ok(Output::new(
+            format!(
+                "Has subscribers: {}",
+                data.custom_view_has_subscribers.has_subscribers
+            ),
+            data.custom_view_has_subscribers,
+        ))
     }
 
     async fn handle_search_issue_figma_file_key(
         &self,
         params: search_issue_figma_file_key::SearchIssueFigmaFileKeyParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::NodeList<types::Issue>>, Error> {
         let vars = serde_json::json!({ "fileKey": params.file_key });
         let data: response::SearchIssueFigmaFileKeyData = self
             .client
-            .execute_json(queries::SEARCH_ISSUE_FIGMA_FILE_KEY, vars)
+            .execute(queries::SEARCH_ISSUE_FIGMA_FILE_KEY, vars)
             .await?;
-        match data.issue_figma_file_key_search {
-            Some(issue) => Ok(format::format_issue_summary(&issue)),
-            None => Ok("No issues found for this Figma file key.".into()),
-        }
+        let text = match data.issue_figma_file_key_search {
+            Some(ref issue) => format::format_issue_summary(issue),
+            None => "No issues found for this Figma file key.".into(),
+        };
+        let nodes = data.issue_figma_file_key_search.into_iter().collect();
+        Ok(Output::new(text, types::NodeList { nodes }))
     }
 
     async fn handle_update_initiative_update(
         &self,
         params: update_initiative_update::UpdateInitiativeUpdateParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::InitiativeUpdateMutationResult>, Error> {
         let mut input = serde_json::Map::new();
         let mut has_fields = false;
         if let Some(ref body) = params.body {
@@ -10644,13 +13791,17 @@ impl LinearMcp {
         if !has_fields {
             return Err(Error::InvalidInput("No fields to update.".into()));
         }
-        let vars = serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
+        let vars =
+            serde_json::json!({ "id": params.id, "input": serde_json::Value::Object(input) });
         let data: response::UpdateInitiativeUpdateData = self
             .client
-            .execute_json(queries::UPDATE_INITIATIVE_UPDATE_MUTATION, vars)
+            .execute(queries::UPDATE_INITIATIVE_UPDATE_MUTATION, vars)
             .await?;
         if data.initiative_update_update.success {
-            Ok(format!("Initiative update {} updated.", params.id))
+            Ok(Output::new(
+                format!("Initiative update {} updated.", params.id),
+                data.initiative_update_update,
+            ))
         } else {
             Err(Error::GraphQL("Initiative update update failed".into()))
         }
@@ -10659,82 +13810,98 @@ impl LinearMcp {
     async fn handle_list_comments_all(
         &self,
         params: list_comments_all::ListCommentsAllParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::NodeList<types::Comment>>, Error> {
         let first = params.limit.unwrap_or(50);
         let vars = serde_json::json!({ "first": first });
         let data: response::ListCommentsAllData = self
             .client
-            .execute_json(queries::LIST_COMMENTS_ALL, vars)
+            .execute(queries::LIST_COMMENTS_ALL, vars)
             .await?;
         if data.comments.nodes.is_empty() {
-            return Ok("No comments found.".into());
+            return Ok(Output::new("No comments found.", data.comments));
         }
-        let lines: Vec<String> = data.comments.nodes.iter()
-            .map(|c| format::format_comment_detail(c))
+        let lines: Vec<String> = data
+            .comments
+            .nodes
+            .iter()
+            .map(format::format_comment_detail)
             .collect();
-        Ok(lines.join("\n\n"))
+        Ok(Output::new(lines.join("\n\n"), data.comments))
     }
 
     async fn handle_get_issue_label(
         &self,
         params: get_issue_label::GetIssueLabelParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::Label>, Error> {
         let vars = serde_json::json!({ "id": params.id });
-        let data: response::GetIssueLabelData = self
-            .client
-            .execute_json(queries::GET_ISSUE_LABEL, vars)
-            .await?;
-        Ok(format::format_label(&data.issue_label))
+        let data: response::GetIssueLabelData =
+            self.client.execute(queries::GET_ISSUE_LABEL, vars).await?;
+        Ok(Output::new(
+            format::format_label(&data.issue_label),
+            data.issue_label,
+        ))
     }
 
     async fn handle_get_issue_relation(
         &self,
         params: get_issue_relation::GetIssueRelationParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::IssueRelation>, Error> {
         let vars = serde_json::json!({ "id": params.id });
         let data: response::GetIssueRelationData = self
             .client
-            .execute_json(queries::GET_ISSUE_RELATION, vars)
+            .execute(queries::GET_ISSUE_RELATION, vars)
             .await?;
-        Ok(format::format_issue_relation(&data.issue_relation))
+        Ok(Output::new(
+            format::format_issue_relation(&data.issue_relation),
+            data.issue_relation,
+        ))
     }
 
     async fn handle_list_issue_relations(
         &self,
         params: list_issue_relations::ListIssueRelationsParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::NodeList<types::IssueRelation>>, Error> {
         let first = params.limit.unwrap_or(50);
         let vars = serde_json::json!({ "first": first });
         let data: response::ListIssueRelationsData = self
             .client
-            .execute_json(queries::LIST_ISSUE_RELATIONS, vars)
+            .execute(queries::LIST_ISSUE_RELATIONS, vars)
             .await?;
         if data.issue_relations.nodes.is_empty() {
-            return Ok("No issue relations found.".into());
+            return Ok(Output::new(
+                "No issue relations found.",
+                data.issue_relations,
+            ));
         }
-        let lines: Vec<String> = data.issue_relations.nodes.iter()
-            .map(|r| format::format_issue_relation(r))
+        let lines: Vec<String> = data
+            .issue_relations
+            .nodes
+            .iter()
+            .map(format::format_issue_relation)
             .collect();
-        Ok(lines.join("\n"))
+        Ok(Output::new(lines.join("\n"), data.issue_relations))
     }
 
     async fn handle_list_external_users(
         &self,
         params: list_external_users::ListExternalUsersParams,
-    ) -> Result<String, Error> {
+    ) -> Result<Output<types::NodeList<types::ExternalUser>>, Error> {
         let first = params.limit.unwrap_or(50);
         let vars = serde_json::json!({ "first": first });
         let data: response::ExternalUsersData = self
             .client
-            .execute_json(queries::LIST_EXTERNAL_USERS, vars)
+            .execute(queries::LIST_EXTERNAL_USERS, vars)
             .await?;
         if data.external_users.nodes.is_empty() {
-            return Ok("No external users found.".into());
+            return Ok(Output::new("No external users found.", data.external_users));
         }
-        let lines: Vec<String> = data.external_users.nodes.iter()
-            .map(|u| format::format_external_user(u))
+        let lines: Vec<String> = data
+            .external_users
+            .nodes
+            .iter()
+            .map(format::format_external_user)
             .collect();
-        Ok(lines.join("\n"))
+        Ok(Output::new(lines.join("\n"), data.external_users))
     }
 }
 
@@ -10755,7 +13922,11 @@ fn chrono_now_iso() -> String {
     let mut y = 1970i64;
     let mut remaining = days as i64;
     loop {
-        let days_in_year = if y % 4 == 0 && (y % 100 != 0 || y % 400 == 0) { 366 } else { 365 };
+        let days_in_year = if y % 4 == 0 && (y % 100 != 0 || y % 400 == 0) {
+            366
+        } else {
+            365
+        };
         if remaining < days_in_year {
             break;
         }
@@ -10766,7 +13937,16 @@ fn chrono_now_iso() -> String {
     let month_days: [i64; 12] = [
         31,
         if leap { 29 } else { 28 },
-        31, 30, 31, 30, 31, 31, 30, 31, 30, 31,
+        31,
+        30,
+        31,
+        30,
+        31,
+        31,
+        30,
+        31,
+        30,
+        31,
     ];
     let mut m = 0usize;
     for (i, &md) in month_days.iter().enumerate() {
@@ -10788,6 +13968,115 @@ fn chrono_now_iso() -> String {
     )
 }
 
+/// Variables for the `ResolveIssues` queries that look up `identifiers`,
+/// at most 250 (Linear's page limit) per query.
+fn resolve_issues_pages(identifiers: &[String]) -> Vec<serde_json::Value> {
+    const PAGE: usize = 250;
+    identifiers
+        .chunks(PAGE)
+        .map(|chunk| {
+            let or: Vec<serde_json::Value> = chunk
+                .iter()
+                .filter_map(|id| Identifier::parse(id))
+                .map(|id| {
+                    serde_json::json!({
+                        "team": { "key": { "eq": id.team_key } },
+                        "number": { "eq": id.number },
+                    })
+                })
+                .collect();
+            serde_json::json!({ "filter": { "or": or }, "first": chunk.len() })
+        })
+        .collect()
+}
+
+/// Tool error with the message as text and, in `_meta`, a machine-readable
+/// `code` plus whether retrying later may help. Not `structuredContent`:
+/// that must match the tool's `outputSchema`, which describes successes.
 fn error_result(err: &Error) -> CallToolResult {
-    CallToolResult::error(vec![Content::text(format!("Error: {}", err))])
+    let mut meta = Meta::new();
+    meta.insert("error".into(), error_data(err));
+    CallToolResult {
+        content: vec![Content::text(format!("Error [{}]: {}", err.code(), err))],
+        structured_content: None,
+        is_error: Some(true),
+        meta: Some(meta),
+    }
+}
+
+/// `list_issues` parameters from the JSON a client would send.
+fn list_issues_args(args: serde_json::Value) -> list_issues::ListIssuesParams {
+    serde_json::from_value(args).expect("valid list_issues arguments")
+}
+
+fn parse_resource(uri: &str) -> Result<LinearResource, McpError> {
+    LinearResource::parse(uri)
+        .ok_or_else(|| McpError::resource_not_found(format!("unknown resource '{}'", uri), None))
+}
+
+/// A failed prompt as a JSON-RPC error: bad or unknown arguments are
+/// invalid params, anything else an internal error.
+fn request_error(err: Error) -> McpError {
+    let data = Some(error_data(&err));
+    match err {
+        Error::NotFound(_)
+        | Error::Validation { .. }
+        | Error::InvalidInput(_)
+        | Error::Ambiguous { .. } => McpError::invalid_params(err.to_string(), data),
+        _ => McpError::internal_error(err.to_string(), data),
+    }
+}
+
+/// A failed resource read or subscription as a JSON-RPC error.
+fn resource_error(err: Error) -> McpError {
+    match err {
+        Error::NotFound(_) => {
+            let data = Some(error_data(&err));
+            McpError::resource_not_found(err.to_string(), data)
+        }
+        err => request_error(err),
+    }
+}
+
+/// The machine-readable form of an error: its code, whether a retry may
+/// help, and any offending field, retry delay or candidates.
+fn error_data(err: &Error) -> serde_json::Value {
+    let mut error = serde_json::json!({
+        "code": err.code(),
+        "retryable": err.is_retryable(),
+        "message": err.to_string(),
+    });
+    match err {
+        Error::Validation {
+            field: Some(field), ..
+        } => error["field"] = field.as_str().into(),
+        Error::RateLimited {
+            retry_after: Some(secs),
+            ..
+        } => error["retryAfterSeconds"] = (*secs).into(),
+        Error::Ambiguous { candidates, .. } => error["candidates"] = candidates.clone().into(),
+        _ => {}
+    }
+    error
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn issue_lookups_are_split_into_pages() {
+        let identifiers: Vec<String> = (1..=600).map(|n| format!("ENG-{n}")).collect();
+        let pages = resolve_issues_pages(&identifiers);
+        let sizes: Vec<u64> = pages.iter().map(|p| p["first"].as_u64().unwrap()).collect();
+        assert_eq!(sizes, [250, 250, 100]);
+        let filters: Vec<&serde_json::Value> = pages
+            .iter()
+            .flat_map(|p| p["filter"]["or"].as_array().unwrap())
+            .collect();
+        assert_eq!(filters.len(), 600);
+        assert_eq!(filters[250]["number"]["eq"], 251);
+        assert_eq!(filters[599]["team"]["key"]["eq"], "ENG");
+        assert!(resolve_issues_pages(&[]).is_empty());
+    }
 }
@@ -0,0 +1,48 @@
+//! Resource subscriptions of one MCP session. Linear has no way to push
+//! changes to a client like this one, so subscribed resources are polled.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+/// How often subscribed resources are checked when the profile doesn't say.
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Subscribed resource URIs, each with the version of it last seen.
+#[derive(Debug, Default)]
+pub struct Subscriptions {
+    watched: Mutex<HashMap<String, String>>,
+    polling: AtomicBool,
+}
+
+impl Subscriptions {
+    pub fn insert(&self, uri: String, version: String) {
+        self.watched.lock().unwrap().insert(uri, version);
+    }
+
+    pub fn remove(&self, uri: &str) {
+        self.watched.lock().unwrap().remove(uri);
+    }
+
+    pub fn uris(&self) -> Vec<String> {
+        self.watched.lock().unwrap().keys().cloned().collect()
+    }
+
+    /// Record the latest `version` of `uri`, returning whether it changed.
+    /// A URI unsubscribed from meanwhile never counts as changed.
+    pub fn update(&self, uri: &str, version: String) -> bool {
+        match self.watched.lock().unwrap().get_mut(uri) {
+            Some(seen) if *seen != version => {
+                *seen = version;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether the caller should start the poll loop: true only the first time.
+    pub fn start_polling(&self) -> bool {
+        !self.polling.swap(true, Ordering::SeqCst)
+    }
+}
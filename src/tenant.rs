@@ -0,0 +1,228 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use axum::http::{header::AUTHORIZATION, request::Parts};
+use rmcp::{RoleServer, service::RequestContext};
+use sha2::{Digest, Sha256};
+
+use crate::client::LinearClient;
+
+/// Key in the `initialize` request's `_meta` object that carries a Linear token.
+pub const INIT_META_KEY: &str = "linearApiKey";
+
+/// Most tenants kept at once; the least recently used one makes room.
+const MAX_TENANTS: usize = 1024;
+
+/// Tenants unused for this long are forgotten.
+const IDLE_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// A token's SHA-256 digest, so the registry holds no secrets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TokenKey([u8; 32]);
+
+impl TokenKey {
+    fn of(token: &str) -> Self {
+        Self(Sha256::digest(token.as_bytes()).into())
+    }
+}
+
+/// Linear client bound to a single API token. Its resolver cache lives in
+/// the client.
+#[derive(Clone)]
+pub struct Tenant {
+    pub key: TokenKey,
+    pub client: LinearClient,
+}
+
+struct Entry {
+    tenant: Tenant,
+    last_used: Instant,
+}
+
+/// Hands out one `Tenant` per distinct token so that cached lookups and
+/// viewer identity never cross between users of a shared server.
+///
+/// A tenant is only kept once Linear has accepted its token (see
+/// [`TenantRegistry::keep`]), so requests with made-up tokens leave nothing
+/// behind.
+#[derive(Clone)]
+pub struct TenantRegistry {
+    /// Client whose settings (connection pool, read-only mode, cache limits)
+    /// every tenant inherits.
+    template: LinearClient,
+    tenants: Arc<Mutex<HashMap<TokenKey, Entry>>>,
+    /// When set, requests without their own token are rejected instead of
+    /// falling back to the server-wide key.
+    require_session_key: bool,
+}
+
+impl TenantRegistry {
+    pub fn new(template: LinearClient, require_session_key: bool) -> Self {
+        Self {
+            template,
+            tenants: Arc::default(),
+            require_session_key,
+        }
+    }
+
+    pub fn requires_session_key(&self) -> bool {
+        self.require_session_key
+    }
+
+    /// The tenant kept for `token`, or a new one with an empty cache that is
+    /// only kept if [`keep`](Self::keep) is called once it has been used.
+    pub fn get(&self, token: &str) -> Tenant {
+        let key = TokenKey::of(token);
+        let mut tenants = self.tenants.lock().unwrap_or_else(|e| e.into_inner());
+        match tenants.get_mut(&key) {
+            Some(entry) if entry.last_used.elapsed() < IDLE_TTL => {
+                entry.last_used = Instant::now();
+                return entry.tenant.clone();
+            }
+            Some(_) => {
+                tenants.remove(&key);
+            }
+            None => {}
+        }
+        Tenant {
+            key,
+            client: self.template.with_api_key(token.to_string()),
+        }
+    }
+
+    /// Keep `tenant` for later requests with its token, if Linear has
+    /// accepted the token. Idle tenants are dropped first, then the least
+    /// recently used one when the registry is full.
+    pub fn keep(&self, tenant: &Tenant) {
+        if !tenant.client.is_authenticated() {
+            return;
+        }
+        let mut tenants = self.tenants.lock().unwrap_or_else(|e| e.into_inner());
+        if tenants.contains_key(&tenant.key) {
+            return;
+        }
+        // New tenants are rare (one per accepted token), so a scan is fine.
+        tenants.retain(|_, entry| entry.last_used.elapsed() < IDLE_TTL);
+        if tenants.len() >= MAX_TENANTS
+            && let Some(oldest) = tenants
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| *key)
+        {
+            tenants.remove(&oldest);
+        }
+        tenants.insert(
+            tenant.key,
+            Entry {
+                tenant: tenant.clone(),
+                last_used: Instant::now(),
+            },
+        );
+    }
+
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.tenants.lock().unwrap().len()
+    }
+}
+
+/// Extract the caller's Linear token from a request's `Authorization` header.
+pub fn request_token(context: &RequestContext<RoleServer>) -> Option<String> {
+    context
+        .extensions
+        .get::<Parts>()
+        .and_then(|parts| parts.headers.get(AUTHORIZATION))
+        .and_then(|value| value.to_str().ok())
+        .and_then(normalize_authorization)
+}
+
+/// Extract a Linear token from the `linearApiKey` entry of an `initialize`
+/// request's `_meta`.
+pub fn init_token(context: &RequestContext<RoleServer>) -> Option<String> {
+    context
+        .meta
+        .get(INIT_META_KEY)
+        .and_then(|value| value.as_str())
+        .and_then(normalize_authorization)
+}
+
+/// Turn a header value into what Linear expects in its `Authorization` header.
+///
+/// Personal API keys (`lin_api_…`) are sent bare; anything else is treated as
+/// an OAuth access token and sent with a `Bearer` prefix.
+fn normalize_authorization(value: &str) -> Option<String> {
+    let value = value.trim();
+    let token = value
+        .strip_prefix("Bearer ")
+        .or_else(|| value.strip_prefix("bearer "))
+        .unwrap_or(value)
+        .trim();
+    if token.is_empty() {
+        None
+    } else if token.starts_with("lin_api_") {
+        Some(token.to_string())
+    } else {
+        Some(format!("Bearer {}", token))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::ClientOptions;
+
+    fn registry() -> TenantRegistry {
+        let client = LinearClient::new(String::new(), &ClientOptions::default()).unwrap();
+        TenantRegistry::new(client, false)
+    }
+
+    fn accept(registry: &TenantRegistry, token: &str) {
+        let tenant = registry.get(token);
+        tenant.client.mark_authenticated();
+        registry.keep(&tenant);
+    }
+
+    fn is_kept(registry: &TenantRegistry, token: &str) -> bool {
+        registry.get(token).client.is_authenticated()
+    }
+
+    #[test]
+    fn unaccepted_tokens_are_not_kept() {
+        let registry = registry();
+        let tenant = registry.get("lin_api_made_up");
+        registry.keep(&tenant);
+        assert_eq!(registry.len(), 0);
+    }
+
+    #[test]
+    fn accepted_tokens_are_kept_by_digest() {
+        let registry = registry();
+        accept(&registry, "lin_api_good");
+        assert!(is_kept(&registry, "lin_api_good"));
+        assert!(!is_kept(&registry, "lin_api_other"));
+        assert_eq!(registry.len(), 1);
+        assert_eq!(
+            TokenKey::of("lin_api_good"),
+            registry.get("lin_api_good").key
+        );
+        assert_ne!(TokenKey::of("lin_api_good"), TokenKey::of("lin_api_other"));
+    }
+
+    #[test]
+    fn least_recently_used_tenant_makes_room() {
+        let registry = registry();
+        for n in 0..MAX_TENANTS {
+            accept(&registry, &format!("lin_api_{n}"));
+        }
+        assert!(is_kept(&registry, "lin_api_0"));
+        accept(&registry, "lin_api_new");
+        assert_eq!(registry.len(), MAX_TENANTS);
+        assert!(is_kept(&registry, "lin_api_new"));
+        assert!(is_kept(&registry, "lin_api_0"));
+        let dropped = (1..MAX_TENANTS)
+            .filter(|n| !is_kept(&registry, &format!("lin_api_{n}")))
+            .count();
+        assert_eq!(dropped, 1);
+    }
+}
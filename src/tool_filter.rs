@@ -0,0 +1,233 @@
+/// Named tool profiles. Entries use the same syntax as `--tools`: exact tool
+/// names or globs.
+const PROFILES: &[(&str, &[&str])] = &[
+    (
+        "core",
+        &[
+            "list_issues",
+            "search_issues",
+            "get_issue",
+            "my_issues",
+            "create_issue",
+            "update_issue",
+            "add_comment",
+            "list_comments",
+            "list_teams",
+            "list_users",
+            "get_viewer",
+            "list_states",
+            "list_labels",
+            "list_projects",
+            "get_project",
+            "search_projects",
+            "list_cycles",
+            "get_cycle",
+            "list_documents",
+            "get_document",
+            "search_documents",
+        ],
+    ),
+    (
+        "triage",
+        &[
+            "list_triage_issues",
+            "triage_issue",
+            "list_issues",
+            "search_issues",
+            "get_issue",
+            "get_issue_history",
+            "update_issue",
+            "add_comment",
+            "list_comments",
+            "add_issue_label",
+            "remove_issue_label",
+            "create_issue_relation",
+            "list_states",
+            "list_labels",
+            "list_teams",
+            "list_users",
+            "list_triage_responsibilities",
+            "get_triage_responsibility",
+        ],
+    ),
+    (
+        "admin",
+        &[
+            "*_team",
+            "list_archived_teams",
+            "*team_membership*",
+            "*workflow_state*",
+            "*template*",
+            "*webhook*",
+            "*emoji*",
+            "*git_automation*",
+            "*email_intake*",
+            "*time_schedule*",
+            "*triage_responsibilit*",
+            "list_integrations",
+            "query_audit_log",
+            "list_users",
+            "get_user",
+            "update_user",
+            "list_external_users",
+            "get_organization",
+            "get_application_info",
+            "get_rate_limit_status",
+        ],
+    ),
+    (
+        "releases",
+        &["*release*", "list_issues", "search_issues", "get_issue"],
+    ),
+];
+
+/// Selects which tools the server exposes.
+///
+/// Both lists hold tool names, globs (`*` and `?`), or profile names. An
+/// empty include list means "every tool"; excludes always win.
+#[derive(Debug, Clone, Default)]
+pub struct ToolFilter {
+    include: Vec<String>,
+    exclude: Vec<String>,
+}
+
+impl ToolFilter {
+    pub fn new<I, E>(include: I, exclude: E) -> Self
+    where
+        I: IntoIterator<Item = String>,
+        E: IntoIterator<Item = String>,
+    {
+        Self {
+            include: expand_profiles(include),
+            exclude: expand_profiles(exclude),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.include.is_empty() && self.exclude.is_empty()
+    }
+
+    pub fn allows(&self, tool: &str) -> bool {
+        let included = self.include.is_empty() || self.include.iter().any(|p| glob_match(p, tool));
+        included && !self.exclude.iter().any(|p| glob_match(p, tool))
+    }
+
+    /// Patterns (after profile expansion) that match none of `tools`, so
+    /// typos can be reported instead of silently hiding everything.
+    pub fn unmatched<'a>(&'a self, tools: &[&str]) -> Vec<&'a str> {
+        self.include
+            .iter()
+            .chain(&self.exclude)
+            .filter(|p| !tools.iter().any(|t| glob_match(p, t)))
+            .map(String::as_str)
+            .collect()
+    }
+}
+
+/// Split a comma-separated list, dropping blanks.
+pub fn split_list(list: &str) -> Vec<String> {
+    list.split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+pub fn profile_names() -> impl Iterator<Item = &'static str> {
+    PROFILES.iter().map(|(name, _)| *name)
+}
+
+fn expand_profiles<I: IntoIterator<Item = String>>(patterns: I) -> Vec<String> {
+    let mut out = Vec::new();
+    for pattern in patterns {
+        match PROFILES.iter().find(|(name, _)| *name == pattern) {
+            Some((_, tools)) => out.extend(tools.iter().map(|t| t.to_string())),
+            None => out.push(pattern),
+        }
+    }
+    out
+}
+
+/// Match `name` against a glob supporting `*` (any run) and `?` (any one char).
+fn glob_match(pattern: &str, name: &str) -> bool {
+    let p: Vec<char> = pattern.chars().collect();
+    let n: Vec<char> = name.chars().collect();
+    let (mut pi, mut ni) = (0, 0);
+    // Position of the last `*` seen and the name index it was tried at.
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while ni < n.len() {
+        if pi < p.len() && (p[pi] == '?' || p[pi] == n[ni]) {
+            pi += 1;
+            ni += 1;
+        } else if pi < p.len() && p[pi] == '*' {
+            backtrack = Some((pi, ni));
+            pi += 1;
+        } else if let Some((star, matched)) = backtrack {
+            pi = star + 1;
+            ni = matched + 1;
+            backtrack = Some((star, matched + 1));
+        } else {
+            return false;
+        }
+    }
+    p[pi..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filter(include: &str, exclude: &str) -> ToolFilter {
+        ToolFilter::new(split_list(include), split_list(exclude))
+    }
+
+    #[test]
+    fn globs_match_whole_names() {
+        assert!(glob_match("list_issues", "list_issues"));
+        assert!(!glob_match("list_issue", "list_issues"));
+        assert!(glob_match("list_*", "list_issues"));
+        assert!(glob_match("*_issue", "get_issue"));
+        assert!(!glob_match("*_issue", "get_issues"));
+        assert!(glob_match("*release*", "release"));
+        assert!(glob_match("get_?ser", "get_user"));
+        assert!(!glob_match("get_?ser", "get_sers"));
+    }
+
+    #[test]
+    fn globs_handle_empty_and_repeated_stars() {
+        assert!(glob_match("", ""));
+        assert!(!glob_match("", "a"));
+        assert!(glob_match("*", ""));
+        assert!(glob_match("**", "anything"));
+        assert!(!glob_match("?", ""));
+        // A later `*` has to backtrack past an earlier partial match.
+        assert!(glob_match("*a*b", "xaxaxb"));
+        assert!(!glob_match("*a*b", "xaxaxbx"));
+        assert!(glob_match("a*a*a", "aaa"));
+        assert!(!glob_match("a*a*a", "aa"));
+    }
+
+    #[test]
+    fn profiles_expand_and_excludes_win() {
+        let core = filter("core", "");
+        assert!(core.allows("get_issue"));
+        assert!(!core.allows("delete_issue"));
+
+        let admin = filter("admin", "delete_*");
+        assert!(admin.allows("create_team"));
+        assert!(admin.allows("list_webhooks"));
+        assert!(!admin.allows("delete_team"));
+
+        let everything = filter("", "*_webhook");
+        assert!(everything.allows("list_issues"));
+        assert!(!everything.allows("delete_webhook"));
+    }
+
+    #[test]
+    fn patterns_matching_nothing_are_reported() {
+        let filter = filter("list_isues, get_*", "delete_issue");
+        let tools = ["list_issues", "get_issue", "delete_issue"];
+        assert_eq!(filter.unmatched(&tools), ["list_isues"]);
+    }
+}
@@ -6,7 +6,7 @@ pub struct AddIssueToCycleParams {
     /// Issue identifier (e.g. 'ENG-123') or UUID
     #[serde(rename = "issueId")]
     pub issue_id: String,
-    /// Cycle UUID
+    /// Cycle: UUID, 'current', 'next', 'previous', number or name (in the issue's team)
     #[serde(rename = "cycleId")]
     pub cycle_id: String,
 }
@@ -5,6 +5,6 @@ use serde::Deserialize;
 pub struct BatchCreateIssuesParams {
     /// JSON array of issue objects
     pub issues: String,
-    /// Team key for all issues
-    pub team: String,
+    /// Team key for all issues. Defaults to the profile's default_team
+    pub team: Option<String>,
 }
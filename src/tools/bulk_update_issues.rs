@@ -7,7 +7,7 @@ pub struct BulkUpdateIssuesParams {
     pub ids: String,
     /// New state/status name (e.g. "In Progress", "Done")
     pub state: Option<String>,
-    /// Assignee email address (use "none" to unassign)
+    /// Assignee: email, name, "me" or UUID (use "none" to unassign)
     pub assignee: Option<String>,
     /// Priority: urgent, high, normal, low, none
     pub priority: Option<String>,
@@ -19,7 +19,7 @@ pub struct BulkUpdateIssuesParams {
     pub remove_labels: Option<String>,
     /// Project name or UUID to move issues to
     pub project: Option<String>,
-    /// Cycle UUID to assign issues to
+    /// Cycle to assign issues to: UUID, 'current', 'next', 'previous', number or name
     pub cycle: Option<String>,
     /// Team key to move issues to (e.g. "ENG")
     pub team: Option<String>,
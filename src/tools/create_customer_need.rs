@@ -10,6 +10,9 @@ pub struct CreateCustomerNeedParams {
     /// Need description
     pub body: Option<String>,
     /// Priority (0=none, 1=urgent, 2=high, 3=medium, 4=low)
-    #[serde(default, deserialize_with = "super::serde_helpers::u32_from_str_or_num")]
+    #[serde(
+        default,
+        deserialize_with = "super::serde_helpers::u32_from_str_or_num"
+    )]
     pub priority: Option<u32>,
 }
@@ -3,14 +3,14 @@ use serde::Deserialize;
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct CreateCycleParams {
-    /// Team key (e.g. 'ENG') — required
-    pub team: String,
+    /// Team key (e.g. 'ENG'). Defaults to the profile's default_team
+    pub team: Option<String>,
     /// Cycle name
     pub name: Option<String>,
-    /// Start date (ISO format, e.g. '2025-01-01')
+    /// Start date (e.g. '2025-01-01', 'next monday', or an ISO timestamp)
     #[serde(rename = "startsAt")]
     pub starts_at: String,
-    /// End date (ISO format, e.g. '2025-01-15')
+    /// End date (e.g. '2025-01-15', '2w', 'end of month', or an ISO timestamp)
     #[serde(rename = "endsAt")]
     pub ends_at: String,
 }
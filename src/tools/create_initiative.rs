@@ -9,9 +9,9 @@ pub struct CreateInitiativeParams {
     pub description: Option<String>,
     /// Status: Planned, Active, or Completed
     pub status: Option<String>,
-    /// Owner email address
+    /// Owner: email, name, "me" or UUID
     pub owner: Option<String>,
-    /// Target completion date (YYYY-MM-DD)
+    /// Target completion date (e.g. '2025-12-31', 'Q4', 'end of year')
     #[serde(rename = "targetDate")]
     pub target_date: Option<String>,
 }
@@ -5,22 +5,25 @@ use super::list_issues::PriorityLevel;
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct CreateIssueParams {
-    /// Team key (e.g. 'ENG') — required
-    pub team: String,
+    /// Team key (e.g. 'ENG'). Defaults to the profile's default_team
+    pub team: Option<String>,
     /// Issue title
     pub title: String,
     /// Issue description (markdown)
     pub description: Option<String>,
-    /// Assignee email address
+    /// Assignee: email, name, "me" or UUID
     pub assignee: Option<String>,
     /// Workflow state name (e.g. 'Todo', 'In Progress')
     pub status: Option<String>,
     /// Priority level
     pub priority: Option<PriorityLevel>,
     /// Point estimate
-    #[serde(default, deserialize_with = "super::serde_helpers::f64_from_str_or_num")]
+    #[serde(
+        default,
+        deserialize_with = "super::serde_helpers::f64_from_str_or_num"
+    )]
     pub estimate: Option<f64>,
-    /// Due date (ISO format, e.g. '2025-03-01')
+    /// Due date (e.g. '2025-03-01', 'tomorrow', 'next friday', '2w', 'end of cycle')
     #[serde(rename = "dueDate")]
     pub due_date: Option<String>,
     /// Comma-separated label names to apply (e.g. 'Bug, Frontend')
@@ -32,9 +35,9 @@ pub struct CreateIssueParams {
     /// Project milestone name (resolved against the issue's project)
     #[serde(rename = "projectMilestone")]
     pub project_milestone: Option<String>,
-    /// Cycle UUID to assign the issue to
+    /// Cycle to assign the issue to: UUID, 'current', 'next', 'previous', number or name
     #[serde(rename = "cycleId")]
     pub cycle_id: Option<String>,
-    /// Comma-separated subscriber emails to add
+    /// Comma-separated subscribers to add (emails, names or "me")
     pub subscribers: Option<String>,
 }
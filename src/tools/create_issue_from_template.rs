@@ -3,16 +3,16 @@ use serde::Deserialize;
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct CreateIssueFromTemplateParams {
-    /// Template ID (UUID) — use list_templates to find available templates
+    /// Exact template name or UUID — use list_templates to find available templates
     #[serde(rename = "templateId")]
     pub template_id: String,
-    /// Team key (e.g. "ENG") — required
-    pub team: String,
+    /// Team key (e.g. 'ENG'). Defaults to the profile's default_team
+    pub team: Option<String>,
     /// Override the template's default title
     pub title: Option<String>,
     /// Override the template's default description
     pub description: Option<String>,
-    /// Assignee email address
+    /// Assignee: email, name, "me" or UUID
     pub assignee: Option<String>,
     /// Priority: urgent, high, normal, low, none
     pub priority: Option<String>,
@@ -9,12 +9,12 @@ pub struct CreateProjectParams {
     pub description: Option<String>,
     /// Comma-separated team keys (e.g. 'ENG, DESIGN')
     pub teams: Option<String>,
-    /// Lead email address
+    /// Lead: email, name, "me" or UUID
     pub lead: Option<String>,
-    /// Target date (ISO format, e.g. '2025-06-01')
+    /// Target date (e.g. '2025-06-01', 'end of Q3', 'next month')
     #[serde(rename = "targetDate")]
     pub target_date: Option<String>,
-    /// Start date (ISO format)
+    /// Start date (e.g. '2025-04-01', 'next monday', 'Q3')
     #[serde(rename = "startDate")]
     pub start_date: Option<String>,
 }
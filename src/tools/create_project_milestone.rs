@@ -9,7 +9,7 @@ pub struct CreateProjectMilestoneParams {
     pub name: String,
     /// Milestone description
     pub description: Option<String>,
-    /// Target date (ISO format)
+    /// Target date (e.g. '2025-06-01', 'in 2 weeks', 'end of cycle')
     #[serde(rename = "targetDate")]
     pub target_date: Option<String>,
 }
@@ -14,10 +14,10 @@ pub struct CreateReleaseParams {
     /// Commit SHA
     #[serde(rename = "commitSha")]
     pub commit_sha: Option<String>,
-    /// Start date (ISO format)
+    /// Start date (e.g. '2025-04-01', 'today')
     #[serde(rename = "startDate")]
     pub start_date: Option<String>,
-    /// Target date (ISO format)
+    /// Target date (e.g. '2025-06-01', 'friday', 'end of cycle')
     #[serde(rename = "targetDate")]
     pub target_date: Option<String>,
 }
@@ -3,6 +3,6 @@ use serde::Deserialize;
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct DeleteDocumentParams {
-    /// Document UUID
+    /// Document title or UUID
     pub id: String,
 }
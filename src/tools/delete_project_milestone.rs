@@ -3,6 +3,6 @@ use serde::Deserialize;
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct DeleteProjectMilestoneParams {
-    /// Project milestone UUID
+    /// Project milestone name or UUID
     pub id: String,
 }
@@ -3,6 +3,6 @@ use serde::Deserialize;
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct DeleteViewParams {
-    /// Custom view UUID
+    /// Custom view name or UUID
     pub id: String,
 }
@@ -3,6 +3,8 @@ use serde::Deserialize;
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct GetCycleParams {
-    /// Cycle UUID
+    /// Cycle: UUID, 'current', 'next', 'previous', number (e.g. '12') or name
     pub id: String,
+    /// Team key the cycle belongs to, when not given as a UUID. Defaults to the profile's default_team
+    pub team: Option<String>,
 }
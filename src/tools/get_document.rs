@@ -3,6 +3,6 @@ use serde::Deserialize;
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct GetDocumentParams {
-    /// Document UUID
+    /// Document title or UUID
     pub id: String,
 }
@@ -3,6 +3,6 @@ use serde::Deserialize;
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct GetProjectMilestoneParams {
-    /// UUID of the project milestone to retrieve
+    /// Name or UUID of the project milestone to retrieve
     pub id: String,
 }
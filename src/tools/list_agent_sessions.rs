@@ -4,6 +4,9 @@ use serde::Deserialize;
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct ListAgentSessionsParams {
     /// Max results (default 25)
-    #[serde(default, deserialize_with = "super::serde_helpers::u32_from_str_or_num")]
+    #[serde(
+        default,
+        deserialize_with = "super::serde_helpers::u32_from_str_or_num"
+    )]
     pub limit: Option<u32>,
 }
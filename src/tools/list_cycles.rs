@@ -3,9 +3,12 @@ use serde::Deserialize;
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct ListCyclesParams {
-    /// Team key (e.g. 'ENG') — required
-    pub team: String,
+    /// Team key (e.g. 'ENG'). Defaults to the profile's default_team
+    pub team: Option<String>,
     /// Max results (default 25)
-    #[serde(default, deserialize_with = "super::serde_helpers::u32_from_str_or_num")]
+    #[serde(
+        default,
+        deserialize_with = "super::serde_helpers::u32_from_str_or_num"
+    )]
     pub limit: Option<u32>,
 }
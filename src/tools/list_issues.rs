@@ -5,20 +5,45 @@ use serde::Deserialize;
 pub struct ListIssuesParams {
     /// Team key (e.g. 'ENG')
     pub team: Option<String>,
-    /// Assignee email or display name
+    /// Assignee email or display name, or 'me'. Like the other name filters
+    /// below, takes 'none' for issues without one, 'any' for issues with
+    /// one, and 'not <name>' to exclude a value.
     pub assignee: Option<String>,
-    /// Creator email or display name
+    /// Creator email or display name, or 'me'
     pub creator: Option<String>,
-    /// Workflow state name (e.g. 'In Progress', 'Done')
+    /// Workflow state name (e.g. 'In Progress', 'not Done')
     pub status: Option<String>,
-    /// Project name
+    /// Project name, or part of it
     pub project: Option<String>,
-    /// Label name
+    /// Label name (e.g. 'Bug', 'not Bug', 'none')
     pub label: Option<String>,
+    /// Cycle: 'current', 'next', 'previous', a number, or a name
+    pub cycle: Option<String>,
+    /// Project milestone name
+    pub milestone: Option<String>,
+    /// Parent issue identifier (e.g. 'ENG-123'); 'none' for top-level issues
+    pub parent: Option<String>,
+    /// Subscriber email or display name, or 'me'
+    pub subscriber: Option<String>,
+    /// Name of a customer that requested the issue
+    pub customer: Option<String>,
+    /// SLA status: breached, high risk, medium risk, low risk, completed, failed
+    pub sla: Option<String>,
+    /// Filter to issues in (true) or out of (false) triage
+    pub triage: Option<bool>,
+    /// Filter to issues with (true) or without (false) an estimate
+    #[serde(rename = "hasEstimate")]
+    pub has_estimate: Option<bool>,
+    /// Filter to issues with (true) or without (false) attachments
+    #[serde(rename = "hasAttachments")]
+    pub has_attachments: Option<bool>,
     /// Priority level
     pub priority: Option<PriorityLevel>,
     /// Filter by exact estimate value
-    #[serde(default, deserialize_with = "super::serde_helpers::f64_from_str_or_num")]
+    #[serde(
+        default,
+        deserialize_with = "super::serde_helpers::f64_from_str_or_num"
+    )]
     pub estimate: Option<f64>,
     /// Filter to issues that are blocked by another issue
     #[serde(rename = "hasBlockedByRelation")]
@@ -30,43 +55,58 @@ pub struct ListIssuesParams {
     #[serde(rename = "orderBy")]
     pub order_by: Option<OrderBy>,
     /// Max results (default 25)
-    #[serde(default, deserialize_with = "super::serde_helpers::u32_from_str_or_num")]
+    #[serde(
+        default,
+        deserialize_with = "super::serde_helpers::u32_from_str_or_num"
+    )]
     pub limit: Option<u32>,
     /// Pagination cursor from a previous response
     pub cursor: Option<String>,
-    /// Filter issues due before this date (ISO format, e.g. '2025-03-01')
+    /// Filter issues due before this date (e.g. '2025-03-01', 'friday', 'end of month', 'end of cycle')
     #[serde(rename = "dueBefore")]
     pub due_before: Option<String>,
-    /// Filter issues due after this date (ISO format, e.g. '2025-01-01')
+    /// Filter issues due after this date (e.g. '2025-01-01', 'today', 'Q3')
     #[serde(rename = "dueAfter")]
     pub due_after: Option<String>,
-    /// Filter issues created before this date (ISO format)
+    /// Filter issues created before this date (ISO date or timestamp, or e.g. 'last monday', '-30d')
     #[serde(rename = "createdBefore")]
     pub created_before: Option<String>,
-    /// Filter issues created after this date (ISO format)
+    /// Filter issues created after this date (ISO date or timestamp, or e.g. '-7d', 'this week')
     #[serde(rename = "createdAfter")]
     pub created_after: Option<String>,
-    /// Filter issues updated before this date (ISO format)
+    /// Filter issues updated before this date (ISO date or timestamp, or e.g. '-2w')
     #[serde(rename = "updatedBefore")]
     pub updated_before: Option<String>,
-    /// Filter issues updated after this date (ISO format)
+    /// Filter issues updated after this date (ISO date or timestamp, or e.g. '-24h', 'yesterday')
     #[serde(rename = "updatedAfter")]
     pub updated_after: Option<String>,
-    /// Filter issues completed before this date (ISO format)
+    /// Filter issues completed before this date (ISO date or timestamp, or e.g. 'start of month')
     #[serde(rename = "completedBefore")]
     pub completed_before: Option<String>,
-    /// Filter issues completed after this date (ISO format)
+    /// Filter issues completed after this date (ISO date or timestamp, or e.g. 'start of cycle', 'last quarter')
     #[serde(rename = "completedAfter")]
     pub completed_after: Option<String>,
-    /// Filter issues canceled before this date (ISO format)
+    /// Filter issues canceled before this date (ISO date or timestamp, or a relative date)
     #[serde(rename = "canceledBefore")]
     pub canceled_before: Option<String>,
-    /// Filter issues canceled after this date (ISO format)
+    /// Filter issues canceled after this date (ISO date or timestamp, or a relative date)
     #[serde(rename = "canceledAfter")]
     pub canceled_after: Option<String>,
-    /// Filter issues snoozed until after this date (ISO format)
+    /// Filter issues snoozed until after this date (ISO date or timestamp, or a relative date)
     #[serde(rename = "snoozedUntilAfter")]
     pub snoozed_until_after: Option<String>,
+    /// Filter expression, combined with the other filters. Conditions join
+    /// with and/or/not and parentheses, e.g. `priority >= high and (label in
+    /// (Bug, P0) or parent.state = "In Review") and assignee != me`. Fields:
+    /// title, number, priority, estimate, due, created, updated, started,
+    /// completed, canceled, team, assignee, creator, state, state.type,
+    /// project, project.state, project.lead, label, cycle, milestone,
+    /// subscriber, customer, sla, attachments, triage, blocked, blocking,
+    /// parent, and parent.<field>. Operators: = != < <= > >= ~ (contains) !~,
+    /// in (...), not in (...), is none, is not none. Dates take the same
+    /// forms as the date parameters (quote multi-word ones containing
+    /// 'and'/'or'); priorities compare by urgency.
+    pub filter: Option<String>,
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
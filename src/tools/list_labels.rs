@@ -6,6 +6,9 @@ pub struct ListLabelsParams {
     /// Team key to filter labels for a specific team
     pub team: Option<String>,
     /// Max results (default 50)
-    #[serde(default, deserialize_with = "super::serde_helpers::u32_from_str_or_num")]
+    #[serde(
+        default,
+        deserialize_with = "super::serde_helpers::u32_from_str_or_num"
+    )]
     pub limit: Option<u32>,
 }
@@ -10,7 +10,10 @@ pub struct ListProjectsParams {
     /// Filter by lead email or display name
     pub lead: Option<String>,
     /// Max results (default 50)
-    #[serde(default, deserialize_with = "super::serde_helpers::u32_from_str_or_num")]
+    #[serde(
+        default,
+        deserialize_with = "super::serde_helpers::u32_from_str_or_num"
+    )]
     pub limit: Option<u32>,
 }
 
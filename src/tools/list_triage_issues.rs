@@ -3,9 +3,12 @@ use serde::Deserialize;
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct ListTriageIssuesParams {
-    /// Team key (e.g. "ENG") — required since triage is per-team
-    pub team: String,
+    /// Team key (e.g. "ENG"). Defaults to the profile's default_team
+    pub team: Option<String>,
     /// Max results to return (default 50)
-    #[serde(default, deserialize_with = "super::serde_helpers::i32_from_str_or_num")]
+    #[serde(
+        default,
+        deserialize_with = "super::serde_helpers::i32_from_str_or_num"
+    )]
     pub limit: Option<i32>,
 }
@@ -12,8 +12,14 @@ pub struct MyIssuesParams {
     pub team: Option<String>,
     /// Filter by priority level
     pub priority: Option<PriorityLevel>,
+    /// Filter expression in list_issues' `filter` syntax, e.g. `due <
+    /// "end of week" or priority = urgent`
+    pub filter: Option<String>,
     /// Max results (default 50)
-    #[serde(default, deserialize_with = "super::serde_helpers::u32_from_str_or_num")]
+    #[serde(
+        default,
+        deserialize_with = "super::serde_helpers::u32_from_str_or_num"
+    )]
     pub limit: Option<u32>,
     /// Pagination cursor from a previous response
     pub cursor: Option<String>,
@@ -11,8 +11,14 @@ pub struct SearchIssuesParams {
     pub status: Option<String>,
     /// Filter by assignee email or name
     pub assignee: Option<String>,
+    /// Filter expression in list_issues' `filter` syntax, e.g. `label = Bug
+    /// and not state.type in (completed, canceled)`
+    pub filter: Option<String>,
     /// Max results (default 25)
-    #[serde(default, deserialize_with = "super::serde_helpers::u32_from_str_or_num")]
+    #[serde(
+        default,
+        deserialize_with = "super::serde_helpers::u32_from_str_or_num"
+    )]
     pub limit: Option<u32>,
     /// Pagination cursor from a previous response
     pub cursor: Option<String>,
@@ -5,6 +5,6 @@ use serde::Deserialize;
 pub struct SubscribeToIssueParams {
     /// Issue identifier (e.g. 'ENG-123') or UUID
     pub issue: String,
-    /// Optional user email to subscribe (defaults to the API key owner)
+    /// Optional user (email, name or UUID) to subscribe (defaults to the API key owner)
     pub user: Option<String>,
 }
@@ -7,7 +7,7 @@ pub struct TriageIssueParams {
     pub id: String,
     /// Target state name to move to (e.g. "Todo", "Backlog", "In Progress")
     pub state: String,
-    /// Assignee email address (optional)
+    /// Assignee: email, name, "me" or UUID (optional)
     pub assignee: Option<String>,
     /// Priority: urgent, high, normal, low, none (optional)
     pub priority: Option<String>,
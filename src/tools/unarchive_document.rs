@@ -3,6 +3,6 @@ use serde::Deserialize;
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct UnarchiveDocumentParams {
-    /// UUID of the document to unarchive
+    /// Title or UUID of the document to unarchive
     pub id: String,
 }
@@ -5,6 +5,6 @@ use serde::Deserialize;
 pub struct UnsubscribeFromIssueParams {
     /// Issue identifier (e.g. 'ENG-123') or UUID
     pub issue: String,
-    /// Optional user email to unsubscribe (defaults to the API key owner)
+    /// Optional user (email, name or UUID) to unsubscribe (defaults to the API key owner)
     pub user: Option<String>,
 }
@@ -3,16 +3,18 @@ use serde::Deserialize;
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct UpdateCycleParams {
-    /// Cycle UUID
+    /// Cycle: UUID, 'current', 'next', 'previous', number (e.g. '12') or name
     pub id: String,
+    /// Team key the cycle belongs to, when not given as a UUID. Defaults to the profile's default_team
+    pub team: Option<String>,
     /// New cycle name
     pub name: Option<String>,
     /// New description
     pub description: Option<String>,
-    /// New start date (ISO format, e.g. '2025-01-01')
+    /// New start date (e.g. '2025-01-01', 'next monday', or an ISO timestamp)
     #[serde(rename = "startsAt")]
     pub starts_at: Option<String>,
-    /// New end date (ISO format, e.g. '2025-01-15')
+    /// New end date (e.g. '2025-01-15', 'friday', 'end of month', or an ISO timestamp)
     #[serde(rename = "endsAt")]
     pub ends_at: Option<String>,
 }
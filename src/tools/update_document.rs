@@ -3,7 +3,7 @@ use serde::Deserialize;
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct UpdateDocumentParams {
-    /// Document UUID
+    /// Document title or UUID
     pub id: String,
     /// New title
     pub title: Option<String>,
@@ -11,9 +11,9 @@ pub struct UpdateInitiativeParams {
     pub description: Option<String>,
     /// Status: Planned, Active, or Completed
     pub status: Option<String>,
-    /// Owner email address (use "none" to unset)
+    /// Owner: email, name, "me" or UUID (use "none" to unset)
     pub owner: Option<String>,
-    /// Target completion date (YYYY-MM-DD)
+    /// Target completion date (e.g. '2025-12-31', 'Q4', 'end of year')
     #[serde(rename = "targetDate")]
     pub target_date: Option<String>,
 }
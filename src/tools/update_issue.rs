@@ -13,14 +13,17 @@ pub struct UpdateIssueParams {
     pub description: Option<String>,
     /// New workflow state name (e.g. 'In Progress', 'Done')
     pub status: Option<String>,
-    /// New assignee email (use 'none' to unassign)
+    /// New assignee: email, name, "me" or UUID (use 'none' to unassign)
     pub assignee: Option<String>,
     /// New priority level
     pub priority: Option<PriorityLevel>,
     /// New point estimate
-    #[serde(default, deserialize_with = "super::serde_helpers::f64_from_str_or_num")]
+    #[serde(
+        default,
+        deserialize_with = "super::serde_helpers::f64_from_str_or_num"
+    )]
     pub estimate: Option<f64>,
-    /// New due date (ISO format, or 'none' to clear)
+    /// New due date (e.g. '2025-03-01', 'friday', '+3d', 'end of cycle'), or 'none' to clear
     #[serde(rename = "dueDate")]
     pub due_date: Option<String>,
     /// Comma-separated label names to set (replaces existing labels)
@@ -32,9 +35,9 @@ pub struct UpdateIssueParams {
     /// Project milestone name (resolved against the issue's project)
     #[serde(rename = "projectMilestone")]
     pub project_milestone: Option<String>,
-    /// Cycle UUID to assign the issue to
+    /// Cycle to assign the issue to: UUID, 'current', 'next', 'previous', number or name
     #[serde(rename = "cycleId")]
     pub cycle_id: Option<String>,
-    /// Comma-separated subscriber emails to add
+    /// Comma-separated subscribers to add (emails, names or "me")
     pub subscribers: Option<String>,
 }
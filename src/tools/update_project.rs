@@ -11,12 +11,12 @@ pub struct UpdateProjectParams {
     pub description: Option<String>,
     /// New state (e.g. 'planned', 'started', 'paused', 'completed', 'canceled')
     pub state: Option<String>,
-    /// New lead email (use 'none' to remove lead)
+    /// New lead: email, name, "me" or UUID (use 'none' to remove lead)
     pub lead: Option<String>,
-    /// New target date (ISO format, or 'none' to clear)
+    /// New target date (e.g. '2025-06-01', 'Q3', 'end of month'), or 'none' to clear
     #[serde(rename = "targetDate")]
     pub target_date: Option<String>,
-    /// New start date (ISO format, or 'none' to clear)
+    /// New start date (e.g. '2025-04-01', 'next week'), or 'none' to clear
     #[serde(rename = "startDate")]
     pub start_date: Option<String>,
 }
@@ -3,13 +3,13 @@ use serde::Deserialize;
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct UpdateProjectMilestoneParams {
-    /// Project milestone UUID
+    /// Project milestone name or UUID
     pub id: String,
     /// New milestone name
     pub name: Option<String>,
     /// New description
     pub description: Option<String>,
-    /// New target date (ISO format, e.g. '2025-06-01')
+    /// New target date (e.g. '2025-06-01', 'next friday', 'end of month')
     #[serde(rename = "targetDate")]
     pub target_date: Option<String>,
 }
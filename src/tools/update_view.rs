@@ -3,7 +3,7 @@ use serde::Deserialize;
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct UpdateViewParams {
-    /// Custom view UUID
+    /// Custom view name or UUID
     pub id: String,
     /// New view name
     pub name: Option<String>,
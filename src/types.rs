@@ -1,7 +1,8 @@
 #![allow(dead_code)]
-use serde::Deserialize;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Issue {
     pub id: String,
@@ -43,7 +44,7 @@ pub struct Issue {
 }
 
 /// Lightweight project milestone reference.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ProjectMilestoneRef {
     pub id: String,
@@ -51,7 +52,7 @@ pub struct ProjectMilestoneRef {
 }
 
 /// Lightweight issue reference for parent/children links.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct IssueRef {
     pub identifier: String,
@@ -59,14 +60,14 @@ pub struct IssueRef {
 }
 
 /// Lightweight team reference (id + key).
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct TeamRef {
     pub id: String,
     pub key: String,
 }
 
 /// Lightweight user reference (for creator, subscribers).
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct UserRef {
     pub display_name: String,
@@ -74,7 +75,7 @@ pub struct UserRef {
 }
 
 /// Lightweight cycle reference (for issue detail).
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CycleRef {
     pub id: String,
@@ -83,13 +84,13 @@ pub struct CycleRef {
 }
 
 /// Project member reference (display name only, for list views).
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ProjectMemberRef {
     pub display_name: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct User {
     pub id: String,
@@ -100,7 +101,7 @@ pub struct User {
     pub active: Option<bool>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Team {
     pub id: String,
@@ -113,14 +114,14 @@ pub struct Team {
 }
 
 /// Default issue state reference for teams.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct TeamDefaultState {
     pub id: String,
     pub name: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct WorkflowState {
     pub id: String,
@@ -130,7 +131,7 @@ pub struct WorkflowState {
     pub color: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Project {
     pub id: String,
@@ -147,7 +148,7 @@ pub struct Project {
     pub members: Option<NodeList<ProjectMemberRef>>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Label {
     pub id: String,
@@ -158,14 +159,14 @@ pub struct Label {
 }
 
 /// Lightweight label reference for parent links.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct LabelRef {
     pub id: String,
     pub name: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Comment {
     pub id: String,
@@ -178,31 +179,31 @@ pub struct Comment {
 }
 
 /// Lightweight comment parent reference for threading.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CommentParentRef {
     pub id: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CommentUser {
     pub display_name: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct NodeList<T> {
     pub nodes: Vec<T>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct PageInfo {
     pub has_next_page: bool,
     pub end_cursor: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Connection<T> {
     pub nodes: Vec<T>,
@@ -210,7 +211,7 @@ pub struct Connection<T> {
 }
 
 /// Viewer (authenticated user) with minimal fields.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Viewer {
     pub id: String,
@@ -219,7 +220,7 @@ pub struct Viewer {
 }
 
 /// Cycle entity.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Cycle {
     pub id: String,
@@ -235,7 +236,7 @@ pub struct Cycle {
 }
 
 /// Lightweight issue reference for cycle issue lists.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CycleIssueRef {
     pub id: String,
@@ -245,7 +246,7 @@ pub struct CycleIssueRef {
 }
 
 /// Issue relation entity.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct IssueRelation {
     pub id: String,
@@ -256,26 +257,26 @@ pub struct IssueRelation {
 }
 
 /// Result of a create/update mutation.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct MutationResult {
     pub success: bool,
     pub issue: Option<Issue>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct CommentMutationResult {
     pub success: bool,
     pub comment: Option<Comment>,
 }
 
 /// Generic success result (for delete mutations, archive, etc.).
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct SuccessResult {
     pub success: bool,
 }
 
 /// Result for issue relation mutations.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct IssueRelationMutationResult {
     pub success: bool,
@@ -283,7 +284,7 @@ pub struct IssueRelationMutationResult {
 }
 
 /// Result for label mutations.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct LabelMutationResult {
     pub success: bool,
@@ -303,7 +304,7 @@ pub fn priority_label(priority: i32) -> &'static str {
 
 // ---- #17: Documents ----
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Document {
     pub id: String,
@@ -315,13 +316,13 @@ pub struct Document {
     pub creator: Option<DocumentCreator>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct DocumentProject {
     pub name: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct DocumentCreator {
     pub display_name: String,
@@ -329,7 +330,7 @@ pub struct DocumentCreator {
 
 // ---- #18: Project detail ----
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ProjectDetail {
     pub id: String,
@@ -348,14 +349,14 @@ pub struct ProjectDetail {
     pub members: Option<NodeList<ProjectMember>>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ProjectLead {
     pub id: String,
     pub display_name: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ProjectTeam {
     pub id: String,
@@ -363,7 +364,7 @@ pub struct ProjectTeam {
     pub name: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ProjectMember {
     pub id: String,
@@ -371,7 +372,7 @@ pub struct ProjectMember {
 }
 
 /// Result of a project create/update mutation.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct ProjectMutationResult {
     pub success: bool,
     pub project: Option<ProjectDetail>,
@@ -379,7 +380,7 @@ pub struct ProjectMutationResult {
 
 // ---- #19: Project updates ----
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ProjectUpdate {
     pub id: String,
@@ -389,13 +390,13 @@ pub struct ProjectUpdate {
     pub user: Option<ProjectUpdateUser>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ProjectUpdateUser {
     pub display_name: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct ProjectUpdateMutationResult {
     pub success: bool,
     #[serde(rename = "projectUpdate")]
@@ -404,7 +405,7 @@ pub struct ProjectUpdateMutationResult {
 
 // ---- #20: Project milestones ----
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ProjectMilestone {
     pub id: String,
@@ -414,7 +415,7 @@ pub struct ProjectMilestone {
     pub sort_order: Option<f64>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct ProjectMilestoneMutationResult {
     pub success: bool,
     #[serde(rename = "projectMilestone")]
@@ -423,7 +424,7 @@ pub struct ProjectMilestoneMutationResult {
 
 // ---- #21: Initiatives ----
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Initiative {
     pub id: String,
@@ -440,7 +441,7 @@ pub struct Initiative {
 }
 
 /// Lightweight project reference for initiatives.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct InitiativeProjectRef {
     pub id: String,
@@ -449,7 +450,7 @@ pub struct InitiativeProjectRef {
 
 // ---- #22: Notifications ----
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Notification {
     pub id: String,
@@ -460,7 +461,7 @@ pub struct Notification {
     pub issue: Option<NotificationIssue>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct NotificationIssue {
     pub identifier: String,
@@ -468,14 +469,14 @@ pub struct NotificationIssue {
 }
 
 // NOTE: notificationUpdate returns a NotificationPayload
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct NotificationMutationResult {
     pub success: bool,
 }
 
 // ---- #23: Attachments ----
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Attachment {
     pub id: String,
@@ -484,7 +485,7 @@ pub struct Attachment {
     pub created_at: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct AttachmentMutationResult {
     pub success: bool,
     pub attachment: Option<Attachment>,
@@ -492,14 +493,14 @@ pub struct AttachmentMutationResult {
 
 // ---- #24: Reactions ----
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Reaction {
     pub id: String,
     pub emoji: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct ReactionMutationResult {
     pub success: bool,
     pub reaction: Option<Reaction>,
@@ -507,7 +508,7 @@ pub struct ReactionMutationResult {
 
 // ---- #25: Custom Views ----
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CustomView {
     pub id: String,
@@ -518,7 +519,7 @@ pub struct CustomView {
 
 // ---- #26: Favorites ----
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Favorite {
     pub id: String,
@@ -528,7 +529,7 @@ pub struct Favorite {
     pub project: Option<DocumentProject>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct FavoriteMutationResult {
     pub success: bool,
     pub favorite: Option<Favorite>,
@@ -536,7 +537,7 @@ pub struct FavoriteMutationResult {
 
 // ---- #29: Templates ----
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Template {
     pub id: String,
@@ -547,7 +548,7 @@ pub struct Template {
 
 // ---- #30: Issue History ----
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct IssueHistoryEntry {
     pub id: String,
@@ -559,19 +560,19 @@ pub struct IssueHistoryEntry {
     pub removed_labels: Option<Vec<HistoryLabel>>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct HistoryState {
     pub name: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct HistoryActor {
     pub display_name: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct HistoryLabel {
     pub name: String,
@@ -579,7 +580,7 @@ pub struct HistoryLabel {
 
 // ---- #31: Webhooks ----
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Webhook {
     pub id: String,
@@ -589,7 +590,7 @@ pub struct Webhook {
     pub resource_types: Option<Vec<String>>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct WebhookMutationResult {
     pub success: bool,
     pub webhook: Option<Webhook>,
@@ -597,7 +598,7 @@ pub struct WebhookMutationResult {
 
 // ---- #32: Integrations and Audit Log ----
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Integration {
     pub id: String,
@@ -605,7 +606,7 @@ pub struct Integration {
     pub created_at: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct AuditEntry {
     pub id: String,
@@ -618,7 +619,7 @@ pub struct AuditEntry {
 
 // ---- #33: Enriched Team and User ----
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct TeamDetail {
     pub id: String,
@@ -628,7 +629,7 @@ pub struct TeamDetail {
     pub timezone: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct TeamMutationResult {
     pub success: bool,
     pub team: Option<TeamDetail>,
@@ -655,7 +656,7 @@ pub struct TeamMutationResult {
 
 // ---- Cycle detail (for create_cycle mutation) ----
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CycleDetail {
     pub id: String,
@@ -666,7 +667,7 @@ pub struct CycleDetail {
 }
 
 // Deletion result (used by delete_webhook, favorite_delete, reaction_delete, etc.)
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct DeleteMutationResult {
     pub success: bool,
 }
@@ -674,13 +675,13 @@ pub struct DeleteMutationResult {
 // ---- Phase 12: Remaining tools ----
 
 /// Batch update result.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct BatchUpdateResult {
     pub success: bool,
     pub issues: Vec<BatchUpdatedIssue>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct BatchUpdatedIssue {
     pub id: String,
     pub identifier: String,
@@ -688,13 +689,13 @@ pub struct BatchUpdatedIssue {
     pub state: Option<IssueStateRef>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct IssueStateRef {
     pub name: String,
 }
 
 /// Document search result.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct DocumentSearchResult {
     pub id: String,
@@ -707,18 +708,18 @@ pub struct DocumentSearchResult {
     pub creator: Option<DocumentCreatorRef>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct DocumentProjectRef {
     pub name: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct DocumentCreatorRef {
     pub display_name: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct DocumentSearchConnection {
     pub nodes: Vec<DocumentSearchResult>,
@@ -726,13 +727,13 @@ pub struct DocumentSearchConnection {
 }
 
 /// Initiative mutation result.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct InitiativeMutationResult {
     pub success: bool,
     pub initiative: Option<InitiativeDetail>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct InitiativeDetail {
     pub id: String,
@@ -749,7 +750,7 @@ pub struct InitiativeDetail {
 }
 
 /// Custom view with issues.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct CustomViewWithIssues {
     pub id: String,
     pub name: String,
@@ -757,7 +758,7 @@ pub struct CustomViewWithIssues {
 }
 
 /// Custom view mutation result.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CustomViewMutationResult {
     pub success: bool,
@@ -767,7 +768,7 @@ pub struct CustomViewMutationResult {
 // ---- Agent Sessions & Activities ----
 
 /// Agent session entity.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct AgentSession {
     pub id: String,
@@ -784,7 +785,7 @@ pub struct AgentSession {
 }
 
 /// Comment reference for agent sessions.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct AgentSessionComment {
     pub id: String,
@@ -792,7 +793,7 @@ pub struct AgentSessionComment {
 }
 
 /// Lightweight agent activity reference.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct AgentActivityRef {
     pub id: String,
@@ -801,7 +802,7 @@ pub struct AgentActivityRef {
 }
 
 /// Agent session mutation result.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct AgentSessionMutationResult {
     pub success: bool,
@@ -809,7 +810,7 @@ pub struct AgentSessionMutationResult {
 }
 
 /// Agent activity mutation result.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct AgentActivityMutationResult {
     pub success: bool,
@@ -819,7 +820,7 @@ pub struct AgentActivityMutationResult {
 // ---- Customer Management ----
 
 /// Customer entity.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Customer {
     pub id: String,
@@ -837,7 +838,7 @@ pub struct Customer {
 }
 
 /// Customer status reference.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CustomerStatusRef {
     pub display_name: Option<String>,
@@ -845,14 +846,14 @@ pub struct CustomerStatusRef {
 }
 
 /// Customer tier reference.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CustomerTierRef {
     pub name: Option<String>,
 }
 
 /// Customer need (feature request linked to an issue).
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CustomerNeed {
     pub id: String,
@@ -864,7 +865,7 @@ pub struct CustomerNeed {
 }
 
 /// Lightweight customer reference.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CustomerRef {
     pub id: String,
@@ -872,14 +873,14 @@ pub struct CustomerRef {
 }
 
 /// Customer mutation result.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct CustomerMutationResult {
     pub success: bool,
     pub customer: Option<Customer>,
 }
 
 /// Customer need mutation result.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CustomerNeedMutationResult {
     pub success: bool,
@@ -889,7 +890,7 @@ pub struct CustomerNeedMutationResult {
 // ---- Initiative Updates ----
 
 /// Initiative status update.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct InitiativeStatusUpdate {
     pub id: String,
@@ -901,7 +902,7 @@ pub struct InitiativeStatusUpdate {
 }
 
 /// Initiative update mutation result.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct InitiativeUpdateMutationResult {
     pub success: bool,
@@ -911,7 +912,7 @@ pub struct InitiativeUpdateMutationResult {
 // ---- Initiative-to-Project Links ----
 
 /// Initiative-to-project link.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct InitiativeToProject {
     pub id: String,
@@ -920,21 +921,21 @@ pub struct InitiativeToProject {
 }
 
 /// Lightweight initiative name reference.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct InitiativeNameRef {
     pub name: String,
 }
 
 /// Lightweight project name reference.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ProjectNameRefType {
     pub name: String,
 }
 
 /// Initiative-to-project mutation result.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct InitiativeToProjectMutationResult {
     pub success: bool,
@@ -944,7 +945,7 @@ pub struct InitiativeToProjectMutationResult {
 // ---- Project Relations ----
 
 /// Project relation entity.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ProjectRelation {
     pub id: String,
@@ -957,7 +958,7 @@ pub struct ProjectRelation {
 }
 
 /// Project relation mutation result.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ProjectRelationMutationResult {
     pub success: bool,
@@ -967,7 +968,7 @@ pub struct ProjectRelationMutationResult {
 // ---- Releases ----
 
 /// Release entity.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Release {
     pub id: String,
@@ -981,7 +982,7 @@ pub struct Release {
 }
 
 /// Release stage reference.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ReleaseStageRef {
     pub name: String,
@@ -989,14 +990,14 @@ pub struct ReleaseStageRef {
 }
 
 /// Release pipeline reference.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ReleasePipelineRef {
     pub name: String,
 }
 
 /// Release mutation result.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct ReleaseMutationResult {
     pub success: bool,
     pub release: Option<Release>,
@@ -1005,7 +1006,7 @@ pub struct ReleaseMutationResult {
 // ---- Project Search ----
 
 /// Project search result.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ProjectSearchResult {
     pub id: String,
@@ -1021,7 +1022,7 @@ pub struct ProjectSearchResult {
 }
 
 /// Project search connection.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ProjectSearchConnection {
     pub nodes: Vec<ProjectSearchResult>,
@@ -1031,7 +1032,7 @@ pub struct ProjectSearchConnection {
 // ---- Phase 2 (Complete Coverage): New Types ----
 
 /// Workflow state mutation result.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct WorkflowStateMutationResult {
     pub success: bool,
@@ -1039,14 +1040,14 @@ pub struct WorkflowStateMutationResult {
 }
 
 /// Issue priority value (from issuePriorityValues query).
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct IssuePriorityValue {
     pub priority: i32,
     pub label: String,
 }
 
 /// Document content history entry.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct DocumentContentHistoryEntry {
     pub id: String,
@@ -1056,7 +1057,7 @@ pub struct DocumentContentHistoryEntry {
 }
 
 /// Customer status (full entity).
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CustomerStatusFull {
     pub id: String,
@@ -1068,7 +1069,7 @@ pub struct CustomerStatusFull {
 }
 
 /// Customer status mutation result.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CustomerStatusMutationResult {
     pub success: bool,
@@ -1076,7 +1077,7 @@ pub struct CustomerStatusMutationResult {
 }
 
 /// Customer tier (full entity).
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CustomerTierFull {
     pub id: String,
@@ -1088,7 +1089,7 @@ pub struct CustomerTierFull {
 }
 
 /// Customer tier mutation result.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CustomerTierMutationResult {
     pub success: bool,
@@ -1096,7 +1097,7 @@ pub struct CustomerTierMutationResult {
 }
 
 /// Release pipeline (full entity).
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ReleasePipelineFull {
     pub id: String,
@@ -1108,7 +1109,7 @@ pub struct ReleasePipelineFull {
 }
 
 /// Release pipeline mutation result.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ReleasePipelineMutationResult {
     pub success: bool,
@@ -1116,7 +1117,7 @@ pub struct ReleasePipelineMutationResult {
 }
 
 /// Release stage (full entity).
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ReleaseStageFull {
     pub id: String,
@@ -1129,7 +1130,7 @@ pub struct ReleaseStageFull {
 }
 
 /// Release stage mutation result.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ReleaseStageMutationResult {
     pub success: bool,
@@ -1137,7 +1138,7 @@ pub struct ReleaseStageMutationResult {
 }
 
 /// Issue-to-release link.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct IssueToRelease {
     pub id: String,
@@ -1146,7 +1147,7 @@ pub struct IssueToRelease {
 }
 
 /// Lightweight release name reference.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ReleaseNameRef {
     pub id: String,
@@ -1154,7 +1155,7 @@ pub struct ReleaseNameRef {
 }
 
 /// Issue-to-release mutation result.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct IssueToReleaseMutationResult {
     pub success: bool,
@@ -1162,7 +1163,7 @@ pub struct IssueToReleaseMutationResult {
 }
 
 /// Project status (full entity).
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ProjectStatusFull {
     pub id: String,
@@ -1176,7 +1177,7 @@ pub struct ProjectStatusFull {
 }
 
 /// Project status mutation result.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ProjectStatusMutationResult {
     pub success: bool,
@@ -1184,7 +1185,7 @@ pub struct ProjectStatusMutationResult {
 }
 
 /// Project label entity.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ProjectLabel {
     pub id: String,
@@ -1196,7 +1197,7 @@ pub struct ProjectLabel {
 }
 
 /// Project label mutation result.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ProjectLabelMutationResult {
     pub success: bool,
@@ -1204,7 +1205,7 @@ pub struct ProjectLabelMutationResult {
 }
 
 /// Team membership entity.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct TeamMembership {
     pub id: String,
@@ -1215,7 +1216,7 @@ pub struct TeamMembership {
 }
 
 /// Team membership mutation result.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct TeamMembershipMutationResult {
     pub success: bool,
@@ -1223,7 +1224,7 @@ pub struct TeamMembershipMutationResult {
 }
 
 /// Notification subscription entity.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct NotificationSubscription {
     pub id: String,
@@ -1233,7 +1234,7 @@ pub struct NotificationSubscription {
 }
 
 /// Notification subscription mutation result.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct NotificationSubscriptionMutationResult {
     pub success: bool,
@@ -1241,7 +1242,7 @@ pub struct NotificationSubscriptionMutationResult {
 }
 
 /// Template mutation result.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct TemplateMutationResult {
     pub success: bool,
@@ -1249,7 +1250,7 @@ pub struct TemplateMutationResult {
 }
 
 /// Entity external link.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct EntityExternalLink {
     pub id: String,
@@ -1260,7 +1261,7 @@ pub struct EntityExternalLink {
 }
 
 /// Entity external link mutation result.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct EntityExternalLinkMutationResult {
     pub success: bool,
@@ -1268,7 +1269,7 @@ pub struct EntityExternalLinkMutationResult {
 }
 
 /// Custom emoji.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Emoji {
     pub id: String,
@@ -1278,7 +1279,7 @@ pub struct Emoji {
 }
 
 /// Emoji mutation result.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct EmojiMutationResult {
     pub success: bool,
@@ -1286,7 +1287,7 @@ pub struct EmojiMutationResult {
 }
 
 /// Initiative relation.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct InitiativeRelation {
     pub id: String,
@@ -1295,7 +1296,7 @@ pub struct InitiativeRelation {
 }
 
 /// Initiative relation mutation result.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct InitiativeRelationMutationResult {
     pub success: bool,
@@ -1303,7 +1304,7 @@ pub struct InitiativeRelationMutationResult {
 }
 
 /// Time schedule.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct TimeSchedule {
     pub id: String,
@@ -1313,7 +1314,7 @@ pub struct TimeSchedule {
 }
 
 /// Time schedule mutation result.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct TimeScheduleMutationResult {
     pub success: bool,
@@ -1321,7 +1322,7 @@ pub struct TimeScheduleMutationResult {
 }
 
 /// Triage responsibility.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct TriageResponsibility {
     pub id: String,
@@ -1330,7 +1331,7 @@ pub struct TriageResponsibility {
 }
 
 /// Triage responsibility mutation result.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct TriageResponsibilityMutationResult {
     pub success: bool,
@@ -1338,7 +1339,7 @@ pub struct TriageResponsibilityMutationResult {
 }
 
 /// Git automation state.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct GitAutomationState {
     pub id: String,
@@ -1348,7 +1349,7 @@ pub struct GitAutomationState {
 }
 
 /// Git automation state mutation result.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct GitAutomationStateMutationResult {
     pub success: bool,
@@ -1356,7 +1357,7 @@ pub struct GitAutomationStateMutationResult {
 }
 
 /// Git automation target branch.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct GitAutomationTargetBranch {
     pub id: String,
@@ -1366,7 +1367,7 @@ pub struct GitAutomationTargetBranch {
 }
 
 /// Git automation target branch mutation result.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct GitAutomationTargetBranchMutationResult {
     pub success: bool,
@@ -1375,7 +1376,7 @@ pub struct GitAutomationTargetBranchMutationResult {
 }
 
 /// Email intake address.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct EmailIntakeAddress {
     pub id: String,
@@ -1385,7 +1386,7 @@ pub struct EmailIntakeAddress {
 }
 
 /// Email intake address mutation result.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct EmailIntakeAddressMutationResult {
     pub success: bool,
@@ -1393,7 +1394,7 @@ pub struct EmailIntakeAddressMutationResult {
 }
 
 /// Rate limit status.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct RateLimitStatus {
     pub kind: String,
@@ -1401,7 +1402,7 @@ pub struct RateLimitStatus {
 }
 
 /// Rate limit result entry.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct RateLimitResultPayload {
     #[serde(rename = "type")]
@@ -1414,7 +1415,7 @@ pub struct RateLimitResultPayload {
 }
 
 /// Organization.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Organization {
     pub id: String,
@@ -1426,7 +1427,7 @@ pub struct Organization {
 }
 
 /// Application info.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ApplicationInfo {
     pub name: String,
@@ -1438,7 +1439,7 @@ pub struct ApplicationInfo {
 }
 
 /// External user.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ExternalUser {
     pub id: String,
@@ -1448,7 +1449,7 @@ pub struct ExternalUser {
 }
 
 /// User mutation result.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct UserMutationResult {
     pub success: bool,
@@ -1456,7 +1457,7 @@ pub struct UserMutationResult {
 }
 
 /// Favorite update mutation result.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct FavoriteUpdateMutationResult {
     pub success: bool,
@@ -1464,14 +1465,14 @@ pub struct FavoriteUpdateMutationResult {
 }
 
 /// Issue label (single get result).
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct IssueLabelData {
     pub issue_label: Label,
 }
 
 /// Release search connection.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ReleaseSearchConnection {
     pub nodes: Vec<Release>,
@@ -1479,7 +1480,7 @@ pub struct ReleaseSearchConnection {
 }
 
 /// Batch issue create result.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct BatchCreateResult {
     pub success: bool,
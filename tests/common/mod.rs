@@ -0,0 +1,568 @@
+//! Offline test harness: a mock Linear GraphQL endpoint serving the
+//! recorded responses in `tests/fixtures`, and a stdio MCP client driving
+//! the real `linear-mcp` binary against it.
+
+#![allow(dead_code)]
+
+use std::collections::{HashMap, VecDeque};
+use std::io::{BufRead, BufReader, Write};
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
+use std::sync::{Arc, Mutex};
+
+use axum::Json;
+use axum::extract::State;
+use axum::http::header::{AUTHORIZATION, USER_AGENT};
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::post;
+use serde_json::{Value, json};
+
+pub fn fixtures_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures")
+}
+
+/// Operation name of a GraphQL document (`GetIssue` in `query GetIssue(...)`).
+pub fn operation_name(query: &str) -> Option<&str> {
+    let mut words = query.split_whitespace();
+    match words.next() {
+        Some("query" | "mutation") => {}
+        _ => return None,
+    }
+    let name = words.next()?.split(['(', '{']).next()?;
+    (!name.is_empty()).then_some(name)
+}
+
+#[derive(Default)]
+struct MockState {
+    fixtures: HashMap<String, Value>,
+    /// One-shot replies per operation, served before its fixture.
+    replies: HashMap<String, VecDeque<Reply>>,
+    /// Operation name, variables, and `Authorization` header of every
+    /// request received, in order.
+    requests: Vec<(String, Value, Option<String>)>,
+    user_agent: Option<String>,
+}
+
+struct Reply {
+    status: StatusCode,
+    headers: Vec<(String, String)>,
+    body: Value,
+}
+
+/// A local stand-in for `https://api.linear.app/graphql`. Answers each
+/// operation with `tests/fixtures/<OperationName>.json`, or with a GraphQL
+/// error naming the missing fixture.
+pub struct MockLinear {
+    addr: SocketAddr,
+    state: Arc<Mutex<MockState>>,
+    _runtime: tokio::runtime::Runtime,
+}
+
+impl MockLinear {
+    pub fn start() -> Self {
+        let mut fixtures = HashMap::new();
+        for entry in std::fs::read_dir(fixtures_dir()).expect("tests/fixtures") {
+            let path = entry.unwrap().path();
+            if path.extension().is_some_and(|e| e == "json") {
+                let text = std::fs::read_to_string(&path).unwrap();
+                let value = serde_json::from_str(&text)
+                    .unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+                let name = path.file_stem().unwrap().to_string_lossy().into_owned();
+                fixtures.insert(name, value);
+            }
+        }
+        let state = Arc::new(Mutex::new(MockState {
+            fixtures,
+            replies: HashMap::new(),
+            requests: Vec::new(),
+            user_agent: None,
+        }));
+
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .enable_all()
+            .build()
+            .unwrap();
+        let listener = runtime
+            .block_on(tokio::net::TcpListener::bind("127.0.0.1:0"))
+            .unwrap();
+        let addr = listener.local_addr().unwrap();
+        let app = axum::Router::new()
+            .route("/graphql", post(graphql))
+            .with_state(state.clone());
+        runtime.spawn(async move { axum::serve(listener, app).await });
+
+        Self {
+            addr,
+            state,
+            _runtime: runtime,
+        }
+    }
+
+    pub fn url(&self) -> String {
+        format!("http://{}/graphql", self.addr)
+    }
+
+    /// Answer `operation` with `response` instead of its fixture.
+    pub fn set_fixture(&self, operation: &str, response: Value) {
+        let mut state = self.state.lock().unwrap();
+        state.fixtures.insert(operation.to_string(), response);
+    }
+
+    /// Answer the next request for `operation` with `status`, `headers`,
+    /// and `body`; later ones get its fixture again. Replies queue up.
+    pub fn push_reply(&self, operation: &str, status: u16, headers: &[(&str, &str)], body: Value) {
+        let mut state = self.state.lock().unwrap();
+        state
+            .replies
+            .entry(operation.to_string())
+            .or_default()
+            .push_back(Reply {
+                status: StatusCode::from_u16(status).unwrap(),
+                headers: headers
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect(),
+                body,
+            });
+    }
+
+    /// Operation names received so far.
+    pub fn operations(&self) -> Vec<String> {
+        let state = self.state.lock().unwrap();
+        state.requests.iter().map(|(op, ..)| op.clone()).collect()
+    }
+
+    /// Variables of the most recent request for `operation`.
+    pub fn last_variables(&self, operation: &str) -> Option<Value> {
+        let state = self.state.lock().unwrap();
+        state
+            .requests
+            .iter()
+            .rev()
+            .find(|(op, ..)| op == operation)
+            .map(|(_, vars, _)| vars.clone())
+    }
+
+    /// `Authorization` header of every request for `operation`, in order.
+    pub fn authorizations(&self, operation: &str) -> Vec<Option<String>> {
+        let state = self.state.lock().unwrap();
+        state
+            .requests
+            .iter()
+            .filter(|(op, ..)| op == operation)
+            .map(|(.., authorization)| authorization.clone())
+            .collect()
+    }
+
+    /// `User-Agent` of the most recent request.
+    pub fn user_agent(&self) -> Option<String> {
+        self.state.lock().unwrap().user_agent.clone()
+    }
+
+    pub fn clear_requests(&self) {
+        self.state.lock().unwrap().requests.clear();
+    }
+}
+
+async fn graphql(
+    State(state): State<Arc<Mutex<MockState>>>,
+    headers: HeaderMap,
+    Json(body): Json<Value>,
+) -> Response {
+    let query = body["query"].as_str().unwrap_or_default();
+    let operation = operation_name(query).unwrap_or("anonymous").to_string();
+    let mut state = state.lock().unwrap();
+    state.user_agent = headers
+        .get(USER_AGENT)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+    let authorization = headers
+        .get(AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+    state
+        .requests
+        .push((operation.clone(), body["variables"].clone(), authorization));
+    if let Some(reply) = state
+        .replies
+        .get_mut(&operation)
+        .and_then(VecDeque::pop_front)
+    {
+        let mut response = (reply.status, Json(reply.body)).into_response();
+        for (name, value) in reply.headers {
+            response.headers_mut().insert(
+                axum::http::HeaderName::try_from(name).unwrap(),
+                value.parse().unwrap(),
+            );
+        }
+        return response;
+    }
+    Json(match state.fixtures.get(&operation) {
+        Some(response) => response.clone(),
+        None => json!({ "errors": [{ "message": format!("No fixture for {operation}") }] }),
+    })
+    .into_response()
+}
+
+/// A `linear-mcp` command pointed at `mock`, with a throwaway config
+/// directory and none of the caller's `LINEAR_*` settings.
+fn linear_mcp(mock: &MockLinear, args: &[&str], envs: &[(&str, &str)]) -> Command {
+    let config_home = std::env::temp_dir().join(format!("linear-mcp-test-{}", std::process::id()));
+    let mut command = Command::new(env!("CARGO_BIN_EXE_linear-mcp"));
+    for (key, _) in std::env::vars_os() {
+        if key.to_string_lossy().starts_with("LINEAR_") {
+            command.env_remove(key);
+        }
+    }
+    command
+        .args(args)
+        .env("LINEAR_API_URL", mock.url())
+        .env("LINEAR_API_KEY", "lin_api_test")
+        .env("XDG_CONFIG_HOME", &config_home)
+        .env("RUST_LOG", "error")
+        .envs(envs.iter().copied());
+    command
+}
+
+/// The `linear-mcp` binary on stdio, initialized and pointed at a mock.
+pub struct McpSession {
+    child: Child,
+    /// `None` once the session is closed.
+    stdin: Option<ChildStdin>,
+    stdout: BufReader<ChildStdout>,
+    next_id: u64,
+    /// The server's `initialize` result.
+    pub server_info: Value,
+}
+
+impl McpSession {
+    pub fn start(mock: &MockLinear) -> Self {
+        Self::start_with(mock, &[], &[])
+    }
+
+    pub fn start_with(mock: &MockLinear, args: &[&str], envs: &[(&str, &str)]) -> Self {
+        let mut command = linear_mcp(mock, args, envs);
+        command
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit());
+        let mut child = command.spawn().expect("spawn linear-mcp");
+        let stdin = child.stdin.take();
+        let stdout = BufReader::new(child.stdout.take().unwrap());
+        let mut session = Self {
+            child,
+            stdin,
+            stdout,
+            next_id: 1,
+            server_info: Value::Null,
+        };
+        session.server_info = session.request(
+            "initialize",
+            json!({
+                "protocolVersion": "2025-06-18",
+                "capabilities": {},
+                "clientInfo": { "name": "linear-mcp-tests", "version": "1.0" },
+            }),
+        );
+        session.notify("notifications/initialized", Value::Null);
+        session
+    }
+
+    fn send(&mut self, message: Value) {
+        let stdin = self.stdin.as_mut().expect("session is closed");
+        writeln!(stdin, "{message}").unwrap();
+        stdin.flush().unwrap();
+    }
+
+    pub fn notify(&mut self, method: &str, params: Value) {
+        let mut message = json!({ "jsonrpc": "2.0", "method": method });
+        if !params.is_null() {
+            message["params"] = params;
+        }
+        self.send(message);
+    }
+
+    /// Send a request and return the whole response message.
+    pub fn exchange(&mut self, method: &str, params: Value) -> Value {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.send(json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params }));
+        loop {
+            let mut line = String::new();
+            let n = self.stdout.read_line(&mut line).unwrap();
+            assert!(n > 0, "linear-mcp exited while waiting for {method}");
+            let message: Value = serde_json::from_str(&line).unwrap();
+            if message["id"] == id {
+                return message;
+            }
+        }
+    }
+
+    /// Wait for the server to send a `method` notification and return its
+    /// params, skipping any other messages.
+    pub fn notification(&mut self, method: &str) -> Value {
+        loop {
+            let mut line = String::new();
+            let n = self.stdout.read_line(&mut line).unwrap();
+            assert!(n > 0, "linear-mcp exited while waiting for {method}");
+            let message: Value = serde_json::from_str(&line).unwrap();
+            if message["method"] == method {
+                return message["params"].clone();
+            }
+        }
+    }
+
+    /// Send a request and return its `result`, panicking on a JSON-RPC error.
+    pub fn request(&mut self, method: &str, params: Value) -> Value {
+        let message = self.exchange(method, params);
+        if let Some(error) = message.get("error") {
+            panic!("{method} failed: {error}");
+        }
+        message["result"].clone()
+    }
+
+    /// End the session the way a client does, by closing stdin, and wait
+    /// for the server to exit cleanly.
+    pub fn close(&mut self) {
+        self.stdin = None;
+        let status = self.child.wait().unwrap();
+        assert!(status.success(), "linear-mcp exited with {status}");
+    }
+
+    pub fn list_tools(&mut self) -> Vec<Value> {
+        let result = self.request("tools/list", json!({}));
+        result["tools"].as_array().cloned().unwrap_or_default()
+    }
+
+    /// Call a tool. A JSON-RPC error (e.g. an unknown tool) becomes an
+    /// error result carrying its message.
+    pub fn call_tool(&mut self, name: &str, arguments: Value) -> ToolResult {
+        let message = self.exchange(
+            "tools/call",
+            json!({ "name": name, "arguments": arguments }),
+        );
+        match message.get("error") {
+            Some(error) => ToolResult(json!({
+                "isError": true,
+                "content": [{ "type": "text", "text": error["message"] }],
+            })),
+            None => ToolResult(message["result"].clone()),
+        }
+    }
+}
+
+impl Drop for McpSession {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+/// A `tools/call` result.
+pub struct ToolResult(pub Value);
+
+impl ToolResult {
+    pub fn is_error(&self) -> bool {
+        self.0["isError"] == true
+    }
+
+    /// The text of all text content blocks, joined.
+    pub fn text(&self) -> String {
+        self.0["content"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|c| c["text"].as_str())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// The `structuredContent` of the result.
+    pub fn structured(&self) -> &Value {
+        &self.0["structuredContent"]
+    }
+
+    /// The machine-readable error of a failed call, from `_meta`.
+    pub fn error(&self) -> &Value {
+        &self.0["_meta"]["error"]
+    }
+}
+
+/// The `linear-mcp` binary serving streamable HTTP on a free local port,
+/// pointed at a mock.
+pub struct HttpServer {
+    child: Child,
+    /// The MCP endpoint, `http://127.0.0.1:<port>/mcp`.
+    pub url: String,
+}
+
+impl HttpServer {
+    pub fn start(mock: &MockLinear, args: &[&str], envs: &[(&str, &str)]) -> Self {
+        let mut args = args.to_vec();
+        args.extend(["--http", "127.0.0.1:0"]);
+        let mut command = linear_mcp(mock, &args, envs);
+        // The bound port is only known from the startup log line.
+        command
+            .env("RUST_LOG", "linear_mcp=info")
+            .env("NO_COLOR", "1")
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped());
+        let mut child = command.spawn().expect("spawn linear-mcp");
+        let mut stderr = BufReader::new(child.stderr.take().unwrap());
+        let url = loop {
+            let mut line = String::new();
+            let n = stderr.read_line(&mut line).unwrap();
+            assert!(n > 0, "linear-mcp exited before listening");
+            if let Some(at) = line.find("Listening on ") {
+                break line[at + "Listening on ".len()..].trim().to_string();
+            }
+        };
+        // Keep draining the log so the server never blocks on a full pipe.
+        std::thread::spawn(move || std::io::copy(&mut stderr, &mut std::io::sink()));
+        Self { child, url }
+    }
+
+    /// Open an initialized MCP session, sending `headers` with every request.
+    pub fn connect(&self, headers: &[(&str, &str)]) -> HttpSession {
+        self.connect_with_meta(headers, Value::Null)
+    }
+
+    /// Like [`connect`](Self::connect), with `meta` as the `_meta` of the
+    /// `initialize` request.
+    pub fn connect_with_meta(&self, headers: &[(&str, &str)], meta: Value) -> HttpSession {
+        let mut default_headers = reqwest::header::HeaderMap::new();
+        for (name, value) in headers {
+            default_headers.insert(
+                reqwest::header::HeaderName::try_from(*name).unwrap(),
+                value.parse().unwrap(),
+            );
+        }
+        let mut session = HttpSession {
+            client: reqwest::Client::builder()
+                .default_headers(default_headers)
+                .build()
+                .unwrap(),
+            runtime: tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .unwrap(),
+            url: self.url.clone(),
+            session_id: None,
+            next_id: 1,
+            server_info: Value::Null,
+        };
+        let mut params = json!({
+            "protocolVersion": "2025-06-18",
+            "capabilities": {},
+            "clientInfo": { "name": "linear-mcp-tests", "version": "1.0" },
+        });
+        if !meta.is_null() {
+            params["_meta"] = meta;
+        }
+        session.server_info = session.request("initialize", params);
+        session.notify("notifications/initialized", Value::Null);
+        session
+    }
+}
+
+impl Drop for HttpServer {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+/// One MCP session with an [`HttpServer`].
+pub struct HttpSession {
+    client: reqwest::Client,
+    runtime: tokio::runtime::Runtime,
+    url: String,
+    /// `Mcp-Session-Id` assigned by the server on `initialize`.
+    session_id: Option<String>,
+    next_id: u64,
+    /// The server's `initialize` result.
+    pub server_info: Value,
+}
+
+impl HttpSession {
+    /// POST `message`, returning the HTTP status and the first JSON-RPC
+    /// message in the reply that answers `id` (if any).
+    fn post(&mut self, message: Value, id: Option<u64>) -> (u16, Value) {
+        let mut request = self
+            .client
+            .post(&self.url)
+            .header("accept", "application/json, text/event-stream")
+            .json(&message);
+        if let Some(ref session_id) = self.session_id {
+            request = request.header("mcp-session-id", session_id);
+        }
+        self.runtime.block_on(async {
+            let mut response = request.send().await.unwrap();
+            let status = response.status().as_u16();
+            if let Some(session_id) = response.headers().get("mcp-session-id") {
+                self.session_id = Some(session_id.to_str().unwrap().to_string());
+            }
+            let Some(id) = id else {
+                return (status, Value::Null);
+            };
+            // The reply is an SSE stream that may stay open; read events
+            // until the one answering `id` arrives.
+            let mut buffer = String::new();
+            while let Some(chunk) = response.chunk().await.unwrap() {
+                buffer.push_str(&String::from_utf8_lossy(&chunk));
+                for line in buffer.lines() {
+                    let data = line.strip_prefix("data:").unwrap_or(line).trim();
+                    if let Ok(message) = serde_json::from_str::<Value>(data)
+                        && message["id"] == id
+                    {
+                        return (status, message);
+                    }
+                }
+            }
+            panic!("no reply to request {id} (HTTP {status}): {buffer}");
+        })
+    }
+
+    pub fn notify(&mut self, method: &str, params: Value) {
+        let mut message = json!({ "jsonrpc": "2.0", "method": method });
+        if !params.is_null() {
+            message["params"] = params;
+        }
+        let (status, _) = self.post(message, None);
+        assert_eq!(status, 202, "{method} was not accepted");
+    }
+
+    /// Send a request and return the whole response message.
+    pub fn exchange(&mut self, method: &str, params: Value) -> Value {
+        let id = self.next_id;
+        self.next_id += 1;
+        let message = json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params });
+        self.post(message, Some(id)).1
+    }
+
+    /// Send a request and return its `result`, panicking on a JSON-RPC error.
+    pub fn request(&mut self, method: &str, params: Value) -> Value {
+        let message = self.exchange(method, params);
+        if let Some(error) = message.get("error") {
+            panic!("{method} failed: {error}");
+        }
+        message["result"].clone()
+    }
+
+    pub fn list_tools(&mut self) -> Vec<Value> {
+        let result = self.request("tools/list", json!({}));
+        result["tools"].as_array().cloned().unwrap_or_default()
+    }
+
+    pub fn call_tool(&mut self, name: &str, arguments: Value) -> ToolResult {
+        let result = self.request(
+            "tools/call",
+            json!({ "name": name, "arguments": arguments }),
+        );
+        ToolResult(result)
+    }
+}
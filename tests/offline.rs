@@ -0,0 +1,1369 @@
+//! Exercises every tool handler against the mock Linear endpoint.
+
+mod common;
+
+use common::{HttpServer, McpSession, MockLinear, fixtures_dir, operation_name};
+use serde_json::{Map, Value, json};
+
+const QUERIES: &str = include_str!("../src/graphql/queries.rs");
+
+/// Every query constant in `graphql/queries.rs`, as (constant, operation name).
+fn query_operations() -> Vec<(String, Option<String>)> {
+    let mut ops = Vec::new();
+    let mut rest = QUERIES;
+    while let Some(start) = rest.find("pub const ") {
+        rest = &rest[start + "pub const ".len()..];
+        let name = rest.split(':').next().unwrap().to_string();
+        let Some(body) = rest.strip_prefix(&format!("{name}: &str = r#\"")) else {
+            continue;
+        };
+        let end = body.find("\"#").unwrap();
+        ops.push((name, operation_name(&body[..end]).map(str::to_string)));
+        rest = &body[end..];
+    }
+    ops
+}
+
+#[test]
+fn every_query_has_a_fixture() {
+    let ops = query_operations();
+    assert!(ops.len() > 200, "found only {} query constants", ops.len());
+    let mut missing = Vec::new();
+    for (constant, operation) in &ops {
+        match operation {
+            Some(op) if fixtures_dir().join(format!("{op}.json")).exists() => {}
+            Some(op) => missing.push(format!("{constant} ({op}.json)")),
+            None => missing.push(format!("{constant} (anonymous operation)")),
+        }
+    }
+    assert!(
+        missing.is_empty(),
+        "queries without fixtures:\n  {}",
+        missing.join("\n  ")
+    );
+}
+
+#[test]
+fn operation_names_are_unique() {
+    let mut seen = std::collections::HashMap::new();
+    for (constant, operation) in query_operations() {
+        let op = operation.unwrap_or_default();
+        if let Some(other) = seen.insert(op.clone(), constant.clone()) {
+            panic!("{constant} and {other} share operation name {op}");
+        }
+    }
+}
+
+/// Tools whose generated arguments would not make a valid call.
+fn argument_overrides(tool: &str) -> Option<Value> {
+    Some(match tool {
+        "add_favorite" => json!({ "issueId": "ENG-1" }),
+        "batch_create_issues" => json!({ "issues": "[{\"title\":\"Example\"}]" }),
+        "bulk_update_issues" => json!({ "ids": "ENG-1", "priority": "high" }),
+        "create_agent_session" => json!({ "issue": "ENG-1" }),
+        "create_project_relation" => {
+            json!({ "project": "Example name", "relatedProject": "Example name", "type": "blocks" })
+        }
+        "create_template" => {
+            json!({ "name": "Example name", "type": "issue", "template_data": "{\"title\":\"Example\"}" })
+        }
+        "create_time_schedule" => json!({
+            "name": "Example name",
+            "entries": "[{\"startsAt\":\"2026-01-15T00:00:00Z\",\"endsAt\":\"2026-01-16T00:00:00Z\",\"userEmail\":\"user@example.com\"}]",
+        }),
+        "update_time_schedule" => json!({
+            "id": "00000000-0000-4000-8000-000000000001",
+            "name": "Example name",
+        }),
+        _ => return None,
+    })
+}
+
+/// Arguments for a tool, built from its input schema: every required
+/// property gets a value consistent with the fixtures.
+fn arguments_for(tool: &Value) -> Value {
+    let name = tool["name"].as_str().unwrap();
+    if let Some(args) = argument_overrides(name) {
+        return args;
+    }
+    let schema = &tool["inputSchema"];
+    let mut args = value_for_object(schema, schema);
+    // Update tools refuse to send an empty patch; set one optional field.
+    if name.starts_with("update_")
+        && let Some(properties) = schema["properties"].as_object()
+        && let Some((property, prop_schema)) = properties
+            .iter()
+            .find(|(p, _)| args.get(p.as_str()).is_none())
+    {
+        args[property] = value_for(schema, property, prop_schema);
+    }
+    args
+}
+
+fn resolve<'a>(root: &'a Value, schema: &'a Value) -> &'a Value {
+    match schema["$ref"].as_str() {
+        Some(reference) => {
+            let name = reference.rsplit('/').next().unwrap();
+            let defs = root.get("$defs").or_else(|| root.get("definitions"));
+            defs.and_then(|d| d.get(name)).unwrap_or(schema)
+        }
+        None => schema,
+    }
+}
+
+fn value_for_object(root: &Value, schema: &Value) -> Value {
+    let schema = resolve(root, schema);
+    let mut args = Map::new();
+    let required = schema["required"].as_array().cloned().unwrap_or_default();
+    for property in required {
+        let property = property.as_str().unwrap();
+        let prop_schema = &schema["properties"][property];
+        args.insert(property.to_string(), value_for(root, property, prop_schema));
+    }
+    Value::Object(args)
+}
+
+fn value_for(root: &Value, property: &str, schema: &Value) -> Value {
+    let schema = resolve(root, schema);
+    if let Some(values) = schema["enum"].as_array() {
+        return values[0].clone();
+    }
+    if let Some(variants) = schema["oneOf"].as_array().or(schema["anyOf"].as_array()) {
+        let first = variants
+            .iter()
+            .find(|v| v["type"] != "null")
+            .unwrap_or(&variants[0]);
+        if let Some(value) = first.get("const") {
+            return value.clone();
+        }
+        return value_for(root, property, first);
+    }
+    let ty = match &schema["type"] {
+        Value::Array(types) => types
+            .iter()
+            .find(|t| *t != "null")
+            .cloned()
+            .unwrap_or_default(),
+        ty => ty.clone(),
+    };
+    match ty.as_str().unwrap_or("string") {
+        "integer" | "number" => json!(1),
+        "boolean" => json!(false),
+        "array" => json!([value_for(
+            root,
+            property.trim_end_matches('s'),
+            &schema["items"]
+        )]),
+        "object" => value_for_object(root, schema),
+        _ => string_for(property),
+    }
+}
+
+fn string_for(property: &str) -> Value {
+    let p = property.to_ascii_lowercase();
+    let value = if p == "issue"
+        || p == "ids"
+        || p == "identifier"
+        || p.contains("issue") && p.ends_with("id")
+    {
+        "ENG-1"
+    } else if p == "team" || p.ends_with("team_key") || p == "key" {
+        "ENG"
+    } else if p == "id" || p.ends_with("_id") || p.ends_with("_ids") {
+        "00000000-0000-4000-8000-000000000001"
+    } else if p.contains("email") || p == "assignee" || p == "user" {
+        "user@example.com"
+    } else if p.contains("date") || p.ends_with("_at") || property.ends_with("At") {
+        "2026-01-15"
+    } else if p == "filter" {
+        "priority >= high"
+    } else if p.contains("color") {
+        "#5e6ad2"
+    } else if p.contains("url") {
+        "https://example.com"
+    } else {
+        "Example name"
+    };
+    json!(value)
+}
+
+#[test]
+fn every_tool_succeeds_offline() {
+    let mock = MockLinear::start();
+    let config = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/config.toml");
+    let mut mcp = McpSession::start_with(&mock, &["--config", config], &[]);
+    let tools = mcp.list_tools();
+    assert!(tools.len() > 200, "only {} tools listed", tools.len());
+
+    let mut failures = Vec::new();
+    for tool in &tools {
+        let name = tool["name"].as_str().unwrap();
+        let args = arguments_for(tool);
+        let schema = &tool["outputSchema"];
+        if schema["type"] != "object" {
+            failures.push(format!("{name}: no object outputSchema"));
+            continue;
+        }
+        let result = mcp.call_tool(name, args.clone());
+        if result.is_error() {
+            failures.push(format!("{name} {args}: {}", result.text()));
+        } else if result.text().is_empty() {
+            failures.push(format!("{name} {args}: empty result"));
+        } else if let Some(structured) = result.structured().as_object() {
+            let properties = schema["properties"].as_object();
+            for key in structured.keys() {
+                if !properties.is_some_and(|p| p.contains_key(key)) {
+                    failures.push(format!("{name}: '{key}' is not in the outputSchema"));
+                }
+            }
+        } else {
+            failures.push(format!("{name} {args}: no structuredContent"));
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "{} of {} tools failed:\n  {}",
+        failures.len(),
+        tools.len(),
+        failures.join("\n  ")
+    );
+}
+
+#[test]
+fn get_issue_renders_fixture() {
+    let mock = MockLinear::start();
+    let mut mcp = McpSession::start(&mock);
+    let result = mcp.call_tool("get_issue", json!({ "id": "ENG-1" }));
+    assert!(!result.is_error(), "{}", result.text());
+    assert!(result.text().contains("ENG-1"), "{}", result.text());
+    assert_eq!(result.structured()["identifier"], "ENG-1");
+    assert!(mock.operations().contains(&"GetIssue".to_string()));
+}
+
+#[test]
+fn initialize_negotiates_structured_output_and_completions() {
+    let mock = MockLinear::start();
+    let mut mcp = McpSession::start(&mock);
+    assert_eq!(mcp.server_info["protocolVersion"], "2025-06-18");
+    let capabilities = &mcp.server_info["capabilities"];
+    assert!(capabilities["tools"].is_object(), "{capabilities}");
+    assert!(capabilities["completions"].is_object(), "{capabilities}");
+    mcp.close();
+}
+
+#[test]
+fn http_transport_serves_mcp_at_the_mcp_path() {
+    let mock = MockLinear::start();
+    let server = HttpServer::start(&mock, &[], &[]);
+    assert!(server.url.ends_with("/mcp"), "{}", server.url);
+    let mut mcp = server.connect(&[]);
+    assert_eq!(mcp.server_info["protocolVersion"], "2025-06-18");
+    assert_eq!(mcp.server_info["serverInfo"]["name"], "linear-mcp");
+    let tools = mcp.list_tools();
+    assert!(tools.iter().any(|t| t["name"] == "get_issue"));
+    assert!(tools.iter().all(|t| t["inputSchema"].is_object()));
+}
+
+#[test]
+fn failed_calls_carry_errors_in_meta_not_structured_content() {
+    let mock = MockLinear::start();
+    mock.set_fixture(
+        "GetIssue",
+        json!({ "errors": [{ "message": "Entity not found: Issue", "extensions": { "code": "NOT_FOUND" } }] }),
+    );
+    let mut mcp = McpSession::start(&mock);
+    let result = mcp.call_tool(
+        "get_issue",
+        json!({ "id": "00000000-0000-4000-8000-000000000001" }),
+    );
+    assert!(result.is_error());
+    assert!(result.0.get("structuredContent").is_none(), "{}", result.0);
+    assert_eq!(result.error()["code"], "not_found", "{}", result.0);
+    assert!(
+        result.text().starts_with("Error [not_found]"),
+        "{}",
+        result.text()
+    );
+    mcp.close();
+}
+
+#[test]
+fn rate_limited_requests_are_retried_after_the_reset() {
+    let mock = MockLinear::start();
+    mock.push_reply(
+        "GetIssue",
+        429,
+        &[("retry-after", "0")],
+        json!({ "errors": [{ "message": "Rate limit exceeded", "extensions": { "code": "RATELIMITED" } }] }),
+    );
+    let mut mcp = McpSession::start(&mock);
+    let result = mcp.call_tool("get_issue", json!({ "id": "ENG-1" }));
+    assert!(!result.is_error(), "{}", result.text());
+    assert_eq!(result.structured()["identifier"], "ENG-1");
+    assert_eq!(count(&mock, "GetIssue"), 2);
+    mcp.close();
+}
+
+#[test]
+fn failed_mutations_that_may_have_run_are_not_resent() {
+    let mock = MockLinear::start();
+    mock.push_reply(
+        "AddComment",
+        503,
+        &[],
+        json!({ "errors": [{ "message": "Service unavailable" }] }),
+    );
+    let mut mcp = McpSession::start(&mock);
+    let result = mcp.call_tool(
+        "add_comment",
+        json!({ "issueId": "00000000-0000-4000-8000-000000000001", "body": "Example" }),
+    );
+    assert!(result.is_error());
+    assert_eq!(result.error()["code"], "server", "{}", result.0);
+    assert_eq!(count(&mock, "AddComment"), 1);
+    mcp.close();
+}
+
+#[test]
+fn read_only_mode_never_sends_mutations() {
+    let mock = MockLinear::start();
+    let mut mcp = McpSession::start_with(&mock, &["--read-only"], &[]);
+    let tools = mcp.list_tools();
+    assert!(tools.iter().all(|t| t["name"] != "create_issue"));
+    let result = mcp.call_tool("create_issue", json!({ "title": "Example", "team": "ENG" }));
+    assert!(result.is_error());
+    assert!(mock.operations().iter().all(|op| op != "CreateIssue"));
+}
+
+#[test]
+fn record_mode_scrubs_secrets() {
+    let mock = MockLinear::start();
+    mock.set_fixture(
+        "Viewer",
+        json!({ "data": { "viewer": {
+            "id": "00000000-0000-4000-8000-000000000001",
+            "displayName": "Jane",
+            "email": "jane@acme.test",
+            "apiToken": "abc123",
+            "note": "lin_api_0123456789",
+        } } }),
+    );
+    let dir = std::env::temp_dir().join(format!("linear-mcp-record-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let mut mcp = McpSession::start_with(
+        &mock,
+        &[],
+        &[("LINEAR_MCP_RECORD_DIR", dir.to_str().unwrap())],
+    );
+    let result = mcp.call_tool("get_viewer", json!({}));
+    assert!(!result.is_error(), "{}", result.text());
+
+    let recorded = std::fs::read_to_string(dir.join("Viewer.json")).unwrap();
+    let _ = std::fs::remove_dir_all(&dir);
+    assert!(recorded.contains("Jane"));
+    for secret in ["jane@acme.test", "abc123", "lin_api_0123456789"] {
+        assert!(
+            !recorded.contains(secret),
+            "{secret} leaked into {recorded}"
+        );
+    }
+}
+
+#[test]
+fn profile_sets_endpoint_and_user_agent() {
+    let mock = MockLinear::start();
+    let dir = std::env::temp_dir().join(format!("linear-mcp-endpoint-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let config = dir.join("config.toml");
+    std::fs::write(
+        &config,
+        format!(
+            "default_profile = \"test\"\n\n[profiles.test]\napi_url = \"{}\"\nuser_agent = \"acme-bot/1.0\"\n",
+            mock.url()
+        ),
+    )
+    .unwrap();
+    let mut mcp = McpSession::start_with(
+        &mock,
+        &["--config", config.to_str().unwrap()],
+        &[("LINEAR_API_URL", "")],
+    );
+    let result = mcp.call_tool("get_viewer", json!({}));
+    let _ = std::fs::remove_dir_all(&dir);
+    assert!(!result.is_error(), "{}", result.text());
+    assert_eq!(mock.user_agent().as_deref(), Some("acme-bot/1.0"));
+}
+
+fn count(mock: &MockLinear, operation: &str) -> usize {
+    mock.operations()
+        .iter()
+        .filter(|op| *op == operation)
+        .count()
+}
+
+#[test]
+fn resolver_lookups_are_cached_until_a_mutation_changes_them() {
+    let mock = MockLinear::start();
+    let mut mcp = McpSession::start(&mock);
+    for _ in 0..2 {
+        let result = mcp.call_tool("list_cycles", json!({ "team": "eng" }));
+        assert!(!result.is_error(), "{}", result.text());
+    }
+    assert_eq!(count(&mock, "ResolveTeam"), 1);
+
+    let result = mcp.call_tool("update_team", json!({ "id": "ENG", "name": "Platform" }));
+    assert!(!result.is_error(), "{}", result.text());
+    let resolved = count(&mock, "ResolveTeam");
+    mcp.call_tool("list_cycles", json!({ "team": "ENG" }));
+    assert_eq!(count(&mock, "ResolveTeam"), resolved + 1);
+}
+
+#[test]
+fn http_sessions_use_their_own_keys_and_caches() {
+    let mock = MockLinear::start();
+    let server = HttpServer::start(&mock, &["--require-session-key"], &[]);
+    let mut alice = server.connect(&[("authorization", "Bearer lin_api_alice")]);
+    let mut bob = server.connect_with_meta(&[], json!({ "linearApiKey": "lin_api_bob" }));
+    let by = |key: &str| {
+        mock.authorizations("ResolveTeam")
+            .iter()
+            .filter(|a| a.as_deref() == Some(key))
+            .count()
+    };
+
+    for _ in 0..2 {
+        let result = alice.call_tool("list_cycles", json!({ "team": "eng" }));
+        assert!(!result.is_error(), "{}", result.text());
+    }
+    assert_eq!(by("lin_api_alice"), 1);
+    assert_eq!(by("lin_api_bob"), 0);
+
+    // Bob's session starts with an empty cache of its own.
+    let result = bob.call_tool("list_cycles", json!({ "team": "eng" }));
+    assert!(!result.is_error(), "{}", result.text());
+    assert_eq!(by("lin_api_bob"), 1);
+    let result = alice.call_tool("list_cycles", json!({ "team": "eng" }));
+    assert!(!result.is_error(), "{}", result.text());
+    assert_eq!(by("lin_api_alice"), 1);
+
+    let operations = mock.operations();
+    let mut keys: Vec<_> = operations
+        .iter()
+        .flat_map(|op| mock.authorizations(op))
+        .collect();
+    keys.sort();
+    keys.dedup();
+    assert_eq!(
+        keys,
+        [Some("lin_api_alice".into()), Some("lin_api_bob".into())],
+        "every request carries its session's key"
+    );
+}
+
+#[test]
+fn bulk_update_resolves_issues_and_labels_in_one_query_each() {
+    let mock = MockLinear::start();
+    let issues: Vec<Value> = (1..=20)
+        .map(|n| {
+            json!({
+                "id": format!("00000000-0000-4000-8000-{n:012}"),
+                "identifier": format!("ENG-{n}"),
+                "team": { "id": "00000000-0000-4000-8000-000000000001", "key": "ENG" },
+            })
+        })
+        .collect();
+    mock.set_fixture(
+        "ResolveIssues",
+        json!({ "data": { "issues": { "nodes": issues } } }),
+    );
+    let ids: Vec<String> = (1..=20).map(|n| format!("eng-{n}")).collect();
+
+    let mut mcp = McpSession::start(&mock);
+    let result = mcp.call_tool(
+        "bulk_update_issues",
+        json!({
+            "ids": ids.join(","),
+            "addLabels": "Example name",
+            "removeLabels": "example name",
+            "assignee": "user@example.com",
+        }),
+    );
+    assert!(!result.is_error(), "{}", result.text());
+    assert_eq!(count(&mock, "ResolveIssues"), 1);
+    assert_eq!(count(&mock, "ResolveLabels"), 1);
+    assert_eq!(count(&mock, "SearchIssues"), 0);
+    assert_eq!(count(&mock, "GetIssuesTeams"), 0);
+    let sent = mock.last_variables("BatchUpdateIssues").unwrap();
+    assert_eq!(sent["ids"].as_array().unwrap().len(), 20);
+    assert_eq!(mock.operations().len(), 4, "{:?}", mock.operations());
+}
+
+#[test]
+fn issue_references_accept_urls_branches_and_moved_identifiers() {
+    let mock = MockLinear::start();
+    let mut mcp = McpSession::start(&mock);
+    let result = mcp.call_tool(
+        "get_issue",
+        json!({ "id": "https://linear.app/acme/issue/eng-1/fix-login" }),
+    );
+    assert!(!result.is_error(), "{}", result.text());
+    let filter = &mock.last_variables("ResolveIssues").unwrap()["filter"]["or"][0];
+    assert_eq!(filter["team"]["key"]["eq"], "ENG");
+    assert_eq!(filter["number"]["eq"], 1);
+    let result = mcp.call_tool("get_issue", json!({ "id": "alice/eng-1-fix-login" }));
+    assert!(!result.is_error(), "{}", result.text());
+    assert_eq!(count(&mock, "ResolveIssues"), 1);
+
+    // Moved issues aren't found by key + number; `issue(id:)` follows them.
+    mock.set_fixture(
+        "ResolveIssues",
+        json!({ "data": { "issues": { "nodes": [] } } }),
+    );
+    let result = mcp.call_tool("get_issue", json!({ "id": "OPS-7" }));
+    assert!(!result.is_error(), "{}", result.text());
+    assert_eq!(mock.last_variables("ResolveIssue").unwrap()["id"], "OPS-7");
+    assert_eq!(count(&mock, "SearchIssues"), 0);
+
+    mock.set_fixture(
+        "ResolveIssue",
+        json!({ "errors": [{ "message": "Entity not found: Issue" }] }),
+    );
+    let result = mcp.call_tool("get_issue", json!({ "id": "EGN-9" }));
+    assert!(result.is_error());
+    assert!(
+        result.text().contains("Did you mean: ENG-9?"),
+        "{}",
+        result.text()
+    );
+    let result = mcp.call_tool("get_issue", json!({ "id": "ENG-999" }));
+    assert!(result.is_error());
+    assert!(
+        result
+            .text()
+            .contains("Did you mean: ENG-1 (Example title)?"),
+        "{}",
+        result.text()
+    );
+}
+
+#[test]
+fn team_names_scope_states_and_labels_by_the_team_key() {
+    let mock = MockLinear::start();
+    mock.set_fixture(
+        "ResolveTeam",
+        json!({ "data": { "teams": { "nodes": [] } } }),
+    );
+    mock.set_fixture(
+        "ListTeams",
+        json!({ "data": { "teams": { "nodes": [{
+            "id": "00000000-0000-4000-8000-000000000001",
+            "key": "ENG",
+            "name": "Engineering",
+        }] } } }),
+    );
+    let mut mcp = McpSession::start(&mock);
+    let result = mcp.call_tool(
+        "create_issue",
+        json!({
+            "title": "Example",
+            "team": "engineering",
+            "status": "Example name",
+            "labels": "Example name",
+        }),
+    );
+    assert!(!result.is_error(), "{}", result.text());
+    let states = mock.last_variables("ResolveStates").unwrap();
+    assert_eq!(states["filter"]["or"][0]["team"]["key"]["eq"], "ENG");
+    let labels = mock.last_variables("ResolveLabels").unwrap();
+    assert!(labels.to_string().contains("\"ENG\""), "{labels}");
+    assert!(!labels.to_string().contains("ENGINEERING"), "{labels}");
+    let input = &mock.last_variables("CreateIssue").unwrap()["input"];
+    assert_eq!(input["teamId"], "00000000-0000-4000-8000-000000000001");
+    assert_eq!(input["stateId"], "00000000-0000-4000-8000-000000000001");
+    assert_eq!(
+        input["labelIds"],
+        json!(["00000000-0000-4000-8000-000000000001"])
+    );
+    assert_eq!(count(&mock, "ListStates"), 0);
+    mcp.close();
+}
+
+#[test]
+fn names_resolve_fuzzily_with_candidates_when_ambiguous() {
+    let mock = MockLinear::start();
+    let user = |n: u32, name: &str, email: &str| {
+        json!({
+            "id": format!("00000000-0000-4000-8000-{n:012}"),
+            "name": name,
+            "displayName": name.split(' ').next().unwrap().to_lowercase(),
+            "email": email,
+        })
+    };
+    mock.set_fixture(
+        "UserCandidates",
+        json!({ "data": { "users": { "nodes": [
+            user(11, "Alice Smith", "alice@acme.com"),
+            user(12, "Alicia Jones", "alicia@acme.com"),
+            user(13, "Bob Stone", "bob@acme.com"),
+        ] } } }),
+    );
+    mock.set_fixture(
+        "ResolveStates",
+        json!({ "data": { "workflowStates": { "nodes": [] } } }),
+    );
+    let state = |n: u32, name: &str| {
+        json!({
+            "id": format!("00000000-0000-4000-8000-{n:012}"),
+            "name": name,
+            "type": "started",
+            "color": "#5e6ad2",
+            "team": { "id": "00000000-0000-4000-8000-000000000001", "key": "ENG", "name": "Engineering" },
+        })
+    };
+    mock.set_fixture(
+        "ListStates",
+        json!({ "data": { "workflowStates": { "nodes": [
+            state(21, "In Progress"),
+            state(22, "Done"),
+        ] } } }),
+    );
+    let mut mcp = McpSession::start(&mock);
+    let mut update = |assignee: &str, state: Option<&str>| {
+        let mut args = json!({ "id": "ENG-1", "assignee": assignee });
+        if let Some(state) = state {
+            args["status"] = state.into();
+        }
+        mcp.call_tool("update_issue", args)
+    };
+
+    let result = update("Bob", Some("in progres"));
+    assert!(!result.is_error(), "{}", result.text());
+    let input = &mock.last_variables("UpdateIssue").unwrap()["input"];
+    assert_eq!(input["assigneeId"], "00000000-0000-4000-8000-000000000013");
+    assert_eq!(input["stateId"], "00000000-0000-4000-8000-000000000021");
+
+    let result = update("me", None);
+    assert!(!result.is_error(), "{}", result.text());
+    let input = &mock.last_variables("UpdateIssue").unwrap()["input"];
+    assert_eq!(input["assigneeId"], "00000000-0000-4000-8000-000000000001");
+
+    let result = update("ali", None);
+    assert!(result.is_error());
+    let error = result.error();
+    assert_eq!(error["code"], "ambiguous");
+    assert_eq!(
+        error["candidates"],
+        json!([
+            "Alice Smith <alice@acme.com>",
+            "Alicia Jones <alicia@acme.com>"
+        ])
+    );
+
+    let result = update("Bbo Stone", None);
+    assert!(!result.is_error(), "{}", result.text());
+    let result = update("Carol", None);
+    assert!(result.is_error());
+    assert!(
+        result.text().contains("User 'Carol' not found"),
+        "{}",
+        result.text()
+    );
+    assert_eq!(count(&mock, "ResolveUsers"), 0);
+
+    // Deleting a team takes its key, exact name or UUID, never a near miss.
+    mock.set_fixture(
+        "ResolveTeam",
+        json!({ "data": { "teams": { "nodes": [] } } }),
+    );
+    let result = mcp.call_tool("get_team", json!({ "id": "Exampel name" }));
+    assert!(!result.is_error(), "{}", result.text());
+    let result = mcp.call_tool("delete_team", json!({ "id": "Exampel name" }));
+    assert!(result.is_error(), "{}", result.text());
+    assert_eq!(count(&mock, "DeleteTeam"), 0);
+    let result = mcp.call_tool("delete_team", json!({ "id": "example name" }));
+    assert!(!result.is_error(), "{}", result.text());
+    let sent = mock.last_variables("DeleteTeam").unwrap();
+    assert_eq!(sent["id"], "00000000-0000-4000-8000-000000000001");
+}
+
+#[test]
+fn cycles_views_and_documents_resolve_by_name() {
+    let mock = MockLinear::start();
+    let cycle = |n: u32, name: Option<&str>, flag: &str| {
+        let mut cycle = json!({
+            "id": format!("00000000-0000-4000-8000-{n:012}"),
+            "number": n,
+            "name": name,
+            "isActive": false,
+            "isNext": false,
+            "isPrevious": false,
+            "startsAt": "2026-01-12T00:00:00.000Z",
+            "endsAt": "2026-01-26T00:00:00.000Z",
+        });
+        if !flag.is_empty() {
+            cycle[flag] = true.into();
+        }
+        cycle
+    };
+    mock.set_fixture(
+        "ResolveCycles",
+        json!({ "data": { "team": { "cycles": { "nodes": [
+            cycle(11, Some("Sprint Birch"), "isPrevious"),
+            cycle(12, Some("Sprint Aspen"), "isActive"),
+            cycle(13, None, "isNext"),
+        ] } } } }),
+    );
+    let mut mcp = McpSession::start(&mock);
+    let mut cycle_id = |id: &str| {
+        let result = mcp.call_tool("get_cycle", json!({ "id": id, "team": "ENG" }));
+        assert!(!result.is_error(), "{id}: {}", result.text());
+        mock.last_variables("GetCycle").unwrap()["id"].clone()
+    };
+    assert_eq!(cycle_id("current"), "00000000-0000-4000-8000-000000000012");
+    assert_eq!(cycle_id("#13"), "00000000-0000-4000-8000-000000000013");
+    assert_eq!(cycle_id("previous"), "00000000-0000-4000-8000-000000000011");
+    assert_eq!(
+        cycle_id("sprint birch"),
+        "00000000-0000-4000-8000-000000000011"
+    );
+    let result = mcp.call_tool("get_cycle", json!({ "id": "99", "team": "ENG" }));
+    assert!(result.is_error());
+    assert!(result.text().contains("no cycle '99'"), "{}", result.text());
+
+    let result = mcp.call_tool(
+        "add_issue_to_cycle",
+        json!({ "issueId": "ENG-1", "cycleId": "next" }),
+    );
+    assert!(!result.is_error(), "{}", result.text());
+    let input = &mock.last_variables("UpdateIssue").unwrap()["input"];
+    assert_eq!(input["cycleId"], "00000000-0000-4000-8000-000000000013");
+
+    let result = mcp.call_tool(
+        "update_document",
+        json!({ "id": "example title", "title": "Renamed" }),
+    );
+    assert!(!result.is_error(), "{}", result.text());
+    let sent = mock.last_variables("UpdateDocument").unwrap();
+    assert_eq!(sent["id"], "00000000-0000-4000-8000-000000000001");
+    let result = mcp.call_tool("get_view_issues", json!({ "id": "Example" }));
+    assert!(!result.is_error(), "{}", result.text());
+    let sent = mock.last_variables("GetViewIssues").unwrap();
+    assert_eq!(sent["id"], "00000000-0000-4000-8000-000000000001");
+}
+
+#[test]
+fn deletes_and_archives_require_exact_names() {
+    let mock = MockLinear::start();
+    mock.set_fixture(
+        "ResolveCycles",
+        json!({ "data": { "team": { "cycles": { "nodes": [{
+            "id": "00000000-0000-4000-8000-000000000011",
+            "number": 11,
+            "name": "Sprint Birch",
+            "isActive": false,
+            "isNext": false,
+            "isPrevious": false,
+            "startsAt": "2026-01-12T00:00:00.000Z",
+            "endsAt": "2026-01-26T00:00:00.000Z",
+        }] } } } }),
+    );
+    let mut mcp = McpSession::start(&mock);
+
+    for (tool, partial) in [
+        ("delete_view", "Exampel name"),
+        ("delete_document", "Example"),
+        ("delete_project_milestone", "example nam"),
+    ] {
+        let result = mcp.call_tool(tool, json!({ "id": partial }));
+        assert!(result.is_error(), "{tool} {partial}: {}", result.text());
+        assert!(result.text().contains("exact name"), "{}", result.text());
+    }
+    let sent = mock.last_variables("ResolveViews").unwrap();
+    assert_eq!(sent["filter"]["name"]["eqIgnoreCase"], "Exampel name");
+    assert!(
+        mock.operations().iter().all(|op| !op.starts_with("Delete")),
+        "{:?}",
+        mock.operations()
+    );
+
+    let result = mcp.call_tool("delete_view", json!({ "id": "example NAME" }));
+    assert!(!result.is_error(), "{}", result.text());
+    let sent = mock.last_variables("DeleteView").unwrap();
+    assert_eq!(sent["id"], "00000000-0000-4000-8000-000000000001");
+
+    // A fuzzy lookup of the same name is cached, but not reused to archive.
+    let result = mcp.call_tool("get_cycle", json!({ "id": "sprint bir", "team": "ENG" }));
+    assert!(!result.is_error(), "{}", result.text());
+    let result = mcp.call_tool(
+        "archive_cycle",
+        json!({ "id": "sprint bir", "team": "ENG" }),
+    );
+    assert!(result.is_error(), "{}", result.text());
+    assert_eq!(count(&mock, "ArchiveCycle"), 0);
+    let result = mcp.call_tool(
+        "archive_cycle",
+        json!({ "id": "Sprint Birch", "team": "ENG" }),
+    );
+    assert!(!result.is_error(), "{}", result.text());
+    let sent = mock.last_variables("ArchiveCycle").unwrap();
+    assert_eq!(sent["id"], "00000000-0000-4000-8000-000000000011");
+}
+
+#[test]
+fn dates_are_normalized_in_the_profile_timezone() {
+    let mock = MockLinear::start();
+    let mut mcp =
+        McpSession::start_with(&mock, &[], &[("LINEAR_MCP_TIMEZONE", "America/New_York")]);
+
+    let result = mcp.call_tool(
+        "create_issue",
+        json!({ "title": "Example", "team": "ENG", "dueDate": "2026-02-30" }),
+    );
+    assert!(result.is_error());
+    assert!(result.text().contains("dueDate"), "{}", result.text());
+    assert!(mock.operations().is_empty(), "{:?}", mock.operations());
+    let result = mcp.call_tool("list_issues", json!({ "createdAfter": "next blursday" }));
+    assert!(
+        result.text().contains("Unrecognized date"),
+        "{}",
+        result.text()
+    );
+    assert!(mock.operations().is_empty(), "{:?}", mock.operations());
+
+    // The fixture's active cycle ends at 2026-01-26T00:00Z, still the 25th in New York.
+    let result = mcp.call_tool(
+        "create_issue",
+        json!({ "title": "Example", "team": "ENG", "dueDate": "end of cycle" }),
+    );
+    assert!(!result.is_error(), "{}", result.text());
+    let input = &mock.last_variables("CreateIssue").unwrap()["input"];
+    assert_eq!(input["dueDate"], "2026-01-25");
+
+    let result = mcp.call_tool(
+        "create_project",
+        json!({ "name": "Example", "startDate": "Q3 2026", "targetDate": "end of 2026-Q3" }),
+    );
+    assert!(!result.is_error(), "{}", result.text());
+    let input = &mock.last_variables("CreateProject").unwrap()["input"];
+    assert_eq!(input["startDate"], "2026-07-01");
+    assert_eq!(input["targetDate"], "2026-09-30");
+
+    let result = mcp.call_tool(
+        "list_issues",
+        json!({ "createdAfter": "2026-03-01", "dueBefore": "Q1 2027" }),
+    );
+    assert!(!result.is_error(), "{}", result.text());
+    let filter = mock.last_variables("ListIssues").unwrap()["filter"].clone();
+    let filters = filter["and"].as_array().unwrap();
+    assert_eq!(filters[0]["dueDate"]["lt"], "2027-01-01");
+    assert_eq!(filters[1]["createdAt"]["gt"], "2026-03-01T05:00:00.000Z");
+}
+
+#[test]
+fn filter_expressions_compile_to_issue_filters() {
+    let mock = MockLinear::start();
+    let mut mcp = McpSession::start(&mock);
+
+    let result = mcp.call_tool(
+        "list_issues",
+        json!({ "filter": "priority >= high and (label in (Bug, P0) or parent.state = 'In Review') and not assignee = me" }),
+    );
+    assert!(!result.is_error(), "{}", result.text());
+    let filter = mock.last_variables("ListIssues").unwrap()["filter"].clone();
+    assert_eq!(filter["and"][0], json!({ "priority": { "in": [1, 2] } }));
+    let any = &filter["and"][1]["or"];
+    assert_eq!(
+        any[0]["or"][1],
+        json!({ "labels": { "some": { "name": { "eqIgnoreCase": "P0" } } } })
+    );
+    assert_eq!(
+        any[1],
+        json!({ "parent": { "state": { "name": { "eqIgnoreCase": "In Review" } } } })
+    );
+    assert_eq!(
+        filter["and"][2],
+        json!({ "or": [
+            { "assignee": { "null": true } },
+            { "assignee": { "isMe": { "eq": false } } },
+        ] })
+    );
+
+    let result = mcp.call_tool(
+        "my_issues",
+        json!({ "filter": "blocked and not blocking and label != Bug and estimate is none and parent = eng-7" }),
+    );
+    assert!(!result.is_error(), "{}", result.text());
+    let filter = mock.last_variables("MyIssues").unwrap()["filter"].clone();
+    let user = &filter["and"][2]["and"];
+    assert_eq!(user[0], json!({ "hasBlockedByRelations": { "eq": true } }));
+    assert_eq!(user[1], json!({ "hasBlockingRelations": { "eq": false } }));
+    assert_eq!(
+        user[2],
+        json!({ "labels": { "every": { "name": { "neqIgnoreCase": "Bug" } } } })
+    );
+    assert_eq!(user[3], json!({ "estimate": { "null": true } }));
+    assert_eq!(
+        user[4],
+        json!({ "parent": { "team": { "key": { "eqIgnoreCase": "ENG" } }, "number": { "eq": 7 } } })
+    );
+
+    let result = mcp.call_tool(
+        "search_issues",
+        json!({ "query": "login", "filter": "due = 2026-03 or completed > 2026-03-01" }),
+    );
+    assert!(!result.is_error(), "{}", result.text());
+    let filter = mock.last_variables("SearchIssues").unwrap()["filter"].clone();
+    assert_eq!(
+        filter["or"][0],
+        json!({ "dueDate": { "gte": "2026-03-01", "lte": "2026-03-31" } })
+    );
+    assert!(
+        filter["or"][1]["completedAt"]["gte"].is_string(),
+        "{filter}"
+    );
+
+    mock.clear_requests();
+    for (query, error) in [
+        (
+            "priority >= high and (label = Bug",
+            "'(' is never closed (column 22)",
+        ),
+        ("prority = high", "did you mean 'priority'?"),
+        ("title < x", "'<' can't be used with 'title'"),
+        ("estimate = lots", "expected a number, found 'lots'"),
+        ("due < 2026-02-30", "not a valid date"),
+        ("created = none", "always set"),
+    ] {
+        let result = mcp.call_tool("list_issues", json!({ "filter": query }));
+        assert!(result.is_error(), "{query}");
+        assert!(result.text().contains(error), "{query}: {}", result.text());
+    }
+    assert!(mock.operations().is_empty(), "{:?}", mock.operations());
+}
+
+#[test]
+fn list_issues_filters_take_none_any_and_negation() {
+    let mock = MockLinear::start();
+    let mut mcp = McpSession::start(&mock);
+
+    let result = mcp.call_tool(
+        "list_issues",
+        json!({
+            "assignee": "none",
+            "label": "not Bug",
+            "cycle": "current",
+            "milestone": "Beta",
+            "parent": "https://linear.app/acme/issue/ENG-7/login",
+            "subscriber": "me",
+            "customer": "Acme",
+            "sla": "high risk",
+            "triage": false,
+            "hasEstimate": false,
+            "hasAttachments": true,
+        }),
+    );
+    assert!(!result.is_error(), "{}", result.text());
+    let filter = mock.last_variables("ListIssues").unwrap()["filter"].clone();
+    assert_eq!(
+        filter["and"],
+        json!([
+            { "assignee": { "null": true } },
+            { "labels": { "every": { "name": { "neqIgnoreCase": "Bug" } } } },
+            { "cycle": { "isActive": { "eq": true } } },
+            { "projectMilestone": { "name": { "eqIgnoreCase": "Beta" } } },
+            { "parent": { "team": { "key": { "eqIgnoreCase": "ENG" } }, "number": { "eq": 7 } } },
+            { "subscribers": { "some": { "isMe": { "eq": true } } } },
+            { "needs": { "some": { "customer": { "name": { "eqIgnoreCase": "Acme" } } } } },
+            { "slaStatus": { "eq": "HighRisk" } },
+            { "state": { "type": { "neq": "triage" } } },
+            { "estimate": { "null": true } },
+            { "attachments": { "length": { "gt": 0 } } },
+        ])
+    );
+
+    let result = mcp.call_tool(
+        "list_issues",
+        json!({ "project": "not Apollo", "status": "not Done", "parent": "none", "label": "any" }),
+    );
+    assert!(!result.is_error(), "{}", result.text());
+    let filter = mock.last_variables("ListIssues").unwrap()["filter"].clone();
+    assert_eq!(
+        filter["and"],
+        json!([
+            { "state": { "name": { "neqIgnoreCase": "Done" } } },
+            { "or": [
+                { "project": { "null": true } },
+                { "project": { "name": { "notContainsIgnoreCase": "Apollo" } } },
+            ] },
+            { "labels": { "length": { "gt": 0 } } },
+            { "parent": { "null": true } },
+        ])
+    );
+
+    mock.clear_requests();
+    for (args, error) in [
+        (json!({ "sla": "late" }), "expected an SLA status"),
+        (
+            json!({ "parent": "not an issue" }),
+            "expected an issue identifier",
+        ),
+        (json!({ "status": "none" }), "'status' is always set"),
+    ] {
+        let result = mcp.call_tool("list_issues", args.clone());
+        assert!(result.is_error(), "{args}");
+        assert!(result.text().contains(error), "{args}: {}", result.text());
+    }
+    assert!(mock.operations().is_empty(), "{:?}", mock.operations());
+}
+
+#[test]
+fn resources_render_linear_entities() {
+    let mock = MockLinear::start();
+    let config = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/config.toml");
+    let mut mcp = McpSession::start_with(&mock, &["--config", config], &[]);
+
+    let templates = mcp.request("resources/templates/list", json!({}));
+    let uris: Vec<&str> = templates["resourceTemplates"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|t| t["uriTemplate"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        uris,
+        [
+            "linear://issue/{identifier}",
+            "linear://project/{name}",
+            "linear://document/{id}",
+            "linear://cycle/{team}/current",
+        ]
+    );
+    let listed = mcp.request("resources/list", json!({}));
+    assert_eq!(listed["resources"][0]["uri"], "linear://cycle/ENG/current");
+
+    for (uri, operation) in [
+        ("linear://issue/ENG-1", "GetIssue"),
+        ("linear://project/Example%20name", "GetProject"),
+        ("linear://document/Example%20title", "GetDocument"),
+        ("linear://cycle/ENG/current", "GetCycle"),
+    ] {
+        let result = mcp.request("resources/read", json!({ "uri": uri }));
+        let contents = &result["contents"][0];
+        assert_eq!(contents["uri"], uri);
+        assert_eq!(contents["mimeType"], "text/markdown");
+        assert!(!contents["text"].as_str().unwrap().is_empty(), "{uri}");
+        assert!(mock.operations().iter().any(|op| op == operation), "{uri}");
+    }
+
+    let error = mcp.exchange("resources/read", json!({ "uri": "linear://team/ENG" }));
+    assert_eq!(error["error"]["code"], -32002, "{error}");
+}
+
+#[test]
+fn subscribed_resources_notify_when_they_change() {
+    let mock = MockLinear::start();
+    let dir = std::env::temp_dir().join(format!("linear-mcp-poll-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let config = dir.join("config.toml");
+    std::fs::write(
+        &config,
+        "default_profile = \"test\"\n\n[profiles.test]\npoll_interval_secs = 1\n",
+    )
+    .unwrap();
+    let mut mcp = McpSession::start_with(&mock, &["--config", config.to_str().unwrap()], &[]);
+    let _ = std::fs::remove_dir_all(&dir);
+
+    let error = mcp.exchange("resources/subscribe", json!({ "uri": "linear://team/ENG" }));
+    assert_eq!(error["error"]["code"], -32002, "{error}");
+    mcp.request(
+        "resources/subscribe",
+        json!({ "uri": "linear://issue/ENG-1" }),
+    );
+    mcp.request(
+        "resources/subscribe",
+        json!({ "uri": "linear://document/Example%20title" }),
+    );
+    assert_eq!(count(&mock, "IssueVersion"), 1);
+
+    // A teammate comments on the issue.
+    mock.set_fixture(
+        "IssueVersion",
+        json!({ "data": { "issue": {
+            "id": "00000000-0000-4000-8000-000000000001",
+            "updatedAt": "2026-01-15T12:00:00.000Z",
+            "latest": { "nodes": [{ "updatedAt": "2026-01-16T09:30:00.000Z" }] },
+        } } }),
+    );
+    let params = mcp.notification("notifications/resources/updated");
+    assert_eq!(params["uri"], "linear://issue/ENG-1");
+
+    mcp.request(
+        "resources/unsubscribe",
+        json!({ "uri": "linear://issue/ENG-1" }),
+    );
+    mcp.close();
+}
+
+#[test]
+fn prompts_embed_live_workspace_data() {
+    let mock = MockLinear::start();
+    let config = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/config.toml");
+    let mut mcp = McpSession::start_with(&mock, &["--config", config], &[]);
+
+    let listed = mcp.request("prompts/list", json!({}));
+    let names: Vec<&str> = listed["prompts"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|p| p["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        names,
+        [
+            "triage_inbox",
+            "project_update",
+            "sprint_planning",
+            "break_down_issue",
+            "release_notes",
+        ]
+    );
+
+    for (name, args, operations) in [
+        (
+            "triage_inbox",
+            json!({}),
+            &["ListTriageIssues", "ListStates", "ListLabels"][..],
+        ),
+        (
+            "project_update",
+            json!({ "project": "Example name" }),
+            &[
+                "GetProject",
+                "ListProjectMilestones",
+                "ListProjectUpdates",
+                "ListIssues",
+            ][..],
+        ),
+        (
+            "sprint_planning",
+            json!({ "team": "ENG", "cycle": "Example name" }),
+            &["GetCycle", "ListIssues"][..],
+        ),
+        (
+            "break_down_issue",
+            json!({ "issue": "ENG-1" }),
+            &["GetIssue"][..],
+        ),
+        ("release_notes", json!({}), &["ListIssues"][..]),
+    ] {
+        mock.clear_requests();
+        let result = mcp.request("prompts/get", json!({ "name": name, "arguments": args }));
+        let text = result["messages"][0]["content"]["text"].as_str().unwrap();
+        assert!(text.contains("\n\n## "), "{name}: {text}");
+        for operation in operations {
+            assert!(
+                mock.operations().iter().any(|op| op == operation),
+                "{name} did not run {operation}: {:?}",
+                mock.operations()
+            );
+        }
+    }
+    let filter = mock.last_variables("ListIssues").unwrap()["filter"].clone();
+    assert_eq!(
+        filter["and"][1],
+        json!({ "cycle": { "isActive": { "eq": true } } })
+    );
+    assert_eq!(
+        filter["and"][2],
+        json!({ "state": { "type": { "eq": "completed" } } })
+    );
+
+    for (name, args) in [
+        ("break_down_issue", json!({})),
+        ("release_notes", json!({ "since": "someday" })),
+        ("standup", json!({})),
+    ] {
+        let error = mcp.exchange("prompts/get", json!({ "name": name, "arguments": args }));
+        assert_eq!(error["error"]["code"], -32602, "{name}: {error}");
+    }
+}
+
+#[test]
+fn arguments_complete_from_workspace_names() {
+    let mock = MockLinear::start();
+    let state = |n: u32, name: &str| {
+        json!({
+            "id": format!("00000000-0000-4000-8000-{n:012}"),
+            "name": name,
+            "type": "started",
+            "color": "#5e6ad2",
+            "team": { "id": "00000000-0000-4000-8000-000000000001", "key": "ENG", "name": "Engineering" },
+        })
+    };
+    mock.set_fixture(
+        "ListStates",
+        json!({ "data": { "workflowStates": { "nodes": [
+            state(11, "Todo"),
+            state(12, "In Progress"),
+            state(13, "In Review"),
+            state(14, "Done"),
+        ] } } }),
+    );
+    let label = |n: u32, name: &str, team: Option<&str>| {
+        json!({
+            "id": format!("00000000-0000-4000-8000-{n:012}"),
+            "name": name,
+            "team": team.map(|key| json!({ "id": "00000000-0000-4000-8000-000000000001", "key": key })),
+        })
+    };
+    mock.set_fixture(
+        "ResolveLabels",
+        json!({ "data": { "issueLabels": { "nodes": [
+            label(21, "Bug", None),
+            label(22, "Backend", Some("ENG")),
+            label(23, "Billing", Some("OPS")),
+        ] } } }),
+    );
+    let config = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/config.toml");
+    let mut mcp = McpSession::start_with(&mock, &["--config", config], &[]);
+
+    let mut complete = |reference: Value, argument: &str, value: &str, context: Value| {
+        let result = mcp.request(
+            "completion/complete",
+            json!({
+                "ref": reference,
+                "argument": { "name": argument, "value": value },
+                "context": { "arguments": context },
+            }),
+        );
+        result["completion"]["values"].clone()
+    };
+    let prompt = |name: &str| json!({ "type": "ref/prompt", "name": name });
+    let project = json!({ "type": "ref/resource", "uri": "linear://project/{name}" });
+
+    assert_eq!(
+        complete(prompt("sprint_planning"), "team", "e", json!({})),
+        json!(["ENG"])
+    );
+    assert_eq!(
+        complete(
+            prompt("create_issue"),
+            "status",
+            "in",
+            json!({ "team": "eng" })
+        ),
+        json!(["In Progress", "In Review"])
+    );
+    assert_eq!(
+        mock.last_variables("ListStates").unwrap()["filter"],
+        json!({ "team": { "key": { "eqIgnoreCase": "ENG" } } })
+    );
+    assert_eq!(
+        complete(prompt("update_issue"), "state", "do", json!({})),
+        json!(["Done", "Todo"])
+    );
+    assert_eq!(count(&mock, "ListStates"), 1);
+    // Teams that don't exist get nothing, and no list of their own.
+    for team in ["XYZ", "ABC"] {
+        assert_eq!(
+            complete(
+                prompt("create_issue"),
+                "status",
+                "",
+                json!({ "team": team })
+            ),
+            json!([])
+        );
+    }
+    assert_eq!(count(&mock, "ListStates"), 1);
+    assert_eq!(
+        complete(prompt("create_issue"), "labels", "Bug, b", json!({})),
+        json!(["Bug, Backend"])
+    );
+    assert_eq!(
+        complete(project, "name", "ex", json!({})),
+        json!(["Example name"])
+    );
+    assert_eq!(
+        complete(prompt("create_issue"), "assignee", "", json!({})),
+        json!(["me", "user@example.com"])
+    );
+    assert_eq!(
+        complete(prompt("list_issues"), "cycle", "", json!({})),
+        json!(["current", "next", "previous", "1", "Example name"])
+    );
+    assert_eq!(
+        complete(
+            prompt("create_issue_from_template"),
+            "templateId",
+            "exa",
+            json!({})
+        ),
+        json!(["Example name"])
+    );
+    assert_eq!(
+        complete(prompt("create_issue"), "title", "", json!({})),
+        json!([])
+    );
+    assert_eq!(
+        complete(prompt("create_webhook"), "label", "b", json!({})),
+        json!([])
+    );
+
+    let error = mcp.exchange(
+        "completion/complete",
+        json!({
+            "ref": { "type": "ref/prompt", "name": "standup" },
+            "argument": { "name": "team", "value": "" },
+        }),
+    );
+    assert_eq!(error["error"]["code"], -32602, "{error}");
+
+    let result = mcp.call_tool(
+        "create_issue_from_template",
+        json!({ "templateId": "Example name" }),
+    );
+    assert!(!result.is_error(), "{}", result.text());
+    assert_eq!(
+        mock.last_variables("CreateIssue").unwrap()["input"]["templateId"],
+        "00000000-0000-4000-8000-000000000001"
+    );
+    let creates = count(&mock, "CreateIssue");
+    let result = mcp.call_tool(
+        "create_issue_from_template",
+        json!({ "templateId": "Exampel name" }),
+    );
+    assert!(result.is_error());
+    assert_eq!(result.error()["code"], "not_found");
+    assert_eq!(count(&mock, "CreateIssue"), creates);
+    mcp.close();
+}
+
+#[test]
+fn persistent_cache_survives_restart() {
+    let mock = MockLinear::start();
+    let dir = std::env::temp_dir().join(format!("linear-mcp-cache-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let config = dir.join("config.toml");
+    std::fs::write(
+        &config,
+        format!(
+            "[profiles.default.cache]\npath = \"{}\"\n",
+            dir.join("cache.json").display()
+        ),
+    )
+    .unwrap();
+    let args = ["--config", config.to_str().unwrap(), "--profile", "default"];
+
+    let mut mcp = McpSession::start_with(&mock, &args, &[]);
+    mcp.call_tool("list_cycles", json!({ "team": "ENG" }));
+    mcp.close();
+    assert_eq!(count(&mock, "ResolveTeam"), 1);
+
+    let mut mcp = McpSession::start_with(&mock, &args, &[]);
+    let result = mcp.call_tool("list_cycles", json!({ "team": "ENG" }));
+    mcp.close();
+    let _ = std::fs::remove_dir_all(&dir);
+    assert!(!result.is_error(), "{}", result.text());
+    assert_eq!(count(&mock, "ResolveTeam"), 1);
+}